@@ -0,0 +1,29 @@
+use std::env;
+use wfdb::Record;
+use wfdb::testing::fetch_physionet_record;
+
+fn main() -> wfdb::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    let record_path = args.get(1).map_or("mitdb/100", String::as_str);
+    let cache_dir = args.get(2).map_or("target/wfdb-cache", String::as_str);
+
+    println!("Fetching {record_path} into {cache_dir}...");
+    let path = fetch_physionet_record(record_path, cache_dir)?;
+    let record = Record::open(&path)?;
+
+    println!("Record: {}", record.metadata().name());
+    println!("Local path: {}", path.display());
+    println!(
+        "Sampling frequency: {} Hz",
+        record.metadata().sampling_frequency()
+    );
+    println!("Signals: {}", record.signal_count());
+
+    if record.signal_count() > 0 {
+        let mut reader = record.signal_reader(0)?;
+        let samples = reader.read_physical(10)?;
+        println!("First 10 physical samples of signal 0: {samples:?}");
+    }
+
+    Ok(())
+}
@@ -182,10 +182,10 @@ fn demonstrate_multi_segment_seeking(record: &Record) -> wfdb::Result<()> {
         reader.current_segment()
     );
 
-    println!("\nSeeking to sample 100...");
-    match reader.seek_to_sample(100) {
+    println!("\nSeeking to frame 100...");
+    match reader.seek_to_frame(100) {
         Ok(pos) => {
-            println!("  Seek to sample: {pos}");
+            println!("  Seek to frame: {pos}");
             println!("  Now at segment: {}", reader.current_segment());
 
             let frames = reader.read_frames(3)?;
@@ -200,7 +200,7 @@ fn demonstrate_multi_segment_seeking(record: &Record) -> wfdb::Result<()> {
     }
 
     println!("\nReading sequentially across segments...");
-    reader.seek_to_sample(0)?;
+    reader.seek_to_frame(0)?;
     let mut frame_count = 0;
     while let Some(_frame) = reader.read_frame()? {
         frame_count += 1;
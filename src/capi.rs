@@ -0,0 +1,523 @@
+//! C ABI for opening records and reading frames from C, Python (`ctypes`,
+//! `cffi`) or any language with a C foreign function interface.
+//!
+//! Available with the `capi` feature. The functions and `#[repr(C)]`
+//! structs below are a stable interface: they are only extended, and
+//! [`WFDB_CAPI_VERSION`] is bumped when they are. Build a C library from
+//! this crate with
+//! `cargo rustc --release --lib --features capi --crate-type cdylib` (or
+//! `staticlib`), and declare the functions in C as:
+//!
+//! ```c
+//! typedef struct WfdbRecord WfdbRecord;
+//! typedef struct WfdbReader WfdbReader;
+//!
+//! typedef struct {
+//!     uint32_t num_signals;
+//!     uint32_t num_segments;   /* 0 for single-segment records */
+//!     double sampling_frequency;
+//!     int64_t num_samples;     /* -1 when unknown */
+//! } WfdbRecordInfo;
+//!
+//! typedef struct {
+//!     uint32_t format;
+//!     uint32_t samples_per_frame;
+//!     double adc_gain;
+//!     int32_t baseline;
+//!     int32_t adc_zero;
+//!     uint32_t adc_resolution;
+//!     int32_t initial_value;
+//! } WfdbSignalInfo;
+//!
+//! uint32_t wfdb_capi_version(void);
+//! const char *wfdb_last_error(void);
+//! WfdbRecord *wfdb_record_open(const char *path);
+//! void wfdb_record_free(WfdbRecord *record);
+//! int wfdb_record_info(const WfdbRecord *record, WfdbRecordInfo *info);
+//! int wfdb_signal_info(const WfdbRecord *record, size_t index, WfdbSignalInfo *info);
+//! const char *wfdb_signal_description(const WfdbRecord *record, size_t index);
+//! const char *wfdb_signal_units(const WfdbRecord *record, size_t index);
+//! WfdbReader *wfdb_reader_open(const WfdbRecord *record);
+//! void wfdb_reader_free(WfdbReader *reader);
+//! int wfdb_reader_read_frame(WfdbReader *reader, int32_t *samples, size_t len);
+//! int wfdb_reader_read_physical(WfdbReader *reader, double *values, size_t len);
+//! int wfdb_reader_seek(WfdbReader *reader, uint64_t frame);
+//! ```
+//!
+//! Functions returning a pointer return `NULL` on failure, and functions
+//! returning an `int` return `-1`; [`wfdb_last_error`] then describes the
+//! error of the calling thread; a panic inside the library is reported the
+//! same way instead of unwinding into the caller. Records and readers are
+//! freed with their `_free` function, and a reader may outlive its record.
+//! Annotations are not exposed yet.
+
+#![allow(unsafe_code)]
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::record::{PhysicalReader, segment_signals};
+use crate::{Error, Record, Result, Sample, SignalInfo};
+
+/// Version of the C ABI, bumped when functions or struct fields are added.
+pub const WFDB_CAPI_VERSION: u32 = 1;
+
+thread_local! {
+    /// Message of the last error of the thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Record opened through the C ABI.
+pub struct WfdbRecord {
+    /// The record.
+    record: Record,
+    /// Signals of the frames (of the layout or first segment of
+    /// multi-segment records).
+    signals: Vec<SignalInfo>,
+    /// Description and units of each signal, as C strings.
+    strings: Vec<(Option<CString>, CString)>,
+}
+
+/// Frame reader opened through the C ABI.
+pub struct WfdbReader(PhysicalReader);
+
+/// General information on a record.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WfdbRecordInfo {
+    /// Number of signals of each frame.
+    pub num_signals: u32,
+    /// Number of segments (0 for single-segment records).
+    pub num_segments: u32,
+    /// Sampling frequency (Hz) of the frames.
+    pub sampling_frequency: f64,
+    /// Number of frames, or -1 when the header does not give it.
+    pub num_samples: i64,
+}
+
+/// Specification of a signal.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WfdbSignalInfo {
+    /// Storage format (e.g. 212).
+    pub format: u32,
+    /// Samples of the signal per frame.
+    pub samples_per_frame: u32,
+    /// ADC gain (ADC units per physical unit).
+    pub adc_gain: f64,
+    /// Baseline (ADC value of 0 physical units).
+    pub baseline: i32,
+    /// ADC zero.
+    pub adc_zero: i32,
+    /// ADC resolution in bits.
+    pub adc_resolution: u32,
+    /// Value of the first sample.
+    pub initial_value: i32,
+}
+
+impl From<&SignalInfo> for WfdbSignalInfo {
+    fn from(signal: &SignalInfo) -> Self {
+        Self {
+            format: u32::from(u16::from(signal.format())),
+            samples_per_frame: signal.samples_per_frame(),
+            adc_gain: signal.adc_gain(),
+            baseline: signal.baseline(),
+            adc_zero: signal.adc_zero(),
+            adc_resolution: u32::from(signal.adc_resolution()),
+            initial_value: signal.initial_value(),
+        }
+    }
+}
+
+impl WfdbRecord {
+    /// Open a record, with the C strings of its signals.
+    fn open(path: &str) -> Result<Self> {
+        let record = Record::open(path)?;
+        let signals = if record.is_multi_segment() {
+            segment_signals(&record)?
+        } else {
+            record.signal_info().unwrap_or_default().to_vec()
+        };
+        let strings = signals
+            .iter()
+            .map(|signal| {
+                Ok((
+                    signal.description().map(c_string).transpose()?,
+                    c_string(signal.units())?,
+                ))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            record,
+            signals,
+            strings,
+        })
+    }
+}
+
+// [Error handling]
+
+/// Convert a string to a C string.
+fn c_string(text: &str) -> Result<CString> {
+    CString::new(text).map_err(|_| Error::InvalidData(format!("String '{text}' holds a NUL byte")))
+}
+
+/// Record the error of the thread.
+fn set_last_error(error: &Error) {
+    let message = CString::new(error.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Record the error of a result, returning its value if any.
+fn check<T>(result: Result<T>) -> Option<T> {
+    result.map_err(|e| set_last_error(&e)).ok()
+}
+
+/// Run the body of an exported function, recording a panic as the error of
+/// the thread and returning `failed` instead of unwinding into the caller.
+fn guard<T>(failed: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        set_last_error(&Error::InvalidData(format!("Internal error: {message}")));
+        failed
+    })
+}
+
+/// Record an error for a `NULL` argument.
+fn null_argument(name: &str) -> c_int {
+    set_last_error(&Error::InvalidData(format!("Argument '{name}' is NULL")));
+    -1
+}
+
+/// Get the version of the C ABI ([`WFDB_CAPI_VERSION`]).
+#[unsafe(no_mangle)]
+pub const extern "C" fn wfdb_capi_version() -> u32 {
+    WFDB_CAPI_VERSION
+}
+
+/// Get the message of the last error of the calling thread, or `NULL` if
+/// no function failed yet.
+///
+/// The string is valid until the next failing call on the thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn wfdb_last_error() -> *const c_char {
+    guard(ptr::null(), || {
+        LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+    })
+}
+
+// [Records]
+
+/// Open the record at `path` (with or without `.hea`), returning `NULL` on
+/// error.
+///
+/// # Safety
+///
+/// `path` must be `NULL` or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_record_open(path: *const c_char) -> *mut WfdbRecord {
+    guard(ptr::null_mut(), || {
+        if path.is_null() {
+            null_argument("path");
+            return ptr::null_mut();
+        }
+        // SAFETY: `path` is a valid NUL-terminated string, by contract
+        let path = unsafe { CStr::from_ptr(path) };
+        let Some(path) = check(
+            path.to_str()
+                .map_err(|_| Error::InvalidPath("Record path is not UTF-8".to_string())),
+        ) else {
+            return ptr::null_mut();
+        };
+        check(WfdbRecord::open(path))
+            .map_or(ptr::null_mut(), |record| Box::into_raw(Box::new(record)))
+    })
+}
+
+/// Free a record opened by [`wfdb_record_open`]. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `record` must be `NULL` or a record returned by [`wfdb_record_open`],
+/// not freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_record_free(record: *mut WfdbRecord) {
+    guard((), || {
+        if !record.is_null() {
+            // SAFETY: `record` was allocated by `wfdb_record_open`, by contract
+            drop(unsafe { Box::from_raw(record) });
+        }
+    });
+}
+
+/// Fill `info` with the general information of a record, returning 0, or
+/// -1 on error.
+///
+/// # Safety
+///
+/// `record` must be `NULL` or a live record, and `info` `NULL` or valid for
+/// writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_record_info(
+    record: *const WfdbRecord,
+    info: *mut WfdbRecordInfo,
+) -> c_int {
+    guard(-1, || {
+        // SAFETY: `record` is NULL or a live record, by contract
+        let Some(record) = (unsafe { record.as_ref() }) else {
+            return null_argument("record");
+        };
+        // SAFETY: `info` is NULL or valid for writes, by contract
+        let Some(info) = (unsafe { info.as_mut() }) else {
+            return null_argument("info");
+        };
+        let metadata = record.record.metadata();
+        *info = WfdbRecordInfo {
+            num_signals: u32::try_from(record.signals.len()).unwrap_or(u32::MAX),
+            num_segments: metadata
+                .num_segments()
+                .map_or(0, |count| u32::try_from(count).unwrap_or(u32::MAX)),
+            sampling_frequency: metadata.sampling_frequency(),
+            num_samples: record
+                .record
+                .total_samples()
+                .map_or(-1, |count| i64::try_from(count).unwrap_or(i64::MAX)),
+        };
+        0
+    })
+}
+
+/// Get a signal of a record, or `None` after recording an error.
+///
+/// # Safety
+///
+/// `record` must be `NULL` or a live record.
+unsafe fn signal_of<'a>(
+    record: *const WfdbRecord,
+    index: usize,
+) -> Option<(&'a WfdbRecord, &'a SignalInfo)> {
+    // SAFETY: `record` is NULL or a live record, by contract
+    let Some(record) = (unsafe { record.as_ref() }) else {
+        null_argument("record");
+        return None;
+    };
+    let Some(signal) = record.signals.get(index) else {
+        set_last_error(&Error::InvalidData(format!(
+            "Signal index {index} out of bounds for {} signals",
+            record.signals.len()
+        )));
+        return None;
+    };
+    Some((record, signal))
+}
+
+/// Fill `info` with the specification of a signal, returning 0, or -1 on
+/// error.
+///
+/// # Safety
+///
+/// `record` must be `NULL` or a live record, and `info` `NULL` or valid for
+/// writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_signal_info(
+    record: *const WfdbRecord,
+    index: usize,
+    info: *mut WfdbSignalInfo,
+) -> c_int {
+    guard(-1, || {
+        // SAFETY: `record` is NULL or a live record, by contract
+        let Some((_, signal)) = (unsafe { signal_of(record, index) }) else {
+            return -1;
+        };
+        // SAFETY: `info` is NULL or valid for writes, by contract
+        let Some(info) = (unsafe { info.as_mut() }) else {
+            return null_argument("info");
+        };
+        *info = WfdbSignalInfo::from(signal);
+        0
+    })
+}
+
+/// Get the description of a signal, or `NULL` if it has none or on error.
+///
+/// The string is owned by the record, and valid until it is freed.
+///
+/// # Safety
+///
+/// `record` must be `NULL` or a live record.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_signal_description(
+    record: *const WfdbRecord,
+    index: usize,
+) -> *const c_char {
+    guard(ptr::null(), || {
+        // SAFETY: `record` is NULL or a live record, by contract
+        unsafe { signal_of(record, index) }.map_or(ptr::null(), |(record, _)| {
+            record.strings[index]
+                .0
+                .as_ref()
+                .map_or(ptr::null(), |description| description.as_ptr())
+        })
+    })
+}
+
+/// Get the units of a signal, or `NULL` on error.
+///
+/// The string is owned by the record, and valid until it is freed.
+///
+/// # Safety
+///
+/// `record` must be `NULL` or a live record.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_signal_units(
+    record: *const WfdbRecord,
+    index: usize,
+) -> *const c_char {
+    guard(ptr::null(), || {
+        // SAFETY: `record` is NULL or a live record, by contract
+        unsafe { signal_of(record, index) }
+            .map_or(ptr::null(), |(record, _)| record.strings[index].1.as_ptr())
+    })
+}
+
+// [Readers]
+
+/// Open a reader of the frames of a record, returning `NULL` on error.
+///
+/// Multi-segment records are read across segments, with gaps read as
+/// invalid samples.
+///
+/// # Safety
+///
+/// `record` must be `NULL` or a live record.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_reader_open(record: *const WfdbRecord) -> *mut WfdbReader {
+    guard(ptr::null_mut(), || {
+        // SAFETY: `record` is NULL or a live record, by contract
+        let Some(record) = (unsafe { record.as_ref() }) else {
+            null_argument("record");
+            return ptr::null_mut();
+        };
+        check(PhysicalReader::open(&record.record)).map_or(ptr::null_mut(), |(reader, _)| {
+            Box::into_raw(Box::new(WfdbReader(reader)))
+        })
+    })
+}
+
+/// Free a reader opened by [`wfdb_reader_open`]. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `reader` must be `NULL` or a reader returned by [`wfdb_reader_open`],
+/// not freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_reader_free(reader: *mut WfdbReader) {
+    guard((), || {
+        if !reader.is_null() {
+            // SAFETY: `reader` was allocated by `wfdb_reader_open`, by contract
+            drop(unsafe { Box::from_raw(reader) });
+        }
+    });
+}
+
+/// Copy a frame to a C buffer, returning the number of values written, 0
+/// at end of record, or -1 on error.
+///
+/// # Safety
+///
+/// `buffer` must be `NULL` or valid for `len` writes.
+unsafe fn copy_frame<T: Copy>(frame: Result<Option<Vec<T>>>, buffer: *mut T, len: usize) -> c_int {
+    if buffer.is_null() {
+        return null_argument("buffer");
+    }
+    let Some(frame) = check(frame) else {
+        return -1;
+    };
+    let Some(frame) = frame else {
+        return 0;
+    };
+    if frame.len() > len {
+        set_last_error(&Error::InvalidData(format!(
+            "Buffer of {len} values too small for {} signals",
+            frame.len()
+        )));
+        return -1;
+    }
+    // SAFETY: `buffer` is valid for `len` writes, by contract
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer, len) };
+    buffer[..frame.len()].copy_from_slice(&frame);
+    c_int::try_from(frame.len()).unwrap_or(c_int::MAX)
+}
+
+/// Read the next frame as ADC values into `samples`, one per signal.
+///
+/// Returns the number of samples written, 0 at the end of the record, or
+/// -1 on error (including a buffer shorter than the number of signals).
+/// Invalid samples are `INT32_MIN`.
+///
+/// # Safety
+///
+/// `reader` must be `NULL` or a live reader, and `samples` `NULL` or valid
+/// for `len` writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_reader_read_frame(
+    reader: *mut WfdbReader,
+    samples: *mut Sample,
+    len: usize,
+) -> c_int {
+    guard(-1, || {
+        // SAFETY: `reader` is NULL or a live reader, by contract
+        let Some(reader) = (unsafe { reader.as_mut() }) else {
+            return null_argument("reader");
+        };
+        // SAFETY: `samples` is NULL or valid for `len` writes, by contract
+        unsafe { copy_frame(reader.0.read_adc_frame(), samples, len) }
+    })
+}
+
+/// Read the next frame in physical units into `values`, one per signal.
+///
+/// Returns the number of values written, 0 at the end of the record, or -1
+/// on error. Invalid samples are `NaN`.
+///
+/// # Safety
+///
+/// `reader` must be `NULL` or a live reader, and `values` `NULL` or valid
+/// for `len` writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_reader_read_physical(
+    reader: *mut WfdbReader,
+    values: *mut f64,
+    len: usize,
+) -> c_int {
+    guard(-1, || {
+        // SAFETY: `reader` is NULL or a live reader, by contract
+        let Some(reader) = (unsafe { reader.as_mut() }) else {
+            return null_argument("reader");
+        };
+        // SAFETY: `values` is NULL or valid for `len` writes, by contract
+        unsafe { copy_frame(reader.0.read_frame(), values, len) }
+    })
+}
+
+/// Move a reader to a frame number, returning 0, or -1 on error.
+///
+/// # Safety
+///
+/// `reader` must be `NULL` or a live reader.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wfdb_reader_seek(reader: *mut WfdbReader, frame: u64) -> c_int {
+    guard(-1, || {
+        // SAFETY: `reader` is NULL or a live reader, by contract
+        let Some(reader) = (unsafe { reader.as_mut() }) else {
+            return null_argument("reader");
+        };
+        check(reader.0.seek_to_frame(frame)).map_or(-1, |_| 0)
+    })
+}
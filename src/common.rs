@@ -20,6 +20,8 @@ pub type Time = i64;
 ///
 /// > Refer to [WFDB Format Specification](https://wfdb.io/spec/signal-files.html) for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "u16", try_from = "u16"))]
 pub enum SignalFormat {
     /// Null signal format, nothing to read or write.
     Format0,
@@ -0,0 +1,372 @@
+//! Directory trees of WFDB records.
+//!
+//! A [`Database`] is a directory containing record headers, possibly in
+//! nested subdirectories (as in `PhysioNet` databases). Its [`catalog`]
+//! summarizes every record from the headers and file system metadata alone,
+//! without decoding any signal, so that whole archives can be indexed cheaply.
+//!
+//! [`catalog`]: Database::catalog
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use wfdb::Database;
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let database = Database::open("data/mitdb")?;
+//! let catalog = database.catalog()?;
+//!
+//! for entry in &catalog.entries {
+//!     println!("{}: {:?} s", entry.path, entry.duration);
+//! }
+//! std::fs::write("catalog.json", catalog.to_json())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::record::export::json_string;
+use crate::{Error, Record, Result};
+
+/// Directory tree of WFDB records.
+#[derive(Debug, Clone)]
+pub struct Database {
+    /// Root directory of the database.
+    root: PathBuf,
+}
+
+/// Summary of one record in a [`Catalog`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogEntry {
+    /// Record path relative to the database root, without extension and
+    /// with `/` separators (e.g. `p00/100`).
+    pub path: String,
+    /// Record name from the header.
+    pub record_name: String,
+    /// Sampling frequency (Hz).
+    pub sampling_frequency: f64,
+    /// Number of samples per signal (`None` when omitted in the header).
+    pub num_samples: Option<u64>,
+    /// Duration in seconds (`None` when the number of samples is unknown).
+    pub duration: Option<f64>,
+    /// Signal descriptions, in signal order.
+    ///
+    /// For multi-segment records these come from the layout segment, if any.
+    pub channel_names: Vec<Option<String>>,
+    /// Annotator names, i.e. extensions of the other files named after the
+    /// record (e.g. `atr`), sorted.
+    pub annotators: Vec<String>,
+    /// Files belonging to the record (header, signal and annotation files,
+    /// and segment files) with their sizes in bytes, relative to the
+    /// record's directory.
+    pub files: Vec<(String, u64)>,
+    /// Fingerprint of the header contents and the names and sizes of the
+    /// record files, as 16 hexadecimal digits.
+    ///
+    /// It changes whenever the header is edited or a file is added, removed
+    /// or resized, but signal contents are not hashed.
+    pub fingerprint: String,
+}
+
+impl CatalogEntry {
+    /// Get the total size in bytes of the record files.
+    #[must_use]
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|(_, size)| size).sum()
+    }
+}
+
+/// Listing of the records of a [`Database`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Catalog {
+    /// Records, sorted by path.
+    pub entries: Vec<CatalogEntry>,
+    /// Headers that could not be read, as (path, error message) pairs.
+    pub failures: Vec<(String, String)>,
+}
+
+impl Catalog {
+    /// Serialize the catalog as a JSON document.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let channels = entry
+                    .channel_names
+                    .iter()
+                    .map(|name| {
+                        name.as_deref()
+                            .map_or_else(|| "null".to_string(), json_string)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let annotators = entry
+                    .annotators
+                    .iter()
+                    .map(|annotator| json_string(annotator))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let files = entry
+                    .files
+                    .iter()
+                    .map(|(name, size)| {
+                        format!("{{\"name\": {}, \"bytes\": {size}}}", json_string(name))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "    {{\"path\": {}, \"record\": {}, \"sampling_frequency\": {}, \"num_samples\": {}, \"duration\": {}, \"channels\": [{}], \"annotators\": [{}], \"files\": [{}], \"bytes\": {}, \"fingerprint\": {}}}",
+                    json_string(&entry.path),
+                    json_string(&entry.record_name),
+                    entry.sampling_frequency,
+                    entry
+                        .num_samples
+                        .map_or_else(|| "null".to_string(), |n| n.to_string()),
+                    entry
+                        .duration
+                        .map_or_else(|| "null".to_string(), |d| d.to_string()),
+                    channels,
+                    annotators,
+                    files,
+                    entry.total_bytes(),
+                    json_string(&entry.fingerprint)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let failures = self
+            .failures
+            .iter()
+            .map(|(path, error)| {
+                format!(
+                    "    {{\"path\": {}, \"error\": {}}}",
+                    json_string(path),
+                    json_string(error)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        format!("{{\n  \"records\": [\n{entries}\n  ],\n  \"failures\": [\n{failures}\n  ]\n}}\n")
+    }
+}
+
+impl Database {
+    // [Constructors]
+
+    /// Open the database rooted at a directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `root` is not a directory.
+    pub fn open<P: AsRef<Path>>(root: P) -> Result<Self> {
+        let root = root.as_ref();
+        if !root.is_dir() {
+            return Err(Error::InvalidPath(format!(
+                "Database directory not found: {}",
+                root.display()
+            )));
+        }
+        Ok(Self {
+            root: root.to_path_buf(),
+        })
+    }
+
+    // [Accessors]
+
+    /// Get the root directory of the database.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    // [Listing]
+
+    /// List the records of the database.
+    ///
+    /// Returns the path (without extension) of every header in the tree,
+    /// sorted, excluding headers referenced as segments of a multi-segment
+    /// record in the same directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory cannot be read.
+    pub fn records(&self) -> Result<Vec<PathBuf>> {
+        let mut headers = Vec::new();
+        collect_headers(&self.root, &mut headers)?;
+        headers.sort();
+
+        let mut segments = HashSet::new();
+        for header in &headers {
+            if let Ok(record) = Record::open(header) {
+                for segment in record.segment_info().unwrap_or_default() {
+                    segments.insert(record.base_path().join(segment.record_name()));
+                }
+            }
+        }
+
+        Ok(headers
+            .into_iter()
+            .map(|header| header.with_extension(""))
+            .filter(|path| !segments.contains(path))
+            .collect())
+    }
+
+    /// Build a catalog of every record of the database.
+    ///
+    /// Only headers and file system metadata are read. Headers that cannot
+    /// be parsed are reported in [`Catalog::failures`] rather than failing
+    /// the whole catalog.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory cannot be read.
+    pub fn catalog(&self) -> Result<Catalog> {
+        let mut catalog = Catalog::default();
+        for path in self.records()? {
+            let relative = relative_path(&self.root, &path);
+            match catalog_entry(&path, relative.clone()) {
+                Ok(entry) => catalog.entries.push(entry),
+                Err(error) => catalog.failures.push((relative, error.to_string())),
+            }
+        }
+        Ok(catalog)
+    }
+}
+
+/// Recursively collect the paths of the header files under `dir`.
+fn collect_headers(dir: &Path, headers: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            collect_headers(&path, headers)?;
+        } else if path.extension().is_some_and(|ext| ext == "hea") {
+            headers.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Format `path` relative to `root` with `/` separators.
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Summarize the record at `path` (without extension).
+fn catalog_entry(path: &Path, relative: String) -> Result<CatalogEntry> {
+    let record = Record::open(path)?;
+    let metadata = record.metadata();
+    let header_path = path.with_extension("hea");
+    let mut files = vec![file_name(&header_path)];
+    let mut channel_names = Vec::new();
+    if let Some(signals) = record.signal_info() {
+        channel_names = signals.iter().map(|s| s.description.clone()).collect();
+        files.extend(signals.iter().map(|s| s.file_name().to_string()));
+    } else if let Some(segments) = record.segment_info() {
+        for (index, segment) in segments.iter().enumerate() {
+            if segment.is_null_segment() {
+                continue;
+            }
+            let segment_record = Record::open(record.base_path().join(segment.record_name()))?;
+            files.push(format!("{}.hea", segment.record_name()));
+            let signals = segment_record.signal_info().unwrap_or_default();
+            files.extend(signals.iter().map(|s| s.file_name().to_string()));
+            // The layout segment (first, with no samples) lists every channel
+            if index == 0 && segment.num_samples == 0 {
+                channel_names = signals.iter().map(|s| s.description.clone()).collect();
+            }
+        }
+    }
+    files.retain(|name| name != "~");
+    let signal_files: HashSet<String> = files.iter().cloned().collect();
+
+    let mut annotators = Vec::new();
+    for entry in std::fs::read_dir(record.base_path())? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if signal_files.contains(&name) {
+            continue;
+        }
+        if let Some((stem, extension)) = name.rsplit_once('.')
+            && stem == metadata.name()
+        {
+            annotators.push(extension.to_string());
+        }
+    }
+    annotators.sort();
+    files.extend(
+        annotators
+            .iter()
+            .map(|a| format!("{}.{a}", metadata.name())),
+    );
+
+    let mut seen = HashSet::new();
+    files.retain(|name| seen.insert(name.clone()));
+
+    let mut sized_files = Vec::with_capacity(files.len());
+    for name in files {
+        let size = std::fs::metadata(record.base_path().join(&name)).map_or(0, |m| m.len());
+        sized_files.push((name, size));
+    }
+
+    let mut hasher = Fnv1a::new();
+    hasher.update(&std::fs::read(&header_path)?);
+    for (name, size) in &sized_files {
+        hasher.update(name.as_bytes());
+        hasher.update(&size.to_le_bytes());
+    }
+
+    let sampling_frequency = metadata.sampling_frequency();
+    #[allow(clippy::cast_precision_loss)]
+    let duration = metadata
+        .num_samples()
+        .map(|n| n as f64 / sampling_frequency);
+
+    Ok(CatalogEntry {
+        path: relative,
+        record_name: metadata.name().to_string(),
+        sampling_frequency,
+        num_samples: metadata.num_samples(),
+        duration,
+        channel_names,
+        annotators,
+        files: sized_files,
+        fingerprint: hasher.finish(),
+    })
+}
+
+/// Get the file name of a path as a string.
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// 64-bit FNV-1a hasher, stable across platforms and releases.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
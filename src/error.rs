@@ -1,10 +1,16 @@
+use std::fmt;
+
 use thiserror::Error;
 
 /// Errors that may occur within the Rust WFDB library.
 ///
 /// This enum represents possible error conditions that can arise
 /// when working with waveform database format files by the library.
+///
+/// New variants may be added in minor releases, so matches need a wildcard
+/// arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Indicates that the specified signal format code is not supported.
     ///
@@ -36,6 +42,219 @@ pub enum Error {
     Io(#[from] std::io::Error),
 
     /// Indicates an invalid header format.
+    ///
+    /// The contained [`HeaderError`] describes the problem, with the header
+    /// line and field it was found in when known.
     #[error("Invalid header: {0}")]
-    InvalidHeader(String),
+    InvalidHeader(HeaderError),
+
+    /// Indicates that a signal file could not be opened.
+    ///
+    /// Unlike other I/O errors, this names the file of the record that is
+    /// missing or unreadable.
+    #[error("Invalid path: Failed to open signal file '{}': {source}", path.display())]
+    SignalFile {
+        /// Path of the signal file.
+        path: std::path::PathBuf,
+        /// Error opening the file.
+        source: std::io::Error,
+    },
+
+    /// Indicates that a signal file holds data that cannot be decoded.
+    #[error("Signal decode error in '{file}' at byte {byte_offset}: {reason}")]
+    SignalDecode {
+        /// Name of the signal file, as given in the header.
+        file: String,
+        /// Byte offset in the file at which decoding stopped.
+        byte_offset: u64,
+        /// Description of the problem.
+        reason: String,
+    },
+
+    /// Indicates malformed or out-of-range data outside of header parsing.
+    ///
+    /// The contained string describes the problem.
+    #[error("Invalid data: {0}")]
+    InvalidData(String),
+
+    /// Indicates that fetching data from a remote source failed.
+    ///
+    /// The contained string describes the failed request.
+    #[error("Remote error: {0}")]
+    Remote(String),
+
+    /// Indicates that a record is being written by another writer.
+    ///
+    /// The contained path is the header file of the record, whose advisory
+    /// lock is held by another process (or another writer of this one).
+    #[error("Record locked by another writer: '{}'", .0.display())]
+    Locked(std::path::PathBuf),
+
+    /// Indicates that an operation was cancelled by its progress callback.
+    ///
+    /// See [`Progress`](crate::record::Progress).
+    #[error("Operation cancelled")]
+    Cancelled,
+
+    /// Indicates that an operation went over its budget.
+    ///
+    /// The contained string describes the limit that was reached. See
+    /// [`Budget`](crate::record::Budget).
+    #[error("Budget exceeded: {0}")]
+    BudgetExceeded(String),
+}
+
+impl Error {
+    /// Build a header error about the given field.
+    pub(crate) fn header_field(field: HeaderField, reason: impl Into<String>) -> Self {
+        Self::InvalidHeader(HeaderError::new(field, reason))
+    }
+
+    /// Attach the (1-based) header line a header error was found on,
+    /// unless already known.
+    #[must_use]
+    pub(crate) fn at_line(mut self, line: usize) -> Self {
+        if let Self::InvalidHeader(error) = &mut self {
+            error.line.get_or_insert(line);
+        }
+        self
+    }
+}
+
+/// Description of an invalid header, with its position when known.
+///
+/// Displayed as its reason only, so that [`Error::InvalidHeader`] reads
+/// the same whether or not a position is known. Built from a message with
+/// `From<String>` (e.g. `Error::InvalidHeader(message.into())`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HeaderError {
+    /// Line of the header (1-based).
+    line: Option<usize>,
+    /// Field of the line.
+    field: Option<HeaderField>,
+    /// Description of the problem.
+    reason: String,
+}
+
+impl HeaderError {
+    /// Build a header error about the given field.
+    pub(crate) fn new(field: HeaderField, reason: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            field: Some(field),
+            reason: reason.into(),
+        }
+    }
+
+    /// Set the (1-based) header line of the error.
+    #[must_use]
+    pub(crate) const fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Get the line (1-based) of the header holding the invalid field, if
+    /// known.
+    #[must_use]
+    pub const fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Get the field that is invalid, if known.
+    #[must_use]
+    pub const fn field(&self) -> Option<HeaderField> {
+        self.field
+    }
+
+    /// Get the description of the problem.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+impl From<String> for HeaderError {
+    fn from(reason: String) -> Self {
+        Self {
+            line: None,
+            field: None,
+            reason,
+        }
+    }
+}
+
+impl From<&str> for HeaderError {
+    fn from(reason: &str) -> Self {
+        Self::from(reason.to_string())
+    }
+}
+
+/// Field of a header line.
+///
+/// See [`HeaderError::field`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HeaderField {
+    // [Record line]
+    /// Record name.
+    RecordName,
+    /// Number of segments.
+    NumSegments,
+    /// Number of signals.
+    NumSignals,
+    /// Sampling frequency.
+    SamplingFrequency,
+    /// Counter frequency.
+    CounterFrequency,
+    /// Base counter value.
+    BaseCounter,
+    /// Number of samples per signal.
+    NumSamples,
+    /// Base time.
+    BaseTime,
+    /// Base date.
+    BaseDate,
+
+    // [Signal specification line]
+    /// Signal file name.
+    FileName,
+    /// Signal format.
+    Format,
+    /// Samples per frame.
+    SamplesPerFrame,
+    /// Skew.
+    Skew,
+    /// Byte offset.
+    ByteOffset,
+    /// ADC gain.
+    Gain,
+    /// Baseline.
+    Baseline,
+    /// Units.
+    Units,
+    /// ADC resolution.
+    AdcResolution,
+    /// ADC zero.
+    AdcZero,
+    /// Initial value.
+    InitialValue,
+    /// Checksum.
+    Checksum,
+    /// Block size.
+    BlockSize,
+    /// Description.
+    Description,
+
+    // [Segment specification line]
+    /// Segment record name.
+    SegmentName,
+    /// Number of samples of the segment.
+    SegmentLength,
 }
@@ -1,14 +1,83 @@
+use std::fmt;
 use std::io::BufRead;
+use std::ops::RangeInclusive;
 
-use crate::{Error, Result};
+use crate::number::Number;
+use crate::{Error, HeaderError, HeaderField, Result};
 
 use super::{Metadata, SegmentInfo, SignalInfo};
 
+/// Parse a floating-point header field, rejecting `NaN` and infinite values.
+///
+/// Values that overflow `f64` (e.g., `1e999`) parse as infinity and are
+/// rejected as well.
+///
+/// __INTERNAL USE ONLY__
+pub(super) fn parse_finite(field: &str, name: &str, kind: HeaderField) -> Result<f64> {
+    let value: f64 = field
+        .parse()
+        .map_err(|e| Error::header_field(kind, format!("Invalid {name}: {e}")))?;
+
+    if !value.is_finite() {
+        return Err(Error::header_field(
+            kind,
+            format!("Invalid {name}: expected a finite number, got '{field}'"),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Check that the magnitude of a parsed header value is plausible,
+/// returning the problem otherwise.
+///
+/// __INTERNAL USE ONLY__
+pub(super) fn check_range(
+    value: f64,
+    range: &RangeInclusive<f64>,
+    name: &str,
+    kind: HeaderField,
+) -> Option<HeaderError> {
+    (!range.contains(&value.abs())).then(|| {
+        HeaderError::new(
+            kind,
+            format!(
+                "{name} {} is outside the plausible range of {} to {}",
+                Number(value),
+                Number(*range.start()),
+                Number(*range.end())
+            ),
+        )
+    })
+}
+
+/// Options for parsing a header.
+///
+/// See [`Header::from_reader_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Accept values outside the plausible ranges of
+    /// [`limits`](crate::limits), reporting them as warnings instead of
+    /// errors.
+    pub lenient: bool,
+}
+
+impl ParseOptions {
+    /// Report implausible values as warnings instead of errors.
+    #[must_use]
+    pub const fn lenient(mut self) -> Self {
+        self.lenient = true;
+        self
+    }
+}
+
 /// Header specifications containing either signal or segment data.
 ///
 /// A WFDB header contains either signal specifications (for single-segment)
 /// or segment specifications (for multi-segment). These two are mutually exclusive.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Specifications {
     /// Single-segment record with signal specifications.
     SingleSegment {
@@ -87,6 +156,7 @@ impl Specifications {
 /// 100s 21600
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     /// Record metadata from the record line.
     pub metadata: Metadata,
@@ -118,17 +188,68 @@ impl Header {
     /// - The record line is missing or invalid
     /// - Signal/segment specifications are missing or invalid
     /// - The number of specifications doesn't match the record line
+    /// - A frequency, gain or counter value is outside its plausible range
+    ///   (see [`limits`](crate::limits))
     pub fn from_reader<R: BufRead>(reader: &mut R) -> Result<Self> {
+        Self::from_reader_with_options(reader, &ParseOptions::default()).map(|(header, _)| header)
+    }
+
+    /// Parse a WFDB header from a buffered reader, with custom options.
+    ///
+    /// Returns the header with the values accepted as warnings under
+    /// [`ParseOptions::lenient`] (always empty otherwise), each with the
+    /// line and field it was found in. A header parsed leniently can be
+    /// opened with [`Record::from_header`](crate::Record::from_header).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`from_reader`](Self::from_reader), except
+    /// for implausible values in lenient mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::Header;
+    /// use wfdb::header::ParseOptions;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let text = "rec 1 250\nrec.dat 16 1e300\n";
+    /// assert!(Header::from_reader(&mut text.as_bytes()).is_err());
+    ///
+    /// let options = ParseOptions::default().lenient();
+    /// let (header, warnings) = Header::from_reader_with_options(&mut text.as_bytes(), &options)?;
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].line(), Some(2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_reader_with_options<R: BufRead>(
+        reader: &mut R,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<HeaderError>)> {
         // Use iterator-based approach with proper line handling
         let lines: Vec<String> = reader.lines().collect::<std::io::Result<Vec<String>>>()?;
 
-        Self::from_lines(&lines)
+        Self::from_lines(&lines, *options)
     }
 
     /// Parse a WFDB header from a slice of lines.
     ///
     /// This is the internal parsing function used by `from_reader`.
-    fn from_lines(lines: &[String]) -> Result<Self> {
+    fn from_lines(lines: &[String], options: ParseOptions) -> Result<(Self, Vec<HeaderError>)> {
+        let mut warnings = Vec::new();
+        // Report implausible values of a line, as errors unless lenient
+        let mut check = |issues: Vec<HeaderError>, line: usize| {
+            for issue in issues {
+                let issue = issue.with_line(line);
+                if !options.lenient {
+                    return Err(Error::InvalidHeader(issue));
+                }
+                warnings.push(issue);
+            }
+            Ok(())
+        };
+
         // Find the first non-empty, non-comment line (record line)
         let record_line_idx = lines
             .iter()
@@ -136,10 +257,12 @@ impl Header {
                 let trimmed = line.trim();
                 !trimmed.is_empty() && !trimmed.starts_with('#')
             })
-            .ok_or_else(|| Error::InvalidHeader("Missing record line in header".to_string()))?;
+            .ok_or_else(|| Error::InvalidHeader("Missing record line in header".into()))?;
 
         // Parse the record line
-        let metadata = Metadata::from_record_line(&lines[record_line_idx])?;
+        let metadata = Metadata::parse_record_line(&lines[record_line_idx])
+            .map_err(|e| e.at_line(record_line_idx + 1))?;
+        check(metadata.range_issues(), record_line_idx + 1)?;
         let mut line_idx = record_line_idx + 1;
 
         // Determine if this is a multi-segment record
@@ -161,16 +284,21 @@ impl Header {
                     continue;
                 }
 
-                segment_specs.push(SegmentInfo::from_segment_line(line)?);
+                segment_specs.push(
+                    SegmentInfo::from_segment_line(line).map_err(|e| e.at_line(line_idx + 1))?,
+                );
                 line_idx += 1;
             }
 
             if segment_specs.len() < num_segments {
-                return Err(Error::InvalidHeader(format!(
-                    "Expected {} segment specifications, found {}",
-                    num_segments,
-                    segment_specs.len()
-                )));
+                return Err(Error::InvalidHeader(
+                    format!(
+                        "Expected {} segment specifications, found {}",
+                        num_segments,
+                        segment_specs.len()
+                    )
+                    .into(),
+                ));
             }
 
             (None, Some(segment_specs))
@@ -188,16 +316,22 @@ impl Header {
                     continue;
                 }
 
-                signal_specs.push(SignalInfo::from_signal_line(line)?);
+                let signal =
+                    SignalInfo::parse_signal_line(line).map_err(|e| e.at_line(line_idx + 1))?;
+                check(signal.range_issues(), line_idx + 1)?;
+                signal_specs.push(signal);
                 line_idx += 1;
             }
 
             if signal_specs.len() < num_signals {
-                return Err(Error::InvalidHeader(format!(
-                    "Expected {} signal specifications, found {}",
-                    num_signals,
-                    signal_specs.len()
-                )));
+                return Err(Error::InvalidHeader(
+                    format!(
+                        "Expected {} signal specifications, found {}",
+                        num_signals,
+                        signal_specs.len()
+                    )
+                    .into(),
+                ));
             }
 
             (Some(signal_specs), None)
@@ -225,11 +359,14 @@ impl Header {
             _ => unreachable!("Either signals or segments should be Some, but not both"),
         };
 
-        Ok(Self {
-            metadata,
-            specifications,
-            info_strings,
-        })
+        Ok((
+            Self {
+                metadata,
+                specifications,
+                info_strings,
+            },
+            warnings,
+        ))
     }
 
     // [Accessors]
@@ -294,3 +431,44 @@ impl Header {
         self.metadata.num_segments
     }
 }
+
+impl fmt::Display for Header {
+    /// Format the header as the contents of a `.hea` file.
+    ///
+    /// The output parses back with [`Header::from_reader`]. Each line is
+    /// terminated by a newline, and info strings are written as comment
+    /// lines after the specifications.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use wfdb::Header;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let text = "100 1 360 650000\n100.dat 212 200 11 1024 995 -22131 0 MLII\n";
+    /// let header = Header::from_reader(&mut Cursor::new(text))?;
+    /// assert_eq!(header.to_string(), text);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.metadata)?;
+        match &self.specifications {
+            Specifications::SingleSegment { signals } => {
+                for signal in signals {
+                    writeln!(f, "{signal}")?;
+                }
+            }
+            Specifications::MultiSegment { segments } => {
+                for segment in segments {
+                    writeln!(f, "{segment}")?;
+                }
+            }
+        }
+        for info in &self.info_strings {
+            writeln!(f, "#{info}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+
+use crate::{Error, HeaderField, Result};
+
+/// Start time of a recording (`HH:MM:SS` on the record line).
+///
+/// Available without the `chrono` feature; with it, convert with
+/// [`to_naive_time()`](Self::to_naive_time) or
+/// [`Metadata::base_time()`](crate::Metadata::base_time).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseTime {
+    /// Hours (0 to 23).
+    pub hours: u8,
+    /// Minutes (0 to 59).
+    pub minutes: u8,
+    /// Seconds (0 to 59).
+    pub seconds: u8,
+}
+
+/// Start date of a recording (`DD/MM/YYYY` on the record line).
+///
+/// Available without the `chrono` feature; with it, convert with
+/// [`to_naive_date()`](Self::to_naive_date) or
+/// [`Metadata::base_date()`](crate::Metadata::base_date).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseDate {
+    /// Year.
+    pub year: i32,
+    /// Month (1 to 12).
+    pub month: u8,
+    /// Day of the month (1 to 31).
+    pub day: u8,
+}
+
+impl BaseTime {
+    /// Create a base time, without checking that it is a valid time of day.
+    #[must_use]
+    pub const fn new(hours: u8, minutes: u8, seconds: u8) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Parse a base time in `HH:MM:SS` format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is not a valid time of day.
+    pub fn parse(field: &str) -> Result<Self> {
+        let invalid = || {
+            Error::header_field(
+                HeaderField::BaseTime,
+                format!("Invalid base time '{field}', expected HH:MM:SS"),
+            )
+        };
+        let mut parts = field.split(':');
+        let mut next = || {
+            parts
+                .next()
+                .and_then(parse_number::<u8>)
+                .ok_or_else(invalid)
+        };
+        let time = Self::new(next()?, next()?, next()?);
+        if parts.next().is_some() || time.hours > 23 || time.minutes > 59 || time.seconds > 59 {
+            return Err(invalid());
+        }
+        Ok(time)
+    }
+
+    /// Convert to a `chrono` time.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// Returns `None` if the fields are not a valid time of day.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn to_naive_time(self) -> Option<NaiveTime> {
+        NaiveTime::from_hms_opt(
+            u32::from(self.hours),
+            u32::from(self.minutes),
+            u32::from(self.seconds),
+        )
+    }
+}
+
+impl BaseDate {
+    /// Create a base date, without checking that it is a valid date.
+    #[must_use]
+    pub const fn new(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Parse a base date in `DD/MM/YYYY` format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is not a valid date.
+    pub fn parse(field: &str) -> Result<Self> {
+        let invalid = || {
+            Error::header_field(
+                HeaderField::BaseDate,
+                format!("Invalid base date '{field}', expected DD/MM/YYYY"),
+            )
+        };
+        let mut parts = field.split('/');
+        let day = parts
+            .next()
+            .and_then(parse_number::<u8>)
+            .ok_or_else(invalid)?;
+        let month = parts
+            .next()
+            .and_then(parse_number::<u8>)
+            .ok_or_else(invalid)?;
+        let year = parts
+            .next()
+            .and_then(parse_number::<i32>)
+            .ok_or_else(invalid)?;
+        if parts.next().is_some()
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return Err(invalid());
+        }
+        Ok(Self::new(year, month, day))
+    }
+
+    /// Convert to a `chrono` date.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// Returns `None` if the fields are not a valid date.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn to_naive_date(self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, u32::from(self.month), u32::from(self.day))
+    }
+}
+
+impl fmt::Display for BaseTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hours, self.minutes, self.seconds
+        )
+    }
+}
+
+impl fmt::Display for BaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.day, self.month, self.year)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveTime> for BaseTime {
+    /// Convert a `chrono` time, dropping fractions of a second.
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(time: NaiveTime) -> Self {
+        Self::new(time.hour() as u8, time.minute() as u8, time.second() as u8)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for BaseDate {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(date: NaiveDate) -> Self {
+        Self::new(date.year(), date.month() as u8, date.day() as u8)
+    }
+}
+
+/// Parse an unsigned decimal number of at most 4 digits.
+fn parse_number<T: std::str::FromStr>(text: &str) -> Option<T> {
+    if text.is_empty() || text.len() > 4 || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// Get the number of days in a month of the proleptic Gregorian calendar.
+const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -1,6 +1,16 @@
-use chrono::{NaiveDate, NaiveTime};
+use std::fmt;
 
-use crate::{Error, Result};
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use super::{BaseDate, BaseTime};
+use super::{check_range, parse_finite};
+use crate::limits::{
+    MAX_RECORD_NAME_LEN, MAX_SAMPLES, MAX_SEGMENTS, MAX_SIGNALS, PLAUSIBLE_BASE_COUNTER,
+    PLAUSIBLE_FREQUENCY,
+};
+use crate::number::Number;
+use crate::{Error, HeaderError, HeaderField, Result};
 
 /// Return type for parsed optional fields from a WFDB header record line.
 ///
@@ -10,8 +20,8 @@ struct OptionalFields {
     counter_frequency: Option<f64>,
     base_counter: Option<f64>,
     num_samples: Option<u64>,
-    base_time: Option<NaiveTime>,
-    base_date: Option<NaiveDate>,
+    base_time: Option<BaseTime>,
+    base_date: Option<BaseDate>,
 }
 
 /// Type of optional field detected by format.
@@ -49,6 +59,7 @@ enum ParseState {
 /// - `24_record/2 4 102400` (Many fields are optional.)
 /// - `rec 2 12:30:45 01/01/2000` (Optional fields can be omitted in the middle.)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Metadata {
     /// Identifier for the record (letters, digits, underscores only).
     pub name: String,
@@ -65,9 +76,9 @@ pub struct Metadata {
     /// Total samples per signal.
     pub num_samples: Option<u64>,
     /// Start time of the recording (HH:MM:SS).
-    pub base_time: Option<NaiveTime>,
+    pub base_time: Option<BaseTime>,
     /// Start date of the recording (DD/MM/YYYY).
-    pub base_date: Option<NaiveDate>,
+    pub base_date: Option<BaseDate>,
 }
 
 impl Metadata {
@@ -85,24 +96,48 @@ impl Metadata {
     ///
     /// # Errors
     ///
-    /// Will return an error if the format of the record line is invalid.
+    /// Will return an error if the format of the record line is invalid, or
+    /// a frequency or counter value is outside its plausible range (see
+    /// [`limits`](crate::limits)).
     pub fn from_record_line(line: &str) -> Result<Self> {
+        let metadata = Self::parse_record_line(line)?;
+        metadata
+            .range_issues()
+            .into_iter()
+            .next()
+            .map_or(Ok(metadata), |issue| Err(Error::InvalidHeader(issue)))
+    }
+
+    /// Parse a record line, without checking the ranges of its values.
+    pub(super) fn parse_record_line(line: &str) -> Result<Self> {
         let line = line.trim();
         let mut parts = line.split_whitespace();
 
         // Resolve first part: record name (required) and number of segments (optional)
-        let (name, num_segments) = Self::parse_record_name(
-            parts
-                .next()
-                .ok_or_else(|| Error::InvalidHeader("Missing record name".to_string()))?,
-        )?;
+        let (name, num_segments) =
+            Self::parse_record_name(parts.next().ok_or_else(|| {
+                Error::header_field(HeaderField::RecordName, "Missing record name")
+            })?)?;
 
         // Resolve second part: number of signals (required)
         let num_signals = parts
             .next()
-            .ok_or_else(|| Error::InvalidHeader("Missing number of signals".to_string()))?
+            .ok_or_else(|| {
+                Error::header_field(HeaderField::NumSignals, "Missing number of signals")
+            })?
             .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid number of signals: {e}")))?;
+            .map_err(|e| {
+                Error::header_field(
+                    HeaderField::NumSignals,
+                    format!("Invalid number of signals: {e}"),
+                )
+            })?;
+        if num_signals > MAX_SIGNALS {
+            return Err(Error::header_field(
+                HeaderField::NumSignals,
+                format!("Number of signals {num_signals} exceeds the maximum of {MAX_SIGNALS}"),
+            ));
+        }
 
         // Collect remaining optional fields
         let remaining: Vec<&str> = parts.collect();
@@ -146,17 +181,26 @@ impl Metadata {
                 }
                 FieldType::NumSamples => {
                     let n = field.parse().map_err(|e| {
-                        Error::InvalidHeader(format!("Invalid number of samples: {e}"))
+                        Error::header_field(
+                            HeaderField::NumSamples,
+                            format!("Invalid number of samples: {e}"),
+                        )
                     })?;
+                    if n > MAX_SAMPLES {
+                        return Err(Error::header_field(
+                            HeaderField::NumSamples,
+                            format!("Number of samples {n} exceeds the maximum of {MAX_SAMPLES}"),
+                        ));
+                    }
                     num_samples = Some(n);
                     state = ParseState::AfterNumSamples;
                 }
                 FieldType::Time => {
-                    base_time = Self::parse_base_time(field)?;
+                    base_time = Some(BaseTime::parse(field)?);
                     state = ParseState::AfterTime;
                 }
                 FieldType::Date => {
-                    base_date = Self::parse_base_date(field)?;
+                    base_date = Some(BaseDate::parse(field)?);
                     state = ParseState::AfterDate;
                 }
             }
@@ -181,8 +225,9 @@ impl Metadata {
         // Time: contains colon (HH:MM:SS)
         if field.contains(':') {
             if state >= ParseState::AfterTime {
-                return Err(Error::InvalidHeader(
-                    "Duplicate or out-of-order time field".to_string(),
+                return Err(Error::header_field(
+                    HeaderField::BaseTime,
+                    "Duplicate or out-of-order time field",
                 ));
             }
             return Ok(FieldType::Time);
@@ -192,22 +237,25 @@ impl Metadata {
         if field.matches('/').count() == 2 {
             if state >= ParseState::AfterTime {
                 if state >= ParseState::AfterDate {
-                    return Err(Error::InvalidHeader(
-                        "Duplicate or out-of-order date field".to_string(),
+                    return Err(Error::header_field(
+                        HeaderField::BaseDate,
+                        "Duplicate or out-of-order date field",
                     ));
                 }
                 return Ok(FieldType::Date);
             }
-            return Err(Error::InvalidHeader(
-                "Date field appears before time field".to_string(),
+            return Err(Error::header_field(
+                HeaderField::BaseDate,
+                "Date field appears before time field",
             ));
         }
 
         // Frequency with counter: contains single `/` or `(`
         if field.contains('/') || field.contains('(') {
             if state >= ParseState::AfterFrequency {
-                return Err(Error::InvalidHeader(
-                    "Duplicate or out-of-order frequency field".to_string(),
+                return Err(Error::header_field(
+                    HeaderField::SamplingFrequency,
+                    "Duplicate or out-of-order frequency field",
                 ));
             }
             return Ok(FieldType::Frequency);
@@ -217,9 +265,9 @@ impl Metadata {
         match state {
             ParseState::Start => Ok(FieldType::Frequency),
             ParseState::AfterFrequency => Ok(FieldType::NumSamples),
-            _ => Err(Error::InvalidHeader(format!(
-                "Unexpected numeric field '{field}' after time/date"
-            ))),
+            _ => Err(Error::InvalidHeader(
+                format!("Unexpected numeric field '{field}' after time/date").into(),
+            )),
         }
     }
 
@@ -228,11 +276,23 @@ impl Metadata {
         let (name, num_segments) = match field.split_once('/') {
             Some((name, num_segments)) => {
                 let num_segments = num_segments.parse().map_err(|e| {
-                    Error::InvalidHeader(format!("Invalid number of segments: {e}"))
+                    Error::header_field(
+                        HeaderField::NumSegments,
+                        format!("Invalid number of segments: {e}"),
+                    )
                 })?;
                 if num_segments == 0 {
-                    return Err(Error::InvalidHeader(
-                        "Number of segments must be greater than zero".to_string(),
+                    return Err(Error::header_field(
+                        HeaderField::NumSegments,
+                        "Number of segments must be greater than zero",
+                    ));
+                }
+                if num_segments > MAX_SEGMENTS {
+                    return Err(Error::header_field(
+                        HeaderField::NumSegments,
+                        format!(
+                            "Number of segments {num_segments} exceeds the maximum of {MAX_SEGMENTS}"
+                        ),
                     ));
                 }
                 (name, Some(num_segments))
@@ -242,12 +302,24 @@ impl Metadata {
 
         // The record name only contains letters, digits, and underscores
         if name.is_empty() {
-            return Err(Error::InvalidHeader("Record name is empty".to_string()));
+            return Err(Error::header_field(
+                HeaderField::RecordName,
+                "Record name is empty",
+            ));
         }
         if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
-            return Err(Error::InvalidHeader(format!(
-                "Record name '{name}' contains invalid characters, expected letters, digits, and underscores"
-            )));
+            return Err(Error::header_field(
+                HeaderField::RecordName,
+                format!(
+                    "Record name '{name}' contains invalid characters, expected letters, digits, and underscores"
+                ),
+            ));
+        }
+        if name.len() > MAX_RECORD_NAME_LEN {
+            return Err(Error::header_field(
+                HeaderField::RecordName,
+                format!("Record name '{name}' is longer than {MAX_RECORD_NAME_LEN} characters"),
+            ));
         }
 
         Ok((name.to_string(), num_segments))
@@ -261,23 +333,25 @@ impl Metadata {
         };
 
         if sampling_part.is_empty() {
-            return Err(Error::InvalidHeader(
-                "Sampling frequency is empty".to_string(),
+            return Err(Error::header_field(
+                HeaderField::SamplingFrequency,
+                "Sampling frequency is empty",
             ));
         }
 
-        let sampling_frequency = Some(
-            sampling_part
-                .parse()
-                .map_err(|e| Error::InvalidHeader(format!("Invalid sampling frequency: {e}")))?,
-        );
+        let sampling_frequency = Some(parse_finite(
+            sampling_part,
+            "sampling frequency",
+            HeaderField::SamplingFrequency,
+        )?);
 
         if let Some(sampling_frequency) = sampling_frequency
             && sampling_frequency <= 0.0
         {
-            return Err(Error::InvalidHeader(format!(
-                "Sampling frequency must be greater than zero, got {sampling_frequency}"
-            )));
+            return Err(Error::header_field(
+                HeaderField::SamplingFrequency,
+                format!("Sampling frequency must be greater than zero, got {sampling_frequency}"),
+            ));
         }
 
         let (counter_frequency, base_counter) = match counter_part {
@@ -293,16 +367,23 @@ impl Metadata {
         // Check for parentheses: counter_freq(base_counter)
         if let Some(paren_start) = field.find('(') {
             let paren_end = field.find(')').ok_or_else(|| {
-                Error::InvalidHeader("Missing closing parenthesis in counter frequency".to_string())
+                Error::header_field(
+                    HeaderField::CounterFrequency,
+                    "Missing closing parenthesis in counter frequency",
+                )
             })?;
 
-            let counter_freq = field[..paren_start]
-                .parse()
-                .map_err(|e| Error::InvalidHeader(format!("Invalid counter frequency: {e}")))?;
+            let counter_freq = parse_finite(
+                &field[..paren_start],
+                "counter frequency",
+                HeaderField::CounterFrequency,
+            )?;
 
-            let base_counter = field[paren_start + 1..paren_end]
-                .parse()
-                .map_err(|e| Error::InvalidHeader(format!("Invalid base counter value: {e}")))?;
+            let base_counter = parse_finite(
+                &field[paren_start + 1..paren_end],
+                "base counter value",
+                HeaderField::BaseCounter,
+            )?;
 
             if counter_freq <= 0.0 {
                 return Ok((None, None));
@@ -310,9 +391,8 @@ impl Metadata {
 
             Ok((Some(counter_freq), Some(base_counter)))
         } else {
-            let counter_freq = field
-                .parse()
-                .map_err(|e| Error::InvalidHeader(format!("Invalid counter frequency: {e}")))?;
+            let counter_freq =
+                parse_finite(field, "counter frequency", HeaderField::CounterFrequency)?;
 
             if counter_freq <= 0.0 {
                 return Ok((None, None));
@@ -322,20 +402,37 @@ impl Metadata {
         }
     }
 
-    /// Parse time in HH:MM:SS format
-    fn parse_base_time(field: &str) -> Result<Option<NaiveTime>> {
-        let time = NaiveTime::parse_from_str(field, "%H:%M:%S").map_err(|_| {
-            Error::InvalidHeader(format!("Invalid base time '{field}', expected HH:MM:SS"))
-        })?;
-        Ok(Some(time))
-    }
-
-    /// Parse date in DD/MM/YYYY format
-    fn parse_base_date(field: &str) -> Result<Option<NaiveDate>> {
-        let date = NaiveDate::parse_from_str(field, "%d/%m/%Y").map_err(|_| {
-            Error::InvalidHeader(format!("Invalid base date '{field}', expected DD/MM/YYYY"))
-        })?;
-        Ok(Some(date))
+    /// Find the values of the record line outside their plausible ranges.
+    pub(super) fn range_issues(&self) -> Vec<HeaderError> {
+        [
+            self.sampling_frequency.and_then(|value| {
+                check_range(
+                    value,
+                    &PLAUSIBLE_FREQUENCY,
+                    "Sampling frequency",
+                    HeaderField::SamplingFrequency,
+                )
+            }),
+            self.counter_frequency.and_then(|value| {
+                check_range(
+                    value,
+                    &PLAUSIBLE_FREQUENCY,
+                    "Counter frequency",
+                    HeaderField::CounterFrequency,
+                )
+            }),
+            self.base_counter.and_then(|value| {
+                check_range(
+                    value,
+                    &PLAUSIBLE_BASE_COUNTER,
+                    "Base counter value",
+                    HeaderField::BaseCounter,
+                )
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 
     // [Accessors]
@@ -390,15 +487,135 @@ impl Metadata {
         self.num_samples
     }
 
-    /// Get the base time of the metadata.
+    /// Get the base time of the metadata as `(hours, minutes, seconds)`.
+    ///
+    /// Available without the `chrono` feature.
     #[must_use]
-    pub const fn base_time(&self) -> Option<NaiveTime> {
+    pub fn base_time_hms(&self) -> Option<(u8, u8, u8)> {
         self.base_time
+            .map(|time| (time.hours, time.minutes, time.seconds))
+    }
+
+    /// Get the base date of the metadata as `(year, month, day)`.
+    ///
+    /// Available without the `chrono` feature.
+    #[must_use]
+    pub fn base_date_ymd(&self) -> Option<(i32, u8, u8)> {
+        self.base_date.map(|date| (date.year, date.month, date.day))
+    }
+
+    /// Get the base time of the metadata.
+    ///
+    /// Requires the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn base_time(&self) -> Option<NaiveTime> {
+        self.base_time?.to_naive_time()
     }
 
     /// Get the base date of the metadata.
+    ///
+    /// Requires the `chrono` feature.
+    #[cfg(feature = "chrono")]
     #[must_use]
-    pub const fn base_date(&self) -> Option<NaiveDate> {
-        self.base_date
+    pub fn base_date(&self) -> Option<NaiveDate> {
+        self.base_date?.to_naive_date()
+    }
+
+    /// Get the date and time of the first sample.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// Returns `None` unless both the base time and date are given.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn base_datetime(&self) -> Option<NaiveDateTime> {
+        Some(self.base_date()?.and_time(self.base_time()?))
+    }
+
+    // [Counter conversions]
+
+    /// Convert a sample number to a counter value.
+    ///
+    /// Sample numbers count frames, at the sampling frequency of the record
+    /// line; signals with several samples per frame have more samples per
+    /// second. The counter starts from the base counter at sample 0 and
+    /// advances at the counter frequency (which defaults to the sampling
+    /// frequency).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::Metadata;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let metadata = Metadata::from_record_line("100 2 360/72(1000) 650000")?;
+    ///
+    /// assert_eq!(metadata.sample_to_counter(720), 1144.0);
+    /// assert_eq!(metadata.counter_to_sample(1144.0), Some(720));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn sample_to_counter(&self, sample: u64) -> f64 {
+        (sample as f64 / self.sampling_frequency())
+            .mul_add(self.counter_frequency(), self.base_counter())
+    }
+
+    /// Convert a counter value to the nearest sample number.
+    ///
+    /// This is the inverse of [`sample_to_counter`](Self::sample_to_counter).
+    /// Returns `None` if the counter value is not finite, or is before the
+    /// first sample.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn counter_to_sample(&self, counter: f64) -> Option<u64> {
+        let sample = ((counter - self.base_counter()) * self.sampling_frequency()
+            / self.counter_frequency())
+        .round();
+        (sample.is_finite() && sample >= 0.0).then_some(sample as u64)
+    }
+}
+
+impl fmt::Display for Metadata {
+    /// Format the metadata as a header record line.
+    ///
+    /// This is the inverse of [`Metadata::from_record_line`]. Because the
+    /// optional fields are positional, an omitted field is written with its
+    /// default value when a later field is present: the sampling frequency
+    /// when the number of samples is given, and a base time of `00:00:00`
+    /// when only the base date is given. A base counter without a counter
+    /// frequency cannot be represented and is not written. Numbers are
+    /// written with [`format_number`](crate::number::format_number).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(num_segments) = self.num_segments {
+            write!(f, "/{num_segments}")?;
+        }
+        write!(f, " {}", self.num_signals)?;
+
+        if self.sampling_frequency.is_some()
+            || self.counter_frequency.is_some()
+            || self.num_samples.is_some()
+        {
+            write!(f, " {}", Number(self.sampling_frequency()))?;
+            if let Some(counter_frequency) = self.counter_frequency {
+                write!(f, "/{}", Number(counter_frequency))?;
+                if let Some(base_counter) = self.base_counter {
+                    write!(f, "({})", Number(base_counter))?;
+                }
+            }
+        }
+        if let Some(num_samples) = self.num_samples {
+            write!(f, " {num_samples}")?;
+        }
+        if self.base_time.is_some() || self.base_date.is_some() {
+            write!(f, " {}", self.base_time.unwrap_or_default())?;
+        }
+        if let Some(date) = self.base_date {
+            write!(f, " {date}")?;
+        }
+        Ok(())
     }
 }
@@ -3,11 +3,16 @@
 //! This module handles reading and parsing of WFDB header files (.hea).
 
 mod common;
+mod datetime;
 mod metadata;
 mod segment_info;
 mod signal_info;
+mod signal_type;
 
-pub use common::{Header, Specifications};
+pub use common::{Header, ParseOptions, Specifications};
+use common::{check_range, parse_finite};
+pub use datetime::{BaseDate, BaseTime};
 pub use metadata::Metadata;
 pub use segment_info::SegmentInfo;
 pub use signal_info::SignalInfo;
+pub use signal_type::SignalType;
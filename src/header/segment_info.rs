@@ -1,4 +1,7 @@
-use crate::{Error, Result};
+use std::fmt;
+
+use crate::limits::{MAX_RECORD_NAME_LEN, MAX_SAMPLES};
+use crate::{Error, HeaderField, Result};
 
 /// Segment specification from a WFDB header segment line.
 ///
@@ -11,6 +14,7 @@ use crate::{Error, Result};
 /// - `~ 1800` _null segment (alternative notation)_
 /// - `ecg_segment_01 360000` _named segment with 360000 samples_
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SegmentInfo {
     /// Name of the record that comprises this segment.
     ///
@@ -35,30 +39,50 @@ impl SegmentInfo {
         // First field: record name (required)
         let record_name = parts
             .next()
-            .ok_or_else(|| Error::InvalidHeader("Missing record name".to_string()))?
+            .ok_or_else(|| Error::header_field(HeaderField::SegmentName, "Missing record name"))?
             // After this, the record name is guaranteed to be non-empty
             .to_string();
 
         // Validate record name contains only letters, digits, underscores, or tilde
         if !Self::is_valid_record_name(&record_name) {
-            return Err(Error::InvalidHeader(format!(
-                "Invalid record name '{record_name}': must contain only letters, digits, underscores, or '~'"
-            )));
+            return Err(Error::header_field(
+                HeaderField::SegmentName,
+                format!(
+                    "Invalid record name '{record_name}': must contain only letters, digits, underscores, or '~'"
+                ),
+            ));
+        }
+        if record_name.len() > MAX_RECORD_NAME_LEN {
+            return Err(Error::header_field(
+                HeaderField::SegmentName,
+                format!(
+                    "Record name '{record_name}' is longer than {MAX_RECORD_NAME_LEN} characters"
+                ),
+            ));
         }
 
         // Second field: number of samples per signal (required)
-        let num_samples_str = parts
-            .next()
-            .ok_or_else(|| Error::InvalidHeader("Missing number of samples".to_string()))?;
+        let num_samples_str = parts.next().ok_or_else(|| {
+            Error::header_field(HeaderField::SegmentLength, "Missing number of samples")
+        })?;
 
-        let num_samples = num_samples_str
-            .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid number of samples: {e}")))?;
+        let num_samples = num_samples_str.parse().map_err(|e| {
+            Error::header_field(
+                HeaderField::SegmentLength,
+                format!("Invalid number of samples: {e}"),
+            )
+        })?;
+        if num_samples > MAX_SAMPLES {
+            return Err(Error::header_field(
+                HeaderField::SegmentLength,
+                format!("Number of samples {num_samples} exceeds the maximum of {MAX_SAMPLES}"),
+            ));
+        }
 
         // Check for extra fields
         if parts.next().is_some() {
             return Err(Error::InvalidHeader(
-                "Extra fields found in segment specification line".to_string(),
+                "Extra fields found in segment specification line".into(),
             ));
         }
 
@@ -104,3 +128,10 @@ impl SegmentInfo {
         self.record_name == "~"
     }
 }
+
+impl fmt::Display for SegmentInfo {
+    /// Format the segment specification as a header segment line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.record_name, self.num_samples)
+    }
+}
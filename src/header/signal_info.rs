@@ -1,4 +1,10 @@
-use crate::{Error, Result, Sample, SignalFormat};
+use std::fmt;
+
+use super::{SignalType, check_range, parse_finite};
+use crate::limits::{MAX_DESCRIPTION_LEN, MAX_SAMPLES_PER_FRAME, MAX_UNITS_LEN, PLAUSIBLE_GAIN};
+use crate::number::Number;
+use crate::signal::SignalScale;
+use crate::{Error, HeaderError, HeaderField, Result, Sample, SignalFormat};
 
 /// Parsed format field components.
 ///
@@ -15,7 +21,7 @@ struct OptionalFields {
     adc_resolution: Option<u8>,
     adc_zero: Option<i32>,
     initial_value: Option<Sample>,
-    checksum: Option<i32>,
+    checksum: Option<i16>,
     block_size: Option<i32>,
     description: Option<String>,
 }
@@ -60,6 +66,7 @@ enum ParseState {
 /// - `sig.dat 16x2:100+512 200(500)/uV 12 2048 0 0 0 Channel A`
 ///   _with samples per frame, skew, byte offset, and full ADC specifications_
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SignalInfo {
     /// Name of the file containing the signal samples.
     pub file_name: String,
@@ -72,6 +79,8 @@ pub struct SignalInfo {
     /// Byte offset from beginning of file to sample 0.
     pub byte_offset: Option<u64>,
     /// ADC gain in ADC units per physical unit.
+    ///
+    /// A gain of 0 marks an uncalibrated signal (see [`SignalInfo::is_calibrated`]).
     pub adc_gain: Option<f64>,
     /// Baseline value in ADC units corresponding to 0 physical units.
     pub baseline: Option<i32>,
@@ -83,8 +92,11 @@ pub struct SignalInfo {
     pub adc_zero: Option<i32>,
     /// Initial sample value (for difference formats).
     pub initial_value: Option<Sample>,
-    /// Checksum of all samples.
-    pub checksum: Option<i32>,
+    /// 16-bit checksum of all samples.
+    ///
+    /// Headers write it either signed (`-1279`) or unsigned (`64257`); both
+    /// are stored as the same two's complement `i16`.
+    pub checksum: Option<i16>,
     /// Block size in bytes for special files (usually 0).
     pub block_size: Option<i32>,
     /// Human-readable description of the signal.
@@ -130,21 +142,34 @@ impl SignalInfo {
     ///
     /// # Errors
     ///
-    /// Will return an error if the format of the signal specification line is invalid.
+    /// Will return an error if the format of the signal specification line is
+    /// invalid, or the gain is outside its plausible range (see
+    /// [`limits`](crate::limits)).
     pub fn from_signal_line(line: &str) -> Result<Self> {
+        let signal = Self::parse_signal_line(line)?;
+        signal
+            .range_issues()
+            .into_iter()
+            .next()
+            .map_or(Ok(signal), |issue| Err(Error::InvalidHeader(issue)))
+    }
+
+    /// Parse a signal specification line, without checking the ranges of
+    /// its values.
+    pub(super) fn parse_signal_line(line: &str) -> Result<Self> {
         let line = line.trim();
         let mut parts = line.split_whitespace();
 
         // First field: file name (required)
         let file_name = parts
             .next()
-            .ok_or_else(|| Error::InvalidHeader("Missing file name".to_string()))?
+            .ok_or_else(|| Error::header_field(HeaderField::FileName, "Missing file name"))?
             .to_string();
 
         // Second field: format (required), possibly with modifiers
         let format_field = parts
             .next()
-            .ok_or_else(|| Error::InvalidHeader("Missing format field".to_string()))?;
+            .ok_or_else(|| Error::header_field(HeaderField::Format, "Missing format field"))?;
 
         let (format, samples_per_frame, skew, byte_offset) =
             Self::parse_format_field(format_field)?;
@@ -185,50 +210,62 @@ impl SignalInfo {
         // Extract byte_offset (if present, marked with '+')
         if let Some(plus_pos) = format_str.find('+') {
             let offset_str = &format_str[plus_pos + 1..];
-            byte_offset = Some(
-                offset_str
-                    .parse()
-                    .map_err(|e| Error::InvalidHeader(format!("Invalid byte offset: {e}")))?,
-            );
+            byte_offset = Some(offset_str.parse().map_err(|e| {
+                Error::header_field(HeaderField::ByteOffset, format!("Invalid byte offset: {e}"))
+            })?);
             format_str = &format_str[..plus_pos];
         }
 
         // Extract skew (if present, marked with ':')
         if let Some(colon_pos) = format_str.find(':') {
             let skew_str = &format_str[colon_pos + 1..];
-            skew = Some(
-                skew_str
-                    .parse()
-                    .map_err(|e| Error::InvalidHeader(format!("Invalid skew: {e}")))?,
-            );
+            skew = Some(skew_str.parse().map_err(|e| {
+                Error::header_field(HeaderField::Skew, format!("Invalid skew: {e}"))
+            })?);
             format_str = &format_str[..colon_pos];
         }
 
         // Extract samples_per_frame (if present, marked with 'x')
         if let Some(x_pos) = format_str.find('x') {
             let spf_str = &format_str[x_pos + 1..];
-            samples_per_frame =
-                Some(spf_str.parse().map_err(|e| {
-                    Error::InvalidHeader(format!("Invalid samples per frame: {e}"))
-                })?);
+            samples_per_frame = Some(spf_str.parse().map_err(|e| {
+                Error::header_field(
+                    HeaderField::SamplesPerFrame,
+                    format!("Invalid samples per frame: {e}"),
+                )
+            })?);
             if let Some(spf) = samples_per_frame
                 && spf == 0
             {
-                return Err(Error::InvalidHeader(
-                    "Samples per frame must be greater than zero".to_string(),
+                return Err(Error::header_field(
+                    HeaderField::SamplesPerFrame,
+                    "Samples per frame must be greater than zero",
+                ));
+            }
+            if let Some(spf) = samples_per_frame
+                && spf > MAX_SAMPLES_PER_FRAME
+            {
+                return Err(Error::header_field(
+                    HeaderField::SamplesPerFrame,
+                    format!(
+                        "Samples per frame {spf} exceeds the maximum of {MAX_SAMPLES_PER_FRAME}"
+                    ),
                 ));
             }
             format_str = &format_str[..x_pos];
         }
 
         // Parse the base format code
-        let format_code: u16 = format_str
-            .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid format code: {e}")))?;
+        let format_code: u16 = format_str.parse().map_err(|e| {
+            Error::header_field(HeaderField::Format, format!("Invalid format code: {e}"))
+        })?;
 
         // Convert to SignalFormat enum
         let format = SignalFormat::try_from(format_code).map_err(|_| {
-            Error::InvalidHeader(format!("Unsupported signal format: {format_code}"))
+            Error::header_field(
+                HeaderField::Format,
+                format!("Unsupported signal format: {format_code}"),
+            )
         })?;
 
         Ok((format, samples_per_frame, skew, byte_offset))
@@ -292,7 +329,7 @@ impl SignalInfo {
                     state = ParseState::AfterBlockSize;
                 }
                 FieldType::Description => {
-                    description = Some(Self::join_description(&fields[field_idx..]));
+                    description = Some(Self::join_description(&fields[field_idx..])?);
                     break;
                 }
             }
@@ -319,17 +356,26 @@ impl SignalInfo {
     fn detect_field_type(field: &str, state: ParseState) -> Result<FieldType> {
         match state {
             ParseState::Start => {
-                // Try to detect gain field (has '/' or '(' or is valid positive float)
+                // Try to detect gain field (has '/' or '(' or is valid non-negative float)
                 if field.contains('/') || field.contains('(') {
-                    // Check if it's a valid gain field
-                    if Self::parse_gain_field(field).is_ok() {
+                    // Check if it's a valid gain field (non-finite gains and
+                    // overlong units are still detected as gain so that
+                    // parsing rejects them)
+                    if Self::parse_gain_field(field).is_ok()
+                        || Self::gain_prefix(field).is_some_and(|g| {
+                            !g.is_finite()
+                                || field
+                                    .split_once('/')
+                                    .is_some_and(|(_, units)| units.len() > MAX_UNITS_LEN)
+                        })
+                    {
                         Ok(FieldType::Gain)
                     } else {
                         Ok(FieldType::Description)
                     }
                 } else if let Ok(val) = field.parse::<f64>() {
-                    // Positive float is gain, zero or negative is block_size
-                    if val > 0.0 {
+                    // Non-negative (or non-finite) float is gain, negative is block_size
+                    if val >= 0.0 || !val.is_finite() {
                         Ok(FieldType::Gain)
                     } else if field.parse::<i32>().is_ok() {
                         Ok(FieldType::BlockSize)
@@ -348,45 +394,50 @@ impl SignalInfo {
                 if field.parse::<u8>().is_ok() {
                     Ok(FieldType::Resolution)
                 } else {
-                    Err(Error::InvalidHeader(format!(
-                        "Expected ADC resolution after gain, found '{field}'"
-                    )))
+                    Err(Error::header_field(
+                        HeaderField::AdcResolution,
+                        format!("Expected ADC resolution after gain, found '{field}'"),
+                    ))
                 }
             }
             ParseState::AfterResolution => {
                 if field.parse::<i32>().is_ok() {
                     Ok(FieldType::AdcZero)
                 } else {
-                    Err(Error::InvalidHeader(format!(
-                        "Expected ADC zero after resolution, found '{field}'"
-                    )))
+                    Err(Error::header_field(
+                        HeaderField::AdcZero,
+                        format!("Expected ADC zero after resolution, found '{field}'"),
+                    ))
                 }
             }
             ParseState::AfterZero => {
                 if field.parse::<i32>().is_ok() {
                     Ok(FieldType::InitialValue)
                 } else {
-                    Err(Error::InvalidHeader(format!(
-                        "Expected initial value after ADC zero, found '{field}'"
-                    )))
+                    Err(Error::header_field(
+                        HeaderField::InitialValue,
+                        format!("Expected initial value after ADC zero, found '{field}'"),
+                    ))
                 }
             }
             ParseState::AfterInitial => {
                 if field.parse::<i32>().is_ok() {
                     Ok(FieldType::Checksum)
                 } else {
-                    Err(Error::InvalidHeader(format!(
-                        "Expected checksum after initial value, found '{field}'"
-                    )))
+                    Err(Error::header_field(
+                        HeaderField::Checksum,
+                        format!("Expected checksum after initial value, found '{field}'"),
+                    ))
                 }
             }
             ParseState::AfterChecksum => {
                 if field.parse::<i32>().is_ok() {
                     Ok(FieldType::BlockSize)
                 } else {
-                    Err(Error::InvalidHeader(format!(
-                        "Expected block size after checksum, found '{field}'"
-                    )))
+                    Err(Error::header_field(
+                        HeaderField::BlockSize,
+                        format!("Expected block size after checksum, found '{field}'"),
+                    ))
                 }
             }
             ParseState::AfterBlockSize => Ok(FieldType::Description),
@@ -395,37 +446,52 @@ impl SignalInfo {
 
     /// Parse ADC resolution field.
     fn parse_resolution(field: &str) -> Result<u8> {
-        field
-            .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid ADC resolution: {e}")))
+        field.parse().map_err(|e| {
+            Error::header_field(
+                HeaderField::AdcResolution,
+                format!("Invalid ADC resolution: {e}"),
+            )
+        })
     }
 
     /// Parse ADC zero field.
     fn parse_adc_zero(field: &str) -> Result<i32> {
-        field
-            .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid ADC zero: {e}")))
+        field.parse().map_err(|e| {
+            Error::header_field(HeaderField::AdcZero, format!("Invalid ADC zero: {e}"))
+        })
     }
 
     /// Parse initial value field.
     fn parse_initial_value(field: &str) -> Result<Sample> {
-        field
-            .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid initial value: {e}")))
+        field.parse().map_err(|e| {
+            Error::header_field(
+                HeaderField::InitialValue,
+                format!("Invalid initial value: {e}"),
+            )
+        })
     }
 
-    /// Parse checksum field.
-    fn parse_checksum(field: &str) -> Result<i32> {
-        field
-            .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid checksum: {e}")))
+    /// Parse checksum field, accepting signed and unsigned 16-bit values.
+    fn parse_checksum(field: &str) -> Result<i16> {
+        let value: i32 = field.parse().map_err(|e| {
+            Error::header_field(HeaderField::Checksum, format!("Invalid checksum: {e}"))
+        })?;
+        if !(i32::from(i16::MIN)..=i32::from(u16::MAX)).contains(&value) {
+            return Err(Error::header_field(
+                HeaderField::Checksum,
+                format!("Checksum {value} is not a 16-bit value"),
+            ));
+        }
+        // Unsigned values wrap around to the equivalent signed value
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(value as i16)
     }
 
     /// Parse block size field.
     fn parse_block_size(field: &str) -> Result<i32> {
-        field
-            .parse()
-            .map_err(|e| Error::InvalidHeader(format!("Invalid block size: {e}")))
+        field.parse().map_err(|e| {
+            Error::header_field(HeaderField::BlockSize, format!("Invalid block size: {e}"))
+        })
     }
 
     /// Parse ADC gain field: `gain[(baseline)][/units]`
@@ -437,7 +503,16 @@ impl SignalInfo {
         if let Some(slash_pos) = field.find('/') {
             let units_str = &field[slash_pos + 1..];
             if units_str.is_empty() {
-                return Err(Error::InvalidHeader("Units field is empty".to_string()));
+                return Err(Error::header_field(
+                    HeaderField::Units,
+                    "Units field is empty",
+                ));
+            }
+            if units_str.len() > MAX_UNITS_LEN {
+                return Err(Error::header_field(
+                    HeaderField::Units,
+                    format!("Units '{units_str}' are longer than {MAX_UNITS_LEN} characters"),
+                ));
             }
             units = Some(units_str.to_string());
             gain_part = &field[..slash_pos];
@@ -448,44 +523,68 @@ impl SignalInfo {
         // Extract baseline (if present, surrounded by parentheses)
         if let Some(paren_start) = gain_part.find('(') {
             let paren_end = gain_part.find(')').ok_or_else(|| {
-                Error::InvalidHeader("Missing closing parenthesis in baseline".to_string())
+                Error::header_field(
+                    HeaderField::Baseline,
+                    "Missing closing parenthesis in baseline",
+                )
             })?;
 
-            baseline = Some(
-                gain_part[paren_start + 1..paren_end]
-                    .parse()
-                    .map_err(|e| Error::InvalidHeader(format!("Invalid baseline value: {e}")))?,
-            );
+            baseline = Some(gain_part[paren_start + 1..paren_end].parse().map_err(|e| {
+                Error::header_field(
+                    HeaderField::Baseline,
+                    format!("Invalid baseline value: {e}"),
+                )
+            })?);
 
             gain_part = &gain_part[..paren_start];
         }
 
         // Parse the gain value
         if gain_part.is_empty() {
-            return Err(Error::InvalidHeader("ADC gain is empty".to_string()));
+            return Err(Error::header_field(HeaderField::Gain, "ADC gain is empty"));
         }
 
-        let gain = Some(
-            gain_part
-                .parse()
-                .map_err(|e| Error::InvalidHeader(format!("Invalid ADC gain: {e}")))?,
-        );
+        let gain = Some(parse_finite(gain_part, "ADC gain", HeaderField::Gain)?);
 
-        // Validate gain is positive
+        // Validate gain is not negative (zero means uncalibrated)
         if let Some(g) = gain
-            && g <= 0.0
+            && g < 0.0
         {
-            return Err(Error::InvalidHeader(format!(
-                "ADC gain must be greater than zero, got {g}"
-            )));
+            return Err(Error::header_field(
+                HeaderField::Gain,
+                format!("ADC gain must not be negative, got {g}"),
+            ));
         }
 
         Ok((gain, baseline, units))
     }
 
+    /// Parse the numeric part of a gain field (before any baseline or units).
+    fn gain_prefix(field: &str) -> Option<f64> {
+        let end = field.find(['(', '/']).unwrap_or(field.len());
+        field[..end].parse().ok()
+    }
+
     /// Join remaining fields into a description string.
-    fn join_description(fields: &[&str]) -> String {
-        fields.join(" ")
+    fn join_description(fields: &[&str]) -> Result<String> {
+        let description = fields.join(" ");
+        if description.len() > MAX_DESCRIPTION_LEN {
+            return Err(Error::header_field(
+                HeaderField::Description,
+                format!("Signal description is longer than {MAX_DESCRIPTION_LEN} characters"),
+            ));
+        }
+        Ok(description)
+    }
+
+    /// Find the values of the signal specification line outside their
+    /// plausible ranges.
+    pub(super) fn range_issues(&self) -> Vec<HeaderError> {
+        self.adc_gain
+            .filter(|&gain| gain != 0.0)
+            .and_then(|gain| check_range(gain, &PLAUSIBLE_GAIN, "ADC gain", HeaderField::Gain))
+            .into_iter()
+            .collect()
     }
 
     // [Accessors]
@@ -535,6 +634,22 @@ impl SignalInfo {
         self.adc_gain.unwrap_or(Self::DEFAULT_ADC_GAIN)
     }
 
+    /// Check if the signal is calibrated.
+    ///
+    /// A gain of 0 in the header marks an uncalibrated signal: its samples
+    /// have no known relation to physical units, so physical conversions
+    /// return raw ADC values.
+    #[must_use]
+    pub fn is_calibrated(&self) -> bool {
+        self.adc_gain() > 0.0
+    }
+
+    /// Get the conversion between ADC values and physical units.
+    #[must_use]
+    pub fn scale(&self) -> SignalScale {
+        SignalScale::from(self)
+    }
+
     /// Get the baseline value of the signal.
     ///
     /// Fallback to the ADC zero value when omitted.
@@ -584,9 +699,9 @@ impl SignalInfo {
         self.initial_value.unwrap_or_else(|| self.adc_zero())
     }
 
-    /// Get the checksum of the signal.
+    /// Get the checksum of the signal, in its signed form.
     #[must_use]
-    pub const fn checksum(&self) -> Option<i32> {
+    pub const fn checksum(&self) -> Option<i16> {
         self.checksum
     }
 
@@ -603,4 +718,76 @@ impl SignalInfo {
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
+
+    /// Guess the physiologic type of the signal from its description and
+    /// units.
+    ///
+    /// See [`SignalType::classify`] for the conventions recognized.
+    #[must_use]
+    pub fn signal_type(&self) -> SignalType {
+        SignalType::classify(self.description(), self.units())
+    }
+}
+
+impl fmt::Display for SignalInfo {
+    /// Format the signal specification as a header signal line.
+    ///
+    /// This is the inverse of [`SignalInfo::from_signal_line`]. Because the
+    /// optional fields are positional, every field before the last present
+    /// one is written, using its default value when omitted. In particular,
+    /// a description is always preceded by all the ADC fields. The gain is
+    /// written with [`format_number`](crate::number::format_number).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.file_name, u16::from(self.format))?;
+        if let Some(samples_per_frame) = self.samples_per_frame {
+            write!(f, "x{samples_per_frame}")?;
+        }
+        if let Some(skew) = self.skew {
+            write!(f, ":{skew}")?;
+        }
+        if let Some(byte_offset) = self.byte_offset {
+            write!(f, "+{byte_offset}")?;
+        }
+
+        // Number of positional fields to write, up to the last present one
+        let present = [
+            self.adc_gain.is_some() || self.baseline.is_some() || self.units.is_some(),
+            self.adc_resolution.is_some(),
+            self.adc_zero.is_some(),
+            self.initial_value.is_some(),
+            self.checksum.is_some(),
+            self.block_size.is_some(),
+            self.description.is_some(),
+        ];
+        let count = present.iter().rposition(|&p| p).map_or(0, |i| i + 1);
+
+        if count > 0 {
+            write!(f, " {}", Number(self.adc_gain()))?;
+            if let Some(baseline) = self.baseline {
+                write!(f, "({baseline})")?;
+            }
+            if let Some(units) = &self.units {
+                write!(f, "/{units}")?;
+            }
+        }
+        if count > 1 {
+            write!(f, " {}", self.adc_resolution())?;
+        }
+        if count > 2 {
+            write!(f, " {}", self.adc_zero())?;
+        }
+        if count > 3 {
+            write!(f, " {}", self.initial_value())?;
+        }
+        if count > 4 {
+            write!(f, " {}", self.checksum.unwrap_or(0))?;
+        }
+        if count > 5 {
+            write!(f, " {}", self.block_size())?;
+        }
+        if let Some(description) = &self.description {
+            write!(f, " {description}")?;
+        }
+        Ok(())
+    }
 }
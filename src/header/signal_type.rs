@@ -0,0 +1,121 @@
+/// Physiologic type of a signal, guessed from its header.
+///
+/// See [`SignalInfo::signal_type()`](crate::SignalInfo::signal_type).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignalType {
+    /// Electrocardiogram (e.g. `MLII`, `V5`, `ECG`).
+    Ecg,
+    /// Arterial blood pressure (e.g. `ABP`, `ART`).
+    Abp,
+    /// Photoplethysmogram (e.g. `PLETH`, `PPG`).
+    Ppg,
+    /// Respiration (e.g. `RESP`, `Thor`, `Airflow`).
+    Resp,
+    /// Electroencephalogram (e.g. `EEG Fpz-Cz`, `C3-A2`).
+    Eeg,
+    /// Oxygen saturation (e.g. `SpO2`, `SaO2`).
+    SpO2,
+    /// Any other or unrecognized signal.
+    Unknown,
+}
+
+/// Descriptions of ECG leads.
+const ECG_NAMES: &[&str] = &[
+    "ECG", "EKG", "I", "II", "III", "AVR", "AVL", "AVF", "V", "V1", "V2", "V3", "V4", "V5", "V6",
+    "MLI", "MLII", "MLIII", "ML5", "MV1", "MV2", "MV4", "MV5", "D3", "D4", "CM2", "CM4", "CM5",
+];
+
+/// Descriptions of arterial blood pressure signals.
+const ABP_NAMES: &[&str] = &["ABP", "ART", "AOBP", "AO", "BP", "IBP", "RADIAL", "FEM"];
+
+/// Descriptions of photoplethysmograms.
+const PPG_NAMES: &[&str] = &["PLETH", "PPG", "PLE"];
+
+/// Descriptions of respiration signals.
+const RESP_NAMES: &[&str] = &[
+    "RESP",
+    "RESPIRATION",
+    "THOR",
+    "THORAX",
+    "ABDO",
+    "ABDOMEN",
+    "ABD",
+    "AIRFLOW",
+    "FLOW",
+    "CHEST",
+];
+
+/// Descriptions of oxygen saturation signals.
+const SPO2_NAMES: &[&str] = &["SPO2", "SAO2", "SO2", "O2SAT", "SAT"];
+
+/// Names of EEG electrodes (10-20 system), as used in bipolar derivations.
+const EEG_ELECTRODES: &[&str] = &[
+    "FP1", "FP2", "FPZ", "AF3", "AF4", "F3", "F4", "F7", "F8", "FZ", "FC1", "FC2", "FC5", "FC6",
+    "C3", "C4", "CZ", "CP1", "CP2", "CP5", "CP6", "T3", "T4", "T5", "T6", "T7", "T8", "P3", "P4",
+    "P7", "P8", "PZ", "PO3", "PO4", "O1", "O2", "OZ", "A1", "A2", "M1", "M2",
+];
+
+impl SignalType {
+    /// Guess the type of a signal from its description and units.
+    ///
+    /// The description is matched first, case insensitively, against the
+    /// names used across `PhysioNet` databases (e.g. `MLII`, `ABP`,
+    /// `PLETH`, `EEG Fpz-Cz`). A description of several words matches if any
+    /// of its words does, and numbered ECG channels (`ECG1`) are recognized.
+    /// Signals without a recognized description fall back on their units:
+    /// `mmHg` for blood pressure, `uV` for EEG and `%` for oxygen saturation.
+    /// `mV` alone is too common to decide on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::header::SignalType;
+    ///
+    /// assert_eq!(SignalType::classify(Some("MLII"), "mV"), SignalType::Ecg);
+    /// assert_eq!(SignalType::classify(Some("EEG Fpz-Cz"), "uV"), SignalType::Eeg);
+    /// assert_eq!(SignalType::classify(None, "mmHg"), SignalType::Abp);
+    /// assert_eq!(SignalType::classify(Some("Temp"), "degC"), SignalType::Unknown);
+    /// ```
+    #[must_use]
+    pub fn classify(description: Option<&str>, units: &str) -> Self {
+        let words: Vec<String> = description
+            .unwrap_or_default()
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '_')
+            .filter(|word| !word.is_empty())
+            .map(str::to_ascii_uppercase)
+            .collect();
+        let any_word = |names: &[&str]| words.iter().any(|word| names.contains(&word.as_str()));
+
+        if any_word(SPO2_NAMES) {
+            Self::SpO2
+        } else if any_word(&["EEG"]) || words.iter().any(|word| is_eeg_derivation(word)) {
+            Self::Eeg
+        } else if any_word(ECG_NAMES)
+            || words
+                .iter()
+                .any(|word| word.starts_with("ECG") || word.starts_with("EKG"))
+        {
+            Self::Ecg
+        } else if any_word(ABP_NAMES) {
+            Self::Abp
+        } else if any_word(PPG_NAMES) {
+            Self::Ppg
+        } else if any_word(RESP_NAMES) {
+            Self::Resp
+        } else {
+            match units.trim() {
+                "mmHg" => Self::Abp,
+                "uV" | "\u{b5}V" | "\u{3bc}V" => Self::Eeg,
+                "%" => Self::SpO2,
+                _ => Self::Unknown,
+            }
+        }
+    }
+}
+
+/// Check if a word is a bipolar EEG derivation such as `FPZ-CZ`.
+fn is_eeg_derivation(word: &str) -> bool {
+    word.split_once('-').is_some_and(|(first, second)| {
+        EEG_ELECTRODES.contains(&first) && EEG_ELECTRODES.contains(&second)
+    })
+}
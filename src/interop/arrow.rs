@@ -0,0 +1,262 @@
+//! Conversion of signals to Apache Arrow record batches and Parquet files.
+//!
+//! Available with the `arrow` feature. The frames of a record become rows:
+//! a `sample` column with the sample number, a `time` column with the
+//! elapsed time in seconds, and one column of physical values per signal.
+//! Invalid samples (and the gaps of multi-segment records) are nulls.
+//! Columns are named after the signal descriptions (`signal_<n>` when a
+//! description is missing) and carry their units in the `units` field
+//! metadata; the schema carries the record name and sampling frequency.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use wfdb::Record;
+//! use wfdb::interop::arrow::{self, ArrowOptions};
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let record = Record::open("data/100")?;
+//!
+//! let batches = record.to_arrow()?;
+//! println!("{} rows", batches.iter().map(|b| b.num_rows()).sum::<usize>());
+//!
+//! arrow::write_parquet(&record, "100.parquet", &ArrowOptions::default())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, UInt64Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+
+use crate::number::Number;
+use crate::record::{PhysicalReader, RecordClock};
+use crate::{Error, Record, Result, SignalInfo};
+
+/// Name of the column of sample numbers.
+pub const SAMPLE_COLUMN: &str = "sample";
+
+/// Name of the column of elapsed times, in seconds.
+pub const TIME_COLUMN: &str = "time";
+
+/// Default number of frames per record batch.
+pub const DEFAULT_BATCH_SIZE: usize = 65_536;
+
+/// Options of [`batches`] and [`write_parquet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrowOptions {
+    /// Number of frames per record batch (the last batch may be shorter).
+    pub batch_size: usize,
+}
+
+impl Default for ArrowOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+}
+
+impl ArrowOptions {
+    /// Put the given number of frames in each record batch.
+    #[must_use]
+    pub const fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+}
+
+/// Iterator over the record batches of a record.
+///
+/// Created by [`batches`]. Frames are read one batch at a time, so a record
+/// of any length can be converted in bounded memory.
+pub struct RecordBatches {
+    /// Reader of the frames.
+    reader: PhysicalReader,
+    /// Clock giving the time of each frame.
+    clock: RecordClock,
+    /// Schema of the batches.
+    schema: SchemaRef,
+    /// Frames per batch.
+    batch_size: usize,
+    /// Sample number of the next frame.
+    next_sample: u64,
+    /// Whether the end of the record (or an error) has been reached.
+    done: bool,
+}
+
+impl RecordBatches {
+    /// Get the schema of the batches.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    /// Read the next batch, or `None` at the end of the record.
+    fn read_batch(&mut self) -> Result<Option<RecordBatch>> {
+        let num_channels = self.schema.fields().len() - 2;
+        let mut columns = vec![Vec::with_capacity(self.batch_size); num_channels];
+        let mut count = 0;
+        while count < self.batch_size {
+            let Some(frame) = self.reader.read_frame()? else {
+                break;
+            };
+            for (column, value) in columns.iter_mut().zip(frame) {
+                column.push((!value.is_nan()).then_some(value));
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return Ok(None);
+        }
+
+        let samples: Vec<u64> = (self.next_sample..).take(count).collect();
+        let times: Vec<f64> = samples
+            .iter()
+            .map(|&sample| self.clock.elapsed(sample))
+            .collect();
+        self.next_sample += count as u64;
+
+        let mut arrays: Vec<ArrayRef> = vec![
+            Arc::new(UInt64Array::from(samples)),
+            Arc::new(Float64Array::from(times)),
+        ];
+        arrays.extend(
+            columns
+                .into_iter()
+                .map(|column| Arc::new(Float64Array::from(column)) as ArrayRef),
+        );
+        RecordBatch::try_new(self.schema(), arrays)
+            .map(Some)
+            .map_err(|e| Error::InvalidData(format!("Cannot build record batch: {e}")))
+    }
+}
+
+impl Iterator for RecordBatches {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.read_batch() {
+            Ok(Some(batch)) => Some(Ok(batch)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Read the frames of a record as Arrow record batches.
+///
+/// Multi-segment records are read across segments, with the gains of each
+/// segment and the signals of the layout (or first) segment.
+///
+/// # Errors
+///
+/// Returns an error if the batch size is zero or the record's readers
+/// cannot be created. Errors reading frames are returned by the iterator,
+/// which then ends.
+pub fn batches(record: &Record, options: &ArrowOptions) -> Result<RecordBatches> {
+    if options.batch_size == 0 {
+        return Err(Error::InvalidData(
+            "Record batches must hold at least one frame".to_string(),
+        ));
+    }
+    let (reader, signals) = PhysicalReader::open(record)?;
+    Ok(RecordBatches {
+        reader,
+        clock: record.clock(),
+        schema: schema(record, &signals),
+        batch_size: options.batch_size,
+        next_sample: 0,
+        done: false,
+    })
+}
+
+/// Write the frames of a record as a Parquet file, returning the number of
+/// frames written.
+///
+/// The file holds the record batches of [`batches`], with the schema
+/// metadata.
+///
+/// # Errors
+///
+/// Returns an error if the record cannot be read (see [`batches`]) or the
+/// file cannot be written.
+pub fn write_parquet<P: AsRef<Path>>(
+    record: &Record,
+    path: P,
+    options: &ArrowOptions,
+) -> Result<u64> {
+    let batches = batches(record, options)?;
+    let file = File::create(path)?;
+    let mut writer =
+        ArrowWriter::try_new(file, batches.schema(), None).map_err(|e| parquet_error(&e))?;
+    let mut num_frames = 0;
+    for batch in batches {
+        let batch = batch?;
+        num_frames += batch.num_rows() as u64;
+        writer.write(&batch).map_err(|e| parquet_error(&e))?;
+    }
+    writer.close().map_err(|e| parquet_error(&e))?;
+    Ok(num_frames)
+}
+
+impl Record {
+    /// Read every frame of the record as Arrow record batches of
+    /// [`DEFAULT_BATCH_SIZE`] frames.
+    ///
+    /// See the [module documentation](crate::interop::arrow) for the columns, and
+    /// [`batches`] to stream long records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signal files or segment headers cannot be
+    /// read.
+    pub fn to_arrow(&self) -> Result<Vec<RecordBatch>> {
+        batches(self, &ArrowOptions::default())?.collect()
+    }
+}
+
+/// Build the schema of the batches of a record.
+fn schema(record: &Record, signals: &[SignalInfo]) -> SchemaRef {
+    let mut fields = vec![
+        Field::new(SAMPLE_COLUMN, DataType::UInt64, false),
+        Field::new(TIME_COLUMN, DataType::Float64, false),
+    ];
+    fields.extend(signals.iter().enumerate().map(|(index, signal)| {
+        let name = signal
+            .description()
+            .map_or_else(|| format!("signal_{index}"), ToString::to_string);
+        Field::new(name, DataType::Float64, true).with_metadata(HashMap::from([(
+            "units".to_string(),
+            signal.units().to_string(),
+        )]))
+    }));
+
+    let metadata = record.metadata();
+    Arc::new(Schema::new(fields).with_metadata(HashMap::from([
+        ("record".to_string(), metadata.name().to_string()),
+        (
+            "sampling_frequency".to_string(),
+            Number(metadata.sampling_frequency()).to_string(),
+        ),
+    ])))
+}
+
+/// Convert an error of the Parquet writer.
+fn parquet_error(error: &parquet::errors::ParquetError) -> Error {
+    Error::InvalidData(format!("Cannot write Parquet file: {error}"))
+}
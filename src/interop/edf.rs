@@ -0,0 +1,901 @@
+//! Export and import of EDF and EDF+ files.
+//!
+//! The [European Data Format](https://www.edfplus.info/specs/edf.html)
+//! stores 16-bit samples in data records of a fixed duration, with a linear
+//! mapping from a digital range to a physical range for each signal. Its
+//! EDF+ extension adds an `EDF Annotations` signal, which holds the start
+//! time of each data record. [`to_edf`] maps the gain, baseline and ADC
+//! range of WFDB signals to these ranges, as the `mit2edf` tool of the WFDB
+//! software package does, and [`read`] opens an EDF file as a record, as
+//! `edf2mit` does.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::header::{BaseDate, BaseTime, Specifications};
+use crate::number::Number;
+use crate::record::{ReadSeek, SignalSource};
+use crate::signal::INVALID_SAMPLE;
+use crate::{Error, Header, Metadata, Record, Result, Sample, SignalFormat, SignalInfo};
+
+/// Size in bytes of the fixed part of an EDF header, and of the part of each
+/// signal.
+const HEADER_BLOCK_SIZE: usize = 256;
+
+/// Offset of the number of data records in an EDF header.
+const NUM_RECORDS_OFFSET: u64 = 236;
+
+/// Widths of the fields of a signal in an EDF header: label, transducer,
+/// physical dimension, physical minimum and maximum, digital minimum and
+/// maximum, prefiltering, samples per data record and reserved.
+const SIGNAL_FIELD_WIDTHS: [usize; 10] = [16, 80, 8, 8, 8, 8, 8, 80, 8, 32];
+
+/// Label of the EDF+ annotation signals.
+const ANNOTATION_LABEL: &str = "EDF Annotations";
+
+/// Longest data record duration (in seconds) tried to hold a whole number of
+/// frames.
+const MAX_RECORD_DURATION: u32 = 60;
+
+/// Number of 2-byte samples of the `EDF Annotations` signal in each data
+/// record, enough for the time-keeping annotation.
+const ANNOTATION_SAMPLES: usize = 16;
+
+/// Month abbreviations of the EDF+ `Startdate` subfield.
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Variant of the European Data Format to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdfVariant {
+    /// Plain EDF.
+    Edf,
+    /// Continuous EDF+ (`EDF+C`), with an `EDF Annotations` signal holding
+    /// the start time of each data record.
+    #[default]
+    EdfPlus,
+}
+
+/// Options of [`to_edf`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdfOptions {
+    /// Variant of the format.
+    pub variant: EdfVariant,
+    /// Indices of the signals to export, in order (`None` exports every
+    /// signal).
+    pub signals: Option<Vec<usize>>,
+    /// Local patient identification field (`None` writes `X X X X` for
+    /// EDF+, and leaves it blank for EDF).
+    pub patient: Option<String>,
+    /// Local recording identification field (`None` writes
+    /// `Startdate dd-MMM-yyyy X X X` for EDF+, and the record name for EDF).
+    pub recording: Option<String>,
+}
+
+impl EdfOptions {
+    /// Write the given variant of the format.
+    #[must_use]
+    pub const fn with_variant(mut self, variant: EdfVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Export only the given signals, in this order.
+    #[must_use]
+    pub fn with_signals(mut self, signals: &[usize]) -> Self {
+        self.signals = Some(signals.to_vec());
+        self
+    }
+
+    /// Set the patient identification field.
+    ///
+    /// For EDF+, it should follow the subfields of the specification (code,
+    /// sex, birthdate and name, `X` when unknown).
+    #[must_use]
+    pub fn with_patient(mut self, patient: impl Into<String>) -> Self {
+        self.patient = Some(patient.into());
+        self
+    }
+
+    /// Set the recording identification field.
+    ///
+    /// For EDF+, it should follow the subfields of the specification
+    /// (`Startdate`, start date, administration code, technician and
+    /// equipment, `X` when unknown).
+    #[must_use]
+    pub fn with_recording(mut self, recording: impl Into<String>) -> Self {
+        self.recording = Some(recording.into());
+        self
+    }
+}
+
+/// A WFDB signal mapped to an EDF signal.
+struct EdfSignal<'a> {
+    /// Index of the signal in the record.
+    index: usize,
+    /// Specification of the signal.
+    info: &'a SignalInfo,
+    /// Right shift applied to ADC values wider than 16 bits.
+    shift: u32,
+    /// Offset subtracted after the shift, to fit the ADC range in 16 bits.
+    offset: i64,
+    /// Smallest digital value, also written for invalid samples.
+    digital_min: i16,
+    /// Largest digital value.
+    digital_max: i16,
+    /// Number of samples in each data record.
+    samples_per_record: usize,
+}
+
+impl<'a> EdfSignal<'a> {
+    /// Map the ADC range of a signal (given by its resolution and ADC zero)
+    /// to 16-bit digital values.
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(index: usize, info: &'a SignalInfo, frames_per_record: usize) -> Self {
+        let resolution = u32::from(info.adc_resolution().clamp(1, 32));
+        let shift = resolution.saturating_sub(16);
+        let half = 1i64 << (resolution - 1);
+        let zero = i64::from(info.adc_zero());
+        let low = (zero - half) >> shift;
+        let high = ((zero + half - 1) >> shift).min(low + i64::from(u16::MAX));
+        let offset = if low < i64::from(i16::MIN) || high > i64::from(i16::MAX) {
+            low - i64::from(i16::MIN)
+        } else {
+            0
+        };
+        Self {
+            index,
+            info,
+            shift,
+            offset,
+            digital_min: (low - offset) as i16,
+            digital_max: (high - offset) as i16,
+            samples_per_record: frames_per_record * info.samples_per_frame() as usize,
+        }
+    }
+
+    /// Convert an ADC value to a digital value, clamped to the digital range.
+    #[allow(clippy::cast_possible_truncation)]
+    fn digital(&self, sample: Sample) -> i16 {
+        if sample == INVALID_SAMPLE {
+            return self.digital_min;
+        }
+        let value = (i64::from(sample) >> self.shift) - self.offset;
+        value.clamp(i64::from(self.digital_min), i64::from(self.digital_max)) as i16
+    }
+
+    /// Convert a digital value to physical units.
+    #[allow(clippy::cast_precision_loss)]
+    fn physical(&self, digital: i16) -> f64 {
+        let adc = ((i64::from(digital) + self.offset) << self.shift) as f64;
+        if self.info.is_calibrated() {
+            (adc - f64::from(self.info.baseline())) / self.info.adc_gain()
+        } else {
+            adc
+        }
+    }
+
+    /// Get the header fields of the signal, in the order of
+    /// [`SIGNAL_FIELD_WIDTHS`].
+    fn fields(&self) -> Result<Vec<String>> {
+        let label = self
+            .info
+            .description()
+            .map_or_else(|| format!("signal {}", self.index), str::to_string);
+        Ok(vec![
+            label,
+            String::new(),
+            self.info.units().to_string(),
+            number_field(self.physical(self.digital_min))?,
+            number_field(self.physical(self.digital_max))?,
+            self.digital_min.to_string(),
+            self.digital_max.to_string(),
+            String::new(),
+            self.samples_per_record.to_string(),
+            String::new(),
+        ])
+    }
+}
+
+/// Export signals of a record as an EDF or EDF+ file, like `mit2edf`.
+///
+/// Each signal keeps its samples per frame, and data records last one
+/// second, or the shortest whole number of seconds holding a whole number of
+/// frames. The digital range of a signal is its ADC range (given by the ADC
+/// resolution and zero), shifted to fit 16 bits, and narrowed for signals
+/// wider than 16 bits. The physical range follows from the gain and
+/// baseline; uncalibrated signals keep their ADC values. Samples outside
+/// the ADC range are clamped, and invalid samples are written as the
+/// digital minimum, which also pads the last data record.
+///
+/// The start date and time come from the header's base date and time
+/// (`01.01.85 00.00.00` when omitted). With [`EdfVariant::EdfPlus`], an
+/// `EDF Annotations` signal is appended with the start time of each data
+/// record. Annotations are not exported yet.
+///
+/// Returns the number of data records written.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The record is a multi-segment record
+/// - A signal index is out of bounds
+/// - No data record of at most 60 seconds holds a whole number of frames
+/// - A physical range does not fit in the 8 characters of its field
+/// - The signal files cannot be read, or the output file cannot be written
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+/// use wfdb::interop::edf::{self, EdfOptions};
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// edf::to_edf(&record, "100.edf", &EdfOptions::default())?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_edf<P: AsRef<Path>>(record: &Record, path: P, options: &EdfOptions) -> Result<u64> {
+    if record.is_multi_segment() {
+        return Err(Error::InvalidData(
+            "EDF export of multi-segment records is not yet supported".into(),
+        ));
+    }
+    let infos = record.signal_info().unwrap_or_default();
+    let selected = options
+        .signals
+        .clone()
+        .unwrap_or_else(|| (0..infos.len()).collect());
+    if let Some(&index) = selected.iter().find(|&&i| i >= infos.len()) {
+        return Err(Error::InvalidData(format!(
+            "Signal index {index} out of bounds (record has {} signals)",
+            infos.len()
+        )));
+    }
+
+    let (duration, frames_per_record) = record_duration(record.metadata().sampling_frequency())?;
+    let signals: Vec<EdfSignal> = selected
+        .iter()
+        .map(|&index| EdfSignal::new(index, &infos[index], frames_per_record))
+        .collect();
+    let header = edf_header(record, options, &signals, duration)?;
+
+    let path = path.as_ref();
+    let file = File::create(path).map_err(|e| {
+        Error::InvalidPath(format!(
+            "Failed to create export file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let mut writer = BufWriter::new(file);
+    writer.write_all(&header)?;
+    let num_records = write_data_records(
+        record,
+        &mut writer,
+        &signals,
+        frames_per_record,
+        (options.variant == EdfVariant::EdfPlus).then_some(duration),
+    )?;
+
+    // The number of data records is only known once they are written
+    writer.seek(SeekFrom::Start(NUM_RECORDS_OFFSET))?;
+    writer.write_all(text_field(&num_records.to_string(), 8).as_bytes())?;
+    writer.flush()?;
+    Ok(num_records)
+}
+
+/// Find the duration (in seconds) of the shortest data record holding a
+/// whole number of frames, and that number.
+fn record_duration(frame_frequency: f64) -> Result<(u32, usize)> {
+    (1..=MAX_RECORD_DURATION)
+        .find_map(|duration| {
+            let frames = frame_frequency * f64::from(duration);
+            let rounded = frames.round();
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            ((rounded - frames).abs() < 1e-6 && rounded >= 1.0)
+                .then_some((duration, rounded as usize))
+        })
+        .ok_or_else(|| {
+            Error::InvalidData(format!(
+                "No EDF data record of at most {MAX_RECORD_DURATION} s holds a whole number of frames at {frame_frequency} Hz"
+            ))
+        })
+}
+
+/// Build the EDF header, with a placeholder number of data records.
+fn edf_header(
+    record: &Record,
+    options: &EdfOptions,
+    signals: &[EdfSignal],
+    duration: u32,
+) -> Result<Vec<u8>> {
+    let plus = options.variant == EdfVariant::EdfPlus;
+    let metadata = record.metadata();
+    let date = metadata
+        .base_date_ymd()
+        .filter(|&(year, _, _)| (1985..=2084).contains(&year));
+    let num_signals = signals.len() + usize::from(plus);
+    if num_signals > 9999 {
+        return Err(Error::InvalidData(format!(
+            "{num_signals} signals do not fit in an EDF header"
+        )));
+    }
+
+    let patient = options.patient.clone().unwrap_or_else(|| {
+        if plus {
+            "X X X X".to_string()
+        } else {
+            String::new()
+        }
+    });
+    let recording = options.recording.clone().unwrap_or_else(|| {
+        if !plus {
+            metadata.name().to_string()
+        } else if let Some((year, month, day)) = date {
+            format!(
+                "Startdate {day:02}-{}-{year} X X X",
+                MONTHS[usize::from(month.clamp(1, 12)) - 1]
+            )
+        } else {
+            "Startdate X X X X".to_string()
+        }
+    });
+    let (year, month, day) = date.unwrap_or((1985, 1, 1));
+    let (hours, minutes, seconds) = metadata.base_time_hms().unwrap_or((0, 0, 0));
+
+    let mut header = String::with_capacity(HEADER_BLOCK_SIZE * (num_signals + 1));
+    header.push_str(&text_field("0", 8));
+    header.push_str(&text_field(&patient, 80));
+    header.push_str(&text_field(&recording, 80));
+    let _ = write!(header, "{day:02}.{month:02}.{:02}", year % 100);
+    let _ = write!(header, "{hours:02}.{minutes:02}.{seconds:02}");
+    header.push_str(&text_field(
+        &(HEADER_BLOCK_SIZE * (num_signals + 1)).to_string(),
+        8,
+    ));
+    header.push_str(&text_field(if plus { "EDF+C" } else { "" }, 44));
+    header.push_str(&text_field("-1", 8));
+    header.push_str(&text_field(&duration.to_string(), 8));
+    header.push_str(&text_field(&num_signals.to_string(), 4));
+
+    let mut fields = signals
+        .iter()
+        .map(EdfSignal::fields)
+        .collect::<Result<Vec<_>>>()?;
+    if plus {
+        fields.push(vec![
+            ANNOTATION_LABEL.to_string(),
+            String::new(),
+            String::new(),
+            "-1".to_string(),
+            "1".to_string(),
+            i16::MIN.to_string(),
+            i16::MAX.to_string(),
+            String::new(),
+            ANNOTATION_SAMPLES.to_string(),
+            String::new(),
+        ]);
+    }
+    for (field, &width) in SIGNAL_FIELD_WIDTHS.iter().enumerate() {
+        for signal in &fields {
+            header.push_str(&text_field(&signal[field], width));
+        }
+    }
+    Ok(header.into_bytes())
+}
+
+/// Write the data records, returning their number.
+///
+/// With `annotation_duration`, each data record ends with an
+/// `EDF Annotations` signal giving its start time.
+fn write_data_records<W: Write>(
+    record: &Record,
+    writer: &mut W,
+    signals: &[EdfSignal],
+    frames_per_record: usize,
+    annotation_duration: Option<u32>,
+) -> Result<u64> {
+    let mut reader = record.multi_signal_reader()?;
+    let mut buffers: Vec<Vec<u8>> = signals
+        .iter()
+        .map(|signal| Vec::with_capacity(2 * signal.samples_per_record))
+        .collect();
+    let mut frame = Vec::new();
+    let mut frames = 0;
+    let mut num_records = 0u64;
+    loop {
+        let more = reader.read_frame_samples_into(&mut frame)?;
+        if more {
+            for (signal, buffer) in signals.iter().zip(&mut buffers) {
+                for &sample in &frame[signal.index] {
+                    buffer.extend_from_slice(&signal.digital(sample).to_le_bytes());
+                }
+            }
+            frames += 1;
+        }
+        if frames == frames_per_record || (!more && frames > 0) {
+            for (signal, buffer) in signals.iter().zip(&mut buffers) {
+                while buffer.len() < 2 * signal.samples_per_record {
+                    buffer.extend_from_slice(&signal.digital_min.to_le_bytes());
+                }
+                writer.write_all(buffer)?;
+                buffer.clear();
+            }
+            if let Some(duration) = annotation_duration {
+                // Time-keeping annotation: onset, then an empty annotation list
+                let mut annotation =
+                    format!("+{}\x14\x14\0", num_records * u64::from(duration)).into_bytes();
+                annotation.resize(2 * ANNOTATION_SAMPLES, 0);
+                writer.write_all(&annotation)?;
+            }
+            num_records += 1;
+            frames = 0;
+        }
+        if !more {
+            return Ok(num_records);
+        }
+    }
+}
+
+/// Format text as a header field: printable ASCII, truncated or padded with
+/// spaces to `width` bytes.
+fn text_field(text: &str, width: usize) -> String {
+    let mut field: String = text
+        .chars()
+        .map(|c| {
+            if c == ' ' || c.is_ascii_graphic() {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(width)
+        .collect();
+    while field.len() < width {
+        field.push(' ');
+    }
+    field
+}
+
+/// Format a physical value in at most 8 characters, dropping decimals as
+/// needed.
+fn number_field(value: f64) -> Result<String> {
+    let text = Number(value).to_string();
+    if text.len() <= 8 {
+        return Ok(text);
+    }
+    (0..8)
+        .rev()
+        .map(|decimals| format!("{value:.decimals$}"))
+        .find(|text| text.len() <= 8)
+        .ok_or_else(|| {
+            Error::InvalidData(format!(
+                "Physical value {value} does not fit in an EDF header field"
+            ))
+        })
+}
+
+/// Open an EDF or EDF+ file as a record, like `edf2mit`, without converting
+/// its samples.
+///
+/// Each data record of the file is one frame of the record: the frame
+/// frequency is the inverse of the data record duration, and each signal
+/// has as many samples per frame as per data record. Samples are read in
+/// format 16, straight from the file. The gain and baseline of each signal
+/// follow from its physical and digital ranges (the baseline is rounded to
+/// an integer), its ADC resolution is 16 bits and its ADC zero is the middle
+/// of the digital range. The record is named after the file stem.
+///
+/// The start date and time become the base date and time, with the year of
+/// the EDF+ `Startdate` subfield when given (otherwise 1985 to 2084, as the
+/// specification clips it). The patient and recording identification fields
+/// are kept as info strings. The `EDF Annotations` signals of EDF+ files
+/// are skipped; their annotations are not read yet.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The file cannot be read, or is not an EDF file (e.g. a BDF file)
+/// - A header field is invalid
+/// - The file is discontinuous EDF+ (`EDF+D`), which has no single frame
+///   frequency
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::interop::edf;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = edf::read("data/SC4001E0-PSG.edf")?;
+///
+/// for signal in record.signal_info().unwrap_or_default() {
+///     println!("{}: {} Hz", signal.file_name, signal.samples_per_frame());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Record> {
+    let path = path.as_ref();
+    let open_error = |e: io::Error| {
+        Error::InvalidPath(format!(
+            "Failed to open EDF file '{}': {}",
+            path.display(),
+            e
+        ))
+    };
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| Error::InvalidPath(format!("Invalid EDF path '{}'", path.display())))?
+        .to_string();
+    let name = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let mut file = File::open(path).map_err(open_error)?;
+    let file_size = file.metadata().map_err(open_error)?.len();
+    let mut fixed = [0u8; HEADER_BLOCK_SIZE];
+    file.read_exact(&mut fixed)?;
+    if text(&fixed, 0, 8) != "0" {
+        return Err(Error::InvalidHeader(
+            format!("'{}' is not an EDF file", path.display()).into(),
+        ));
+    }
+    let num_signals: usize = parse_field(&fixed, 252, 4, "number of signals")?;
+    let mut signal_block = vec![0u8; HEADER_BLOCK_SIZE * num_signals];
+    file.read_exact(&mut signal_block)?;
+
+    let (header, layout) = parse_header(name, &file_name, &fixed, &signal_block, file_size)?;
+    let source = EdfSource {
+        path: path.to_path_buf(),
+        file_name,
+        layout: Arc::new(layout),
+    };
+    Ok(Record::from_header_and_source(header, source))
+}
+
+/// Parse the fixed and signal parts of an EDF header into a WFDB header and
+/// the layout of the data records.
+fn parse_header(
+    name: String,
+    file_name: &str,
+    fixed: &[u8],
+    signal_block: &[u8],
+    file_size: u64,
+) -> Result<(Header, RecordLayout)> {
+    let num_signals = signal_block.len() / HEADER_BLOCK_SIZE;
+    let header_bytes: u64 = parse_field(fixed, 184, 8, "number of header bytes")?;
+    if header_bytes != (HEADER_BLOCK_SIZE * (num_signals + 1)) as u64 {
+        return Err(Error::InvalidHeader(
+            format!("EDF header size {header_bytes} does not match {num_signals} signals").into(),
+        ));
+    }
+    let reserved = text(fixed, 192, 44);
+    if reserved.starts_with("EDF+D") {
+        return Err(Error::InvalidHeader(
+            "Discontinuous EDF+ files are not yet supported".into(),
+        ));
+    }
+    let plus = reserved.starts_with("EDF+");
+    let duration: f64 = parse_field(fixed, 244, 8, "data record duration")?;
+    if !(duration.is_finite() && duration > 0.0) {
+        return Err(Error::InvalidHeader(
+            format!("Invalid EDF data record duration {duration}").into(),
+        ));
+    }
+
+    // Signal fields are stored field by field: all labels, then all
+    // transducers, etc.
+    let column = |field: usize| -> Vec<String> {
+        let offset: usize = SIGNAL_FIELD_WIDTHS[..field].iter().sum();
+        let width = SIGNAL_FIELD_WIDTHS[field];
+        (0..num_signals)
+            .map(|k| text(signal_block, offset * num_signals + k * width, width))
+            .collect()
+    };
+    let labels = column(0);
+    let units = column(2);
+    let ranges = (3..=6).map(column).collect::<Vec<_>>();
+    let samples_per_record = column(8)
+        .iter()
+        .map(|field| parse_number::<u32>(field, "samples per data record"))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut layout = RecordLayout {
+        header_bytes,
+        record_bytes: samples_per_record.iter().map(|&n| 2 * u64::from(n)).sum(),
+        kept: Vec::new(),
+        kept_bytes: 0,
+        num_records: 0,
+    };
+    let mut signals = Vec::new();
+    let mut start = 0;
+    for (k, &samples) in samples_per_record.iter().enumerate() {
+        let len = 2 * u64::from(samples);
+        if !(plus && labels[k] == ANNOTATION_LABEL) {
+            match layout.kept.last_mut() {
+                Some((kept_start, kept_len)) if *kept_start + *kept_len == start => {
+                    *kept_len += len;
+                }
+                _ => layout.kept.push((start, len)),
+            }
+            layout.kept_bytes += len;
+            signals.push(signal_info(
+                file_name,
+                &labels[k],
+                &units[k],
+                samples,
+                [
+                    parse_number(&ranges[0][k], "physical minimum")?,
+                    parse_number(&ranges[1][k], "physical maximum")?,
+                ],
+                [
+                    parse_number(&ranges[2][k], "digital minimum")?,
+                    parse_number(&ranges[3][k], "digital maximum")?,
+                ],
+            ));
+        }
+        start += len;
+    }
+
+    // The number of data records is -1 while recording: count them instead
+    let data_bytes = file_size.saturating_sub(header_bytes);
+    let available = data_bytes.checked_div(layout.record_bytes).unwrap_or(0);
+    layout.num_records = match parse_field::<i64>(fixed, 236, 8, "number of data records")? {
+        count if count >= 0 => available.min(count.unsigned_abs()),
+        _ => available,
+    };
+
+    let patient = text(fixed, 8, 80);
+    let recording = text(fixed, 88, 80);
+    let info_strings = [("patient", &patient), ("recording", &recording)]
+        .into_iter()
+        .filter(|(_, field)| !field.is_empty())
+        .map(|(name, field)| format!(" {name}: {field}"))
+        .collect();
+    let header = Header {
+        metadata: Metadata {
+            name,
+            num_segments: None,
+            num_signals: signals.len(),
+            sampling_frequency: Some(1.0 / duration),
+            counter_frequency: None,
+            base_counter: None,
+            num_samples: Some(layout.num_records),
+            base_time: BaseTime::parse(&text(fixed, 176, 8).replace('.', ":")).ok(),
+            base_date: base_date(&text(fixed, 168, 8), &recording, plus),
+        },
+        specifications: Specifications::SingleSegment { signals },
+        info_strings,
+    };
+    Ok((header, layout))
+}
+
+/// Build the specification of an EDF signal, from its physical and digital
+/// ranges.
+#[allow(clippy::cast_possible_truncation)]
+fn signal_info(
+    file_name: &str,
+    label: &str,
+    units: &str,
+    samples_per_record: u32,
+    [physical_min, physical_max]: [f64; 2],
+    [digital_min, digital_max]: [i32; 2],
+) -> SignalInfo {
+    let gain = f64::from(digital_max - digital_min) / (physical_max - physical_min);
+    let (gain, baseline) = if gain.is_finite() && gain > 0.0 {
+        (
+            gain,
+            physical_min.mul_add(-gain, f64::from(digital_min)).round() as i32,
+        )
+    } else {
+        // Uncalibrated
+        (0.0, 0)
+    };
+    SignalInfo {
+        file_name: file_name.to_string(),
+        format: SignalFormat::Format16,
+        samples_per_frame: Some(samples_per_record),
+        skew: None,
+        byte_offset: None,
+        adc_gain: Some(gain),
+        baseline: Some(baseline),
+        // Spaces would split the gain field of a header line
+        units: (!units.is_empty()).then(|| units.replace(' ', "_")),
+        adc_resolution: Some(16),
+        adc_zero: Some((digital_min + digital_max + 1).div_euclid(2)),
+        initial_value: None,
+        checksum: None,
+        block_size: None,
+        description: (!label.is_empty()).then(|| label.to_string()),
+    }
+}
+
+/// Parse the start date of an EDF header (`dd.mm.yy`), taking the year from
+/// the `Startdate dd-MMM-yyyy` subfield of EDF+ files when given.
+fn base_date(start_date: &str, recording: &str, plus: bool) -> Option<BaseDate> {
+    let mut parts = start_date.split('.');
+    let day = parts.next()?;
+    let month = parts.next()?;
+    let short_year: i32 = parts.next()?.parse().ok()?;
+    let full_year = recording
+        .strip_prefix("Startdate ")
+        .filter(|_| plus)
+        .and_then(|subfields| subfields.split(' ').next())
+        .and_then(|date| date.rsplit('-').next())
+        .and_then(|year| year.parse().ok());
+    let year = full_year.unwrap_or(if short_year < 85 {
+        2000 + short_year
+    } else {
+        1900 + short_year
+    });
+    BaseDate::parse(&format!("{day}/{month}/{year}")).ok()
+}
+
+/// Get a text field of an EDF header, without its padding.
+fn text(bytes: &[u8], start: usize, width: usize) -> String {
+    String::from_utf8_lossy(&bytes[start..start + width])
+        .trim()
+        .to_string()
+}
+
+/// Parse a numeric field of an EDF header.
+fn parse_field<T: std::str::FromStr>(
+    bytes: &[u8],
+    start: usize,
+    width: usize,
+    name: &str,
+) -> Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    parse_number(&text(bytes, start, width), name)
+}
+
+/// Parse a number of an EDF header field.
+fn parse_number<T: std::str::FromStr>(field: &str, name: &str) -> Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    field
+        .parse()
+        .map_err(|e| Error::InvalidHeader(format!("Invalid EDF {name} '{field}': {e}").into()))
+}
+
+/// Layout of the data records of an EDF file.
+#[derive(Debug)]
+struct RecordLayout {
+    /// Size in bytes of the header.
+    header_bytes: u64,
+    /// Size in bytes of a data record.
+    record_bytes: u64,
+    /// Byte ranges (start and length) of the kept signals in a data record.
+    kept: Vec<(u64, u64)>,
+    /// Number of bytes kept from each data record.
+    kept_bytes: u64,
+    /// Number of data records.
+    num_records: u64,
+}
+
+/// Signal source reading the data records of an EDF file, without its
+/// header and annotation signals.
+struct EdfSource {
+    /// Path of the EDF file.
+    path: PathBuf,
+    /// File name of the signals in the record header.
+    file_name: String,
+    /// Layout of the data records.
+    layout: Arc<RecordLayout>,
+}
+
+impl SignalSource for EdfSource {
+    fn open(&self, file_name: &str) -> Result<Box<dyn ReadSeek>> {
+        if file_name != self.file_name {
+            return Err(Error::InvalidPath(format!(
+                "File '{file_name}' not found in EDF file '{}'",
+                self.path.display()
+            )));
+        }
+        let file = File::open(&self.path).map_err(|e| {
+            Error::InvalidPath(format!(
+                "Failed to open EDF file '{}': {}",
+                self.path.display(),
+                e
+            ))
+        })?;
+        Ok(Box::new(EdfReader {
+            file: BufReader::new(file),
+            layout: Arc::clone(&self.layout),
+            position: 0,
+            file_position: None,
+        }))
+    }
+}
+
+/// Byte stream of the kept signals of an EDF file, in format 16 frames.
+struct EdfReader {
+    /// Buffered EDF file.
+    file: BufReader<File>,
+    /// Layout of the data records.
+    layout: Arc<RecordLayout>,
+    /// Position in the stream.
+    position: u64,
+    /// Position in the file, when known.
+    file_position: Option<u64>,
+}
+
+impl Read for EdfReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let layout = &self.layout;
+        if buf.is_empty()
+            || layout.kept_bytes == 0
+            || self.position >= layout.kept_bytes * layout.num_records
+        {
+            return Ok(0);
+        }
+
+        // Find the byte range of the file holding the position
+        let record = self.position / layout.kept_bytes;
+        let mut offset = self.position % layout.kept_bytes;
+        let mut range = (0, 0);
+        for &(start, len) in &layout.kept {
+            if offset < len {
+                range = (
+                    layout.header_bytes + record * layout.record_bytes + start + offset,
+                    len - offset,
+                );
+                break;
+            }
+            offset -= len;
+        }
+        let (target, available) = range;
+
+        match self.file_position {
+            Some(current) if current == target => {}
+            Some(current) if target > current => {
+                let jump = i64::try_from(target - current).unwrap_or(i64::MAX);
+                self.file.seek_relative(jump)?;
+            }
+            _ => {
+                self.file.seek(SeekFrom::Start(target))?;
+            }
+        }
+        self.file_position = Some(target);
+
+        let len = buf
+            .len()
+            .min(usize::try_from(available).unwrap_or(usize::MAX));
+        let count = self.file.read(&mut buf[..len]).inspect_err(|_| {
+            self.file_position = None;
+        })?;
+        self.position += count as u64;
+        self.file_position = Some(target + count as u64);
+        Ok(count)
+    }
+}
+
+impl Seek for EdfReader {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let length = self.layout.kept_bytes * self.layout.num_records;
+        let target = match position {
+            SeekFrom::Start(target) => Some(target),
+            SeekFrom::End(delta) => length.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.position.checked_add_signed(delta),
+        };
+        self.position = target.ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
@@ -0,0 +1,10 @@
+//! Conversion of records to and from other biosignal formats.
+//!
+//! - [`edf`]: European Data Format (EDF and EDF+), as written by the
+//!   `mit2edf` tool of the WFDB software package
+//! - `arrow`: Apache Arrow record batches and Parquet files, with the
+//!   `arrow` feature
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod edf;
@@ -1,18 +1,92 @@
 //! __WFDB__ (Waveform Database) library for pure Rust.
 //!
-//! This library provides _decoding_ ~~and _encoding_~~(maybe in the future) support for
+//! This library provides _decoding_ and _encoding_ support for
 //! `PhysioNet`'s  [WFDB](https://physionet.org/content/wfdb) format files.
+//!
+//! # Stability
+//!
+//! The core read path (headers, records, signal readers and decoders) is
+//! stabilizing toward 1.0. Public items of the core are not removed or
+//! changed without notice: a replacement is added first, and the old item is
+//! kept for at least one minor release as a `#[deprecated]` shim pointing to
+//! it (e.g. `SegmentReader::seek_to_sample`, renamed to
+//! [`seek_to_frame`](record::SegmentReader::seek_to_frame)).
+//!
+//! [`Error`] and [`HeaderError`] are `#[non_exhaustive]`: variants and
+//! fields may be added in minor releases, so matches on [`Error`] need a
+//! wildcard arm. Release 0.1.7 changes the payload of
+//! [`Error::InvalidHeader`] from a `String` to a [`HeaderError`], which
+//! cannot go through a deprecation shim; build one from a message with
+//! `.into()` (`Error::InvalidHeader(message.into())`) and read the message
+//! back with [`HeaderError::reason`]. Missing signal files and undecodable
+//! signal data, previously reported as [`Error::InvalidPath`] and
+//! [`Error::InvalidHeader`], are now [`Error::SignalFile`] and
+//! [`Error::SignalDecode`], and invalid arguments (such as an out-of-bounds
+//! signal index) and operations a record does not support are now
+//! [`Error::InvalidData`].
+//!
+//! Release 0.1.7 also changes the fields
+//! [`Metadata::base_time`](header::Metadata::base_time) and
+//! [`Metadata::base_date`](header::Metadata::base_date) from `chrono`'s
+//! `NaiveTime` and `NaiveDate` to [`header::BaseTime`] and
+//! [`header::BaseDate`], so that headers parse without the `chrono`
+//! feature. Public fields cannot be deprecated either; with `chrono`,
+//! convert them with `BaseTime::to_naive_time` and
+//! `BaseDate::to_naive_date`, or build them with `.into()` from the
+//! `chrono` types.
+//!
+//! Experimental modules are only available with the `unstable` feature, and
+//! may change in any release:
+//!
+//! - `qc`: signal quality checks
+//! - `record::trigger`: threshold and slope triggers
+//!
+//! # Features
+//!
+//! Reading headers and signals needs no optional dependency. Integrations
+//! are behind cargo features:
+//!
+//! - `chrono` (default): `chrono` dates and times for base times, sample
+//!   timestamps and time-based seeks. Without it, base times and dates are
+//!   still parsed, as [`header::BaseTime`] and [`header::BaseDate`].
+//! - `flac`: decoders for FLAC-compressed signal formats (508, 516 and 524)
+//! - `remote`: opening records from HTTP(S) URLs, with a local cache
+//! - `async`: `futures::Stream` adapters for frame readers
+//! - `arrow`: Apache Arrow record batches and Parquet export of signals
+//! - `capi`: C ABI for opening records and reading frames from C or Python
+//! - `ndarray`: `ndarray::Array2` results for bulk reads
+//! - `serde`: `Serialize` and `Deserialize` for [`Header`] and the types it
+//!   holds, e.g. to dump metadata to JSON or YAML
+//! - `fetch-examples`: download helpers for public `PhysioNet` records
+//! - `testing`: a reader injecting short reads, interruptions and delays,
+//!   for resilience tests
+//! - `unstable`: experimental modules, see above
 
 // pub mod annotation;
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod database;
 pub mod header;
+pub mod interop;
+pub mod limits;
+pub mod locator;
+pub mod number;
+pub mod prelude;
+#[cfg(feature = "unstable")]
+pub mod qc;
 pub mod record;
+#[cfg(feature = "remote")]
+pub mod remote;
 pub mod signal;
+#[cfg(any(feature = "fetch-examples", feature = "testing"))]
+pub mod testing;
 
 // Internal module declaration
 mod common;
 mod error;
 
 pub use common::*;
-pub use error::Error;
+pub use database::Database;
+pub use error::{Error, HeaderError, HeaderField};
 pub use header::{Header, Metadata, SegmentInfo, SignalInfo};
-pub use record::{MultiSignalReader, Record, SignalReader};
+pub use record::{MultiSignalReader, Record, SignalReader, WaveformFile};
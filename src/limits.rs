@@ -0,0 +1,76 @@
+//! Limits of WFDB records.
+//!
+//! These constants mirror the limits of the reference WFDB library, so that
+//! every record accepted by this crate can also be read by other WFDB
+//! implementations. Header parsing rejects values beyond these limits with
+//! [`Error::InvalidHeader`](crate::Error::InvalidHeader), and since
+//! [`Record::create()`](crate::Record::create) validates the header it
+//! writes, created records conform as well.
+//!
+//! # Examples
+//!
+//! ```
+//! use wfdb::Header;
+//! use wfdb::limits::MAX_RECORD_NAME_LEN;
+//!
+//! let name = "r".repeat(MAX_RECORD_NAME_LEN + 1);
+//! let text = format!("{name} 1 250\n{name}.dat 16\n");
+//! assert!(Header::from_reader(&mut text.as_bytes()).is_err());
+//! ```
+//!
+//! The plausible ranges of frequencies, gains and counter values are not
+//! limits of the WFDB library: they catch nonsensical values (e.g., a gain
+//! of `1e300`), and are only warnings when parsing with
+//! [`ParseOptions::lenient`](crate::header::ParseOptions::lenient).
+
+use std::ops::RangeInclusive;
+
+/// Maximum length of a record name, in bytes (`WFDB_MAXRNL`).
+///
+/// Also applies to the names of the segments of multi-segment records.
+pub const MAX_RECORD_NAME_LEN: usize = 50;
+
+/// Maximum number of signals in a record.
+///
+/// Signal numbers are C `int`s in the WFDB library.
+pub const MAX_SIGNALS: usize = 0x7FFF_FFFF;
+
+/// Maximum number of segments in a multi-segment record.
+///
+/// Segment numbers are C `int`s in the WFDB library.
+pub const MAX_SEGMENTS: usize = 0x7FFF_FFFF;
+
+/// Maximum number of samples per signal in a record or segment.
+///
+/// Sample numbers are 64-bit signed integers (`WFDB_Time`, and
+/// [`Time`](crate::Time) in this crate).
+pub const MAX_SAMPLES: u64 = i64::MAX.unsigned_abs();
+
+/// Maximum number of samples of a signal per frame.
+///
+/// Samples per frame are C `int`s in the WFDB library.
+pub const MAX_SAMPLES_PER_FRAME: u32 = i32::MAX.unsigned_abs();
+
+/// Maximum length of the units of a signal, in bytes (`WFDB_MAXUSL`).
+pub const MAX_UNITS_LEN: usize = 50;
+
+/// Maximum length of the description of a signal, in bytes (`WFDB_MAXDSL`).
+pub const MAX_DESCRIPTION_LEN: usize = 100;
+
+/// Maximum length of the auxiliary information of an annotation, in bytes.
+///
+/// Auxiliary strings are stored with a one-byte length prefix.
+pub const MAX_AUX_LEN: usize = 255;
+
+/// Plausible range of sampling and counter frequencies, in Hz.
+pub const PLAUSIBLE_FREQUENCY: RangeInclusive<f64> = 1e-9..=1e9;
+
+/// Plausible range of nonzero ADC gains (magnitude), in ADC units per
+/// physical unit.
+///
+/// A gain of zero means the signal is uncalibrated and is always accepted.
+pub const PLAUSIBLE_GAIN: RangeInclusive<f64> = 1e-12..=1e12;
+
+/// Plausible magnitude of base counter values: counters are integers, and
+/// `f64` holds every integer up to 2^53 exactly.
+pub const PLAUSIBLE_BASE_COUNTER: RangeInclusive<f64> = 0.0..=9_007_199_254_740_992.0;
@@ -0,0 +1,212 @@
+//! Resolution of record names against the WFDB search path.
+//!
+//! The WFDB software package finds records by name (`mitdb/100`) in a list
+//! of directories and URLs, read from the `WFDB` environment variable. A
+//! [`Locator`] holds such a list, from the environment or configured
+//! programmatically, and opens the first record found.
+//!
+//! [`Record::open`] falls back to the `WFDB` path when a relative record
+//! path is not found from the working directory, so that records are found
+//! the same way as by the C library.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use wfdb::locator::Locator;
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let locator = Locator::new()
+//!     .with_directory("/data/physionet")
+//!     .with_url("https://physionet.org/files");
+//!
+//! // Found as /data/physionet/mitdb/100.hea, or downloaded (with the
+//! // `remote` feature)
+//! let record = locator.open("mitdb/100")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::record::header_path;
+use crate::{Error, Record, Result};
+
+/// Environment variable holding the WFDB search path.
+pub const PATH_ENV: &str = "WFDB";
+
+/// URL schemes recognized in a search path.
+const URL_SCHEMES: [&str; 2] = ["http", "https"];
+
+/// Entry of a WFDB search path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchEntry {
+    /// Local directory (`.` for the working directory).
+    Directory(PathBuf),
+    /// Base URL of remote records, used with the `remote` feature.
+    Url(String),
+}
+
+/// Ordered list of directories and URLs searched for records.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Locator {
+    /// Entries, in search order.
+    entries: Vec<SearchEntry>,
+}
+
+impl Locator {
+    /// Create an empty locator, which finds no record.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Create a locator from the `WFDB` environment variable.
+    ///
+    /// See [`parse`](Self::parse) for its syntax. When it is unset or empty,
+    /// only the working directory is searched.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let locator = env::var(PATH_ENV)
+            .map(|path| Self::parse(&path))
+            .unwrap_or_default();
+        if locator.entries.is_empty() {
+            Self::new().with_directory(".")
+        } else {
+            locator
+        }
+    }
+
+    /// Parse a search path in the syntax of the `WFDB` environment variable.
+    ///
+    /// Entries are separated by whitespace, or by `:` (`;` on Windows) as
+    /// in `PATH`. Entries starting with `http://` or `https://` are URLs,
+    /// and others are directories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::locator::{Locator, SearchEntry};
+    ///
+    /// let locator = Locator::parse(". /data/physionet https://physionet.org/files");
+    /// assert_eq!(locator.entries().len(), 3);
+    /// assert_eq!(
+    ///     locator.entries()[2],
+    ///     SearchEntry::Url("https://physionet.org/files".to_string())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parse(path: &str) -> Self {
+        let separator = if cfg!(windows) { ';' } else { ':' };
+        let mut locator = Self::new();
+        for token in path.split_whitespace() {
+            let mut pieces = token.split(separator).peekable();
+            while let Some(piece) = pieces.next() {
+                if URL_SCHEMES.contains(&piece)
+                    && let Some(rest) = pieces.next_if(|rest| rest.starts_with("//"))
+                {
+                    locator = locator.with_url(&format!("{piece}:{rest}"));
+                } else if !piece.is_empty() {
+                    locator = locator.with_directory(piece);
+                }
+            }
+        }
+        locator
+    }
+
+    /// Append a directory to the search path.
+    #[must_use]
+    pub fn with_directory<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+        self.entries.push(SearchEntry::Directory(directory.into()));
+        self
+    }
+
+    /// Append a base URL to the search path.
+    ///
+    /// URLs are only searched with the `remote` feature.
+    #[must_use]
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.entries
+            .push(SearchEntry::Url(url.trim_end_matches('/').to_string()));
+        self
+    }
+
+    /// Get the entries of the search path, in search order.
+    #[must_use]
+    pub fn entries(&self) -> &[SearchEntry] {
+        &self.entries
+    }
+
+    /// Find the header file of a record in the directories of the search
+    /// path.
+    ///
+    /// `record` is a record name, possibly with a directory
+    /// (`mitdb/100`); the `.hea` extension may be omitted. Absolute paths
+    /// are not searched. Returns `None` if no directory holds the header.
+    #[must_use]
+    pub fn find<P: AsRef<Path>>(&self, record: P) -> Option<PathBuf> {
+        let record = record.as_ref();
+        if record.is_absolute() {
+            return Some(header_path(record)).filter(|path| path.exists());
+        }
+        self.entries.iter().find_map(|entry| match entry {
+            SearchEntry::Directory(directory) => {
+                Some(header_path(&directory.join(record))).filter(|path| path.exists())
+            }
+            SearchEntry::Url(_) => None,
+        })
+    }
+
+    /// Open the first record found in the search path.
+    ///
+    /// Entries are tried in order: a directory holding the header is used,
+    /// and with the `remote` feature, the record is downloaded from a URL
+    /// into the default cache directory when the server has it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no entry holds the record, or if the header
+    /// found cannot be read or parsed.
+    pub fn open<P: AsRef<Path>>(&self, record: P) -> Result<Record> {
+        let record = record.as_ref();
+        self.locate(record)?.ok_or_else(|| {
+            Error::InvalidPath(format!(
+                "Record '{}' not found in the WFDB path",
+                record.display()
+            ))
+        })
+    }
+
+    /// Open the first record found in the search path, or `None` if no
+    /// entry holds it.
+    pub(crate) fn locate(&self, record: &Path) -> Result<Option<Record>> {
+        if record.is_absolute() {
+            return self.find(record).map(Record::open).transpose();
+        }
+        for entry in &self.entries {
+            match entry {
+                SearchEntry::Directory(directory) => {
+                    let path = header_path(&directory.join(record));
+                    if path.exists() {
+                        return Record::open(path).map(Some);
+                    }
+                }
+                #[cfg(feature = "remote")]
+                SearchEntry::Url(url) => {
+                    let Some(name) = record.to_str() else {
+                        continue;
+                    };
+                    let name = name.strip_suffix(".hea").unwrap_or(name);
+                    if let Ok(record) = Record::open_url(&format!("{url}/{name}")) {
+                        return Ok(Some(record));
+                    }
+                }
+                #[cfg(not(feature = "remote"))]
+                SearchEntry::Url(_) => {}
+            }
+        }
+        Ok(None)
+    }
+}
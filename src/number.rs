@@ -0,0 +1,58 @@
+//! Canonical formatting of numbers written to headers and exports.
+//!
+//! Every writer of the crate formats floating-point values (gains,
+//! frequencies, physical values) with [`format_number`], so that files
+//! written on any platform are byte-identical and read back to the same
+//! values.
+
+use std::fmt;
+
+/// Format a number as the shortest text that parses back to the same value.
+///
+/// The digits come from the Ryū algorithm, which does not depend on the
+/// platform or its C library. The WFDB conventions are applied on top:
+///
+/// - Integers are written without a decimal point (`200`, not `200.0`)
+/// - Negative zero is written as `0`
+/// - Magnitudes below `1e-5` or from `1e16` use an exponent (`1e-7`,
+///   `2.5e20`), which header parsers and `strtod` accept
+/// - Non-finite values are written as `NaN`, `inf` and `-inf`
+///
+/// # Examples
+///
+/// ```
+/// use wfdb::number::format_number;
+///
+/// assert_eq!(format_number(200.0), "200");
+/// assert_eq!(format_number(0.1 + 0.2), "0.30000000000000004");
+/// assert_eq!(format_number(1e-7), "1e-7");
+/// assert_eq!(format_number(-0.0), "0");
+/// ```
+#[must_use]
+pub fn format_number(value: f64) -> String {
+    Number(value).to_string()
+}
+
+/// Display adapter writing a number as [`format_number`] does, without an
+/// intermediate `String`: `write!(f, "{}", Number(gain))`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Number(pub f64);
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.0;
+        if value.is_nan() {
+            return f.write_str("NaN");
+        }
+        if value.is_infinite() {
+            return f.write_str(if value > 0.0 { "inf" } else { "-inf" });
+        }
+        if value == 0.0 {
+            return f.write_str("0");
+        }
+
+        let mut buffer = ryu::Buffer::new();
+        let text = buffer.format_finite(value);
+        f.write_str(text.strip_suffix(".0").unwrap_or(text))
+    }
+}
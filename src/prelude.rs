@@ -0,0 +1,32 @@
+//! Commonly used types and traits, for glob import.
+//!
+//! The crate keeps its items in topical modules ([`header`](crate::header),
+//! [`record`](crate::record), [`signal`](crate::signal)); the prelude gathers
+//! the ones most programs need, so a single import covers opening a record,
+//! reading its signals and using the decoder and filter traits:
+//!
+//! ```no_run
+//! use wfdb::prelude::*;
+//!
+//! # fn main() -> Result<()> {
+//! let record = Record::open("data/100")?;
+//! let mut reader = record.signal_reader(0)?;
+//! reader.seek_to_sample_index(SampleIndex(3600))?;
+//! let samples: Vec<Sample> = reader.read_samples(360)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The prelude only re-exports items available in every build; items behind
+//! cargo features are imported from their modules.
+
+pub use crate::record::{
+    FrameIndex, Interpolation, Pipeline, ReaderOptions, RecordClock, SampleIndex, SegmentReader,
+    SignalSource, SignalStats,
+};
+pub use crate::signal::filter::Filter;
+pub use crate::signal::{FormatDecoder, FormatEncoder, SignalScale};
+pub use crate::{
+    Error, Header, Metadata, MultiSignalReader, Record, Result, Sample, SegmentInfo, SignalFormat,
+    SignalInfo, SignalReader,
+};
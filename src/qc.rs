@@ -0,0 +1,195 @@
+//! Signal quality heuristics.
+//!
+//! These checks help clean large datasets before analysis. They are
+//! heuristics: results should be reviewed rather than trusted blindly.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use wfdb::Record;
+//! use wfdb::qc::detect_inversion;
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let record = Record::open("data/100")?;
+//! let mut reader = record.signal_reader(0)?;
+//!
+//! let estimate = detect_inversion(&mut reader)?;
+//! if estimate.is_inverted() {
+//!     // Flip the lead when reading it
+//!     let name = reader.description().unwrap_or_default().to_string();
+//!     let matrix = record.pipeline().invert(&[name.as_str()]).physical().to_matrix()?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::signal::INVALID_SAMPLE;
+use crate::{Error, Result, SignalReader};
+
+/// Duration (seconds) of signal analyzed by [`detect_inversion`].
+pub const INVERSION_ANALYSIS_SECONDS: f64 = 60.0;
+
+/// Minimum number of detected beats for a conclusive inversion estimate.
+pub const MIN_INVERSION_BEATS: usize = 5;
+
+/// Minimum time (seconds) between two detected beats.
+const REFRACTORY_SECONDS: f64 = 0.25;
+
+/// Half-width (seconds) of the window searched for the R-wave around a beat.
+const QRS_HALF_WIDTH_SECONDS: f64 = 0.06;
+
+/// Half-width (seconds) of the window used to estimate the local baseline.
+const BASELINE_HALF_WIDTH_SECONDS: f64 = 0.2;
+
+/// Fraction of the largest slopes above which a sample starts a beat.
+const SLOPE_THRESHOLD_FRACTION: f64 = 0.4;
+
+/// Result of [`detect_inversion`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InversionEstimate {
+    /// Number of beats analyzed.
+    pub beats: usize,
+    /// Fraction of beats whose dominant QRS deflection is negative (0 to 1).
+    pub negative_fraction: f64,
+}
+
+impl InversionEstimate {
+    /// Check whether the lead appears inverted.
+    ///
+    /// Requires at least [`MIN_INVERSION_BEATS`] beats and a majority of
+    /// beats with a negative dominant deflection.
+    #[must_use]
+    pub fn is_inverted(&self) -> bool {
+        self.beats >= MIN_INVERSION_BEATS && self.negative_fraction > 0.5
+    }
+
+    /// Get how consistently the beats agree on a polarity (0 to 1).
+    ///
+    /// `1.0` means every beat has the same polarity; `0.0` means an even split.
+    #[must_use]
+    pub fn confidence(&self) -> f64 {
+        (self.negative_fraction - 0.5).abs() * 2.0
+    }
+}
+
+/// Estimate whether an ECG lead is inverted.
+///
+/// Reads up to [`INVERSION_ANALYSIS_SECONDS`] of signal from the reader's
+/// current position, detects beats from the steepest slopes and, for each
+/// beat, compares the largest positive and negative deflections from the
+/// local baseline. In most leads the R-wave dominates, so a majority of
+/// negative deflections suggests the lead is inverted (or is a lead such as
+/// aVR that is normally negative).
+///
+/// The reader is advanced past the analyzed samples.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The reader's sampling frequency is not available
+/// - Reading from the signal file fails
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn detect_inversion(reader: &mut SignalReader) -> Result<InversionEstimate> {
+    let frequency = reader.sampling_frequency().ok_or_else(|| {
+        Error::InvalidHeader("Sampling frequency not available for inversion detection".into())
+    })?;
+
+    let count = (INVERSION_ANALYSIS_SECONDS * frequency).ceil() as usize;
+    let samples = fill_invalid(&reader.read_samples(count)?);
+
+    let beats = detect_beats(&samples, frequency);
+    let qrs_half_width = ((QRS_HALF_WIDTH_SECONDS * frequency).round() as usize).max(1);
+    let baseline_half_width = ((BASELINE_HALF_WIDTH_SECONDS * frequency).round() as usize).max(1);
+
+    let negative = beats
+        .iter()
+        .filter(|&&beat| {
+            let baseline = median(window(&samples, beat, baseline_half_width));
+            let qrs = window(&samples, beat, qrs_half_width);
+            let max = qrs.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b)) - baseline;
+            let min = qrs.iter().fold(f64::INFINITY, |a, &b| a.min(b)) - baseline;
+            -min > max
+        })
+        .count();
+
+    Ok(InversionEstimate {
+        beats: beats.len(),
+        negative_fraction: if beats.is_empty() {
+            0.0
+        } else {
+            negative as f64 / beats.len() as f64
+        },
+    })
+}
+
+/// Convert samples to `f64`, replacing invalid samples with the previous value.
+fn fill_invalid(samples: &[i32]) -> Vec<f64> {
+    let mut previous = 0.0;
+    samples
+        .iter()
+        .map(|&s| {
+            if s != INVALID_SAMPLE {
+                previous = f64::from(s);
+            }
+            previous
+        })
+        .collect()
+}
+
+/// Detect beats as the steepest points separated by the refractory period.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn detect_beats(samples: &[f64], frequency: f64) -> Vec<usize> {
+    if samples.len() < 2 {
+        return Vec::new();
+    }
+
+    let slopes: Vec<f64> = samples.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+    let mut sorted = slopes.clone();
+    sorted.sort_by(f64::total_cmp);
+    let peak_slope = sorted[(sorted.len() - 1) * 99 / 100];
+    if peak_slope <= 0.0 {
+        return Vec::new();
+    }
+
+    let threshold = peak_slope * SLOPE_THRESHOLD_FRACTION;
+    let refractory = ((REFRACTORY_SECONDS * frequency).round() as usize).max(1);
+
+    let mut beats = Vec::new();
+    let mut i = 0;
+    while i < slopes.len() {
+        if slopes[i] >= threshold {
+            // Anchor the beat at the steepest slope within the refractory window
+            let end = (i + refractory).min(slopes.len());
+            let steepest = (i..end)
+                .max_by(|&a, &b| slopes[a].total_cmp(&slopes[b]))
+                .unwrap_or(i);
+            beats.push(steepest);
+            i = steepest + refractory;
+        } else {
+            i += 1;
+        }
+    }
+    beats
+}
+
+/// Get the samples within `half_width` of `center`.
+fn window(samples: &[f64], center: usize, half_width: usize) -> &[f64] {
+    let start = center.saturating_sub(half_width);
+    let end = (center + half_width + 1).min(samples.len());
+    &samples[start..end]
+}
+
+/// Compute the median of a non-empty slice.
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted[sorted.len() / 2]
+}
@@ -0,0 +1,118 @@
+//! Conversions of bulk reads to `ndarray` arrays.
+//!
+//! Available with the `ndarray` feature.
+
+use ndarray::{Array2, ShapeBuilder};
+
+use crate::record::SignalMatrix;
+use crate::{Error, MultiSignalReader, Record, Result};
+
+/// Build a `samples × channels` array from values stored channel after
+/// channel, without copying them.
+fn channel_major_array(
+    values: Vec<f64>,
+    num_samples: usize,
+    num_channels: usize,
+) -> Result<Array2<f64>> {
+    Array2::from_shape_vec((num_samples, num_channels).f(), values)
+        .map_err(|e| Error::InvalidData(format!("Cannot build array: {e}")))
+}
+
+impl SignalMatrix {
+    /// Convert the matrix to a `samples × channels` array.
+    ///
+    /// The columns are moved into the array in column-major (Fortran)
+    /// order, so each channel stays contiguous.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the columns do not all have the same length.
+    pub fn into_ndarray(self) -> Result<Array2<f64>> {
+        let (num_samples, num_channels) = (self.num_samples(), self.num_channels());
+        channel_major_array(self.columns.concat(), num_samples, num_channels)
+    }
+}
+
+impl MultiSignalReader {
+    /// Read up to `num_frames` frames as a `samples × channels` array of
+    /// physical values.
+    ///
+    /// The array has fewer rows only at the end of the record, and none
+    /// after it. Each channel is contiguous (column-major order). Invalid
+    /// samples are `NaN`, uncalibrated signals keep their raw ADC values,
+    /// and signals with several samples per frame are averaged over the
+    /// frame, as in [`read_block`](Self::read_block).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frames`](Self::read_frames).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut reader = record.multi_signal_reader()?;
+    ///
+    /// let epoch = reader.read_array(3600)?;
+    /// let means = epoch.mean_axis(ndarray::Axis(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_array(&mut self, num_frames: usize) -> Result<Array2<f64>> {
+        let block = self.read_block(num_frames)?;
+        let values = (0..block.num_channels())
+            .filter_map(|channel| block.channel_physical(channel))
+            .collect::<Vec<_>>()
+            .concat();
+        channel_major_array(values, block.num_frames(), block.num_channels())
+    }
+}
+
+impl Record {
+    /// Read every signal as a `samples × channels` array of physical values.
+    ///
+    /// Single-segment records are read as by
+    /// [`read_all_physical`](Self::read_all_physical), and each channel is
+    /// contiguous (column-major order). Multi-segment records are read
+    /// across segments with the gains of each segment, in row-major order;
+    /// null segments and signals missing from a segment are `NaN`, as are
+    /// invalid samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signal files or segment headers cannot be
+    /// read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let data = record.to_ndarray()?;
+    ///
+    /// let (samples, channels) = data.dim();
+    /// let mlii = data.column(0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_ndarray(&self) -> Result<Array2<f64>> {
+        if !self.is_multi_segment() {
+            return self.read_all_physical()?.into_ndarray();
+        }
+
+        let mut reader = self.segment_reader()?;
+        let num_channels = self.metadata().num_signals();
+        let mut values = Vec::new();
+        while let Some(frame) = reader.read_frame_physical()? {
+            values.extend(frame);
+        }
+        let num_samples = values.len() / num_channels.max(1);
+        Array2::from_shape_vec((num_samples, num_channels), values)
+            .map_err(|e| Error::InvalidData(format!("Cannot build array: {e}")))
+    }
+}
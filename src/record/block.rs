@@ -0,0 +1,169 @@
+//! Blocks of frames stored contiguously per channel.
+
+use std::sync::Arc;
+
+use crate::Sample;
+use crate::record::frame::FrameChannels;
+
+/// Consecutive frames of a record, stored channel by channel.
+///
+/// Read with [`MultiSignalReader::read_block`](crate::MultiSignalReader::read_block).
+/// The samples of all channels live in one buffer, each channel being a
+/// contiguous slice of [`num_frames`](Self::num_frames) samples
+/// (struct-of-arrays layout), which suits windowed and vectorized processing
+/// better than a `Vec<Vec<Sample>>` of frames. Signals with several samples
+/// per frame are averaged over the frame, as in
+/// [`read_frame`](crate::MultiSignalReader::read_frame).
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// let mut reader = record.multi_signal_reader()?;
+///
+/// // Ten-second windows, reusing the same buffer
+/// let mut block = reader.read_block(3600)?;
+/// while !block.is_empty() {
+///     let mlii = block.channel(0).unwrap();
+///     let peak = mlii.iter().max();
+///     reader.read_block_into(&mut block, 3600)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Block {
+    /// Sample number of the first frame.
+    start_sample: u64,
+    /// Number of frames.
+    num_frames: usize,
+    /// Samples, channel after channel.
+    samples: Vec<Sample>,
+    /// Names and scales of the channels.
+    channels: Arc<FrameChannels>,
+}
+
+impl Block {
+    /// Get the sample number of the first frame.
+    #[must_use]
+    pub const fn start_sample(&self) -> u64 {
+        self.start_sample
+    }
+
+    /// Get the number of frames.
+    #[must_use]
+    pub const fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+
+    /// Get the number of channels.
+    #[must_use]
+    pub fn num_channels(&self) -> usize {
+        self.channels.names.len()
+    }
+
+    /// Check if the block has no frames.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.num_frames == 0
+    }
+
+    /// Get the samples of a channel, or `None` if the index is out of
+    /// bounds.
+    #[must_use]
+    pub fn channel(&self, index: usize) -> Option<&[Sample]> {
+        if index >= self.num_channels() {
+            return None;
+        }
+        let start = index * self.num_frames;
+        self.samples.get(start..start + self.num_frames)
+    }
+
+    /// Get the samples of the first channel with the given description.
+    #[must_use]
+    pub fn channel_by_name(&self, name: &str) -> Option<&[Sample]> {
+        let index = self
+            .channels
+            .names
+            .iter()
+            .position(|n| n.as_deref() == Some(name))?;
+        self.channel(index)
+    }
+
+    /// Get the description of a channel, if any.
+    #[must_use]
+    pub fn channel_name(&self, index: usize) -> Option<&str> {
+        self.channels.names.get(index)?.as_deref()
+    }
+
+    /// Iterate over the samples of each channel, in channel order.
+    pub fn channels(&self) -> impl Iterator<Item = &[Sample]> {
+        (0..self.num_channels()).filter_map(|index| self.channel(index))
+    }
+
+    /// Convert the samples of a channel to physical values, or `None` if the
+    /// index is out of bounds.
+    ///
+    /// Invalid samples are `NaN`, and uncalibrated signals keep their raw
+    /// ADC values.
+    #[must_use]
+    pub fn channel_physical(&self, index: usize) -> Option<Vec<f64>> {
+        let scale = self.channels.scales.get(index)?;
+        let samples = self.channel(index)?;
+        let mut values = vec![0.0; samples.len()];
+        scale.to_physical_slice(samples, &mut values);
+        Some(values)
+    }
+
+    /// Get every sample, channel after channel.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    /// Take every sample, channel after channel.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<Sample> {
+        self.samples
+    }
+
+    /// Prepare the block for `capacity` frames starting at a sample number.
+    pub(crate) fn reset(
+        &mut self,
+        start_sample: u64,
+        capacity: usize,
+        channels: &Arc<FrameChannels>,
+    ) {
+        if !Arc::ptr_eq(&self.channels, channels) {
+            self.channels = Arc::clone(channels);
+        }
+        self.start_sample = start_sample;
+        self.num_frames = capacity;
+        self.samples.clear();
+        self.samples.resize(capacity * self.num_channels(), 0);
+    }
+
+    /// Store a frame at a position of the block.
+    pub(crate) fn set_frame(&mut self, position: usize, frame: &[Sample]) {
+        for (index, &sample) in frame.iter().enumerate() {
+            self.samples[index * self.num_frames + position] = sample;
+        }
+    }
+
+    /// Keep the first `num_frames` frames of the block.
+    pub(crate) fn truncate(&mut self, num_frames: usize) {
+        if num_frames >= self.num_frames {
+            return;
+        }
+        for index in 1..self.num_channels() {
+            let start = index * self.num_frames;
+            self.samples
+                .copy_within(start..start + num_frames, index * num_frames);
+        }
+        self.num_frames = num_frames;
+        self.samples.truncate(num_frames * self.num_channels());
+    }
+}
@@ -0,0 +1,166 @@
+//! Canonical JSON description of a record, for diffing.
+
+use std::fmt::Display;
+
+use crate::number::Number;
+use crate::record::export::json_string;
+use crate::{Record, SegmentInfo, SignalInfo};
+
+impl Record {
+    /// Describe the header of this record as canonical JSON.
+    ///
+    /// The document covers the metadata (record line), the signal
+    /// specifications of single-segment records, the segment
+    /// specifications of multi-segment records and the info strings. Its
+    /// form is fixed so that two versions of a record can be compared with a
+    /// plain text diff:
+    ///
+    /// - Object keys are sorted, and each key is on its own line
+    /// - Values are given as written in the header, with `null` for omitted
+    ///   fields (defaults are not filled in)
+    /// - Numbers use the canonical formatting of [`crate::number`]
+    /// - Signals and segments keep their order in the header
+    ///
+    /// Annotations are not covered yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::Record;
+    /// use wfdb::record::MemorySource;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::from_sources("rec 1 250 2\nrec.dat 16 200/mV\n", MemorySource::new())?;
+    /// let json = record.to_canonical_json();
+    ///
+    /// assert!(json.contains("\"sampling_frequency\": 250\n"));
+    /// assert!(json.contains("\"adc_gain\": 200,"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        let metadata = self.metadata();
+        let metadata = object(
+            vec![
+                ("base_counter", optional(metadata.base_counter.map(number))),
+                ("base_date", optional(metadata.base_date.as_ref().map(text))),
+                ("base_time", optional(metadata.base_time.as_ref().map(text))),
+                (
+                    "counter_frequency",
+                    optional(metadata.counter_frequency.map(number)),
+                ),
+                ("name", json_string(&metadata.name)),
+                ("num_samples", optional(metadata.num_samples)),
+                ("num_segments", optional(metadata.num_segments)),
+                ("num_signals", metadata.num_signals.to_string()),
+                (
+                    "sampling_frequency",
+                    optional(metadata.sampling_frequency.map(number)),
+                ),
+            ],
+            1,
+        );
+        let signals = self.signal_info().map_or_else(
+            || "null".to_string(),
+            |signals| array(signals.iter().map(signal), 1),
+        );
+        let segments = self.segment_info().map_or_else(
+            || "null".to_string(),
+            |segments| array(segments.iter().map(segment), 1),
+        );
+        let info = array(self.info_strings().iter().map(|s| json_string(s)), 1);
+
+        let mut json = object(
+            vec![
+                ("info", info),
+                ("metadata", metadata),
+                ("segments", segments),
+                ("signals", signals),
+            ],
+            0,
+        );
+        json.push('\n');
+        json
+    }
+}
+
+/// Describe a signal specification as a JSON object, at nesting level 2.
+fn signal(signal: &SignalInfo) -> String {
+    object(
+        vec![
+            ("adc_gain", optional(signal.adc_gain.map(number))),
+            ("adc_resolution", optional(signal.adc_resolution)),
+            ("adc_zero", optional(signal.adc_zero)),
+            ("baseline", optional(signal.baseline)),
+            ("block_size", optional(signal.block_size)),
+            ("byte_offset", optional(signal.byte_offset)),
+            ("checksum", optional(signal.checksum)),
+            (
+                "description",
+                optional(signal.description.as_deref().map(json_string)),
+            ),
+            ("file_name", json_string(&signal.file_name)),
+            ("format", u16::from(signal.format).to_string()),
+            ("initial_value", optional(signal.initial_value)),
+            ("samples_per_frame", optional(signal.samples_per_frame)),
+            ("skew", optional(signal.skew)),
+            ("units", optional(signal.units.as_deref().map(json_string))),
+        ],
+        2,
+    )
+}
+
+/// Describe a segment specification as a JSON object, at nesting level 2.
+fn segment(segment: &SegmentInfo) -> String {
+    object(
+        vec![
+            ("num_samples", segment.num_samples.to_string()),
+            ("record_name", json_string(&segment.record_name)),
+        ],
+        2,
+    )
+}
+
+/// Format a JSON object with sorted keys, one per line, at nesting `level`.
+fn object(mut fields: Vec<(&str, String)>, level: usize) -> String {
+    fields.sort_by_key(|&(key, _)| key);
+    let indent = "  ".repeat(level + 1);
+    let members: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{indent}{}: {value}", json_string(key)))
+        .collect();
+    format!("{{\n{}\n{}}}", members.join(",\n"), "  ".repeat(level))
+}
+
+/// Format a JSON array, one item per line, at nesting `level`.
+fn array<I: IntoIterator<Item = String>>(items: I, level: usize) -> String {
+    let indent = "  ".repeat(level + 1);
+    let items: Vec<String> = items
+        .into_iter()
+        .map(|item| format!("{indent}{item}"))
+        .collect();
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+    format!("[\n{}\n{}]", items.join(",\n"), "  ".repeat(level))
+}
+
+/// Format an optional value, `null` when absent.
+fn optional<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
+/// Format a number canonically, `null` when not finite.
+fn number(value: f64) -> String {
+    if value.is_finite() {
+        Number(value).to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+/// Format a displayable value as a JSON string.
+fn text<T: Display>(value: T) -> String {
+    json_string(&value.to_string())
+}
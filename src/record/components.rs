@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::SignalInfo;
+
+/// Locations of the files making up a record, for [`Record::open_components`].
+///
+/// By default every file is resolved relative to the header's directory.
+/// Overrides allow a record to be assembled from several directories, e.g.
+/// a header and signal files on a read-only mirror with annotations kept in
+/// a local working directory.
+///
+/// [`Record::open_components`]: crate::Record::open_components
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+/// use wfdb::record::RecordComponents;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let mut components = RecordComponents::new("/mirror/mitdb/headers/100");
+/// components
+///     .signal_dir_overrides
+///     .insert("100.dat".to_string(), "/mirror/mitdb/signals".into());
+/// components.annotation_dir = Some("annotations".into());
+///
+/// let record = Record::open_components(&components)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordComponents {
+    /// Path of the header file (the `.hea` extension may be omitted).
+    pub header: PathBuf,
+    /// Directory containing each signal file, by file name as written in
+    /// the header. Signal files not listed are resolved relative to the
+    /// header's directory.
+    pub signal_dir_overrides: BTreeMap<String, PathBuf>,
+    /// Directory containing the annotation files (`None` for the header's
+    /// directory).
+    pub annotation_dir: Option<PathBuf>,
+}
+
+impl RecordComponents {
+    /// Create components resolving every file relative to the header.
+    #[must_use]
+    pub fn new<P: Into<PathBuf>>(header: P) -> Self {
+        Self {
+            header: header.into(),
+            signal_dir_overrides: BTreeMap::new(),
+            annotation_dir: None,
+        }
+    }
+}
+
+/// Resolve the path of a signal file, applying any directory override.
+pub fn resolve_signal_path(
+    base_path: &Path,
+    signal_dirs: &BTreeMap<String, PathBuf>,
+    file_name: &str,
+) -> PathBuf {
+    signal_dirs
+        .get(file_name)
+        .map_or(base_path, PathBuf::as_path)
+        .join(file_name)
+}
+
+/// Get the byte offset of the data of each signal in its file.
+///
+/// Several records (or several groups of one record) may store their data in
+/// sections of one file, starting at different byte offsets. Signals in the
+/// same file and at the same offset are interleaved. A signal without an
+/// explicit offset belongs to the section of the previous signal in the same
+/// file, as the WFDB library only requires the offset on the first signal of
+/// a group.
+pub fn signal_byte_offsets(signals: &[SignalInfo]) -> Vec<u64> {
+    let mut offsets: Vec<u64> = Vec::with_capacity(signals.len());
+    for (index, signal) in signals.iter().enumerate() {
+        let offset = signal.byte_offset.unwrap_or_else(|| {
+            signals[..index]
+                .iter()
+                .zip(&offsets)
+                .rev()
+                .find(|(previous, _)| previous.file_name == signal.file_name)
+                .map_or(0, |(_, &offset)| offset)
+        });
+        offsets.push(offset);
+    }
+    offsets
+}
@@ -0,0 +1,1013 @@
+//! Exporting record signals to other file layouts.
+//!
+//! These helpers turn a WFDB record into files that are easier to consume by
+//! tools outside the WFDB ecosystem (e.g., ML data loaders or audio players).
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::num::NonZeroUsize;
+use std::ops::RangeBounds;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::number::Number;
+use crate::record::index::half_open;
+use crate::record::progress::ProgressCounter;
+use crate::record::timing::{RecordClock, elapsed_time};
+use crate::record::{Budget, MultiSignalReader, Progress};
+use crate::signal::RoundingMode;
+use crate::{Error, Record, Result, SignalInfo};
+
+/// Number of samples read per chunk while exporting a channel.
+const EXPORT_CHUNK_SIZE: usize = 4096;
+
+/// Output layout of a per-channel export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelFormat {
+    /// Text file with one physical value per line.
+    Csv,
+    /// Raw little-endian `f32` physical values.
+    F32,
+}
+
+impl ChannelFormat {
+    /// Get the file extension used for this format.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::F32 => "f32",
+        }
+    }
+}
+
+/// Options controlling how values are written by [`per_channel_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExportOptions {
+    /// Number of decimals written for each CSV value (`None` writes the
+    /// shortest representation that reads back to the same value, see
+    /// [`format_number`](crate::number::format_number)).
+    ///
+    /// Ignored by binary formats.
+    pub decimals: Option<usize>,
+    /// Rounding applied when writing a fixed number of decimals.
+    pub rounding: RoundingMode,
+}
+
+/// Description of one exported channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelEntry {
+    /// Index of the signal in the record.
+    pub index: usize,
+    /// Signal description (or `None` when omitted in the header).
+    pub name: Option<String>,
+    /// Physical units of the exported values.
+    pub units: String,
+    /// Whether the signal is calibrated (otherwise raw ADC values are exported).
+    pub calibrated: bool,
+    /// Sampling frequency (Hz) of the exported values: the frame rate of the
+    /// record times the samples per frame of the signal.
+    pub sampling_frequency: f64,
+    /// Name of the exported file (relative to the export directory).
+    pub file_name: String,
+    /// Number of samples written.
+    pub num_samples: u64,
+}
+
+/// Manifest describing the files written by [`per_channel`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Manifest {
+    /// Name of the exported record.
+    pub record_name: String,
+    /// Sampling frequency (Hz) of the record, in frames per second (see
+    /// [`ChannelEntry::sampling_frequency`] for the rate of each channel).
+    pub sampling_frequency: f64,
+    /// Layout of the channel files.
+    pub format: ChannelFormat,
+    /// Exported channels, ordered by signal index.
+    pub channels: Vec<ChannelEntry>,
+}
+
+impl Manifest {
+    /// File name of the manifest written next to the channel files.
+    pub const FILE_NAME: &'static str = "manifest.json";
+
+    /// Serialize the manifest as a JSON document.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let channels = self
+            .channels
+            .iter()
+            .map(|channel| {
+                format!(
+                    "    {{\"index\": {}, \"name\": {}, \"units\": {}, \"calibrated\": {}, \"sampling_frequency\": {}, \"file\": {}, \"num_samples\": {}}}",
+                    channel.index,
+                    channel
+                        .name
+                        .as_deref()
+                        .map_or_else(|| "null".to_string(), json_string),
+                    json_string(&channel.units),
+                    channel.calibrated,
+                    Number(channel.sampling_frequency),
+                    json_string(&channel.file_name),
+                    channel.num_samples
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        format!(
+            "{{\n  \"record\": {},\n  \"sampling_frequency\": {},\n  \"format\": {},\n  \"channels\": [\n{}\n  ]\n}}\n",
+            json_string(&self.record_name),
+            Number(self.sampling_frequency),
+            json_string(self.format.extension()),
+            channels
+        )
+    }
+}
+
+/// Export every channel of a record into its own file.
+///
+/// Channels are exported in parallel, by at most one thread per available
+/// CPU (see [`std::thread::available_parallelism`]). Each file is
+/// named `<record>_<index>.<ext>` and a [`Manifest`] is written to
+/// `manifest.json` in the same directory.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The record is a multi-segment record
+/// - A signal file cannot be read
+/// - The output directory or files cannot be written
+pub fn per_channel<P: AsRef<Path>>(
+    record: &Record,
+    dir: P,
+    format: ChannelFormat,
+) -> Result<Manifest> {
+    per_channel_with_options(record, dir, format, &ExportOptions::default())
+}
+
+/// Export every channel of a record into its own file, with custom value
+/// formatting.
+///
+/// Behaves like [`per_channel`]; use `options` to match the number format of
+/// another tool, e.g. three decimals rounded half to even.
+///
+/// # Errors
+///
+/// Returns the same errors as [`per_channel`].
+pub fn per_channel_with_options<P: AsRef<Path>>(
+    record: &Record,
+    dir: P,
+    format: ChannelFormat,
+    options: &ExportOptions,
+) -> Result<Manifest> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    let record_name = record.metadata().name();
+
+    let num_channels = record.signal_count();
+    let num_workers = std::thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(num_channels);
+    let next_channel = AtomicUsize::new(0);
+
+    let mut results = std::thread::scope(|scope| {
+        // Spawn every worker before joining so they run concurrently
+        #[allow(clippy::needless_collect)]
+        let handles: Vec<_> = (0..num_workers)
+            .map(|_| {
+                let next_channel = &next_channel;
+                scope.spawn(move || {
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next_channel.fetch_add(1, Ordering::Relaxed);
+                        if index >= num_channels {
+                            return results;
+                        }
+                        let path =
+                            dir.join(format!("{record_name}_{index}.{}", format.extension()));
+                        results
+                            .push((index, export_channel(record, index, &path, format, options)));
+                    }
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect::<Vec<_>>()
+    });
+    results.sort_by_key(|&(index, _)| index);
+    let channels = results
+        .into_iter()
+        .map(|(_, channel)| channel)
+        .collect::<Result<Vec<_>>>()?;
+
+    let manifest = Manifest {
+        record_name: record_name.to_string(),
+        sampling_frequency: record.metadata().sampling_frequency(),
+        format,
+        channels,
+    };
+
+    std::fs::write(dir.join(Manifest::FILE_NAME), manifest.to_json())?;
+
+    Ok(manifest)
+}
+
+/// Export a single channel into the file at `path`.
+fn export_channel(
+    record: &Record,
+    index: usize,
+    path: &Path,
+    format: ChannelFormat,
+    options: &ExportOptions,
+) -> Result<ChannelEntry> {
+    let mut reader = record.signal_reader(index)?;
+
+    let file = File::create(path).map_err(|e| {
+        Error::InvalidPath(format!(
+            "Failed to create export file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let mut writer = BufWriter::new(file);
+
+    let mut buffer = vec![0.0; EXPORT_CHUNK_SIZE];
+    let mut num_samples = 0u64;
+    loop {
+        let n = reader.read_physical_buf(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+
+        for &value in &buffer[..n] {
+            match format {
+                ChannelFormat::Csv => match options.decimals {
+                    Some(decimals) => {
+                        let value = round_decimals(value, decimals, options.rounding);
+                        writeln!(writer, "{value:.decimals$}")?;
+                    }
+                    None => writeln!(writer, "{}", Number(value))?,
+                },
+                #[allow(clippy::cast_possible_truncation)]
+                ChannelFormat::F32 => writer.write_all(&(value as f32).to_le_bytes())?,
+            }
+        }
+        num_samples += n as u64;
+    }
+    writer.flush()?;
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    Ok(ChannelEntry {
+        index,
+        name: reader.description().map(str::to_string),
+        units: reader.units().to_string(),
+        calibrated: reader.signal_info().is_calibrated(),
+        sampling_frequency: reader.sampling_frequency().unwrap_or_default(),
+        file_name,
+        num_samples,
+    })
+}
+
+/// Round a value to a number of decimals.
+fn round_decimals(value: f64, decimals: usize, rounding: RoundingMode) -> f64 {
+    let factor = 10f64.powi(i32::try_from(decimals).unwrap_or(i32::MAX));
+    if factor.is_finite() {
+        rounding.round(value * factor) / factor
+    } else {
+        value
+    }
+}
+
+/// Size in bytes of the `.npy` preamble (magic, version, header) written by [`windows_npy`].
+///
+/// A fixed size allows the header to be rewritten in place once the number of
+/// windows is known.
+const NPY_PREAMBLE_SIZE: usize = 192;
+
+/// Manifest describing the tensor written by [`windows_npy`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowManifest {
+    /// Name of the exported record.
+    pub record_name: String,
+    /// Sampling frequency (Hz) of the exported signals.
+    pub sampling_frequency: f64,
+    /// Number of frames in each window.
+    pub window: usize,
+    /// Number of frames between the starts of consecutive windows.
+    pub stride: usize,
+    /// Number of windows written.
+    pub num_windows: u64,
+    /// Signal descriptions, in channel order.
+    pub channel_names: Vec<Option<String>>,
+    /// Physical units, in channel order.
+    pub channel_units: Vec<String>,
+    /// Whether each channel is calibrated (otherwise raw ADC values are exported).
+    pub channel_calibrated: Vec<bool>,
+    /// Name of the `.npy` file (relative to the export directory).
+    pub file_name: String,
+}
+
+impl WindowManifest {
+    /// Shape of the exported tensor: `(windows, channels, window)`.
+    #[must_use]
+    pub const fn shape(&self) -> (u64, usize, usize) {
+        (self.num_windows, self.channel_names.len(), self.window)
+    }
+
+    /// Serialize the manifest as a JSON document.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let channels = self
+            .channel_names
+            .iter()
+            .zip(&self.channel_units)
+            .zip(&self.channel_calibrated)
+            .map(|((name, units), calibrated)| {
+                format!(
+                    "    {{\"name\": {}, \"units\": {}, \"calibrated\": {}}}",
+                    name.as_deref()
+                        .map_or_else(|| "null".to_string(), json_string),
+                    json_string(units),
+                    calibrated
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let (windows, num_channels, window) = self.shape();
+
+        format!(
+            "{{\n  \"record\": {},\n  \"sampling_frequency\": {},\n  \"file\": {},\n  \"dtype\": \"<f4\",\n  \"shape\": [{windows}, {num_channels}, {window}],\n  \"window\": {},\n  \"stride\": {},\n  \"channels\": [\n{}\n  ]\n}}\n",
+            json_string(&self.record_name),
+            Number(self.sampling_frequency),
+            json_string(&self.file_name),
+            self.window,
+            self.stride,
+            channels
+        )
+    }
+}
+
+/// Export fixed-size windows of all signals as a `NumPy` `.npy` tensor.
+///
+/// The record is read in a single streaming pass. Every window of `window`
+/// frames (starting every `stride` frames) is written as a `float32` block of
+/// shape `(channels, window)` in physical units, producing a tensor of shape
+/// `(windows, channels, window)` in `<record>_windows.npy`. A
+/// [`WindowManifest`] is written next to it as `<record>_windows.json`.
+///
+/// Trailing frames that do not fill a complete window are dropped.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `window` or `stride` is zero
+/// - The record is a multi-segment record
+/// - The signal files cannot be read
+/// - The output directory or files cannot be written
+pub fn windows_npy<P: AsRef<Path>>(
+    record: &Record,
+    dir: P,
+    window: usize,
+    stride: usize,
+) -> Result<WindowManifest> {
+    if window == 0 || stride == 0 {
+        return Err(Error::InvalidData(
+            "Window length and stride must be greater than zero".into(),
+        ));
+    }
+
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    let signals = record
+        .signal_info()
+        .ok_or_else(|| Error::InvalidHeader("No signal specifications in header".into()))?;
+    let mut reader = record.multi_signal_reader()?;
+
+    let record_name = record.metadata().name();
+    let file_name = format!("{record_name}_windows.npy");
+    let path = dir.join(&file_name);
+    let file = File::create(&path).map_err(|e| {
+        Error::InvalidPath(format!(
+            "Failed to create export file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let mut writer = BufWriter::new(file);
+
+    // Placeholder header, rewritten once the number of windows is known
+    writer.write_all(&npy_preamble(0, signals.len(), window))?;
+
+    let mut frames: VecDeque<Vec<f64>> = VecDeque::with_capacity(window);
+    let mut to_skip = 0usize;
+    let mut num_windows = 0u64;
+    loop {
+        let chunk = reader.read_frames_physical(EXPORT_CHUNK_SIZE)?;
+        if chunk.is_empty() {
+            break;
+        }
+
+        for frame in chunk {
+            if to_skip > 0 {
+                to_skip -= 1;
+                continue;
+            }
+            frames.push_back(frame);
+            if frames.len() < window {
+                continue;
+            }
+
+            // Write the window channel by channel
+            for channel in 0..signals.len() {
+                for frame in &frames {
+                    #[allow(clippy::cast_possible_truncation)]
+                    writer.write_all(&(frame[channel] as f32).to_le_bytes())?;
+                }
+            }
+            num_windows += 1;
+
+            let drained = stride.min(window);
+            frames.drain(..drained);
+            to_skip = stride - drained;
+        }
+    }
+
+    writer.seek(SeekFrom::Start(0))?;
+    writer.write_all(&npy_preamble(num_windows, signals.len(), window))?;
+    writer.flush()?;
+
+    let manifest = WindowManifest {
+        record_name: record_name.to_string(),
+        sampling_frequency: record.metadata().sampling_frequency(),
+        window,
+        stride,
+        num_windows,
+        channel_names: signals.iter().map(|s| s.description.clone()).collect(),
+        channel_units: signals.iter().map(|s| s.units().to_string()).collect(),
+        channel_calibrated: signals.iter().map(SignalInfo::is_calibrated).collect(),
+        file_name,
+    };
+
+    std::fs::write(
+        dir.join(format!("{record_name}_windows.json")),
+        manifest.to_json(),
+    )?;
+
+    Ok(manifest)
+}
+
+/// Build a fixed-size `.npy` (version 1.0) preamble for a 3-D `float32` tensor.
+fn npy_preamble(num_windows: u64, num_channels: usize, window: usize) -> Vec<u8> {
+    let mut preamble = b"\x93NUMPY\x01\x00".to_vec();
+    let header_len = NPY_PREAMBLE_SIZE - preamble.len() - 2;
+
+    let mut header = format!(
+        "{{'descr': '<f4', 'fortran_order': False, 'shape': ({num_windows}, {num_channels}, {window}), }}"
+    );
+    // Pad with spaces and terminate with a newline, as required by the format
+    while header.len() < header_len - 1 {
+        header.push(' ');
+    }
+    header.push('\n');
+
+    #[allow(clippy::cast_possible_truncation)]
+    preamble.extend_from_slice(&(header_len as u16).to_le_bytes());
+    preamble.extend_from_slice(header.as_bytes());
+    preamble
+}
+
+/// Size in bytes of the header of a PCM `.wav` file.
+const WAV_HEADER_SIZE: u32 = 44;
+
+/// Export one signal as a mono 16-bit PCM `.wav` file, for listening.
+///
+/// The signal is converted to physical units and resampled to `sample_rate`
+/// (Hz) by [`Record::pipeline()`], with the
+/// [`Linear`](crate::record::Interpolation::Linear) interpolation of a
+/// [`ResamplingReader`](crate::record::ResamplingReader). It is then
+/// normalized: its mean is removed and its peak is scaled to full scale.
+/// Invalid samples become silence. The record is read twice (once to
+/// measure, once to write), so memory use does not grow with its length.
+///
+/// Returns the number of samples written.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The record is a multi-segment record
+/// - The signal index is out of bounds or `sample_rate` is zero
+/// - The signal files cannot be read
+/// - The output file cannot be written, or would exceed the 4 GiB limit of
+///   the format
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+/// use wfdb::record::export;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// export::to_wav(&record, "100_mlii.wav", 0, 8000)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_wav<P: AsRef<Path>>(
+    record: &Record,
+    path: P,
+    signal_index: usize,
+    sample_rate: u32,
+) -> Result<u64> {
+    let num_signals = record.signal_count();
+    if signal_index >= num_signals {
+        return Err(Error::InvalidData(format!(
+            "Signal index {signal_index} out of bounds (record has {num_signals} signals)"
+        )));
+    }
+    if sample_rate == 0 {
+        return Err(Error::InvalidData(
+            "WAV sample rate must be greater than zero".to_string(),
+        ));
+    }
+    let pipeline = record
+        .pipeline()
+        .physical()
+        .resample(f64::from(sample_rate));
+
+    // First pass: mean and peak of the valid values
+    let mut count = 0u64;
+    let mut valid = 0u64;
+    let mut sum = 0.0;
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    pipeline.for_each_frame(|frame| {
+        let value = frame[signal_index];
+        count += 1;
+        if value.is_finite() {
+            valid += 1;
+            sum += value;
+            min = min.min(value);
+            max = max.max(value);
+        }
+    })?;
+    #[allow(clippy::cast_precision_loss)]
+    let mean = if valid > 0 { sum / valid as f64 } else { 0.0 };
+    let peak = (max - mean).max(mean - min);
+    let scale = if peak > 0.0 {
+        f64::from(i16::MAX) / peak
+    } else {
+        0.0
+    };
+
+    let data_size = u32::try_from(count * 2)
+        .ok()
+        .filter(|&size| size <= u32::MAX - WAV_HEADER_SIZE)
+        .ok_or_else(|| Error::InvalidData(format!("{count} samples do not fit in a WAV file")))?;
+
+    let path = path.as_ref();
+    let file = File::create(path).map_err(|e| {
+        Error::InvalidPath(format!(
+            "Failed to create export file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let mut writer = BufWriter::new(file);
+    writer.write_all(&wav_header(data_size, sample_rate))?;
+
+    // Second pass: write the normalized values
+    let mut result = Ok(());
+    pipeline.for_each_frame(|frame| {
+        if result.is_err() {
+            return;
+        }
+        let value = frame[signal_index];
+        let level = if value.is_finite() {
+            ((value - mean) * scale).round()
+        } else {
+            0.0
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let pcm = level.clamp(f64::from(i16::MIN), f64::from(i16::MAX)) as i16;
+        result = writer.write_all(&pcm.to_le_bytes());
+    })?;
+    result?;
+    writer.flush()?;
+
+    Ok(count)
+}
+
+/// Build the header of a mono 16-bit PCM `.wav` file.
+fn wav_header(data_size: u32, sample_rate: u32) -> Vec<u8> {
+    let mut header = Vec::with_capacity(WAV_HEADER_SIZE as usize);
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&(WAV_HEADER_SIZE - 8 + data_size).to_le_bytes());
+    header.extend_from_slice(b"WAVEfmt ");
+    header.extend_from_slice(&16u32.to_le_bytes()); // Size of the fmt chunk
+    header.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    header.extend_from_slice(&1u16.to_le_bytes()); // Mono
+    header.extend_from_slice(&sample_rate.to_le_bytes());
+    header.extend_from_slice(&sample_rate.saturating_mul(2).to_le_bytes()); // Byte rate
+    header.extend_from_slice(&2u16.to_le_bytes()); // Block align
+    header.extend_from_slice(&16u16.to_le_bytes()); // Bits per sample
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&data_size.to_le_bytes());
+    header
+}
+
+/// Time column of a CSV export, as selected by the `-p` options of
+/// `rdsamp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvTime {
+    /// Elapsed time as `'h:mm:ss.mmm'` (`rdsamp -p`), with the hours
+    /// omitted in the first hour.
+    #[default]
+    Elapsed,
+    /// Elapsed time in seconds, with 3 decimals (`rdsamp -ps`).
+    Seconds,
+    /// Date and time as `'[hh:mm:ss.mmm dd/mm/yyyy]'` (`rdsamp -pd`).
+    ///
+    /// Requires the `chrono` feature, and a base time and date in the
+    /// header.
+    #[cfg(feature = "chrono")]
+    DateTime,
+}
+
+/// Options of [`Record::export_csv()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Format of the time column.
+    pub time: CsvTime,
+    /// Indices of the signals to export, in column order (`None` exports
+    /// every signal), like `rdsamp -s`.
+    pub signals: Option<Vec<usize>>,
+    /// First frame to export, like `rdsamp -f`.
+    pub start: u64,
+    /// Frame after the last one to export (`None` exports to the end),
+    /// like `rdsamp -t`.
+    pub end: Option<u64>,
+    /// Number of decimals written for each value.
+    pub decimals: usize,
+    /// Whether to write the two header rows (names, then units), like
+    /// `rdsamp -v`.
+    pub header: bool,
+    /// Whether to write one row per sample at the highest sampling rate,
+    /// like `rdsamp -H`, rather than one row per frame.
+    pub high_resolution: bool,
+    /// Callback reporting the frames written, if any.
+    pub progress: Option<Progress>,
+    /// Limits of the export, if any.
+    pub budget: Option<Budget>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            time: CsvTime::default(),
+            signals: None,
+            start: 0,
+            end: None,
+            decimals: 3,
+            header: true,
+            high_resolution: true,
+            progress: None,
+            budget: None,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Write the time column in the given format.
+    #[must_use]
+    pub const fn with_time(mut self, time: CsvTime) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Export only the given signals, in this order.
+    #[must_use]
+    pub fn with_signals(mut self, signals: &[usize]) -> Self {
+        self.signals = Some(signals.to_vec());
+        self
+    }
+
+    /// Export only a range of frames, in any range form (`0..3600`,
+    /// `..=359`, `1000..`).
+    #[must_use]
+    pub fn with_frames<R: RangeBounds<u64>>(mut self, range: R) -> Self {
+        let (start, end) = half_open(&range);
+        self.start = start;
+        self.end = (end != u64::MAX).then_some(end);
+        self
+    }
+
+    /// Write each value with `decimals` decimals.
+    #[must_use]
+    pub const fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Write or omit the header rows.
+    #[must_use]
+    pub const fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Write one row per sample at the highest sampling rate (the
+    /// default), or one row per frame with signals averaged over each frame.
+    #[must_use]
+    pub const fn with_high_resolution(mut self, high_resolution: bool) -> Self {
+        self.high_resolution = high_resolution;
+        self
+    }
+
+    /// Report the frames written to a callback, which can cancel the
+    /// export. See [`Progress`].
+    #[must_use]
+    pub fn with_progress(mut self, progress: Progress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Bound the work of the export. See [`Budget`].
+    #[must_use]
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+}
+
+impl Record {
+    /// Write signals as CSV in physical units, in the layout of
+    /// `rdsamp -c -H -p -v` from the WFDB software package.
+    ///
+    /// The first column is the time of each row (see [`CsvTime`]), followed
+    /// by one column per signal. Values are written with a fixed number of
+    /// decimals, and invalid samples as `-`. Each frame is written as one
+    /// row per sample of the signal with the most samples per frame, and
+    /// signals with fewer samples repeat each of them, as `rdsamp -H` does.
+    /// Without [`high_resolution`](CsvOptions::high_resolution), each frame
+    /// is one row and signals are averaged over it, as `rdsamp` does without
+    /// `-H`. With [`header`](CsvOptions::header), two rows come first: the
+    /// column names (signal descriptions) and the units, quoted with single
+    /// quotes:
+    ///
+    /// ```text
+    /// 'Elapsed time','MLII','V5'
+    /// 'hh:mm:ss.mmm','mV','mV'
+    /// '0:00.000',-0.145,-0.065
+    /// '0:00.003',-0.145,-0.065
+    /// ```
+    ///
+    /// Returns the number of frames written, which is also the number of rows
+    /// unless signals have several samples per frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - This is a multi-segment record
+    /// - A signal index is out of bounds, or the frame range is reversed
+    /// - [`CsvTime::DateTime`] is requested but the header has no base time
+    ///   and date
+    /// - The signal files cannot be read, or `writer` fails
+    /// - The [progress callback](CsvOptions::with_progress) cancels the export,
+    ///   or the [budget](CsvOptions::with_budget) is spent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    ///
+    /// use wfdb::Record;
+    /// use wfdb::record::export::{CsvOptions, CsvTime};
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    ///
+    /// // Same as `rdsamp -r 100 -c -H -ps -v -s 1 -t 10`
+    /// let options = CsvOptions::default()
+    ///     .with_time(CsvTime::Seconds)
+    ///     .with_signals(&[1])
+    ///     .with_frames(..3600);
+    /// record.export_csv(File::create("100.csv")?, &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_csv<W: Write>(&self, writer: W, options: &CsvOptions) -> Result<u64> {
+        if self.is_multi_segment() {
+            return Err(Error::InvalidData(
+                "CSV export not yet supported for multi-segment records".into(),
+            ));
+        }
+        let signals = self.signal_info().unwrap_or_default();
+        let columns = options
+            .signals
+            .clone()
+            .unwrap_or_else(|| (0..signals.len()).collect());
+        if let Some(&index) = columns.iter().find(|&&i| i >= signals.len()) {
+            return Err(Error::InvalidData(format!(
+                "Signal index {index} out of bounds (record has {} signals)",
+                signals.len()
+            )));
+        }
+        let end = options.end.unwrap_or(u64::MAX);
+        if options.start > end {
+            return Err(Error::InvalidData(format!(
+                "Invalid export range {}..{end}",
+                options.start
+            )));
+        }
+        let mut clock = self.clock();
+        #[cfg(feature = "chrono")]
+        if options.time == CsvTime::DateTime && clock.base_datetime.is_none() {
+            return Err(Error::InvalidData(
+                "Record has no base time and date".into(),
+            ));
+        }
+
+        let mut writer = BufWriter::new(writer);
+        if options.header {
+            write_csv_header(&mut writer, options.time, signals, &columns)?;
+        }
+
+        let total = self
+            .total_samples()
+            .map(|num_samples| num_samples.min(end).saturating_sub(options.start));
+        let mut progress =
+            ProgressCounter::new(options.progress.as_ref(), options.budget.as_ref(), total)?;
+
+        let mut reader = self.multi_signal_reader()?;
+        reader.advance_to_frame(options.start)?;
+        // Rows are timed at the highest sampling rate in high resolution
+        let rows_per_frame = if options.high_resolution {
+            reader
+                .samples_per_frame()
+                .iter()
+                .copied()
+                .max()
+                .unwrap_or(1)
+                .max(1)
+        } else {
+            1
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let row_frequency = clock.sampling_frequency * rows_per_frame as f64;
+        clock.sampling_frequency = row_frequency;
+        let decimals = options.decimals;
+        let mut frame_number = options.start;
+        let mut line = String::new();
+        while frame_number < end {
+            let count = usize::try_from(end - frame_number)
+                .unwrap_or(usize::MAX)
+                .min(EXPORT_CHUNK_SIZE);
+            let rows = if options.high_resolution {
+                read_high_resolution_rows(&mut reader, count, rows_per_frame)?
+            } else {
+                reader.read_frames_physical(count)?
+            };
+            if rows.is_empty() {
+                break;
+            }
+            let num_frames = (rows.len() / rows_per_frame) as u64;
+            let first_row = frame_number.saturating_mul(rows_per_frame as u64);
+            for (row_number, values) in (first_row..).zip(rows) {
+                line.clear();
+                write_csv_time(&mut line, options.time, &clock, row_number);
+                for &index in &columns {
+                    let value = values[index];
+                    if value.is_nan() {
+                        line.push_str(",-");
+                    } else {
+                        let _ = write!(line, ",{value:.decimals$}");
+                    }
+                }
+                writeln!(writer, "{line}")?;
+            }
+            frame_number += num_frames;
+            progress.advance(num_frames)?;
+        }
+        writer.flush()?;
+        progress.finish()?;
+
+        Ok(frame_number - options.start)
+    }
+}
+
+/// Read up to `count` frames as rows of physical values, one row per sample
+/// of the signal with the most samples per frame, like `rdsamp -H`.
+///
+/// Signals with fewer samples per frame repeat each of them.
+fn read_high_resolution_rows(
+    reader: &mut MultiSignalReader,
+    count: usize,
+    rows_per_frame: usize,
+) -> Result<Vec<Vec<f64>>> {
+    let mut rows = Vec::new();
+    let mut samples = Vec::new();
+    for _ in 0..count {
+        if !reader.read_frame_samples_into(&mut samples)? {
+            break;
+        }
+        for row in 0..rows_per_frame {
+            rows.push(
+                samples
+                    .iter()
+                    .zip(reader.scales())
+                    .map(|(signal, scale)| {
+                        signal
+                            .get(signal.len() * row / rows_per_frame)
+                            .map_or(f64::NAN, |&value| scale.to_physical(value))
+                    })
+                    .collect(),
+            );
+        }
+    }
+    Ok(rows)
+}
+
+/// Write the time cell of a CSV row.
+fn write_csv_time(line: &mut String, time: CsvTime, clock: &RecordClock, row: u64) {
+    match time {
+        CsvTime::Elapsed => {
+            let _ = write!(line, "'{}'", elapsed_time(clock.elapsed(row)));
+        }
+        CsvTime::Seconds => {
+            let _ = write!(line, "{:.3}", clock.elapsed(row));
+        }
+        #[cfg(feature = "chrono")]
+        CsvTime::DateTime => {
+            if let Some(datetime) = clock.datetime(row) {
+                let _ = write!(line, "'[{}]'", datetime.format("%H:%M:%S%.3f %d/%m/%Y"));
+            }
+        }
+    }
+}
+
+/// Write the two header rows of a CSV export: the column names, then the
+/// units.
+fn write_csv_header<W: Write>(
+    writer: &mut W,
+    time: CsvTime,
+    signals: &[SignalInfo],
+    columns: &[usize],
+) -> Result<()> {
+    let (time_name, time_units) = match time {
+        CsvTime::Elapsed => ("Elapsed time", "hh:mm:ss.mmm"),
+        CsvTime::Seconds => ("Elapsed time", "seconds"),
+        #[cfg(feature = "chrono")]
+        CsvTime::DateTime => ("Time and date", "hh:mm:ss.mmm dd/mm/yyyy"),
+    };
+    let mut names = format!("'{time_name}'");
+    let mut units = format!("'{time_units}'");
+    for &index in columns {
+        let signal = &signals[index];
+        let name = signal
+            .description()
+            .map_or_else(|| format!("signal {index}"), str::to_string);
+        let _ = write!(names, ",'{name}'");
+        let _ = write!(units, ",'{}'", signal.units());
+    }
+    writeln!(writer, "{names}")?;
+    writeln!(writer, "{units}")?;
+    Ok(())
+}
+
+/// Quote and escape a string as a JSON string literal.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(c));
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
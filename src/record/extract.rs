@@ -0,0 +1,272 @@
+//! Extraction of a range of frames and signals as a new record.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::ops::RangeBounds;
+use std::path::Path;
+
+use crate::header::Specifications;
+use crate::record::Provenance;
+use crate::record::index::half_open;
+use crate::record::lock::WriteLock;
+use crate::record::validation::add_to_checksum;
+use crate::signal::{FormatEncoder, get_encoder, get_interleaved_encoder};
+use crate::{Error, Header, Record, Result, Sample, SignalFormat, SignalInfo};
+
+/// Signals of the output record stored in one signal file.
+struct OutputGroup {
+    /// Name of the output file.
+    file_name: String,
+    /// Source indices of the signals, in order.
+    signals: Vec<usize>,
+    /// Writer of the output file.
+    writer: BufWriter<File>,
+    /// Format of the signals.
+    format: SignalFormat,
+    /// Encoder of the signals' format.
+    encoder: Box<dyn FormatEncoder>,
+    /// Scratch buffer for the samples of one frame.
+    frame: Vec<Sample>,
+    /// Checksum of each signal.
+    checksums: Vec<i16>,
+    /// First sample of each signal.
+    initial_values: Vec<Sample>,
+}
+
+impl Record {
+    /// Write a range of frames and a subset of the signals as a new record,
+    /// like the `snip` tool of the WFDB software package.
+    ///
+    /// `path` is the path of the new record; the `.hea` extension may be
+    /// omitted and its file stem is the record name. `range` selects frames
+    /// in any range form (`1000..2000`, `..=999`, `..`), truncated at the
+    /// end of the record. `signals` lists the indices of the signals to keep
+    /// (`None` keeps all); they are written in their order in this record.
+    ///
+    /// Samples are copied unchanged: each signal keeps its format, samples
+    /// per frame, gain, baseline and units, and signals sharing a file here
+    /// share a file in the new record (`<name>.dat`, or `<name>_<n>.dat`
+    /// when there are several). Skews are applied and dropped, and initial
+    /// values and checksums are recomputed. The base counter is shifted to
+    /// the first frame; the base time and date are dropped unless the range
+    /// starts at the first frame. The info strings are kept, followed by a
+    /// [`Provenance`] entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - This is a multi-segment record
+    /// - A signal index is out of bounds, or the range is reversed
+    /// - A signal format cannot be encoded (e.g. FLAC formats)
+    /// - The file name is not a valid record name
+    /// - Another writer holds the lock of the record (see [`Record::create`])
+    /// - A signal file cannot be read, or the new files cannot be written
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    ///
+    /// // The first minute of MLII
+    /// let snippet = record.extract("out/100_mlii", 0..21_600, Some(&[0]))?;
+    /// assert_eq!(snippet.metadata().num_samples(), Some(21_600));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extract<P: AsRef<Path>, R: RangeBounds<u64>>(
+        &self,
+        path: P,
+        range: R,
+        signals: Option<&[usize]>,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| Error::InvalidPath(format!("Invalid record path '{}'", path.display())))?
+            .to_string();
+        if self.is_multi_segment() {
+            return Err(Error::InvalidData(
+                "Extracting from multi-segment records is not yet supported".into(),
+            ));
+        }
+        let source_signals = self.signal_info().unwrap_or_default();
+
+        let selected = signals.map_or_else(
+            || (0..source_signals.len()).collect(),
+            |signals| {
+                let mut selected = signals.to_vec();
+                selected.sort_unstable();
+                selected.dedup();
+                selected
+            },
+        );
+        if let Some(&index) = selected.iter().find(|&&i| i >= source_signals.len()) {
+            return Err(Error::InvalidData(format!(
+                "Signal index {index} out of bounds (record has {} signals)",
+                source_signals.len()
+            )));
+        }
+
+        let (start, end) = half_open(&range);
+        if start > end {
+            return Err(Error::InvalidData(format!(
+                "Invalid extract range {start}..{end}"
+            )));
+        }
+
+        let lock = WriteLock::acquire(path)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let mut groups = output_groups(dir, &name, source_signals, &selected)?;
+        let num_frames = self.copy_frames(&mut groups, start, end)?;
+
+        let mut output_signals = Vec::with_capacity(selected.len());
+        for group in &groups {
+            output_signals.extend(
+                group
+                    .signals
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &index)| SignalInfo {
+                        file_name: group.file_name.clone(),
+                        skew: None,
+                        byte_offset: None,
+                        initial_value: Some(group.initial_values[k]),
+                        checksum: Some(group.checksums[k]),
+                        block_size: Some(0),
+                        ..source_signals[index].clone()
+                    }),
+            );
+        }
+
+        let mut metadata = self.metadata().clone();
+        metadata.name = name;
+        metadata.num_signals = output_signals.len();
+        metadata.num_samples = Some(num_frames);
+        if start > 0 {
+            if metadata.base_counter.is_some() {
+                metadata.base_counter = Some(self.clock().counter(start));
+            }
+            metadata.base_time = None;
+            metadata.base_date = None;
+        }
+
+        let mut provenance = Provenance::from_record(self);
+        if start > 0 || end < u64::MAX {
+            let end = if end == u64::MAX {
+                String::new()
+            } else {
+                end.to_string()
+            };
+            provenance = provenance.with_transform(format!("slice {start}..{end}"));
+        }
+        if signals.is_some() {
+            let indices: Vec<String> = selected.iter().map(ToString::to_string).collect();
+            provenance = provenance.with_transform(format!("signals {}", indices.join(", ")));
+        }
+        let mut info_strings = self.info_strings().to_vec();
+        info_strings.extend(provenance.to_info_strings());
+
+        let header = Header {
+            metadata,
+            specifications: Specifications::SingleSegment {
+                signals: output_signals,
+            },
+            info_strings,
+        };
+        Self::create_locked(path, &header, Some(lock))
+    }
+
+    /// Copy frames `start..end` of the selected signals to the output files,
+    /// returning the number of frames copied.
+    fn copy_frames(&self, groups: &mut [OutputGroup], start: u64, end: u64) -> Result<u64> {
+        let mut reader = self.multi_signal_reader()?;
+        reader.advance_to_frame(start)?;
+
+        let mut frame = Vec::new();
+        let mut num_frames = 0u64;
+        while num_frames < end - start && reader.read_frame_samples_into(&mut frame)? {
+            for group in groups.iter_mut() {
+                if num_frames == 0 {
+                    group.initial_values = group
+                        .signals
+                        .iter()
+                        .map(|&index| frame[index].first().copied().unwrap_or(0))
+                        .collect();
+                    // Difference formats start from the first sample of
+                    // each signal
+                    let samples_per_frame: Vec<usize> = group
+                        .signals
+                        .iter()
+                        .map(|&index| frame[index].len())
+                        .collect();
+                    group.encoder = get_interleaved_encoder(
+                        group.format,
+                        &group.initial_values,
+                        &samples_per_frame,
+                    )?;
+                }
+                group.frame.clear();
+                for (k, &index) in group.signals.iter().enumerate() {
+                    for &sample in &frame[index] {
+                        group.checksums[k] = add_to_checksum(group.checksums[k], sample);
+                    }
+                    group.frame.extend_from_slice(&frame[index]);
+                }
+                group.encoder.encode(&mut group.writer, &group.frame)?;
+            }
+            num_frames += 1;
+        }
+
+        for group in groups.iter_mut() {
+            group.encoder.finish(&mut group.writer)?;
+            group.writer.flush()?;
+        }
+        Ok(num_frames)
+    }
+}
+
+/// Create one output file per source file of the selected signals, named
+/// `<name>.dat` or, when there are several, `<name>_<n>.dat`.
+fn output_groups(
+    dir: &Path,
+    name: &str,
+    source_signals: &[SignalInfo],
+    selected: &[usize],
+) -> Result<Vec<OutputGroup>> {
+    let mut files: Vec<(&str, Vec<usize>)> = Vec::new();
+    for &index in selected {
+        let file_name = source_signals[index].file_name.as_str();
+        match files.iter_mut().find(|(name, _)| *name == file_name) {
+            Some((_, group)) => group.push(index),
+            None => files.push((file_name, vec![index])),
+        }
+    }
+
+    let count = files.len();
+    files
+        .into_iter()
+        .enumerate()
+        .map(|(n, (_, signals))| {
+            let file_name = if count == 1 {
+                format!("{name}.dat")
+            } else {
+                format!("{name}_{n}.dat")
+            };
+            let format = source_signals[signals[0]].format;
+            Ok(OutputGroup {
+                writer: BufWriter::new(File::create(dir.join(&file_name))?),
+                encoder: get_encoder(format, 0)?,
+                format,
+                file_name,
+                frame: Vec::new(),
+                checksums: vec![0; signals.len()],
+                initial_values: vec![0; signals.len()],
+                signals,
+            })
+        })
+        .collect()
+}
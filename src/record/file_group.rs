@@ -0,0 +1,170 @@
+//! Interleaved signal files shared by the signal readers of their signals.
+
+use std::collections::HashMap;
+use std::io::{BufReader, Seek, SeekFrom};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::record::ReaderOptions;
+use crate::record::index::seek_position;
+use crate::record::source::{ReadSeek, RecordFiles};
+use crate::signal::FormatDecoder;
+use crate::{Error, Result, Sample, SignalFormat};
+
+/// Cursor over a section of a signal file holding interleaved signals.
+///
+/// Frames are decoded in blocks, kept until a reader asks for a frame
+/// outside the block. Readers of the signals of the file share the cursor
+/// (see [`SharedFileGroup`]) and keep their own positions, so readers
+/// moving through the record together open the file once and decode each
+/// frame once.
+pub struct FileGroup {
+    /// Format decoder for the signals of the file.
+    decoder: Box<dyn FormatDecoder>,
+    /// Buffered reader for the signal file.
+    reader: BufReader<Box<dyn ReadSeek>>,
+    /// Name of the signal file.
+    file_name: String,
+    /// Byte offset of the first frame in the file.
+    byte_offset: u64,
+    /// Number of samples in a frame of the file.
+    frame_len: usize,
+    /// Block of consecutive frames decoded from the file.
+    block: Vec<Sample>,
+    /// Frame number of the first frame of `block`.
+    block_start: u64,
+    /// Number of complete frames in `block`.
+    block_frames: usize,
+    /// Maximum number of frames decoded into `block` at a time.
+    block_capacity: usize,
+    /// Frame number at which the file is positioned after the last block,
+    /// if known.
+    file_frame: Option<u64>,
+}
+
+/// Reference-counted handle to a [`FileGroup`].
+#[derive(Clone)]
+pub struct SharedFileGroup(Arc<Mutex<FileGroup>>);
+
+/// File groups opened for the readers of a record, by file name and byte
+/// offset.
+pub type FileGroups = HashMap<(String, u64), SharedFileGroup>;
+
+impl FileGroup {
+    /// Open the section of `file_name` starting at `byte_offset`, holding
+    /// signals with the given initial values and samples per frame.
+    pub fn open(
+        files: &RecordFiles,
+        file_name: &str,
+        format: SignalFormat,
+        byte_offset: u64,
+        initial_values: &[Sample],
+        samples_per_frame: &[usize],
+        options: &ReaderOptions,
+    ) -> Result<Self> {
+        let file = files.open_signal(file_name)?;
+        let mut reader = BufReader::with_capacity(options.effective_buffer_capacity(), file);
+        if byte_offset > 0 {
+            reader.seek(SeekFrom::Start(byte_offset))?;
+        }
+        let decoder =
+            crate::signal::get_interleaved_decoder(format, initial_values, samples_per_frame)?;
+
+        Ok(Self {
+            decoder,
+            reader,
+            file_name: file_name.to_string(),
+            byte_offset,
+            frame_len: samples_per_frame.iter().sum(),
+            block: Vec::new(),
+            block_start: 0,
+            block_frames: 0,
+            block_capacity: options.effective_interleaved_block_frames(),
+            file_frame: Some(0),
+        })
+    }
+
+    /// Get the size (bytes) of a frame.
+    pub fn frame_bytes(&self) -> Result<u64> {
+        let bytes = self.decoder.bytes_per_sample().map_or_else(
+            || self.decoder.bytes_per_frame(self.frame_len),
+            |bytes| Some(self.frame_len * bytes),
+        );
+        bytes.map(|bytes| bytes as u64).ok_or_else(|| {
+            Error::InvalidData("Seeking not supported for this signal format".into())
+        })
+    }
+
+    /// Get sample `position` of `frame`, decoding the block holding the
+    /// frame if needed. Returns `None` at the end of the file.
+    pub fn sample(&mut self, frame: u64, position: usize) -> Result<Option<Sample>> {
+        if !(self.block_start..self.block_start + self.block_frames as u64).contains(&frame)
+            && !self.load_block(frame)?
+        {
+            return Ok(None);
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let offset = (frame - self.block_start) as usize * self.frame_len;
+        Ok(Some(self.block[offset + position]))
+    }
+
+    /// Decode a block of frames starting at `frame`, returning `false` at
+    /// the end of the file.
+    ///
+    /// The file is only seeked when not already positioned at `frame`, so
+    /// sequential reads decode the file in one pass.
+    fn load_block(&mut self, frame: u64) -> Result<bool> {
+        self.block_frames = 0;
+        if self.file_frame != Some(frame) {
+            if self.decoder.is_differential() {
+                // Accumulate the differences of the frames before `frame`,
+                // from the start of the file unless it is behind
+                let from = match self.file_frame {
+                    Some(file_frame) if file_frame < frame => file_frame,
+                    _ => {
+                        self.reader.seek(SeekFrom::Start(self.byte_offset))?;
+                        self.decoder.rewind();
+                        0
+                    }
+                };
+                let samples = seek_position(0, frame - from, self.frame_len as u64)?;
+                let skipped = self.decoder.skip(&mut self.reader, samples)?;
+                if skipped < samples {
+                    self.file_frame = None;
+                    return Ok(false);
+                }
+            } else {
+                let byte_offset = seek_position(self.byte_offset, frame, self.frame_bytes()?)?;
+                self.reader.seek(SeekFrom::Start(byte_offset))?;
+                self.decoder.reset();
+            }
+        }
+
+        self.block.resize(self.block_capacity * self.frame_len, 0);
+        let n = self.decoder.decode_buf(&mut self.reader, &mut self.block)?;
+        let frames = n / self.frame_len;
+        if frames == 0 && n > 0 {
+            return Err(Error::SignalDecode {
+                file: self.file_name.clone(),
+                byte_offset: self.reader.stream_position()?,
+                reason: "Incomplete frame in interleaved signal file".to_string(),
+            });
+        }
+        self.block_start = frame;
+        self.block_frames = frames;
+        self.file_frame = (n == self.block.len()).then_some(frame + frames as u64);
+        Ok(frames > 0)
+    }
+}
+
+impl SharedFileGroup {
+    /// Share a file group.
+    pub fn new(group: FileGroup) -> Self {
+        Self(Arc::new(Mutex::new(group)))
+    }
+
+    /// Lock the file group, recovering it if a reader panicked while
+    /// holding the lock.
+    pub fn lock(&self) -> MutexGuard<'_, FileGroup> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
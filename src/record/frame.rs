@@ -0,0 +1,198 @@
+//! Frames of samples carrying the names and scales of their channels.
+
+use std::ops::Index;
+use std::sync::Arc;
+
+use crate::Sample;
+use crate::signal::{INVALID_SAMPLE, SignalScale};
+
+/// Names and scales of the channels of a reader, shared by its frames.
+#[derive(Debug, Default, PartialEq)]
+pub struct FrameChannels {
+    /// Description of each signal, if any.
+    pub names: Vec<Option<String>>,
+    /// Conversion of each signal to physical units.
+    pub scales: Vec<SignalScale>,
+}
+
+/// One frame of a record: one sample of each signal, with the sample number
+/// of the frame and the names and scales of the channels.
+///
+/// Read with
+/// [`MultiSignalReader::read_labeled_frame`](crate::MultiSignalReader::read_labeled_frame).
+/// Channels are indexed by signal index or by description. The channel
+/// information is shared by all the frames of a reader, so a frame costs one
+/// allocation, as [`read_frame`](crate::MultiSignalReader::read_frame) does;
+/// use [`raw`](Self::raw) or [`into_raw`](Self::into_raw) to get at the
+/// samples directly.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// let mut reader = record.multi_signal_reader()?;
+///
+/// while let Some(frame) = reader.read_labeled_frame()? {
+///     if !frame.is_invalid(0) {
+///         println!("{}: {}", frame.sample_number(), frame["MLII"]);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// Sample number of the frame.
+    sample_number: u64,
+    /// One sample per signal, by signal index.
+    samples: Vec<Sample>,
+    /// Names and scales of the channels.
+    channels: Arc<FrameChannels>,
+}
+
+impl Frame {
+    /// Create a frame from its samples.
+    pub(crate) const fn new(
+        sample_number: u64,
+        samples: Vec<Sample>,
+        channels: Arc<FrameChannels>,
+    ) -> Self {
+        Self {
+            sample_number,
+            samples,
+            channels,
+        }
+    }
+
+    /// Get the sample number of the frame.
+    #[must_use]
+    pub const fn sample_number(&self) -> u64 {
+        self.sample_number
+    }
+
+    /// Get the number of channels.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check if the frame has no channels.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Get the raw ADC values, by signal index.
+    #[must_use]
+    pub fn raw(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    /// Take the raw ADC values, by signal index.
+    #[must_use]
+    pub fn into_raw(self) -> Vec<Sample> {
+        self.samples
+    }
+
+    /// Get the ADC value of a channel, or `None` if the index is out of
+    /// bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Sample> {
+        self.samples.get(index).copied()
+    }
+
+    /// Get the ADC value of the first channel with the given description.
+    #[must_use]
+    pub fn get_by_name(&self, name: &str) -> Option<Sample> {
+        self.get(self.channel_index(name)?)
+    }
+
+    /// Get the index of the first channel with the given description.
+    #[must_use]
+    pub fn channel_index(&self, name: &str) -> Option<usize> {
+        self.channels
+            .names
+            .iter()
+            .position(|n| n.as_deref() == Some(name))
+    }
+
+    /// Get the description of a channel, if any.
+    #[must_use]
+    pub fn channel_name(&self, index: usize) -> Option<&str> {
+        self.channels.names.get(index)?.as_deref()
+    }
+
+    /// Check if the sample of a channel is invalid ([`INVALID_SAMPLE`]).
+    ///
+    /// Returns `false` if the index is out of bounds.
+    #[must_use]
+    pub fn is_invalid(&self, index: usize) -> bool {
+        self.get(index) == Some(INVALID_SAMPLE)
+    }
+
+    /// Check if any sample of the frame is invalid.
+    #[must_use]
+    pub fn has_invalid(&self) -> bool {
+        self.samples.contains(&INVALID_SAMPLE)
+    }
+
+    /// Get the physical value of a channel, or `None` if the index is out of
+    /// bounds.
+    ///
+    /// Invalid samples are `NaN`, and uncalibrated signals keep their raw
+    /// ADC values.
+    #[must_use]
+    pub fn physical(&self, index: usize) -> Option<f64> {
+        Some(
+            self.channels
+                .scales
+                .get(index)?
+                .to_physical(self.get(index)?),
+        )
+    }
+
+    /// Convert the frame to physical values, by signal index.
+    ///
+    /// Invalid samples are `NaN`, and uncalibrated signals keep their raw
+    /// ADC values.
+    #[must_use]
+    pub fn to_physical(&self) -> Vec<f64> {
+        self.samples
+            .iter()
+            .zip(&self.channels.scales)
+            .map(|(&sample, scale)| scale.to_physical(sample))
+            .collect()
+    }
+}
+
+impl Index<usize> for Frame {
+    type Output = Sample;
+
+    /// Get the ADC value of a channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index(&self, index: usize) -> &Sample {
+        &self.samples[index]
+    }
+}
+
+impl Index<&str> for Frame {
+    type Output = Sample;
+
+    /// Get the ADC value of the first channel with the given description.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no channel has the description.
+    fn index(&self, name: &str) -> &Sample {
+        let index = self
+            .channel_index(name)
+            .unwrap_or_else(|| panic!("No channel named '{name}' in the frame"));
+        &self.samples[index]
+    }
+}
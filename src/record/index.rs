@@ -0,0 +1,130 @@
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{Error, Result};
+
+/// Position of a sample within one signal, counted in that signal's samples.
+///
+/// For signals with several samples per frame, sample numbers grow faster
+/// than frame numbers. Accepted by the typed sample seeks
+/// ([`SignalReader::seek_to_sample_index`](crate::SignalReader::seek_to_sample_index),
+/// [`SegmentSignalReader::seek_to_sample_index`](crate::record::SegmentSignalReader::seek_to_sample_index)).
+/// There is deliberately no conversion from `u64`: wrap a number with
+/// `SampleIndex(n)` once its unit is known.
+///
+/// # Examples
+///
+/// ```
+/// use wfdb::record::{FrameIndex, SampleIndex};
+///
+/// // Sample 9 of a signal with 4 samples per frame is in frame 2
+/// assert_eq!(SampleIndex(9).to_frame(4), FrameIndex(2));
+/// assert_eq!(FrameIndex(2).to_sample(4), SampleIndex(8));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SampleIndex(pub u64);
+
+/// Position of a frame within a record.
+///
+/// A frame holds the samples of every signal at one tick of the record's
+/// sampling frequency (several samples for multi-frequency signals).
+/// Accepted by the typed frame seeks
+/// ([`MultiSignalReader::seek_to_frame_index`](crate::MultiSignalReader::seek_to_frame_index),
+/// [`SegmentReader::seek_to_frame_index`](crate::record::SegmentReader::seek_to_frame_index),
+/// [`SignalReader::seek_to_frame_index`](crate::SignalReader::seek_to_frame_index)).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameIndex(pub u64);
+
+impl SampleIndex {
+    /// Get the sample number.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Get the frame holding this sample, for a signal with
+    /// `samples_per_frame` samples per frame.
+    ///
+    /// A `samples_per_frame` of 0 is treated as 1, as in headers.
+    #[must_use]
+    pub fn to_frame(self, samples_per_frame: u32) -> FrameIndex {
+        FrameIndex(self.0 / u64::from(samples_per_frame.max(1)))
+    }
+}
+
+impl FrameIndex {
+    /// Get the frame number.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Get the first sample of this frame, for a signal with
+    /// `samples_per_frame` samples per frame.
+    ///
+    /// A `samples_per_frame` of 0 is treated as 1, as in headers. The sample
+    /// number saturates at `u64::MAX`.
+    #[must_use]
+    pub fn to_sample(self, samples_per_frame: u32) -> SampleIndex {
+        SampleIndex(self.0.saturating_mul(u64::from(samples_per_frame.max(1))))
+    }
+}
+
+impl From<SampleIndex> for u64 {
+    fn from(sample: SampleIndex) -> Self {
+        sample.0
+    }
+}
+
+impl From<FrameIndex> for u64 {
+    fn from(frame: FrameIndex) -> Self {
+        frame.0
+    }
+}
+
+impl fmt::Display for SampleIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sample {}", self.0)
+    }
+}
+
+impl fmt::Display for FrameIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "frame {}", self.0)
+    }
+}
+
+/// Convert a range of positions to half-open `(start, end)` bounds.
+///
+/// An unbounded start is 0 and an unbounded end is `u64::MAX`, which readers
+/// truncate at the end of the record. Bounds are not checked for order.
+pub fn half_open<R: RangeBounds<u64>>(range: &R) -> (u64, u64) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => u64::MAX,
+    };
+    (start, end)
+}
+
+/// Compute `base + index * stride` for a seek (a byte offset or a number of
+/// samples to skip).
+///
+/// # Errors
+///
+/// Returns an error if the position overflows a `u64`.
+pub fn seek_position(base: u64, index: u64, stride: u64) -> Result<u64> {
+    index
+        .checked_mul(stride)
+        .and_then(|offset| offset.checked_add(base))
+        .ok_or_else(|| {
+            Error::InvalidData(format!(
+                "Cannot seek to position {index}: beyond the addressable range of the file"
+            ))
+        })
+}
@@ -0,0 +1,88 @@
+//! Advisory locks held by record writers.
+
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Result};
+
+/// Get the header file of a record path, whose `.hea` extension may be
+/// omitted.
+pub fn header_path(path: &Path) -> PathBuf {
+    if path.extension().is_some_and(|ext| ext == "hea") {
+        path.to_path_buf()
+    } else {
+        path.with_extension("hea")
+    }
+}
+
+/// Exclusive advisory lock on the header file of a record being written.
+///
+/// Writers take it before writing the signal files and keep it until the
+/// header is written, so that two writers cannot interleave their files.
+/// Readers do not take it. The lock is released when dropped; if the
+/// header file was created for the lock and never written, it is removed.
+/// On file systems without locking, records are written unlocked.
+#[derive(Debug)]
+pub struct WriteLock {
+    file: File,
+    path: PathBuf,
+    /// Whether the header file was created by the lock and not yet written.
+    placeholder: bool,
+}
+
+impl WriteLock {
+    /// Lock the header file of the record at `path`, creating it if needed.
+    ///
+    /// An existing header is left as is until
+    /// [`write_header`](Self::write_header).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Locked`] if another writer holds the lock, or an I/O
+    /// error if the header file cannot be opened.
+    pub fn acquire(path: &Path) -> Result<Self> {
+        let path = header_path(path);
+        let placeholder = !path.exists();
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+        let mut lock = Self {
+            file,
+            path,
+            placeholder,
+        };
+        match lock.file.try_lock() {
+            Ok(()) => Ok(lock),
+            Err(TryLockError::Error(error)) if error.kind() == ErrorKind::Unsupported => Ok(lock),
+            Err(TryLockError::Error(error)) => Err(error.into()),
+            Err(TryLockError::WouldBlock) => {
+                // The header file is the other writer's: leave it in place
+                lock.placeholder = false;
+                Err(Error::Locked(lock.path.clone()))
+            }
+        }
+    }
+
+    /// Replace the content of the locked header file with `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the header file cannot be written.
+    pub fn write_header(&mut self, text: &str) -> Result<()> {
+        self.file.set_len(0)?;
+        self.file.write_all(text.as_bytes())?;
+        self.placeholder = false;
+        Ok(())
+    }
+}
+
+impl Drop for WriteLock {
+    fn drop(&mut self) {
+        if self.placeholder {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/// Signal values of several channels with their metadata attached.
+///
+/// Values are stored column-major: one `Vec<f64>` per channel, all of the
+/// same length. Invalid samples are represented as `NaN`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalMatrix {
+    /// Sampling frequency (Hz) of the values.
+    pub sampling_frequency: f64,
+    /// Signal descriptions (or `None` when omitted in the header), in channel order.
+    pub channel_names: Vec<Option<String>>,
+    /// Units of each channel.
+    pub units: Vec<String>,
+    /// Values of each channel.
+    pub columns: Vec<Vec<f64>>,
+}
+
+impl SignalMatrix {
+    /// Get the number of channels.
+    #[must_use]
+    pub const fn num_channels(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Get the number of samples per channel.
+    #[must_use]
+    pub fn num_samples(&self) -> usize {
+        self.columns.first().map_or(0, Vec::len)
+    }
+
+    /// Get the values of a channel by index.
+    #[must_use]
+    pub fn column(&self, channel: usize) -> Option<&[f64]> {
+        self.columns.get(channel).map(Vec::as_slice)
+    }
+
+    /// Get the values of the first channel with the given description.
+    #[must_use]
+    pub fn column_by_name(&self, name: &str) -> Option<&[f64]> {
+        let channel = self
+            .channel_names
+            .iter()
+            .position(|n| n.as_deref() == Some(name))?;
+        self.column(channel)
+    }
+
+    /// Get the duration covered by the samples, in seconds.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn duration(&self) -> f64 {
+        self.num_samples() as f64 / self.sampling_frequency
+    }
+}
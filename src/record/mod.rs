@@ -50,20 +50,95 @@
 //! # }
 //! ```
 
+#[cfg(feature = "ndarray")]
+mod array;
+mod block;
+mod canonical;
+mod components;
+pub mod export;
+mod extract;
+mod file_group;
+mod frame;
+mod index;
+mod lock;
+mod matrix;
 mod multi_signal_reader;
+mod options;
+mod pipeline;
+mod prefetch;
+mod progress;
+mod provenance;
+mod replay;
+mod resampling;
 pub(crate) mod segment;
 mod segment_reader;
+mod segment_signal_reader;
 mod signal_reader;
+mod source;
+mod stats;
+#[cfg(feature = "async")]
+mod stream;
+mod summary;
+mod tee;
+mod timing;
+#[cfg(feature = "unstable")]
+pub mod trigger;
+mod validation;
+mod waveform;
+mod windows;
+pub mod wire;
 
+pub use block::Block;
+pub use components::RecordComponents;
+pub use frame::Frame;
+pub use index::{FrameIndex, SampleIndex};
+pub use matrix::SignalMatrix;
 pub use multi_signal_reader::MultiSignalReader;
+pub use options::{
+    DEFAULT_BUFFER_CAPACITY, DEFAULT_INTERLEAVED_BLOCK_FRAMES, LOW_LATENCY_BUFFER_CAPACITY,
+    ReaderOptions,
+};
+pub use pipeline::Pipeline;
+pub use progress::{Budget, CancelToken, PROGRESS_INTERVAL, Progress};
+pub use provenance::Provenance;
+pub use replay::{Replay, replay};
+pub use resampling::{Interpolation, ResamplingReader};
 pub use segment_reader::SegmentReader;
-pub use signal_reader::SignalReader;
+pub use segment_signal_reader::SegmentSignalReader;
+pub use signal_reader::{READ_AT_COALESCE_GAP, SignalReader};
+pub use source::{MemorySource, ReadSeek, SignalSource};
+pub use stats::SignalStats;
+#[cfg(feature = "async")]
+pub use stream::{FrameStream, STREAM_BUFFER_FRAMES};
+pub use summary::{RecordSummary, SignalSummary};
+pub use tee::TeeBranch;
+pub use timing::{ChunkTiming, RecordClock, TimedFrames};
+pub use validation::{ChecksumCheck, ValidationReport};
+pub use waveform::WaveformFile;
+pub use windows::{Window, Windows};
 
+#[cfg(feature = "capi")]
+pub(crate) use waveform::segment_signals;
+pub(crate) use windows::PhysicalReader;
+
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::BufReader;
+use std::ops::RangeBounds;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDateTime, TimeDelta};
 
+use crate::header::Specifications;
+use crate::locator::Locator;
 use crate::{Error, Header, Metadata, Result, SegmentInfo, SignalInfo};
+use components::resolve_signal_path;
+use file_group::FileGroups;
+use lock::WriteLock;
+use progress::ProgressCounter;
+use source::{RecordFiles, SharedSource};
 
 /// High-level API for working with WFDB records.
 ///
@@ -93,6 +168,12 @@ pub struct Record {
     header: Header,
     /// Base directory path for resolving signal files.
     base_path: PathBuf,
+    /// Directory of each signal file whose location is overridden, by file name.
+    signal_dirs: BTreeMap<String, PathBuf>,
+    /// Directory of the annotation files, when different from the base path.
+    annotation_dir: Option<PathBuf>,
+    /// Source of the record files, when not read from the filesystem.
+    source: Option<SharedSource>,
 }
 
 impl Record {
@@ -100,6 +181,10 @@ impl Record {
 
     /// Open a WFDB record from a filesystem path.
     ///
+    /// The `.hea` extension may be omitted. When a relative path is not
+    /// found from the working directory, it is searched in the `WFDB` path,
+    /// as by the C library; see [`Locator`].
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -108,13 +193,28 @@ impl Record {
     /// - The header file cannot be opened
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
         let path = path.as_ref();
+        if path.is_relative()
+            && !header_path(path).exists()
+            && let Some(record) = Locator::from_env().locate(path)?
+        {
+            return Ok(record);
+        }
+        Self::open_components(&RecordComponents::new(path))
+    }
 
-        // Resolve header file path (add .hea if not present)
-        let header_path = if path.extension().is_some_and(|ext| ext == "hea") {
-            path.to_path_buf()
-        } else {
-            path.with_extension("hea")
-        };
+    /// Open a WFDB record whose files live in different directories.
+    ///
+    /// The header is read from `components.header`; signal and annotation
+    /// files are resolved using the overrides in `components`, falling back
+    /// to the header's directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`open`](Self::open).
+    pub fn open_components(components: &RecordComponents) -> Result<Self> {
+        let path = components.header.as_path();
+
+        let header_path = header_path(path);
 
         // Verify header file exists
         if !header_path.exists() {
@@ -135,7 +235,184 @@ impl Record {
             .unwrap_or_else(|| Path::new("."))
             .to_path_buf();
 
-        Ok(Self { header, base_path })
+        Ok(Self {
+            header,
+            base_path,
+            signal_dirs: components.signal_dir_overrides.clone(),
+            annotation_dir: components.annotation_dir.clone(),
+            source: None,
+        })
+    }
+
+    /// Create a WFDB record by writing its header file.
+    ///
+    /// `path` is the record path; the `.hea` extension may be omitted and its
+    /// file stem must match the record name. The header is validated, written
+    /// (replacing any existing header) and parsed back, and the returned
+    /// record describes the header as written. Signal files are not written.
+    ///
+    /// The header file is written under an exclusive advisory lock, also
+    /// held by [`Record::extract`] and
+    /// [`Pipeline::to_record`](crate::record::Pipeline::to_record) while they
+    /// write the signal files, so that concurrent writers of a record fail
+    /// rather than mix their files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file name does not match the record name
+    /// - The number of signal or segment specifications does not match the
+    ///   record line
+    /// - The header does not parse back (e.g. an invalid record name)
+    /// - Another writer holds the lock of the header file ([`Error::Locked`])
+    /// - The header file cannot be written
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::header::Specifications;
+    /// use wfdb::{Header, Metadata, Record, SignalFormat, SignalInfo};
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let metadata = Metadata::from_record_line("rec 1 500 10000")?;
+    /// let signal = SignalInfo::from_signal_line("rec.dat 16 200/mV 12 0 0 0 0 ECG")?;
+    /// let header = Header {
+    ///     metadata,
+    ///     specifications: Specifications::SingleSegment { signals: vec![signal] },
+    ///     info_strings: vec![" acquired on device 7".to_string()],
+    /// };
+    ///
+    /// let record = Record::create("out/rec", &header)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create<P: AsRef<Path>>(path: P, header: &Header) -> Result<Self> {
+        Self::create_locked(path.as_ref(), header, None)
+    }
+
+    /// Create a record as [`create`](Self::create) does, writing its header
+    /// under `lock` when the writer already took it for the signal files.
+    pub(crate) fn create_locked(
+        path: &Path,
+        header: &Header,
+        lock: Option<WriteLock>,
+    ) -> Result<Self> {
+        let header_path = lock::header_path(path);
+
+        let name = header.metadata.name();
+        if header_path.file_stem().and_then(|stem| stem.to_str()) != Some(name) {
+            return Err(Error::InvalidPath(format!(
+                "Header file '{}' does not match record name '{name}'",
+                header_path.display()
+            )));
+        }
+
+        match &header.specifications {
+            Specifications::SingleSegment { signals } => {
+                if header.metadata.num_segments.is_some()
+                    || signals.len() != header.metadata.num_signals
+                {
+                    return Err(Error::InvalidHeader(
+                        format!(
+                            "Record line declares {} signals, found {} signal specifications",
+                            header.metadata.num_signals,
+                            signals.len()
+                        )
+                        .into(),
+                    ));
+                }
+            }
+            Specifications::MultiSegment { segments } => {
+                if header.metadata.num_segments != Some(segments.len()) {
+                    return Err(Error::InvalidHeader(
+                        format!(
+                            "Record line declares {} segments, found {} segment specifications",
+                            header.metadata.num_segments.unwrap_or(0),
+                            segments.len()
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+
+        // Parse the text back so that only readable headers are written
+        let text = header.to_string();
+        let header = Header::from_reader(&mut text.as_bytes())?;
+        let mut lock = match lock {
+            Some(lock) => lock,
+            None => WriteLock::acquire(&header_path)?,
+        };
+        lock.write_header(&text)?;
+
+        let base_path = header_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+
+        Ok(Self::from_header(header, base_path))
+    }
+
+    /// Open a WFDB record from an HTTP(S) URL.
+    ///
+    /// `url` is the URL of the record without extension, e.g.
+    /// `https://physionet.org/files/mitdb/1.0.0/100`. The header, signal and
+    /// annotation files of the record are downloaded into the
+    /// [default cache directory](crate::remote::default_cache_dir), and
+    /// files already cached are reused. Requests use the default
+    /// [`RemoteOptions`](crate::remote::RemoteOptions).
+    ///
+    /// Requires the `remote` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The URL is malformed
+    /// - The header or a signal file cannot be downloaded
+    /// - The cache directory cannot be written
+    /// - The header is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open_url("https://physionet.org/files/mitdb/1.0.0/100")?;
+    /// let mut reader = record.signal_reader(0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "remote")]
+    pub fn open_url(url: &str) -> Result<Self> {
+        Self::open_url_with_options(
+            url,
+            crate::remote::default_cache_dir(),
+            &crate::remote::RemoteOptions::default(),
+        )
+    }
+
+    /// Open a WFDB record from an HTTP(S) URL, with a custom cache directory
+    /// and request options.
+    ///
+    /// See [`open_url`](Self::open_url). The files are stored in
+    /// `cache_dir/host/path/`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`open_url`](Self::open_url), and an error
+    /// if a download exceeds the limits in `options`.
+    #[cfg(feature = "remote")]
+    pub fn open_url_with_options<P: AsRef<Path>>(
+        url: &str,
+        cache_dir: P,
+        options: &crate::remote::RemoteOptions,
+    ) -> Result<Self> {
+        Self::open(crate::remote::fetch_record(
+            url,
+            cache_dir.as_ref(),
+            options,
+        )?)
     }
 
     /// Create a Record from a parsed header and base path.
@@ -143,7 +420,57 @@ impl Record {
     /// This is primarily for testing purposes.
     #[must_use]
     pub const fn from_header(header: Header, base_path: PathBuf) -> Self {
-        Self { header, base_path }
+        Self {
+            header,
+            base_path,
+            signal_dirs: BTreeMap::new(),
+            annotation_dir: None,
+            source: None,
+        }
+    }
+
+    /// Create a WFDB record whose files are read from a custom source.
+    ///
+    /// The header is parsed from `header`, and the signal files (and the
+    /// segment headers of multi-segment records) are opened from `source` by
+    /// the names written in the header; see [`SignalSource`]. Nothing is read
+    /// from the filesystem, and [`base_path`](Self::base_path) is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header cannot be parsed. Files are opened
+    /// lazily, so missing files are reported when readers are created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::Record;
+    /// use wfdb::record::MemorySource;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let source: MemorySource = [("rec.dat", vec![1, 0, 2, 0])].into_iter().collect();
+    /// let record = Record::from_sources("rec 1 250 2\nrec.dat 16\n", source)?;
+    ///
+    /// let frame = record.multi_signal_reader()?.read_frame()?;
+    /// assert_eq!(frame, vec![1]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_sources<S: SignalSource + 'static>(header: &str, source: S) -> Result<Self> {
+        let header = Header::from_reader(&mut header.as_bytes())?;
+        Ok(Self::from_header_and_source(header, source))
+    }
+
+    /// Create a record from a parsed header whose files are read from a
+    /// custom source.
+    pub(crate) fn from_header_and_source<S: SignalSource + 'static>(
+        header: Header,
+        source: S,
+    ) -> Self {
+        Self {
+            source: Some(SharedSource(Arc::new(source))),
+            ..Self::from_header(header, PathBuf::new())
+        }
     }
 
     // [Accessors]
@@ -154,6 +481,125 @@ impl Record {
         &self.header.metadata
     }
 
+    /// Get the clock of the record, converting sample numbers to times.
+    #[must_use]
+    pub fn clock(&self) -> RecordClock {
+        RecordClock::from_metadata(&self.header.metadata)
+    }
+
+    /// Get the date and time of a sample.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// Returns `None` if the header has no base time and date.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// if let Some(datetime) = record.sample_to_datetime(360 * 60) {
+    ///     println!("One minute in: {datetime}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn sample_to_datetime(&self, sample: u64) -> Option<NaiveDateTime> {
+        self.clock().datetime(sample)
+    }
+
+    /// Get the sample nearest to a date and time.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The header has no base time and date
+    /// - The date and time is before the start or after the end of the record
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/3000003_0003")?;
+    /// let datetime = NaiveDate::from_ymd_opt(2020, 6, 1)
+    ///     .unwrap()
+    ///     .and_hms_opt(10, 30, 0)
+    ///     .unwrap();
+    ///
+    /// let mut reader = record.multi_signal_reader()?;
+    /// reader.seek_to_frame(record.datetime_to_sample(datetime)?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn datetime_to_sample(&self, datetime: NaiveDateTime) -> Result<u64> {
+        let clock = self.clock();
+        if clock.base_datetime.is_none() {
+            return Err(Error::InvalidData(
+                "Record has no base time and date".into(),
+            ));
+        }
+        let sample = clock
+            .sample_at(datetime)
+            .filter(|&sample| {
+                self.metadata()
+                    .num_samples()
+                    .is_none_or(|num_samples| sample < num_samples)
+            })
+            .ok_or_else(|| Error::InvalidData(format!("{datetime} is outside of the record")))?;
+        Ok(sample)
+    }
+
+    /// Get the number of frames of the record.
+    ///
+    /// For multi-segment records, this is the sum of the lengths of the
+    /// segments, null segments included. For single-segment records, it is
+    /// the number of samples of the header, and `None` when omitted.
+    #[must_use]
+    pub fn total_samples(&self) -> Option<u64> {
+        self.segment_info().map_or_else(
+            || self.metadata().num_samples(),
+            |segments| Some(segments.iter().map(SegmentInfo::num_samples).sum()),
+        )
+    }
+
+    /// Get the length of the record.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// Returns `None` if the number of frames is unknown (see
+    /// [`total_samples`](Self::total_samples)).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// if let Some(duration) = record.duration() {
+    ///     println!("{} minutes", duration.num_minutes());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn duration(&self) -> Option<TimeDelta> {
+        let seconds = self.clock().elapsed(self.total_samples()?);
+        Some(TimeDelta::nanoseconds((seconds * 1e9).round() as i64))
+    }
+
     /// Get the header specifications (signals or segments).
     #[must_use]
     pub const fn specifications(&self) -> &crate::header::Specifications {
@@ -182,6 +628,25 @@ impl Record {
         &self.header.info_strings
     }
 
+    /// Get the provenance entries of a derived record, oldest first.
+    ///
+    /// Returns an empty list for records not written by
+    /// [`Pipeline::to_record()`].
+    #[must_use]
+    pub fn provenance(&self) -> Vec<Provenance> {
+        Provenance::from_info_strings(&self.header.info_strings)
+    }
+
+    /// Get a fingerprint of the record, identifying it in provenance entries.
+    ///
+    /// This is a 64-bit FNV-1a hash of the header (as 16 hexadecimal
+    /// digits). Since headers usually hold the checksum of every signal, it
+    /// changes with the samples too, without reading the signal files.
+    #[must_use]
+    pub fn fingerprint(&self) -> String {
+        provenance::fingerprint(self.header.to_string().as_bytes())
+    }
+
     /// Check if this is a multi-segment record.
     #[must_use]
     pub const fn is_multi_segment(&self) -> bool {
@@ -212,6 +677,23 @@ impl Record {
         &self.base_path
     }
 
+    /// Get the path of a signal file, as named in the header.
+    ///
+    /// Applies the overrides given to [`open_components`](Self::open_components).
+    #[must_use]
+    pub fn signal_path(&self, file_name: &str) -> PathBuf {
+        resolve_signal_path(&self.base_path, &self.signal_dirs, file_name)
+    }
+
+    /// Get the directory containing the annotation files.
+    ///
+    /// This is the base path unless overridden with
+    /// [`open_components`](Self::open_components).
+    #[must_use]
+    pub fn annotation_dir(&self) -> &Path {
+        self.annotation_dir.as_deref().unwrap_or(&self.base_path)
+    }
+
     // [Reader creation methods]
 
     /// Create a reader for a single signal.
@@ -219,7 +701,8 @@ impl Record {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - This is a multi-segment record (not yet supported for single signal readers)
+    /// - This is a multi-segment record (use
+    ///   [`segment_signal_reader`](Self::segment_signal_reader) instead)
     /// - The signal index is out of bounds
     /// - The signal file cannot be opened
     /// - The signal format is not supported
@@ -242,18 +725,82 @@ impl Record {
     /// # }
     /// ```
     pub fn signal_reader(&self, signal_index: usize) -> Result<SignalReader> {
+        self.signal_reader_with_options(signal_index, &ReaderOptions::default())
+    }
+
+    /// Create a reader for a single signal with custom reader options.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`signal_reader`](Self::signal_reader).
+    pub fn signal_reader_with_options(
+        &self,
+        signal_index: usize,
+        options: &ReaderOptions,
+    ) -> Result<SignalReader> {
+        let mut readers = self.signal_readers_with_options(&[signal_index], options)?;
+        Ok(readers.remove(0))
+    }
+
+    /// Create readers for several signals, sharing the files they have in
+    /// common.
+    ///
+    /// Readers of signals stored in the same file share one handle to the
+    /// file and one block of decoded frames (see
+    /// [`ReaderOptions::interleaved_block_frames`]), while keeping their own
+    /// positions. Readers moving through the record together thus open each
+    /// file once and decode each frame once, instead of once per reader as
+    /// with separate calls to [`signal_reader`](Self::signal_reader).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`signal_reader`](Self::signal_reader),
+    /// for any of the signals.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut readers = record.signal_readers(&[0, 1])?;
+    ///
+    /// // Both signals come from the same decoded frames of 100.dat
+    /// let mlii = readers[0].read_samples(1000)?;
+    /// let v5 = readers[1].read_samples(1000)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signal_readers(&self, signal_indices: &[usize]) -> Result<Vec<SignalReader>> {
+        self.signal_readers_with_options(signal_indices, &ReaderOptions::default())
+    }
+
+    /// Create readers for several signals with custom reader options.
+    ///
+    /// See [`signal_readers`](Self::signal_readers).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`signal_reader`](Self::signal_reader),
+    /// for any of the signals.
+    pub fn signal_readers_with_options(
+        &self,
+        signal_indices: &[usize],
+        options: &ReaderOptions,
+    ) -> Result<Vec<SignalReader>> {
         if self.is_multi_segment() {
-            return Err(Error::InvalidHeader(
-                "Single signal readers not yet supported for multi-segment records".to_string(),
+            return Err(Error::InvalidData(
+                "Single signal readers not supported for multi-segment records, use segment_signal_reader()".into()
             ));
         }
 
-        let signals = self.signal_info().ok_or_else(|| {
-            Error::InvalidHeader("No signal specifications in header".to_string())
-        })?;
+        let signals = self
+            .signal_info()
+            .ok_or_else(|| Error::InvalidHeader("No signal specifications in header".into()))?;
 
-        if signal_index >= signals.len() {
-            return Err(Error::InvalidHeader(format!(
+        if let Some(&signal_index) = signal_indices.iter().find(|&&i| i >= signals.len()) {
+            return Err(Error::InvalidData(format!(
                 "Signal index {} out of bounds (record has {} signals)",
                 signal_index,
                 signals.len()
@@ -261,14 +808,22 @@ impl Record {
         }
 
         let sampling_frequency = Some(self.metadata().sampling_frequency());
-
-        SignalReader::new(
-            &self.base_path,
-            &signals[signal_index],
-            signals,
-            signal_index,
-            sampling_frequency,
-        )
+        let files = self.files();
+        let mut groups = FileGroups::new();
+        signal_indices
+            .iter()
+            .map(|&signal_index| {
+                SignalReader::new(
+                    &files,
+                    signals,
+                    signal_index,
+                    self.metadata().num_samples(),
+                    sampling_frequency,
+                    options,
+                    &mut groups,
+                )
+            })
+            .collect()
     }
 
     /// Create a reader for all signals (frame-based reading).
@@ -298,23 +853,42 @@ impl Record {
     /// # }
     /// ```
     pub fn multi_signal_reader(&self) -> Result<MultiSignalReader> {
+        self.multi_signal_reader_with_options(&ReaderOptions::default())
+    }
+
+    /// Create a reader for all signals with custom reader options.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`multi_signal_reader`](Self::multi_signal_reader).
+    pub fn multi_signal_reader_with_options(
+        &self,
+        options: &ReaderOptions,
+    ) -> Result<MultiSignalReader> {
         if self.is_multi_segment() {
-            return Err(Error::InvalidHeader(
-                "Multi-signal readers not yet supported for multi-segment records".to_string(),
+            return Err(Error::InvalidData(
+                "Multi-signal readers not yet supported for multi-segment records".into(),
             ));
         }
 
-        let signals = self.signal_info().ok_or_else(|| {
-            Error::InvalidHeader("No signal specifications in header".to_string())
-        })?;
+        let signals = self
+            .signal_info()
+            .ok_or_else(|| Error::InvalidHeader("No signal specifications in header".into()))?;
 
-        MultiSignalReader::new(&self.base_path, signals)
+        MultiSignalReader::new(
+            &self.files(),
+            signals,
+            self.metadata().num_samples(),
+            self.clock(),
+            options,
+        )
     }
 
     /// Create a reader for multi-segment records.
     ///
     /// This reader handles segment switching and provides unified access
-    /// to signals across all segments.
+    /// to signals across all segments; see [`SegmentReader`] for null
+    /// segments and variable-layout records.
     ///
     /// # Errors
     ///
@@ -339,19 +913,366 @@ impl Record {
     /// # }
     /// ```
     pub fn segment_reader(&self) -> Result<SegmentReader> {
+        self.segment_reader_with_options(&ReaderOptions::default())
+    }
+
+    /// Create a reader for multi-segment records with custom reader options.
+    ///
+    /// The options apply to the reader of every segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`segment_reader`](Self::segment_reader).
+    pub fn segment_reader_with_options(&self, options: &ReaderOptions) -> Result<SegmentReader> {
         if !self.is_multi_segment() {
-            return Err(Error::InvalidHeader(
-                "Segment readers only supported for multi-segment records".to_string(),
+            return Err(Error::InvalidData(
+                "Segment readers only supported for multi-segment records".into(),
             ));
         }
 
+        let segments = self
+            .segment_info()
+            .ok_or_else(|| Error::InvalidHeader("No segment specifications in header".into()))?;
+
+        SegmentReader::new(
+            self.files(),
+            segments.to_vec(),
+            self.metadata().num_signals,
+            self.metadata().sampling_frequency(),
+            options.clone(),
+        )
+    }
+
+    /// Create a reader for a single signal of a multi-segment record.
+    ///
+    /// The reader reads the signal continuously across all segments; see
+    /// [`SegmentSignalReader`] for null segments and variable-layout records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - This is not a multi-segment record
+    /// - The signal index is out of bounds
+    /// - The layout segment header cannot be loaded
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/multi_segment_record")?;
+    /// let mut reader = record.segment_signal_reader(0)?;
+    ///
+    /// let samples = reader.read_samples(1000)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn segment_signal_reader(&self, signal_index: usize) -> Result<SegmentSignalReader> {
+        self.segment_signal_reader_with_options(signal_index, &ReaderOptions::default())
+    }
+
+    /// Create a reader for a single signal of a multi-segment record with
+    /// custom reader options.
+    ///
+    /// The options apply to the reader of every segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as
+    /// [`segment_signal_reader`](Self::segment_signal_reader).
+    pub fn segment_signal_reader_with_options(
+        &self,
+        signal_index: usize,
+        options: &ReaderOptions,
+    ) -> Result<SegmentSignalReader> {
         let segments = self.segment_info().ok_or_else(|| {
-            Error::InvalidHeader("No segment specifications in header".to_string())
+            Error::InvalidData(
+                "Segment signal readers only supported for multi-segment records".into(),
+            )
         })?;
 
-        Ok(SegmentReader::new(
-            self.base_path.clone(),
+        if signal_index >= self.metadata().num_signals {
+            return Err(Error::InvalidData(format!(
+                "Signal index {} out of bounds (record has {} signals)",
+                signal_index,
+                self.metadata().num_signals
+            )));
+        }
+
+        SegmentSignalReader::new(
+            self.files(),
             segments.to_vec(),
-        ))
+            signal_index,
+            self.metadata().sampling_frequency(),
+            options.clone(),
+        )
+    }
+
+    // [Bulk reading]
+
+    /// Read every sample of every signal as physical values.
+    ///
+    /// The values are returned column-major, with the channel names, units
+    /// and sampling frequency attached. Invalid samples become `NaN`, and
+    /// uncalibrated signals keep their raw ADC values.
+    ///
+    /// This is a shortcut for `self.pipeline().physical().to_matrix()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is a multi-segment record or the signal
+    /// files cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let matrix = record.read_all_physical()?;
+    ///
+    /// let mlii = matrix.column_by_name("MLII");
+    /// println!("{} s of {} channels", matrix.duration(), matrix.num_channels());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_all_physical(&self) -> Result<SignalMatrix> {
+        self.pipeline().physical().to_matrix()
+    }
+
+    /// Read every sample of every signal as physical values, reporting
+    /// progress to a callback that can cancel the read.
+    ///
+    /// See [`read_all_physical`](Self::read_all_physical) and [`Progress`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_all_physical`](Self::read_all_physical),
+    /// and [`Error::Cancelled`] if the callback returns `false`.
+    pub fn read_all_physical_with_progress<F>(&self, progress: F) -> Result<SignalMatrix>
+    where
+        F: FnMut(u64, Option<u64>) -> bool + Send + 'static,
+    {
+        self.pipeline()
+            .physical()
+            .on_progress(Progress::new(progress))
+            .to_matrix()
+    }
+
+    /// Read the frames in `range` of every signal as physical values.
+    ///
+    /// See [`read_all_physical`](Self::read_all_physical). Any range form is
+    /// accepted (`7..20`, `7..=19`, `100..`), and the range is truncated at
+    /// the end of the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is a multi-segment record, the range is
+    /// reversed, or the signal files cannot be read.
+    pub fn read_physical_frames<R: RangeBounds<u64>>(&self, range: R) -> Result<SignalMatrix> {
+        self.pipeline().frames(range).physical().to_matrix()
+    }
+
+    /// Read samples `start..end` of every signal as physical values.
+    ///
+    /// Same as [`read_physical_frames(start..end)`](Self::read_physical_frames).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_physical_frames`](Self::read_physical_frames).
+    pub fn read_range_physical(&self, start: u64, end: u64) -> Result<SignalMatrix> {
+        self.read_physical_frames(start..end)
+    }
+
+    // [Validation]
+
+    /// Check the signal files against the checksums in the header.
+    ///
+    /// Reads every signal up to the length given in the header (or to the
+    /// end of its file) and computes its 16-bit checksum, as the WFDB
+    /// library does. For multi-segment records, the signals of each segment
+    /// are checked against the segment header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a signal file or segment header cannot be read.
+    /// Checksum mismatches are not errors; they are listed in the report.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let report = record.validate()?;
+    ///
+    /// for check in report.mismatches() {
+    ///     println!(
+    ///         "Signal {}: expected {:?}, computed {}",
+    ///         check.signal_index, check.expected, check.computed
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<ValidationReport> {
+        self.validate_inner(None, None)
+    }
+
+    /// Check the signal files against the checksums in the header,
+    /// reporting progress to a callback that can cancel the check.
+    ///
+    /// See [`validate`](Self::validate) and [`Progress`]. The frames of
+    /// every segment are counted, null segments included.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`validate`](Self::validate), and
+    /// [`Error::Cancelled`] if the callback returns `false`.
+    pub fn validate_with_progress<F>(&self, progress: F) -> Result<ValidationReport>
+    where
+        F: FnMut(u64, Option<u64>) -> bool + Send + 'static,
+    {
+        self.validate_inner(Some(&Progress::new(progress)), None)
+    }
+
+    /// Check the signal files against the checksums in the header, within
+    /// a [`Budget`].
+    ///
+    /// See [`validate`](Self::validate). The frames of every segment are
+    /// counted against the budget, null segments included.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`validate`](Self::validate), and
+    /// [`Error::BudgetExceeded`] or [`Error::Cancelled`] if the budget is
+    /// spent.
+    pub fn validate_with_budget(&self, budget: &Budget) -> Result<ValidationReport> {
+        self.validate_inner(None, Some(budget))
+    }
+
+    // [Statistics]
+
+    /// Compute summary statistics of every signal, in physical units.
+    ///
+    /// Reads the record once, without holding the samples in memory, and
+    /// returns min, max, mean, RMS and the count of invalid samples of each
+    /// signal. Every sample of multi-frequency signals is included. For
+    /// multi-segment records, samples of null segments and of signals
+    /// missing from a segment count as invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a signal file or segment header cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// for (index, stats) in record.signal_stats()?.iter().enumerate() {
+    ///     println!("Signal {index}: mean {}, {} invalid", stats.mean, stats.invalid);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signal_stats(&self) -> Result<Vec<SignalStats>> {
+        if self.segment_info().is_some() {
+            return (0..self.metadata().num_signals())
+                .map(|index| {
+                    let mut reader = self.segment_signal_reader(index)?;
+                    let mut accumulator = stats::StatsAccumulator::default();
+                    loop {
+                        let values = reader.read_physical(4096)?;
+                        if values.is_empty() {
+                            return Ok(accumulator.finish());
+                        }
+                        for value in values {
+                            accumulator.push(value);
+                        }
+                    }
+                })
+                .collect();
+        }
+
+        let mut reader = self.multi_signal_reader()?;
+        let scales = reader.scales().to_vec();
+        let mut accumulators = vec![stats::StatsAccumulator::default(); scales.len()];
+        let mut frame = Vec::with_capacity(scales.len());
+        while reader.read_frame_samples_into(&mut frame)? {
+            for ((accumulator, scale), samples) in accumulators.iter_mut().zip(&scales).zip(&frame)
+            {
+                for &sample in samples {
+                    accumulator.push_sample(scale, sample);
+                }
+            }
+        }
+        Ok(accumulators
+            .iter()
+            .map(stats::StatsAccumulator::finish)
+            .collect())
+    }
+
+    // [Pipelines]
+
+    /// Start a transform pipeline over this record.
+    ///
+    /// See [`Pipeline`] for the available transforms.
+    pub const fn pipeline(&self) -> Pipeline<'_> {
+        Pipeline::new(self)
+    }
+
+    // [Private helper methods]
+
+    /// Check the checksums of the record, reporting to an optional callback
+    /// and within an optional budget.
+    fn validate_inner(
+        &self,
+        progress: Option<&Progress>,
+        budget: Option<&Budget>,
+    ) -> Result<ValidationReport> {
+        let mut counter = ProgressCounter::new(progress, budget, self.total_samples())?;
+        let checksums = match self.segment_info() {
+            Some(segments) => validation::check_segments(
+                &mut segment::SegmentManager::new(self.files(), segments.to_vec()),
+                &mut counter,
+            )?,
+            None => validation::check_signals(
+                &self.files(),
+                self.signal_info().unwrap_or_default(),
+                self.clock(),
+                self.metadata().num_samples(),
+                None,
+                &mut counter,
+            )?,
+        };
+        counter.finish()?;
+        Ok(ValidationReport { checksums })
+    }
+
+    /// Get the location of the record files, for opening readers.
+    fn files(&self) -> RecordFiles {
+        self.source.as_ref().map_or_else(
+            || RecordFiles::Directory {
+                base_path: self.base_path.clone(),
+                signal_dirs: self.signal_dirs.clone(),
+            },
+            |source| RecordFiles::Source(source.clone()),
+        )
+    }
+}
+
+/// Get the path of a record's header file, adding the `.hea` extension if
+/// not present.
+pub(crate) fn header_path(path: &Path) -> PathBuf {
+    if path.extension().is_some_and(|ext| ext == "hea") {
+        path.to_path_buf()
+    } else {
+        path.with_extension("hea")
     }
 }
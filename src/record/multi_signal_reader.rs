@@ -1,116 +1,217 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufReader;
-use std::path::Path;
-
-use crate::signal::FormatDecoder;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufReader, Seek};
+use std::sync::Arc;
+
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
+
+use crate::record::block::Block;
+use crate::record::components::signal_byte_offsets;
+use crate::record::frame::{Frame, FrameChannels};
+use crate::record::index::seek_position;
+use crate::record::prefetch::Prefetcher;
+use crate::record::source::{ReadSeek, RecordFiles};
+use crate::record::timing::seconds_to_sample;
+use crate::record::{FrameIndex, ReaderOptions, RecordClock, TimedFrames};
+use crate::signal::{FormatDecoder, INVALID_SAMPLE, SignalScale};
 use crate::{Error, Result, Sample, SignalInfo};
 
-/// Signal group - signals that share the same file.
+/// Signal group - signals that share the same section of a file.
 struct SignalGroup {
     /// Format decoder for this group.
     decoder: Box<dyn FormatDecoder>,
     /// Buffered reader for the signal file.
-    reader: BufReader<File>,
-    /// Indices of signals in this group (into the original signals array).
-    signal_indices: Vec<usize>,
-    /// Signal info for each signal in this group.
-    signal_infos: Vec<SignalInfo>,
+    reader: BufReader<Box<dyn ReadSeek>>,
+    /// Name of the signal file.
+    file_name: String,
+    /// Byte offset of the group's data in the file.
+    byte_offset: u64,
+    /// Position in a stored frame of each sample of a frame of the group.
+    positions: Vec<usize>,
+    /// Decoded samples of the current frame (all samples of each signal, in
+    /// signal order), reused across reads.
+    buffer: Vec<Sample>,
 }
 
 /// Reader for multiple signals (frame-based).
 ///
 /// Reads one frame at a time, where each frame contains one sample from each signal.
-/// Handles signals in different files and with different formats.
+/// Handles signals in different files and with different formats, and files
+/// shared with other records at different byte offsets. Reading stops after
+/// the number of samples given in the header, if any.
+///
+/// # Multi-frequency records
+///
+/// Signals with several samples per frame (e.g. `format x spf` in MIMIC
+/// waveform headers) are read in full. [`read_frame`](Self::read_frame)
+/// returns their average over the frame, rounded to the nearest integer (or
+/// [`INVALID_SAMPLE`] if any sample is invalid), as `getvec()` does in the
+/// WFDB library, while [`read_frame_samples`](Self::read_frame_samples)
+/// returns every sample.
+///
+/// # Skew
+///
+/// Signals with a skew in the header are shifted back by that many frames,
+/// as `getvec()` does in the WFDB library: frame `t` holds sample `t + skew`
+/// of each signal as stored. Skewed signals read as [`INVALID_SAMPLE`] in the
+/// last frames, once their stored samples run out.
 pub struct MultiSignalReader {
-    /// Signal groups (one per unique file).
+    /// Signal groups (one per file section), while not prefetching.
     groups: Vec<SignalGroup>,
+    /// Frames decoded ahead on a background thread, which then owns the
+    /// signal groups.
+    prefetcher: Option<Prefetcher<Vec<SignalGroup>>>,
+    /// Number of frames decoded ahead, or 0 to decode on the reading thread.
+    prefetch_frames: usize,
     /// Total number of signals.
     num_signals: usize,
-    /// Mapping from signal index to (`group_index`, `index_within_group`).
-    signal_to_group: Vec<(usize, usize)>,
+    /// Samples per frame of each signal.
+    samples_per_frame: Vec<usize>,
+    /// Position of the first sample of each signal in a stored frame.
+    frame_offsets: Vec<usize>,
+    /// Samples of the current frame (skew applied), in stored frame layout.
+    current: Vec<Sample>,
+    /// Names and conversions to physical units, by signal index.
+    channels: Arc<FrameChannels>,
     /// Current frame position.
     current_frame: u64,
+    /// Number of frames in the record, when given in the header.
+    num_frames: Option<u64>,
+    /// Skew of each signal, in frames.
+    skews: Vec<usize>,
+    /// Largest skew of any signal.
+    max_skew: usize,
+    /// Stored frames read ahead for skewed signals, starting at the current frame.
+    pending: VecDeque<Vec<Sample>>,
+    /// Stored frame no longer needed, reused for the next read ahead.
+    spare: Option<Vec<Sample>>,
+    /// Clock of the record, for timing chunks of frames.
+    clock: RecordClock,
 }
 
 impl MultiSignalReader {
     /// Create a new multi-signal reader.
-    pub(crate) fn new(base_path: &Path, signals: &[SignalInfo]) -> Result<Self> {
+    pub(crate) fn new(
+        files: &RecordFiles,
+        signals: &[SignalInfo],
+        num_frames: Option<u64>,
+        clock: RecordClock,
+        options: &ReaderOptions,
+    ) -> Result<Self> {
         if signals.is_empty() {
-            return Err(Error::InvalidHeader("No signals to read".to_string()));
+            return Err(Error::InvalidHeader("No signals to read".into()));
         }
 
-        // Group signals by file name
-        let mut file_groups: HashMap<String, Vec<usize>> = HashMap::new();
-        for (idx, signal) in signals.iter().enumerate() {
+        // Group signals by file name and byte offset
+        let mut file_groups: HashMap<(String, u64), Vec<usize>> = HashMap::new();
+        for (idx, (signal, offset)) in signals.iter().zip(signal_byte_offsets(signals)).enumerate()
+        {
             file_groups
-                .entry(signal.file_name.clone())
+                .entry((signal.file_name.clone(), offset))
                 .or_default()
                 .push(idx);
         }
 
+        let skews = signals
+            .iter()
+            .map(|signal| signal.skew() as usize)
+            .collect::<Vec<_>>();
+        let samples_per_frame = signals
+            .iter()
+            .map(|signal| signal.samples_per_frame().max(1) as usize)
+            .collect::<Vec<_>>();
+        let frame_offsets = samples_per_frame
+            .iter()
+            .scan(0, |offset, &count| {
+                let start = *offset;
+                *offset += count;
+                Some(start)
+            })
+            .collect::<Vec<_>>();
+
         // Create signal groups
         let mut groups = Vec::new();
-        let mut signal_to_group = vec![(0, 0); signals.len()];
-
-        for (file_name, signal_indices) in file_groups {
-            let group_index = groups.len();
 
+        for ((file_name, byte_offset), signal_indices) in file_groups {
             // Get first signal in group for decoder setup
             let first_signal = &signals[signal_indices[0]];
 
             // Open signal file
-            let signal_path = base_path.join(&file_name);
-            let file = File::open(&signal_path).map_err(|e| {
-                Error::InvalidPath(format!(
-                    "Failed to open signal file '{}': {}",
-                    signal_path.display(),
-                    e
-                ))
-            })?;
+            let file = files.open_signal(&file_name)?;
 
-            let mut reader = BufReader::new(file);
+            let mut reader = BufReader::with_capacity(options.effective_buffer_capacity(), file);
 
             // Handle byte offset if specified
-            if let Some(offset) = first_signal.byte_offset {
-                use std::io::Seek;
-                reader.seek(std::io::SeekFrom::Start(offset))?;
+            if byte_offset > 0 {
+                reader.seek(std::io::SeekFrom::Start(byte_offset))?;
             }
 
-            // Create decoder
-            let initial_value = first_signal.initial_value.unwrap_or(0);
-            let decoder = crate::signal::get_decoder(first_signal.format, initial_value)?;
-
-            // Collect signal infos for this group
-            let signal_infos: Vec<SignalInfo> = signal_indices
+            // Create decoder, with the state of each signal of the file
+            let initial_values: Vec<Sample> = signal_indices
                 .iter()
-                .map(|&idx| signals[idx].clone())
+                .map(|&idx| signals[idx].initial_value.unwrap_or(0))
                 .collect();
-
-            // Update signal_to_group mapping
-            for (within_group_idx, &signal_idx) in signal_indices.iter().enumerate() {
-                signal_to_group[signal_idx] = (group_index, within_group_idx);
-            }
+            let group_layout: Vec<usize> = signal_indices
+                .iter()
+                .map(|&idx| samples_per_frame[idx])
+                .collect();
+            let decoder = crate::signal::get_interleaved_decoder(
+                first_signal.format,
+                &initial_values,
+                &group_layout,
+            )?;
 
             groups.push(SignalGroup {
                 decoder,
                 reader,
-                signal_indices: signal_indices.clone(),
-                signal_infos,
+                file_name,
+                byte_offset,
+                positions: signal_indices
+                    .iter()
+                    .flat_map(|&idx| {
+                        frame_offsets[idx]..frame_offsets[idx] + samples_per_frame[idx]
+                    })
+                    .collect(),
+                buffer: vec![0; group_layout.iter().sum()],
             });
         }
 
         Ok(Self {
             groups,
+            prefetcher: None,
+            prefetch_frames: options.prefetch_frames,
             num_signals: signals.len(),
-            signal_to_group,
+            samples_per_frame,
+            frame_offsets,
+            current: Vec::new(),
+            channels: Arc::new(FrameChannels {
+                names: signals
+                    .iter()
+                    .map(|signal| signal.description().map(ToString::to_string))
+                    .collect(),
+                scales: signals
+                    .iter()
+                    .map(|signal| signal.scale().normalized(&options.target_units))
+                    .collect(),
+            }),
             current_frame: 0,
+            num_frames: num_frames.filter(|&n| n > 0 && !options.ignore_length),
+            max_skew: skews.iter().copied().max().unwrap_or(0),
+            skews,
+            pending: VecDeque::new(),
+            spare: None,
+            clock,
         })
     }
 
     /// Read one frame (one sample from each signal).
     ///
-    /// Returns a vector with `num_signals` samples, ordered by signal index.
+    /// Returns a vector with `num_signals` samples, ordered by signal index,
+    /// or an empty vector at the end of the record. Signals with several
+    /// samples per frame are averaged over the frame.
+    ///
+    /// Each call allocates the returned vector; use
+    /// [`read_frame_into`](Self::read_frame_into) to reuse one instead.
     ///
     /// # Errors
     ///
@@ -119,32 +220,264 @@ impl MultiSignalReader {
     /// - The frame is incomplete
     pub fn read_frame(&mut self) -> Result<Vec<Sample>> {
         let mut frame = vec![0; self.num_signals];
+        if !self.read_frame_into(&mut frame)? {
+            frame.clear();
+        }
+        Ok(frame)
+    }
 
-        // Read from each group
-        for group in &mut self.groups {
-            let mut group_samples = vec![0; group.signal_indices.len()];
-            let n = group
-                .decoder
-                .decode_buf(&mut group.reader, &mut group_samples)?;
+    /// Read one frame as a [`Frame`], carrying its sample number and the
+    /// names and scales of the channels.
+    ///
+    /// Returns `None` at the end of the record. Signals with several samples
+    /// per frame are averaged over the frame, as in
+    /// [`read_frame`](Self::read_frame).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frame`](Self::read_frame).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut reader = record.multi_signal_reader()?;
+    ///
+    /// if let Some(frame) = reader.read_labeled_frame()? {
+    ///     println!("MLII at {}: {:?}", frame.sample_number(), frame.physical(0));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_labeled_frame(&mut self) -> Result<Option<Frame>> {
+        let sample_number = self.current_frame;
+        let mut samples = vec![0; self.num_signals];
+        if !self.read_frame_into(&mut samples)? {
+            return Ok(None);
+        }
+        Ok(Some(Frame::new(
+            sample_number,
+            samples,
+            Arc::clone(&self.channels),
+        )))
+    }
 
-            if n == 0 {
-                return Ok(vec![]); // EOF
-            }
+    /// Read one frame into a caller-provided buffer of `num_signals`
+    /// samples, ordered by signal index.
+    ///
+    /// Returns `false` (leaving the buffer unchanged) at the end of the
+    /// record. Signals with several samples per frame are averaged over the
+    /// frame, as in [`read_frame`](Self::read_frame).
+    ///
+    /// Decoded samples go through buffers kept by the reader, and the frame
+    /// is written in place, so this never allocates: the buffer can live on
+    /// the stack or in a larger array of frames. Reading a one-hour,
+    /// two-signal record at 360 Hz (1,296,000 frames) this way makes no
+    /// allocation per frame, while [`read_frame`](Self::read_frame) makes
+    /// one per frame (1,296,001), and made twice as many (2,592,002) before
+    /// the decoding buffers were reused.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer does not hold exactly `num_signals`
+    /// samples, or the same errors as [`read_frame`](Self::read_frame).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut reader = record.multi_signal_reader()?;
+    ///
+    /// let mut frame = vec![0; reader.num_signals()];
+    /// while reader.read_frame_into(&mut frame)? {
+    ///     // Process frame...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_frame_into(&mut self, frame: &mut [Sample]) -> Result<bool> {
+        if frame.len() != self.num_signals {
+            return Err(Error::InvalidData(format!(
+                "Frame buffer holds {} samples, but the record has {} signals",
+                frame.len(),
+                self.num_signals
+            )));
+        }
 
-            if n != group.signal_indices.len() {
-                return Err(Error::InvalidHeader(
-                    "Incomplete frame read from signal group".to_string(),
-                ));
+        // Single-frequency records without skew are read in place
+        if self.max_skew == 0 && self.frame_offsets.len() == self.current_capacity() {
+            if self.at_end() || !self.read_stored_frame(frame)? {
+                return Ok(false);
             }
+            self.current_frame += 1;
+            return Ok(true);
+        }
 
-            // Place samples in correct positions
-            for (within_group_idx, &signal_idx) in group.signal_indices.iter().enumerate() {
-                frame[signal_idx] = group_samples[within_group_idx];
+        if !self.next_frame()? {
+            return Ok(false);
+        }
+        for ((slot, &offset), &count) in frame
+            .iter_mut()
+            .zip(&self.frame_offsets)
+            .zip(&self.samples_per_frame)
+        {
+            *slot = average(&self.current[offset..offset + count]);
+        }
+        Ok(true)
+    }
+
+    /// Read one frame with every sample of each signal.
+    ///
+    /// Returns one vector per signal, ordered by signal index, holding its
+    /// samples per frame (one for most signals), or an empty vector at the
+    /// end of the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frame`](Self::read_frame).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/3000003_0003")?;
+    /// let mut reader = record.multi_signal_reader()?;
+    ///
+    /// let frame = reader.read_frame_samples()?;
+    /// for (signal, samples) in frame.iter().enumerate() {
+    ///     println!("Signal {signal}: {samples:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_frame_samples(&mut self) -> Result<Vec<Vec<Sample>>> {
+        let mut frame = Vec::new();
+        self.read_frame_samples_into(&mut frame)?;
+        Ok(frame)
+    }
+
+    /// Read one frame with every sample of each signal into existing vectors.
+    ///
+    /// See [`read_frame_samples`](Self::read_frame_samples). The outer vector
+    /// is resized to `num_signals`, and the vectors it holds are reused.
+    /// Returns `false` (leaving the vector empty) at the end of the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frame`](Self::read_frame).
+    pub fn read_frame_samples_into(&mut self, frame: &mut Vec<Vec<Sample>>) -> Result<bool> {
+        if !self.next_frame()? {
+            frame.clear();
+            return Ok(false);
+        }
+        frame.resize_with(self.num_signals, Vec::new);
+        for ((samples, &offset), &count) in frame
+            .iter_mut()
+            .zip(&self.frame_offsets)
+            .zip(&self.samples_per_frame)
+        {
+            samples.clear();
+            samples.extend_from_slice(&self.current[offset..offset + count]);
+        }
+        Ok(true)
+    }
+
+    /// Get the number of samples per frame of each signal, by signal index.
+    #[must_use]
+    pub fn samples_per_frame(&self) -> &[usize] {
+        &self.samples_per_frame
+    }
+
+    /// Get the number of samples in a stored frame, across all signals.
+    fn current_capacity(&self) -> usize {
+        self.samples_per_frame.iter().sum()
+    }
+
+    /// Check if the number of frames given in the header has been read.
+    fn at_end(&self) -> bool {
+        self.num_frames
+            .is_some_and(|num_frames| self.current_frame >= num_frames)
+    }
+
+    /// Read the next frame into `current`, applying the skew of each signal.
+    ///
+    /// Returns `false` at the end of the record.
+    fn next_frame(&mut self) -> Result<bool> {
+        if self.at_end() {
+            return Ok(false);
+        }
+
+        if self.max_skew == 0 {
+            let mut current = std::mem::take(&mut self.current);
+            current.resize(self.current_capacity(), 0);
+            let read = self.read_stored_frame(&mut current);
+            self.current = current;
+            if !read? {
+                return Ok(false);
             }
+        } else {
+            // Read ahead the stored frames holding the samples of skewed signals
+            while self.pending.len() <= self.max_skew {
+                let mut stored = self.spare.take().unwrap_or_default();
+                stored.resize(self.current_capacity(), 0);
+                if !self.read_stored_frame(&mut stored)? {
+                    break;
+                }
+                self.pending.push_back(stored);
+            }
+            if self.pending.is_empty() {
+                return Ok(false);
+            }
+            self.current.clear();
+            for ((&skew, &offset), &count) in self
+                .skews
+                .iter()
+                .zip(&self.frame_offsets)
+                .zip(&self.samples_per_frame)
+            {
+                match self.pending.get(skew) {
+                    Some(stored) => self
+                        .current
+                        .extend_from_slice(&stored[offset..offset + count]),
+                    None => self
+                        .current
+                        .extend(std::iter::repeat_n(INVALID_SAMPLE, count)),
+                }
+            }
+            self.spare = self.pending.pop_front();
         }
 
         self.current_frame += 1;
-        Ok(frame)
+        Ok(true)
+    }
+
+    /// Read the next frame as stored in the signal files, without skew.
+    ///
+    /// The samples of each signal are placed at its frame offset. Returns
+    /// `false` at the end of the files.
+    fn read_stored_frame(&mut self, frame: &mut [Sample]) -> Result<bool> {
+        if self.prefetch_frames == 0 {
+            return read_groups(&mut self.groups, frame);
+        }
+        if self.prefetcher.is_none() {
+            self.prefetcher = Some(Prefetcher::spawn(
+                std::mem::take(&mut self.groups),
+                |groups: &mut Vec<SignalGroup>, frame: &mut [Sample]| read_groups(groups, frame),
+                frame.len(),
+                self.prefetch_frames,
+            )?);
+        }
+        self.prefetcher
+            .as_mut()
+            .map_or(Ok(false), |prefetcher| prefetcher.read_frame(frame))
     }
 
     /// Read multiple frames.
@@ -166,6 +499,49 @@ impl MultiSignalReader {
         Ok(frames)
     }
 
+    /// Read up to `num_frames` frames as a [`Block`], storing each channel
+    /// contiguously.
+    ///
+    /// The block has fewer frames only at the end of the record, and none
+    /// after it. Use [`read_block_into`](Self::read_block_into) to reuse the
+    /// buffer of a block.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frames`](Self::read_frames).
+    pub fn read_block(&mut self, num_frames: usize) -> Result<Block> {
+        let mut block = Block::default();
+        self.read_block_into(&mut block, num_frames)?;
+        Ok(block)
+    }
+
+    /// Read up to `num_frames` frames into an existing [`Block`], returning
+    /// the number of frames read.
+    ///
+    /// The buffer of the block is reused, so reading a record block by block
+    /// allocates only when a block grows.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frames`](Self::read_frames).
+    pub fn read_block_into(&mut self, block: &mut Block, num_frames: usize) -> Result<usize> {
+        // Do not allocate past the end of the record
+        let capacity = self.num_frames.map_or(num_frames, |total| {
+            let left = total.saturating_sub(self.current_frame);
+            num_frames.min(usize::try_from(left).unwrap_or(usize::MAX))
+        });
+        block.reset(self.current_frame, capacity, &self.channels);
+
+        let mut frame = vec![0; self.num_signals];
+        let mut count = 0;
+        while count < capacity && self.read_frame_into(&mut frame)? {
+            block.set_frame(count, &frame);
+            count += 1;
+        }
+        block.truncate(count);
+        Ok(count)
+    }
+
     /// Read frames as physical values.
     ///
     /// # Errors
@@ -183,19 +559,62 @@ impl MultiSignalReader {
             .collect())
     }
 
+    /// Read multiple frames, with the timing of the first one.
+    ///
+    /// The timing gives the sample number, elapsed time, counter value and
+    /// (when the header has a base time and date) the date and time of the
+    /// first frame, so that chunks can be stored without recomputing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frames`](Self::read_frames).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut reader = record.multi_signal_reader()?;
+    ///
+    /// loop {
+    ///     let chunk = reader.read_frames_timed(3600)?;
+    ///     if chunk.frames.is_empty() {
+    ///         break;
+    ///     }
+    ///     println!("{} s: {} frames", chunk.timing.elapsed, chunk.frames.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_frames_timed(&mut self, count: usize) -> Result<TimedFrames<Sample>> {
+        let timing = self.clock.timing(self.current_frame);
+        Ok(TimedFrames {
+            timing,
+            frames: self.read_frames(count)?,
+        })
+    }
+
+    /// Read frames as physical values, with the timing of the first one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frames`](Self::read_frames).
+    pub fn read_frames_physical_timed(&mut self, count: usize) -> Result<TimedFrames<f64>> {
+        let timing = self.clock.timing(self.current_frame);
+        Ok(TimedFrames {
+            timing,
+            frames: self.read_frames_physical(count)?,
+        })
+    }
+
     /// Convert a frame of ADC values to physical values.
-    fn frame_to_physical(&self, adc_frame: &[Sample]) -> Vec<f64> {
+    pub(crate) fn frame_to_physical(&self, adc_frame: &[Sample]) -> Vec<f64> {
         adc_frame
             .iter()
-            .enumerate()
-            .map(|(signal_idx, &adc_value)| {
-                let (group_idx, within_group_idx) = self.signal_to_group[signal_idx];
-                let signal_info = &self.groups[group_idx].signal_infos[within_group_idx];
-
-                let baseline = f64::from(signal_info.baseline());
-                let gain = signal_info.adc_gain();
-                (f64::from(adc_value) - baseline) / gain
-            })
+            .zip(&self.channels.scales)
+            .map(|(&adc_value, scale)| scale.to_physical(adc_value))
             .collect()
     }
 
@@ -205,12 +624,25 @@ impl MultiSignalReader {
         self.num_signals
     }
 
+    /// Get the clock of the record.
+    #[must_use]
+    pub const fn clock(&self) -> &RecordClock {
+        &self.clock
+    }
+
+    /// Get the conversion to physical units of each signal, by signal index.
+    #[must_use]
+    pub fn scales(&self) -> &[SignalScale] {
+        &self.channels.scales
+    }
+
     // [Seeking support]
 
     /// Seek all signals to a specific frame (sample) number.
     ///
-    /// All signals are positioned atomically to the same frame.
-    /// Returns the actual frame position after seeking.
+    /// All signals are positioned atomically to the same frame, counted in
+    /// frames rather than samples of multi-frequency signals; see
+    /// [`FrameIndex`]. Returns the actual frame position after seeking.
     ///
     /// # Errors
     ///
@@ -218,35 +650,147 @@ impl MultiSignalReader {
     /// - Seeking is not supported for any signal format
     /// - The seek operation fails
     pub fn seek_to_frame(&mut self, frame: u64) -> Result<u64> {
-        use std::io::Seek;
-
+        if let Some(prefetcher) = self.prefetcher.take() {
+            self.groups = prefetcher.stop()?;
+        }
         // Seek each group to the appropriate position
         for group in &mut self.groups {
-            let num_signals = group.signal_indices.len();
-            let first_signal = &group.signal_infos[0];
-            let initial_offset = first_signal.byte_offset.unwrap_or(0);
+            if group.decoder.is_differential() {
+                // Accumulate the differences from the start of the file
+                group
+                    .reader
+                    .seek(std::io::SeekFrom::Start(group.byte_offset))?;
+                group.decoder.rewind();
+                let samples = seek_position(0, frame, group.buffer.len() as u64)?;
+                group.decoder.skip(&mut group.reader, samples)?;
+                continue;
+            }
 
             // Calculate byte position for this frame
-            if let Some(bytes_per_frame) = group.decoder.bytes_per_frame(num_signals) {
-                let byte_offset = initial_offset + frame * bytes_per_frame as u64;
+            if let Some(bytes_per_frame) = group.decoder.bytes_per_frame(group.buffer.len()) {
+                let byte_offset = seek_position(group.byte_offset, frame, bytes_per_frame as u64)?;
                 group.reader.seek(std::io::SeekFrom::Start(byte_offset))?;
             } else {
-                return Err(Error::InvalidHeader(
-                    "Seeking not supported for this signal format".to_string(),
+                return Err(Error::InvalidData(
+                    "Seeking not supported for this signal format".into(),
                 ));
             }
 
             // Reset decoder state
             group.decoder.reset();
         }
+        self.pending.clear();
 
         self.current_frame = frame;
         Ok(frame)
     }
 
+    /// Seek to a frame given as a [`FrameIndex`].
+    ///
+    /// Same as [`seek_to_frame`](Self::seek_to_frame), with a frame number
+    /// that cannot be mistaken for a sample number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_frame`](Self::seek_to_frame).
+    pub fn seek_to_frame_index(&mut self, frame: FrameIndex) -> Result<u64> {
+        self.seek_to_frame(frame.get())
+    }
+
+    /// Seek all signals to a time from the start of the record.
+    ///
+    /// Converts the time to the nearest frame at the sampling frequency of
+    /// the record. Returns the frame position after seeking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The time is negative
+    /// - Seeking is not supported for any signal format
+    /// - The seek operation fails
+    pub fn seek_to_time(&mut self, seconds: f64) -> Result<u64> {
+        self.seek_to_frame(seconds_to_sample(seconds, self.clock.sampling_frequency)?)
+    }
+
+    /// Seek all signals to a time from the start of the record.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// See [`seek_to_time`](Self::seek_to_time).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_time`](Self::seek_to_time).
+    #[cfg(feature = "chrono")]
+    pub fn seek_to_duration(&mut self, duration: TimeDelta) -> Result<u64> {
+        self.seek_to_time(duration.as_seconds_f64())
+    }
+
+    /// Move to `frame` to read from it, as when seeking, but without
+    /// seeking when already there (so formats that cannot seek can be read
+    /// from the start).
+    pub(crate) fn advance_to_frame(&mut self, frame: u64) -> Result<()> {
+        if frame != self.current_frame {
+            self.seek_to_frame(frame)?;
+        }
+        Ok(())
+    }
+
     /// Get current frame position.
     #[must_use]
     pub const fn position(&self) -> u64 {
         self.current_frame
     }
+
+    /// Get the current position in seconds from the start of the record.
+    #[must_use]
+    pub fn position_seconds(&self) -> f64 {
+        self.clock.elapsed(self.current_frame)
+    }
+}
+
+/// Read the next stored frame from each signal group, placing the samples
+/// of each signal at its frame offset.
+///
+/// Returns `false` at the end of the files.
+fn read_groups(groups: &mut [SignalGroup], frame: &mut [Sample]) -> Result<bool> {
+    for group in groups {
+        let n = group
+            .decoder
+            .decode_buf(&mut group.reader, &mut group.buffer)?;
+
+        if n == 0 {
+            return Ok(false); // EOF
+        }
+
+        if n != group.buffer.len() {
+            return Err(Error::SignalDecode {
+                file: group.file_name.clone(),
+                byte_offset: group.reader.stream_position()?,
+                reason: "Incomplete frame read from signal group".to_string(),
+            });
+        }
+
+        for (&position, &sample) in group.positions.iter().zip(&group.buffer) {
+            frame[position] = sample;
+        }
+    }
+
+    Ok(true)
+}
+
+/// Average the samples of a signal over a frame, as `getvec()` does.
+///
+/// The mean is rounded to the nearest integer, and is invalid if any sample
+/// is.
+fn average(samples: &[Sample]) -> Sample {
+    if let [sample] = samples {
+        return *sample;
+    }
+    if samples.is_empty() || samples.contains(&INVALID_SAMPLE) {
+        return INVALID_SAMPLE;
+    }
+    let sum: i64 = samples.iter().map(|&sample| i64::from(sample)).sum();
+    let count = i64::try_from(samples.len()).unwrap_or(i64::MAX);
+    Sample::try_from((2 * sum + count).div_euclid(2 * count)).unwrap_or(INVALID_SAMPLE)
 }
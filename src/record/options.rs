@@ -0,0 +1,151 @@
+/// Default capacity (bytes) of the read buffer for each signal file.
+pub const DEFAULT_BUFFER_CAPACITY: usize = 8 * 1024;
+
+/// Capacity (bytes) of the read buffer used by [`ReaderOptions::low_latency`].
+pub const LOW_LATENCY_BUFFER_CAPACITY: usize = 64;
+
+/// Default number of frames decoded at a time by a
+/// [`SignalReader`](crate::SignalReader) over an interleaved file.
+pub const DEFAULT_INTERLEAVED_BLOCK_FRAMES: usize = 1024;
+
+/// Options controlling how signal readers access their files.
+///
+/// The default options favor throughput: each signal file is read through a
+/// large buffer, so one system call serves many frames. For real-time
+/// simulation (e.g., replaying a record at 1× speed) the
+/// [`low_latency`](Self::low_latency) preset reads tiny amounts at a time,
+/// so each frame is returned as soon as its bytes are available instead of
+/// after a large buffer fill.
+///
+/// Reads always go through the operating system's page cache; the crate
+/// never opens signal files with direct I/O.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+/// use wfdb::record::ReaderOptions;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// let mut reader = record.multi_signal_reader_with_options(&ReaderOptions::low_latency())?;
+/// let frame = reader.read_frame()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReaderOptions {
+    /// Capacity (bytes) of the read buffer for each signal file.
+    ///
+    /// Values below 1 are treated as 1.
+    pub buffer_capacity: usize,
+    /// Units to normalize physical values to (e.g. `["mV", "mmHg"]`).
+    ///
+    /// Each signal whose units convert to one of these (see
+    /// [`SignalScale::in_units`](crate::signal::SignalScale::in_units))
+    /// reports physical values in the first such unit; other signals keep
+    /// the units of the header. Empty by default.
+    pub target_units: Vec<String>,
+    /// Number of frames decoded at a time by a
+    /// [`SignalReader`](crate::SignalReader) over a file shared with other
+    /// signals.
+    ///
+    /// The reader decodes whole blocks of frames sequentially and extracts
+    /// its own samples in memory, so reading one channel of a 12-lead file
+    /// costs one pass over the file rather than one seek per sample. Larger
+    /// blocks mean fewer reads but more memory (`frames × samples per frame`
+    /// samples). Values below 1 are treated as 1.
+    pub interleaved_block_frames: usize,
+    /// Number of frames a [`MultiSignalReader`](crate::MultiSignalReader)
+    /// decodes ahead on a background thread, or 0 (the default) to decode
+    /// on the reading thread.
+    ///
+    /// The thread decodes the next block of frames while the current one is
+    /// being read and processed, so slow storage or expensive formats delay
+    /// the reader only when it outpaces the thread. Up to three blocks are
+    /// held in memory. Seeking stops the thread, discarding the frames
+    /// decoded ahead; the next read starts it again.
+    pub prefetch_frames: usize,
+    /// Whether single-segment readers read to the end of the signal files
+    /// rather than stopping at the number of samples in the header.
+    ///
+    /// Readers stop at the end of the record by default, so the padding of
+    /// block-aligned files (or data of other records sharing a file) is
+    /// never read as samples. Records whose header gives no length are read
+    /// to the end of their files either way, and the segments of
+    /// multi-segment records always end at their lengths.
+    pub ignore_length: bool,
+}
+
+impl Default for ReaderOptions {
+    fn default() -> Self {
+        Self {
+            buffer_capacity: DEFAULT_BUFFER_CAPACITY,
+            target_units: Vec::new(),
+            interleaved_block_frames: DEFAULT_INTERLEAVED_BLOCK_FRAMES,
+            prefetch_frames: 0,
+            ignore_length: false,
+        }
+    }
+}
+
+impl ReaderOptions {
+    /// Options tuned for small, frequent reads.
+    #[must_use]
+    pub const fn low_latency() -> Self {
+        Self {
+            buffer_capacity: LOW_LATENCY_BUFFER_CAPACITY,
+            target_units: Vec::new(),
+            interleaved_block_frames: 1,
+            prefetch_frames: 0,
+            ignore_length: false,
+        }
+    }
+
+    /// Normalize physical values to the given units.
+    ///
+    /// See [`target_units`](Self::target_units).
+    #[must_use]
+    pub fn with_target_units(mut self, units: &[&str]) -> Self {
+        self.target_units = units.iter().map(|&unit| unit.to_string()).collect();
+        self
+    }
+
+    /// Decode `frames` frames at a time from interleaved files.
+    ///
+    /// See [`interleaved_block_frames`](Self::interleaved_block_frames).
+    #[must_use]
+    pub const fn with_interleaved_block_frames(mut self, frames: usize) -> Self {
+        self.interleaved_block_frames = frames;
+        self
+    }
+
+    /// Decode `frames` frames ahead on a background thread.
+    ///
+    /// See [`prefetch_frames`](Self::prefetch_frames).
+    #[must_use]
+    pub const fn with_prefetch_frames(mut self, frames: usize) -> Self {
+        self.prefetch_frames = frames;
+        self
+    }
+
+    /// Read to the end of the signal files, past the length given in the
+    /// header.
+    ///
+    /// See [`ignore_length`](Self::ignore_length).
+    #[must_use]
+    pub const fn ignore_length(mut self) -> Self {
+        self.ignore_length = true;
+        self
+    }
+
+    /// Get the effective buffer capacity.
+    pub(crate) fn effective_buffer_capacity(&self) -> usize {
+        self.buffer_capacity.max(1)
+    }
+
+    /// Get the effective number of frames per interleaved block.
+    pub(crate) fn effective_interleaved_block_frames(&self) -> usize {
+        self.interleaved_block_frames.max(1)
+    }
+}
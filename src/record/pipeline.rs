@@ -0,0 +1,503 @@
+//! Composable, lazily executed transform pipelines over a record.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::ops::RangeBounds;
+use std::path::Path;
+
+use crate::header::Specifications;
+use crate::record::index::half_open;
+use crate::record::lock::WriteLock;
+use crate::record::progress::ProgressCounter;
+use crate::record::resampling::Resampler;
+use crate::record::validation::add_to_checksum;
+use crate::record::{Budget, Interpolation, Progress, Provenance, SignalMatrix};
+use crate::signal::{INVALID_SAMPLE, SignalScale, get_encoder};
+use crate::{Error, Header, Record, Result, Sample, SignalFormat, SignalInfo};
+
+/// Number of frames read at a time when executing a pipeline.
+const PIPELINE_CHUNK_SIZE: usize = 4096;
+
+/// Builder composing channel selection, slicing, inversion, resampling and
+/// physical conversion over a record.
+///
+/// Created by [`Record::pipeline()`]. Building a pipeline does no I/O; the
+/// transforms are applied in a single streaming pass when the pipeline is
+/// executed with [`to_matrix()`](Self::to_matrix),
+/// [`to_record()`](Self::to_record) or
+/// [`for_each_frame()`](Self::for_each_frame). Transforms are applied in a
+/// fixed order regardless of the order of the builder calls: slice, select,
+/// convert to physical units, invert, resample.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+///
+/// let matrix = record
+///     .pipeline()
+///     .select(&["MLII", "V5"])
+///     .frames(0..3600)
+///     .resample(250.0)
+///     .physical()
+///     .to_matrix()?;
+///
+/// println!("{} samples of {} channels", matrix.num_samples(), matrix.num_channels());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Pipeline<'a> {
+    /// Record to read from.
+    record: &'a Record,
+    /// Signal descriptions to keep, in output order (`None` keeps all).
+    selection: Option<Vec<String>>,
+    /// Frame range to read, `start..end`.
+    range: Option<(u64, u64)>,
+    /// Output sampling frequency (`None` keeps the record's).
+    target_frequency: Option<f64>,
+    /// Whether to convert ADC values to physical units.
+    physical: bool,
+    /// Signal descriptions whose polarity is flipped.
+    inverted: Vec<String>,
+    /// Callback reporting the frames read, if any.
+    progress: Option<Progress>,
+    /// Limits of the execution, if any.
+    budget: Option<Budget>,
+}
+
+impl<'a> Pipeline<'a> {
+    /// Create a pipeline reading every frame of every channel.
+    pub(crate) const fn new(record: &'a Record) -> Self {
+        Self {
+            record,
+            selection: None,
+            range: None,
+            target_frequency: None,
+            physical: false,
+            inverted: Vec::new(),
+            progress: None,
+            budget: None,
+        }
+    }
+
+    // [Builder methods]
+
+    /// Keep only the signals with the given descriptions, in the given order.
+    pub fn select(mut self, names: &[&str]) -> Self {
+        self.selection = Some(names.iter().map(|&name| name.to_string()).collect());
+        self
+    }
+
+    /// Keep only the frames in `range` (in the record's sampling frequency).
+    ///
+    /// Any range form is accepted: `2..5` and `2..=4` keep the same frames,
+    /// and `100..` keeps every frame from 100. The range is truncated at the
+    /// end of the record.
+    pub fn frames<R: RangeBounds<u64>>(mut self, range: R) -> Self {
+        self.range = Some(half_open(&range));
+        self
+    }
+
+    /// Keep only frames `start..end` (in the record's sampling frequency).
+    ///
+    /// Same as [`frames(start..end)`](Self::frames).
+    pub fn slice(self, start: u64, end: u64) -> Self {
+        self.frames(start..end)
+    }
+
+    /// Resample the output to a new sampling frequency (Hz), with the
+    /// [`Interpolation::Linear`] interpolation of a
+    /// [`ResamplingReader`](crate::record::ResamplingReader).
+    pub const fn resample(mut self, frequency: f64) -> Self {
+        self.target_frequency = Some(frequency);
+        self
+    }
+
+    /// Convert ADC values to physical units.
+    ///
+    /// Invalid samples become `NaN`. Uncalibrated signals (gain 0) keep their
+    /// raw ADC values. Without this step, output values are raw ADC values.
+    pub const fn physical(mut self) -> Self {
+        self.physical = true;
+        self
+    }
+
+    /// Flip the polarity of the signals with the given descriptions.
+    ///
+    /// Physical values are negated; ADC values are mirrored around the
+    /// signal's baseline. Use this to correct leads recorded with reversed
+    /// electrodes (see `qc::detect_inversion`, behind the `unstable` feature).
+    pub fn invert(mut self, names: &[&str]) -> Self {
+        self.inverted
+            .extend(names.iter().map(|&name| name.to_string()));
+        self
+    }
+
+    /// Report the progress of the execution to a callback, which can cancel
+    /// it.
+    ///
+    /// Progress counts the frames read from the record, before resampling,
+    /// out of the frames of the slice. See [`Progress`].
+    pub fn on_progress(mut self, progress: Progress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Bound the work of the execution.
+    ///
+    /// The frames read from the record, before resampling, are counted
+    /// against the budget. See [`Budget`].
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    // [Execution]
+
+    /// Execute the pipeline, collecting the output into a [`SignalMatrix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`for_each_frame`](Self::for_each_frame).
+    pub fn to_matrix(self) -> Result<SignalMatrix> {
+        let signals = self.signals()?;
+        let channels = self.channels(signals)?;
+
+        let mut columns = vec![Vec::new(); channels.len()];
+        self.for_each_frame(|frame| {
+            for (column, &value) in columns.iter_mut().zip(frame) {
+                column.push(value);
+            }
+        })?;
+
+        Ok(SignalMatrix {
+            sampling_frequency: self.output_frequency(),
+            channel_names: channels
+                .iter()
+                .map(|&i| signals[i].description.clone())
+                .collect(),
+            units: channels
+                .iter()
+                .map(|&i| signals[i].units().to_string())
+                .collect(),
+            columns,
+        })
+    }
+
+    /// Execute the pipeline, writing the output as a new WFDB record.
+    ///
+    /// `path` is the path of the new record; the `.hea` extension may be
+    /// omitted and its file stem is the record name. The output channels are
+    /// written interleaved to `<name>.dat` in format 16 (format 32 if a
+    /// source signal uses a wider format), with the gain, baseline and units
+    /// of their source signals. Invalid samples stay invalid.
+    ///
+    /// The header keeps the info strings of the source record and appends a
+    /// [`Provenance`] entry naming the source, its fingerprint, the crate
+    /// version and the transforms applied, read back with
+    /// [`Record::provenance()`]. The base time is kept unless the output
+    /// starts after the first frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The pipeline cannot be executed (see [`for_each_frame`](Self::for_each_frame))
+    /// - The file name is not a valid record name
+    /// - Another writer holds the lock of the record (see [`Record::create`])
+    /// - The signal or header file cannot be written
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let derived = record
+    ///     .pipeline()
+    ///     .select(&["MLII"])
+    ///     .resample(250.0)
+    ///     .to_record("out/100_250hz")?;
+    ///
+    /// assert_eq!(derived.provenance()[0].source, "100");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_record<P: AsRef<Path>>(self, path: P) -> Result<Record> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| Error::InvalidPath(format!("Invalid record path '{}'", path.display())))?
+            .to_string();
+        let signals = self.signals()?;
+        let channels = self.channels(signals)?;
+        let format = if signals
+            .iter()
+            .any(|s| matches!(s.format, SignalFormat::Format24 | SignalFormat::Format32))
+        {
+            SignalFormat::Format32
+        } else {
+            SignalFormat::Format16
+        };
+        let scales: Vec<SignalScale> = channels.iter().map(|&i| signals[i].scale()).collect();
+
+        // Write the samples, keeping what the header needs
+        let lock = WriteLock::acquire(path)?;
+        let file_name = format!("{name}.dat");
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let mut writer = BufWriter::new(File::create(dir.join(&file_name))?);
+        let mut encoder = get_encoder(format, 0)?;
+        let mut adc_frame = vec![0; channels.len()];
+        let mut checksums = vec![0i16; channels.len()];
+        let mut initial_values = Vec::new();
+        let mut num_frames = 0u64;
+        let mut write_error = None;
+        self.clone().physical().for_each_frame(|frame| {
+            if write_error.is_some() {
+                return;
+            }
+            for ((adc, scale), &value) in adc_frame.iter_mut().zip(&scales).zip(frame) {
+                *adc = scale.to_adc(value);
+            }
+            if num_frames == 0 {
+                initial_values.clone_from(&adc_frame);
+            }
+            for (checksum, &adc) in checksums.iter_mut().zip(&adc_frame) {
+                *checksum = add_to_checksum(*checksum, adc);
+            }
+            num_frames += 1;
+            if let Err(error) = encoder.encode(&mut writer, &adc_frame) {
+                write_error = Some(error);
+            }
+        })?;
+        if let Some(error) = write_error {
+            return Err(error);
+        }
+        encoder.finish(&mut writer)?;
+        writer.flush()?;
+
+        let output_signals = channels
+            .iter()
+            .enumerate()
+            .map(|(output, &input)| SignalInfo {
+                file_name: file_name.clone(),
+                format,
+                samples_per_frame: None,
+                skew: None,
+                byte_offset: None,
+                initial_value: Some(initial_values.get(output).copied().unwrap_or(0)),
+                checksum: Some(checksums[output]),
+                block_size: Some(0),
+                ..signals[input].clone()
+            })
+            .collect::<Vec<_>>();
+
+        let source = self.record.metadata();
+        let mut metadata = source.clone();
+        metadata.name = name;
+        metadata.num_signals = output_signals.len();
+        metadata.sampling_frequency = Some(self.output_frequency());
+        metadata.counter_frequency = None;
+        metadata.base_counter = None;
+        metadata.num_samples = Some(num_frames);
+        if self.range.is_some_and(|(start, _)| start > 0) {
+            metadata.base_time = None;
+            metadata.base_date = None;
+        }
+
+        let mut info_strings = self.record.info_strings().to_vec();
+        info_strings.extend(self.provenance().to_info_strings());
+        let header = Header {
+            metadata,
+            specifications: Specifications::SingleSegment {
+                signals: output_signals,
+            },
+            info_strings,
+        };
+        Record::create_locked(path, &header, Some(lock))
+    }
+
+    /// Execute the pipeline, passing each output frame to `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - This is a multi-segment record
+    /// - A selected or inverted signal description is not found in the record
+    /// - The slice range is reversed or the resampling frequency is not positive
+    /// - The signal files cannot be read
+    /// - The [progress callback](Self::on_progress) cancels the execution, or
+    ///   the [budget](Self::with_budget) is spent
+    pub fn for_each_frame<F: FnMut(&[f64])>(&self, mut sink: F) -> Result<()> {
+        let signals = self.signals()?;
+        let channels = self.channels(signals)?;
+        let inverted = self
+            .inverted
+            .iter()
+            .map(|name| find_signal(signals, name))
+            .collect::<Result<Vec<_>>>()?;
+        let (start, end) = self.range.unwrap_or((0, u64::MAX));
+        if start > end {
+            return Err(Error::InvalidData(format!(
+                "Invalid pipeline slice {start}..{end}"
+            )));
+        }
+
+        let mut resampler = self
+            .target_frequency
+            .map(|frequency| {
+                Resampler::new(
+                    self.record.metadata().sampling_frequency(),
+                    frequency,
+                    Interpolation::Linear,
+                )
+            })
+            .transpose()?;
+
+        // Truncate the range at the end of the record
+        let num_samples = self.record.total_samples();
+        let (start, end) = num_samples.map_or((start, end), |num_samples| {
+            (start.min(num_samples), end.min(num_samples))
+        });
+        let total = num_samples.map(|_| end - start);
+        let mut progress =
+            ProgressCounter::new(self.progress.as_ref(), self.budget.as_ref(), total)?;
+
+        let mut reader = self.record.multi_signal_reader()?;
+        if start < end {
+            reader.advance_to_frame(start)?;
+        }
+
+        let scales: Vec<SignalScale> = signals.iter().map(SignalInfo::scale).collect();
+        let mut pending = Vec::<Vec<Sample>>::new().into_iter();
+        let mut remaining = end - start;
+        let mut next_frame = || -> Result<Option<Vec<f64>>> {
+            loop {
+                if let Some(frame) = pending.next() {
+                    return Ok(Some(
+                        channels
+                            .iter()
+                            .map(|&channel| {
+                                self.convert(
+                                    &scales[channel],
+                                    frame[channel],
+                                    inverted.contains(&channel),
+                                )
+                            })
+                            .collect(),
+                    ));
+                }
+                if remaining == 0 {
+                    return Ok(None);
+                }
+                let count = usize::try_from(remaining)
+                    .unwrap_or(usize::MAX)
+                    .min(PIPELINE_CHUNK_SIZE);
+                let frames = reader.read_frames(count)?;
+                if frames.is_empty() {
+                    return Ok(None);
+                }
+                remaining -= frames.len() as u64;
+                progress.advance(frames.len() as u64)?;
+                pending = frames.into_iter();
+            }
+        };
+
+        match &mut resampler {
+            Some(resampler) => {
+                while let Some(frame) = resampler.next_frame(&mut next_frame)? {
+                    sink(&frame);
+                }
+            }
+            None => {
+                while let Some(frame) = next_frame()? {
+                    sink(&frame);
+                }
+            }
+        }
+        progress.finish()
+    }
+
+    // [Private helper methods]
+
+    /// Get the signal specifications of the record.
+    fn signals(&self) -> Result<&'a [SignalInfo]> {
+        if self.record.is_multi_segment() {
+            return Err(Error::InvalidData(
+                "Pipelines not yet supported for multi-segment records".into(),
+            ));
+        }
+        self.record
+            .signal_info()
+            .ok_or_else(|| Error::InvalidHeader("No signal specifications in header".into()))
+    }
+
+    /// Resolve the selected signals to indices.
+    fn channels(&self, signals: &[SignalInfo]) -> Result<Vec<usize>> {
+        let Some(selection) = &self.selection else {
+            return Ok((0..signals.len()).collect());
+        };
+
+        selection
+            .iter()
+            .map(|name| find_signal(signals, name))
+            .collect()
+    }
+
+    /// Describe the transforms of the pipeline, in the order they are applied.
+    fn provenance(&self) -> Provenance {
+        let mut provenance = Provenance::from_record(self.record);
+        if let Some((start, end)) = self.range {
+            let end = if end == u64::MAX {
+                String::new()
+            } else {
+                end.to_string()
+            };
+            provenance = provenance.with_transform(format!("slice {start}..{end}"));
+        }
+        if let Some(selection) = &self.selection {
+            provenance = provenance.with_transform(format!("select {}", selection.join(", ")));
+        }
+        if !self.inverted.is_empty() {
+            provenance = provenance.with_transform(format!("invert {}", self.inverted.join(", ")));
+        }
+        if let Some(frequency) = self.target_frequency {
+            provenance = provenance.with_transform(format!("resample {frequency} Hz"));
+        }
+        provenance
+    }
+
+    /// Convert a sample to an output value.
+    fn convert(&self, scale: &SignalScale, value: Sample, inverted: bool) -> f64 {
+        if self.physical && scale.is_calibrated() {
+            let physical = scale.to_physical(value);
+            if inverted { -physical } else { physical }
+        } else if self.physical && value == INVALID_SAMPLE {
+            f64::NAN
+        } else if inverted && value != INVALID_SAMPLE {
+            2.0f64.mul_add(f64::from(scale.baseline), -f64::from(value))
+        } else {
+            f64::from(value)
+        }
+    }
+
+    /// Get the sampling frequency of the output.
+    fn output_frequency(&self) -> f64 {
+        self.target_frequency
+            .unwrap_or_else(|| self.record.metadata().sampling_frequency())
+    }
+}
+
+/// Find the index of the signal with the given description.
+fn find_signal(signals: &[SignalInfo], name: &str) -> Result<usize> {
+    signals
+        .iter()
+        .position(|s| s.description.as_deref() == Some(name))
+        .ok_or_else(|| Error::InvalidData(format!("Signal '{name}' not found in record")))
+}
@@ -0,0 +1,136 @@
+//! Decoding frames ahead of a reader on a background thread.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+use crate::{Error, Result, Sample};
+
+/// Background thread decoding blocks of frames ahead of a reader.
+///
+/// The thread owns the decoding state `S` (files and decoders) while
+/// running, and fills one block while the reader consumes the previous one
+/// (double buffering). Stopping the thread gives the state back, positioned
+/// after the last frame decoded.
+pub struct Prefetcher<S> {
+    /// Blocks decoded by the thread, dropped to stop it.
+    receiver: Option<Receiver<Result<Vec<Sample>>>>,
+    /// Blocks consumed by the reader, returned to the thread for reuse.
+    recycle: Sender<Vec<Sample>>,
+    /// Handle of the thread, returning the decoding state.
+    handle: Option<JoinHandle<S>>,
+    /// Block being consumed.
+    block: Vec<Sample>,
+    /// Position of the next frame in `block`.
+    position: usize,
+    /// Number of samples in a frame.
+    frame_len: usize,
+}
+
+impl<S: Send + 'static> Prefetcher<S> {
+    /// Start decoding blocks of `block_frames` frames of `frame_len`
+    /// samples, reading each frame with `read_frame` (which returns `false`
+    /// at the end of the files).
+    pub fn spawn<F>(
+        mut state: S,
+        mut read_frame: F,
+        frame_len: usize,
+        block_frames: usize,
+    ) -> Result<Self>
+    where
+        F: FnMut(&mut S, &mut [Sample]) -> Result<bool> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        let (recycle, spares) = mpsc::channel::<Vec<Sample>>();
+        let handle = thread::Builder::new()
+            .name("wfdb-prefetch".to_string())
+            .spawn(move || {
+                loop {
+                    let mut block = spares.try_recv().unwrap_or_default();
+                    block.clear();
+                    let mut more = Ok(true);
+                    for _ in 0..block_frames {
+                        let start = block.len();
+                        block.resize(start + frame_len, 0);
+                        more = read_frame(&mut state, &mut block[start..]);
+                        if !matches!(more, Ok(true)) {
+                            block.truncate(start);
+                            break;
+                        }
+                    }
+                    let last = !matches!(more, Ok(true));
+                    // The reader stops the thread by dropping its receiver
+                    if sender.send(more.map(|_| block)).is_err() || last {
+                        return state;
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            receiver: Some(receiver),
+            recycle,
+            handle: Some(handle),
+            block: Vec::new(),
+            position: 0,
+            frame_len,
+        })
+    }
+
+    /// Read the next frame into `frame` (of `frame_len` samples), returning
+    /// `false` at the end of the files.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error met by the thread decoding the frame, after which
+    /// the thread has stopped and no more frames are read.
+    pub fn read_frame(&mut self, frame: &mut [Sample]) -> Result<bool> {
+        if self.position >= self.block.len() {
+            let Some(Ok(block)) = self.receiver.as_ref().map(Receiver::recv) else {
+                return Ok(false); // Thread stopped
+            };
+            let consumed = std::mem::replace(&mut self.block, block?);
+            // The thread may have stopped already
+            let _ = self.recycle.send(consumed);
+            self.position = 0;
+            if self.block.is_empty() {
+                return Ok(false);
+            }
+        }
+        frame.copy_from_slice(&self.block[self.position..self.position + self.frame_len]);
+        self.position += self.frame_len;
+        Ok(true)
+    }
+
+    /// Stop the thread and get the decoding state back.
+    ///
+    /// Frames decoded ahead but not read are discarded, so the state is
+    /// positioned past them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the thread panicked.
+    pub fn stop(mut self) -> Result<S> {
+        self.receiver = None;
+        self.handle.take().map_or_else(
+            || {
+                Err(Error::InvalidData(
+                    "Prefetch thread already stopped".to_string(),
+                ))
+            },
+            |handle| {
+                handle
+                    .join()
+                    .map_err(|_| Error::InvalidData("Prefetch thread panicked".to_string()))
+            },
+        )
+    }
+}
+
+impl<S> Drop for Prefetcher<S> {
+    fn drop(&mut self) {
+        self.receiver = None;
+        if let Some(handle) = self.handle.take() {
+            // A panic of the thread has nothing left to report
+            let _ = handle.join();
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Progress reporting, budgets and cancellation of long reads.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::{Error, Result};
+
+/// Number of frames processed between two reports.
+pub const PROGRESS_INTERVAL: u64 = 4096;
+
+/// Callback receiving the number of frames processed.
+type ProgressFn = dyn FnMut(u64, Option<u64>) -> bool + Send;
+
+/// Callback reporting the progress of a long operation, which can cancel it.
+///
+/// The callback is called with the number of frames processed so far and
+/// the total number of frames of the operation (`None` when the header does
+/// not give the length of the record), every [`PROGRESS_INTERVAL`] frames
+/// and once at the end. It returns `true` to go on, or `false` to stop the
+/// operation, which then returns [`Error::Cancelled`].
+///
+/// Used by [`Pipeline::on_progress`](crate::record::Pipeline::on_progress)
+/// (and so [`Record::read_all_physical_with_progress`](crate::Record::read_all_physical_with_progress)),
+/// [`Record::validate_with_progress`](crate::Record::validate_with_progress)
+/// and [`CsvOptions::with_progress`](crate::record::export::CsvOptions::with_progress).
+/// Clones share the same callback.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// use wfdb::Record;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// let stop = Arc::new(AtomicBool::new(false));
+///
+/// let flag = Arc::clone(&stop);
+/// let report = record.validate_with_progress(move |done, total| {
+///     if let Some(total) = total {
+///         eprint!("\r{:.0}%", 100.0 * done as f64 / total as f64);
+///     }
+///     !flag.load(Ordering::Relaxed)
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Progress(Arc<Mutex<ProgressFn>>);
+
+impl Progress {
+    /// Wrap a callback receiving `(done, total)` and returning whether to go
+    /// on.
+    pub fn new<F: FnMut(u64, Option<u64>) -> bool + Send + 'static>(callback: F) -> Self {
+        Self(Arc::new(Mutex::new(callback)))
+    }
+
+    /// Call the callback, returning an error if it cancels the operation.
+    fn report(&self, done: u64, total: Option<u64>) -> Result<()> {
+        let mut callback = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if callback(done, total) {
+            Ok(())
+        } else {
+            Err(Error::Cancelled)
+        }
+    }
+}
+
+impl fmt::Debug for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Progress")
+    }
+}
+
+impl PartialEq for Progress {
+    /// Progress callbacks are equal when they are clones of each other.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Progress {}
+
+/// Flag cancelling the operations of a [`Budget`] from another thread.
+///
+/// Clones share the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Create a token that is not cancelled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the operations using this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if the token has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for CancelToken {
+    /// Tokens are equal when they are clones of each other.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CancelToken {}
+
+/// Limits on the work of a long operation, for services bounding the cost
+/// of requests.
+///
+/// An operation over more frames than [`max_frames`](Self::with_max_frames)
+/// fails before reading when the length of the record is known, and as soon
+/// as the limit is passed otherwise, with [`Error::BudgetExceeded`]. The
+/// deadline and the [`CancelToken`] are checked every
+/// [`PROGRESS_INTERVAL`] frames; a passed deadline also fails with
+/// [`Error::BudgetExceeded`], and a cancelled token with
+/// [`Error::Cancelled`].
+///
+/// Used by [`Pipeline::with_budget`](crate::record::Pipeline::with_budget),
+/// [`Record::validate_with_budget`](crate::Record::validate_with_budget) and
+/// [`CsvOptions::with_budget`](crate::record::export::CsvOptions::with_budget).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use wfdb::Record;
+/// use wfdb::record::{Budget, CancelToken};
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// let token = CancelToken::new();
+/// let budget = Budget::new()
+///     .with_max_frames(360 * 600)
+///     .with_timeout(Duration::from_secs(5))
+///     .with_cancel_token(token.clone());
+///
+/// // Fails: the record is longer than ten minutes
+/// let matrix = record.pipeline().physical().with_budget(budget).to_matrix();
+/// assert!(matrix.is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Largest number of frames to process.
+    pub max_frames: Option<u64>,
+    /// Time after which the operation fails.
+    pub deadline: Option<Instant>,
+    /// Token cancelling the operation.
+    pub cancel_token: Option<CancelToken>,
+}
+
+impl Budget {
+    /// Create a budget without limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the number of frames processed.
+    #[must_use]
+    pub const fn with_max_frames(mut self, max_frames: u64) -> Self {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    /// Fail operations still running at a deadline.
+    #[must_use]
+    pub const fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Fail operations still running after a timeout from now.
+    #[must_use]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    /// Cancel operations when a token is cancelled.
+    #[must_use]
+    pub fn with_cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel_token = Some(token);
+        self
+    }
+
+    /// Check that `frames` frames are within the budget.
+    fn check_frames(&self, frames: u64) -> Result<()> {
+        match self.max_frames {
+            Some(max_frames) if frames > max_frames => Err(Error::BudgetExceeded(format!(
+                "Operation over {frames} frames exceeds the budget of {max_frames} frames"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the deadline and the cancel token.
+    fn check_time(&self) -> Result<()> {
+        if self
+            .cancel_token
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+        {
+            return Err(Error::Cancelled);
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(Error::BudgetExceeded(
+                "Operation passed its deadline".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Count of the frames processed by an operation, reported to an optional
+/// [`Progress`] callback and checked against an optional [`Budget`].
+pub struct ProgressCounter<'a> {
+    /// Callback, if any.
+    progress: Option<&'a Progress>,
+    /// Limits of the operation, if any.
+    budget: Option<&'a Budget>,
+    /// Total number of frames, if known.
+    total: Option<u64>,
+    /// Frames processed.
+    done: u64,
+    /// Frames processed at the last report.
+    reported: Option<u64>,
+}
+
+impl<'a> ProgressCounter<'a> {
+    /// Start counting the frames of an operation over `total` frames.
+    ///
+    /// Fails if the total is over the budget, or the budget is already
+    /// spent.
+    pub fn new(
+        progress: Option<&'a Progress>,
+        budget: Option<&'a Budget>,
+        total: Option<u64>,
+    ) -> Result<Self> {
+        if let Some(budget) = budget {
+            budget.check_frames(total.unwrap_or(0))?;
+            budget.check_time()?;
+        }
+        Ok(Self {
+            progress,
+            budget,
+            total,
+            done: 0,
+            reported: None,
+        })
+    }
+
+    /// Count processed frames, reporting and checking the deadline every
+    /// [`PROGRESS_INTERVAL`] frames.
+    pub fn advance(&mut self, frames: u64) -> Result<()> {
+        self.done += frames;
+        if let Some(budget) = self.budget {
+            budget.check_frames(self.done)?;
+        }
+        if self.done - self.reported.unwrap_or(0) >= PROGRESS_INTERVAL {
+            if let Some(budget) = self.budget {
+                budget.check_time()?;
+            }
+            self.report()?;
+        }
+        Ok(())
+    }
+
+    /// Report the final count, unless it was just reported.
+    pub fn finish(&mut self) -> Result<()> {
+        if self.reported == Some(self.done) {
+            return Ok(());
+        }
+        self.report()
+    }
+
+    /// Report the current count.
+    fn report(&mut self) -> Result<()> {
+        self.reported = Some(self.done);
+        match self.progress {
+            Some(progress) => progress.report(self.done, self.total),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::Record;
+
+/// Prefix of the info strings holding provenance entries.
+const PROVENANCE_PREFIX: &str = "wfdb-provenance:";
+
+/// Origin of a derived record: the record it was computed from and the
+/// transforms applied to it.
+///
+/// Written as info strings (`# wfdb-provenance: ...` header comments) by
+/// [`Pipeline::to_record()`](crate::record::Pipeline::to_record), and read back by
+/// [`Record::provenance()`]. A record derived from an already derived record
+/// keeps the entries of its source, so the full chain can be audited.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("out/100_250hz")?;
+/// for entry in record.provenance() {
+///     println!("From {} ({}), by {}", entry.source, entry.fingerprint, entry.crate_version);
+///     for transform in &entry.transforms {
+///         println!("  {transform}");
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    /// Name of the source record.
+    pub source: String,
+    /// Fingerprint of the source record (see [`Record::fingerprint()`]).
+    pub fingerprint: String,
+    /// Name and version of the library that derived the record.
+    pub crate_version: String,
+    /// Transforms applied to the source, in order (e.g. `slice 0..3600`).
+    pub transforms: Vec<String>,
+}
+
+impl Provenance {
+    /// Start a provenance entry for a record derived from `source` by this
+    /// version of the crate, without transforms.
+    #[must_use]
+    pub fn from_record(source: &Record) -> Self {
+        Self {
+            source: source.metadata().name().to_string(),
+            fingerprint: source.fingerprint(),
+            crate_version: concat!(env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"))
+                .to_string(),
+            transforms: Vec::new(),
+        }
+    }
+
+    /// Add a transform, described by its name and parameters.
+    #[must_use]
+    pub fn with_transform(mut self, transform: impl Into<String>) -> Self {
+        self.transforms.push(transform.into());
+        self
+    }
+
+    /// Format the entry as header info strings.
+    #[must_use]
+    pub fn to_info_strings(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!(" {PROVENANCE_PREFIX} source {}", self.source),
+            format!(" {PROVENANCE_PREFIX} fingerprint {}", self.fingerprint),
+            format!(" {PROVENANCE_PREFIX} crate {}", self.crate_version),
+        ];
+        lines.extend(
+            self.transforms
+                .iter()
+                .map(|transform| format!(" {PROVENANCE_PREFIX} transform {transform}")),
+        );
+        lines
+    }
+
+    /// Parse the provenance entries found in header info strings, oldest
+    /// first.
+    ///
+    /// Info strings without the provenance prefix are ignored, as are
+    /// provenance lines with unknown keys or appearing before any `source`
+    /// line.
+    #[must_use]
+    pub fn from_info_strings<S: AsRef<str>>(info_strings: &[S]) -> Vec<Self> {
+        let mut entries: Vec<Self> = Vec::new();
+        for info in info_strings {
+            let Some(line) = info.as_ref().trim().strip_prefix(PROVENANCE_PREFIX) else {
+                continue;
+            };
+            let line = line.trim();
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            let value = value.trim().to_string();
+            if key == "source" {
+                entries.push(Self {
+                    source: value,
+                    fingerprint: String::new(),
+                    crate_version: String::new(),
+                    transforms: Vec::new(),
+                });
+                continue;
+            }
+            let Some(entry) = entries.last_mut() else {
+                continue;
+            };
+            match key {
+                "fingerprint" => entry.fingerprint = value,
+                "crate" => entry.crate_version = value,
+                "transform" => entry.transforms.push(value),
+                _ => {}
+            }
+        }
+        entries
+    }
+}
+
+/// Compute the 64-bit FNV-1a hash of `bytes`, as 16 hexadecimal digits.
+pub fn fingerprint(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
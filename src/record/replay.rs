@@ -0,0 +1,136 @@
+//! Real-time replay of records.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::record::{PhysicalReader, ReaderOptions};
+use crate::{Error, Record, Result, Sample};
+
+/// Iterator yielding the frames of a record paced in real time.
+///
+/// Created by [`replay()`]. Frame `n` is returned no earlier than
+/// `n / (sampling_frequency * speed)` seconds after the first call to
+/// [`next()`](Iterator::next), sleeping the current thread as needed. The
+/// schedule is anchored to the start of the replay, so a consumer that falls
+/// behind receives the pending frames immediately until it catches up instead
+/// of accumulating drift.
+pub struct Replay {
+    /// Reader for the record's frames.
+    source: PhysicalReader,
+    /// Time between consecutive frames at the requested speed.
+    frame_interval: Duration,
+    /// Time at which the first frame was returned.
+    start: Option<Instant>,
+    /// Number of frames returned so far.
+    frames_returned: u64,
+    /// Whether the end of the record (or an error) has been reached.
+    done: bool,
+}
+
+impl Replay {
+    /// Get the number of frames returned so far.
+    #[must_use]
+    pub const fn position(&self) -> u64 {
+        self.frames_returned
+    }
+
+    /// Get the time between consecutive frames at the requested speed.
+    #[must_use]
+    pub const fn frame_interval(&self) -> Duration {
+        self.frame_interval
+    }
+
+    /// Get the time at which a frame is due, from the start of the replay.
+    fn elapsed_at(&self, frame: u64) -> Duration {
+        let nanos = self.frame_interval.as_nanos() * u128::from(frame);
+        let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+        let subsec = u32::try_from(nanos % 1_000_000_000).unwrap_or(0);
+        Duration::new(secs, subsec)
+    }
+}
+
+impl Iterator for Replay {
+    type Item = Result<Vec<Sample>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = *self.start.get_or_insert_with(Instant::now);
+        if let Some(due) = start.checked_add(self.elapsed_at(self.frames_returned)) {
+            let now = Instant::now();
+            if due > now {
+                thread::sleep(due - now);
+            }
+        }
+
+        match self.source.read_adc_frame() {
+            Ok(Some(frame)) => {
+                self.frames_returned += 1;
+                Some(Ok(frame))
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Replay the frames of a record paced according to its sampling frequency.
+///
+/// `speed` is a multiplier of real time: `1.0` replays at the recorded rate,
+/// `2.0` twice as fast, `0.5` at half speed. Use [`f64::INFINITY`] to read
+/// frames without pacing. Signal files are read with
+/// [`ReaderOptions::low_latency`], so each frame is returned as soon as it is
+/// due.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `speed` is not a positive number
+/// - The record's readers cannot be created
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+/// use wfdb::record::replay;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+///
+/// // Feed the monitor at the recorded rate
+/// for frame in replay(&record, 1.0)? {
+///     let frame = frame?;
+///     // Send frame to the system under test...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn replay(record: &Record, speed: f64) -> Result<Replay> {
+    if speed.is_nan() || speed <= 0.0 {
+        return Err(Error::InvalidData(format!(
+            "Replay speed must be a positive number, got {speed}"
+        )));
+    }
+
+    let options = ReaderOptions::low_latency();
+    let source = PhysicalReader::with_options(record, &options)?;
+
+    let frames_per_second = record.metadata().sampling_frequency() * speed;
+    let frame_interval = Duration::try_from_secs_f64(1.0 / frames_per_second).unwrap_or_default();
+
+    Ok(Replay {
+        source,
+        frame_interval,
+        start: None,
+        frames_returned: 0,
+        done: false,
+    })
+}
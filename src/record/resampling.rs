@@ -0,0 +1,409 @@
+//! Conversion of frame streams to another sampling frequency.
+
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+
+use crate::signal::INVALID_SAMPLE;
+use crate::{Error, MultiSignalReader, Result, Sample, SignalReader};
+
+/// Half-width (input samples) of the polyphase filter when up-sampling.
+const POLYPHASE_HALF_WIDTH: usize = 8;
+
+/// Number of phases of the polyphase filter bank.
+const POLYPHASE_PHASES: usize = 64;
+
+/// Frame reader wrapped by a [`ResamplingReader`].
+type FrameSource = Box<dyn FnMut() -> Result<Option<Vec<Sample>>> + Send>;
+
+/// Interpolation used by a [`ResamplingReader`] between input samples.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Take the nearest input sample. Fastest, and keeps the original
+    /// values, but aliases when down-sampling.
+    Nearest,
+    /// Interpolate linearly between the two surrounding input samples, as
+    /// the `xform` tool of the WFDB software package does.
+    #[default]
+    Linear,
+    /// Windowed-sinc polyphase filter bank. Band-limits the signal to the
+    /// lower of the two Nyquist frequencies, so down-sampling does not alias.
+    Polyphase,
+}
+
+/// Reader converting frames to another sampling frequency.
+///
+/// Created by [`MultiSignalReader::resample()`] (one value per signal per
+/// frame) or [`SignalReader::resample()`] (one value per frame). Output
+/// frame `n` is taken at time `n / frequency` from the first input frame,
+/// and frames are produced up to the time of the last input frame.
+///
+/// Values stay ADC values, rounded to the nearest integer. An output value
+/// computed from an invalid input sample is invalid.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+/// use wfdb::record::Interpolation;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// let mut reader = record
+///     .multi_signal_reader()?
+///     .resample(250.0, Interpolation::Polyphase)?;
+///
+/// while let Some(frame) = reader.read_frame()? {
+///     // Process frames at 250 Hz...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ResamplingReader {
+    /// Reads the next input frame, returning `None` at end of input.
+    source: FrameSource,
+    /// Interpolation between the input frames.
+    resampler: Resampler,
+}
+
+impl ResamplingReader {
+    /// Wrap a frame source sampled at `input_frequency`.
+    fn new(
+        source: FrameSource,
+        input_frequency: f64,
+        frequency: f64,
+        interpolation: Interpolation,
+    ) -> Result<Self> {
+        Ok(Self {
+            source,
+            resampler: Resampler::new(input_frequency, frequency, interpolation)?,
+        })
+    }
+
+    /// Read the next output frame, or `None` at the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the underlying reader fails.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn read_frame(&mut self) -> Result<Option<Vec<Sample>>> {
+        let source = &mut self.source;
+        let values = self.resampler.next_frame(|| {
+            Ok(source()?.map(|frame| {
+                frame
+                    .iter()
+                    .map(|&sample| {
+                        if sample == INVALID_SAMPLE {
+                            f64::NAN
+                        } else {
+                            f64::from(sample)
+                        }
+                    })
+                    .collect()
+            }))
+        })?;
+        Ok(values.map(|values| {
+            values
+                .iter()
+                .map(|&value| {
+                    if value.is_nan() {
+                        INVALID_SAMPLE
+                    } else {
+                        value.round() as Sample
+                    }
+                })
+                .collect()
+        }))
+    }
+
+    /// Read up to `count` output frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the underlying reader fails.
+    pub fn read_frames(&mut self, count: usize) -> Result<Vec<Vec<Sample>>> {
+        let mut frames = Vec::with_capacity(count);
+        while frames.len() < count {
+            match self.read_frame()? {
+                Some(frame) => frames.push(frame),
+                None => break,
+            }
+        }
+        Ok(frames)
+    }
+
+    /// Get the output sampling frequency (Hz).
+    #[must_use]
+    pub const fn sampling_frequency(&self) -> f64 {
+        self.resampler.sampling_frequency
+    }
+
+    /// Get the interpolation between input samples.
+    #[must_use]
+    pub const fn interpolation(&self) -> Interpolation {
+        self.resampler.interpolation
+    }
+
+    /// Get the number of output frames returned so far.
+    #[must_use]
+    pub const fn position(&self) -> u64 {
+        self.resampler.produced
+    }
+}
+
+/// Interpolation of a stream of frames of values, driving a
+/// [`ResamplingReader`] and the resampling of [`Pipeline`](crate::record::Pipeline).
+///
+/// Input and output values are `f64`, with `NaN` for invalid samples.
+pub struct Resampler {
+    /// Interpolation between input samples.
+    interpolation: Interpolation,
+    /// Output sampling frequency (Hz).
+    sampling_frequency: f64,
+    /// Input frames advanced per output frame.
+    step: f64,
+    /// Polyphase filter taps, per phase (empty for other interpolations).
+    filters: Vec<Vec<f64>>,
+    /// Input frames kept for interpolation.
+    window: VecDeque<Vec<f64>>,
+    /// Input index of the first frame of `window`.
+    window_start: u64,
+    /// Whether the end of the input has been reached.
+    exhausted: bool,
+    /// Number of output frames produced so far.
+    produced: u64,
+}
+
+impl Resampler {
+    /// Create a resampler from `input_frequency` to `frequency` (Hz).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frequency` is not a positive number.
+    pub fn new(input_frequency: f64, frequency: f64, interpolation: Interpolation) -> Result<Self> {
+        if !frequency.is_finite() || frequency <= 0.0 {
+            return Err(Error::InvalidData(format!(
+                "Resampling frequency must be a positive number, got {frequency}"
+            )));
+        }
+        let step = input_frequency / frequency;
+        let filters = if interpolation == Interpolation::Polyphase {
+            polyphase_filters(step)
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            interpolation,
+            sampling_frequency: frequency,
+            step,
+            filters,
+            window: VecDeque::new(),
+            window_start: 0,
+            exhausted: false,
+            produced: 0,
+        })
+    }
+
+    /// Compute the next output frame, reading input frames from `source`,
+    /// or `None` at the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `source`.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn next_frame<S: FnMut() -> Result<Option<Vec<f64>>>>(
+        &mut self,
+        mut source: S,
+    ) -> Result<Option<Vec<f64>>> {
+        let position = self.produced as f64 * self.step;
+        let mut base = position.floor() as u64;
+        let fraction = position - position.floor();
+        let mut phase = (fraction * POLYPHASE_PHASES as f64).round() as usize;
+        if phase == POLYPHASE_PHASES {
+            base += 1;
+            phase = 0;
+        }
+
+        let (first, last) = match self.interpolation {
+            Interpolation::Nearest => {
+                let nearest = (position + 0.5).floor() as u64;
+                (nearest, nearest)
+            }
+            Interpolation::Linear => (position.floor() as u64, position.floor() as u64 + 1),
+            Interpolation::Polyphase => {
+                let half_width = self.filters[0].len() as u64 / 2;
+                (base.saturating_sub(half_width - 1), base + half_width)
+            }
+        };
+
+        // Drop frames no longer needed, then read up to the last one needed
+        while self.window_start < first && self.window.len() > 1 {
+            self.window.pop_front();
+            self.window_start += 1;
+        }
+        while !self.exhausted && self.window_start + (self.window.len() as u64) <= last {
+            match source()? {
+                Some(frame) => self.window.push_back(frame),
+                None => self.exhausted = true,
+            }
+        }
+
+        // Output frames stop at the last input frame
+        let end = self.window_start + self.window.len() as u64;
+        if self.window.is_empty() || position > (end - 1) as f64 {
+            return Ok(None);
+        }
+
+        let values = match self.interpolation {
+            Interpolation::Nearest => self.frame_at(first.min(end - 1)).clone(),
+            Interpolation::Linear => {
+                let a = self.frame_at(first);
+                if fraction == 0.0 || last >= end {
+                    a.clone()
+                } else {
+                    let b = self.frame_at(last);
+                    a.iter()
+                        .zip(b)
+                        .map(|(&a, &b)| (b - a).mul_add(fraction, a))
+                        .collect()
+                }
+            }
+            Interpolation::Polyphase => {
+                let taps = &self.filters[phase];
+                let half_width = taps.len() as u64 / 2;
+                let mut values = vec![0.0; self.window[0].len()];
+                for (k, &weight) in taps.iter().enumerate() {
+                    // Samples beyond either end repeat the edge sample
+                    let index = (base + k as u64 + 1)
+                        .saturating_sub(half_width)
+                        .clamp(self.window_start, end - 1);
+                    for (value, &sample) in values.iter_mut().zip(self.frame_at(index)) {
+                        *value = sample.mul_add(weight, *value);
+                    }
+                }
+                values
+            }
+        };
+
+        self.produced += 1;
+        Ok(Some(values))
+    }
+
+    /// Get an input frame held in the window.
+    #[allow(clippy::cast_possible_truncation)]
+    fn frame_at(&self, index: u64) -> &Vec<f64> {
+        &self.window[(index - self.window_start) as usize]
+    }
+}
+
+impl Iterator for ResamplingReader {
+    type Item = Result<Vec<Sample>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_frame().transpose()
+    }
+}
+
+impl MultiSignalReader {
+    /// Convert the frames of this reader to another sampling frequency (Hz).
+    ///
+    /// Resampling starts at the current position. Signals with several
+    /// samples per frame are averaged over each frame first, as by
+    /// [`read_frame()`](Self::read_frame).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frequency` is not a positive number.
+    pub fn resample(
+        mut self,
+        frequency: f64,
+        interpolation: Interpolation,
+    ) -> Result<ResamplingReader> {
+        let input_frequency = self.clock().sampling_frequency;
+        ResamplingReader::new(
+            Box::new(move || {
+                let frame = self.read_frame()?;
+                Ok((!frame.is_empty()).then_some(frame))
+            }),
+            input_frequency,
+            frequency,
+            interpolation,
+        )
+    }
+}
+
+impl SignalReader {
+    /// Convert this signal to another sampling frequency (Hz).
+    ///
+    /// Resampling starts at the current position. Each output frame holds
+    /// one sample.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frequency` is not a positive number or the
+    /// sampling frequency of the signal is unknown.
+    pub fn resample(
+        mut self,
+        frequency: f64,
+        interpolation: Interpolation,
+    ) -> Result<ResamplingReader> {
+        let input_frequency = self.sampling_frequency().ok_or_else(|| {
+            Error::InvalidHeader("Sampling frequency of the signal is unknown".into())
+        })?;
+        let mut buffer = [0];
+        ResamplingReader::new(
+            Box::new(move || {
+                let n = self.read_samples_buf(&mut buffer)?;
+                Ok((n > 0).then(|| buffer.to_vec()))
+            }),
+            input_frequency,
+            frequency,
+            interpolation,
+        )
+    }
+}
+
+/// Build a windowed-sinc polyphase filter bank for `step` input samples per
+/// output sample.
+///
+/// The cutoff is the lower of the input and output Nyquist frequencies, and
+/// the filter widens accordingly when down-sampling. Each phase is
+/// normalized to unit gain at DC.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn polyphase_filters(step: f64) -> Vec<Vec<f64>> {
+    let cutoff = (1.0 / step).min(1.0);
+    let half_width = (POLYPHASE_HALF_WIDTH as f64 / cutoff).ceil() as usize;
+    let half_width_f = half_width as f64;
+
+    (0..POLYPHASE_PHASES)
+        .map(|phase| {
+            let fraction = phase as f64 / POLYPHASE_PHASES as f64;
+            let mut taps: Vec<f64> = (0..2 * half_width)
+                .map(|k| {
+                    // Distance from the output position to input sample k
+                    let distance = k as f64 + 1.0 - half_width_f - fraction;
+                    let x = cutoff * distance;
+                    let sinc = if x == 0.0 {
+                        1.0
+                    } else {
+                        (PI * x).sin() / (PI * x)
+                    };
+                    let window = 0.5f64.mul_add((PI * distance / half_width_f).cos(), 0.5);
+                    cutoff * sinc * window
+                })
+                .collect();
+            let sum: f64 = taps.iter().sum();
+            for tap in &mut taps {
+                *tap /= sum;
+            }
+            taps
+        })
+        .collect()
+}
@@ -1,7 +1,4 @@
-use std::fs::File;
-use std::io::BufReader;
-use std::path::{Path, PathBuf};
-
+use crate::record::source::RecordFiles;
 use crate::{Error, Header, Result, SegmentInfo, SignalInfo};
 
 /// State of a segment in a multi-segment record.
@@ -20,16 +17,14 @@ enum SegmentState {
 pub struct SegmentData {
     /// Segment header.
     pub header: Header,
-    /// Base path for signal files.
-    pub base_path: PathBuf,
 }
 
 /// Multi-segment record manager.
 ///
 /// Handles lazy loading of segment headers and coordinates segment switching.
 pub struct SegmentManager {
-    /// Base path for the multi-segment record.
-    base_path: PathBuf,
+    /// Files of the multi-segment record.
+    files: RecordFiles,
     /// Segment specifications from the master header.
     segments: Vec<SegmentInfo>,
     /// Current state of each segment.
@@ -43,7 +38,7 @@ pub struct SegmentManager {
 
 impl SegmentManager {
     /// Create a new segment manager.
-    pub fn new(base_path: PathBuf, segments: Vec<SegmentInfo>) -> Self {
+    pub fn new(files: RecordFiles, segments: Vec<SegmentInfo>) -> Self {
         let num_segments = segments.len();
         let states = vec![SegmentState::NotLoaded; num_segments];
 
@@ -57,7 +52,7 @@ impl SegmentManager {
         }
 
         Self {
-            base_path,
+            files,
             segments,
             states,
             current_segment: 0,
@@ -68,7 +63,7 @@ impl SegmentManager {
     /// Load a segment header.
     pub fn load_segment(&mut self, index: usize) -> Result<&SegmentData> {
         if index >= self.segments.len() {
-            return Err(Error::InvalidHeader(format!(
+            return Err(Error::InvalidData(format!(
                 "Segment index {} out of bounds (record has {} segments)",
                 index,
                 self.segments.len()
@@ -84,7 +79,7 @@ impl SegmentManager {
         }
 
         if matches!(&self.states[index], SegmentState::Null) {
-            return Err(Error::InvalidHeader("Cannot load null segment".to_string()));
+            return Err(Error::InvalidHeader("Cannot load null segment".into()));
         }
 
         let segment_info = &self.segments[index];
@@ -93,42 +88,21 @@ impl SegmentManager {
         if segment_info.record_name == "~" {
             self.states[index] = SegmentState::Null;
             return Err(Error::InvalidHeader(
-                "Segment is null (missing data)".to_string(),
+                "Segment is null (missing data)".into(),
             ));
         }
 
         // Load segment header
-        let segment_header_path = self
-            .base_path
-            .join(format!("{}.hea", segment_info.record_name));
-
-        let file = File::open(&segment_header_path).map_err(|e| {
-            Error::InvalidPath(format!(
-                "Failed to open segment header '{}': {}",
-                segment_header_path.display(),
-                e
-            ))
-        })?;
-
-        let mut reader = BufReader::new(file);
-        let header = Header::from_reader(&mut reader)?;
+        let header = self.files.load_segment_header(&segment_info.record_name)?;
 
         // Validate segment
         if header.specifications.is_multi_segment() {
             return Err(Error::InvalidHeader(
-                "Nested multi-segment records are not supported".to_string(),
+                "Nested multi-segment records are not supported".into(),
             ));
         }
 
-        let segment_base_path = segment_header_path
-            .parent()
-            .unwrap_or_else(|| Path::new("."))
-            .to_path_buf();
-
-        let data = SegmentData {
-            header,
-            base_path: segment_base_path,
-        };
+        let data = SegmentData { header };
 
         self.states[index] = SegmentState::Loaded(Box::new(data));
 
@@ -149,13 +123,61 @@ impl SegmentManager {
         data.header
             .specifications
             .signals()
-            .ok_or_else(|| Error::InvalidHeader("Segment has no signals".to_string()))
+            .ok_or_else(|| Error::InvalidHeader("Segment has no signals".into()))
     }
 
-    /// Get base path for current segment.
-    pub fn current_base_path(&mut self) -> Result<&Path> {
-        let data = self.current_segment()?;
-        Ok(&data.base_path)
+    /// Check if the record has a variable layout.
+    ///
+    /// Variable-layout records (e.g. MIMIC) start with a layout segment with
+    /// no samples, which lists every signal of the record; later segments
+    /// hold subsets of these signals, in any order.
+    #[must_use]
+    pub fn is_variable_layout(&self) -> bool {
+        self.segments
+            .first()
+            .is_some_and(|segment| segment.num_samples == 0 && !segment.is_null_segment())
+    }
+
+    /// Get the signal descriptions of the layout segment, in layout order.
+    ///
+    /// Returns `None` for fixed-layout records.
+    pub fn layout_descriptions(&mut self) -> Result<Option<Vec<String>>> {
+        if !self.is_variable_layout() {
+            return Ok(None);
+        }
+        let layout = self.load_segment(0)?;
+        let descriptions = layout
+            .header
+            .signals()
+            .unwrap_or_default()
+            .iter()
+            .map(|signal| signal.description().unwrap_or_default().to_string())
+            .collect();
+        Ok(Some(descriptions))
+    }
+
+    /// Map the signals of a segment onto the layout.
+    ///
+    /// For each signal description of the layout, gives the index of the
+    /// first signal of the segment with that description, or `None` if the
+    /// segment does not have it.
+    pub fn layout_map(&mut self, index: usize, layout: &[String]) -> Result<Vec<Option<usize>>> {
+        let data = self.load_segment(index)?;
+        let signals = data.header.signals().unwrap_or_default();
+        Ok(layout
+            .iter()
+            .map(|name| {
+                signals
+                    .iter()
+                    .position(|signal| signal.description().unwrap_or_default() == name)
+            })
+            .collect())
+    }
+
+    /// Get the files of the record.
+    #[must_use]
+    pub const fn files(&self) -> &RecordFiles {
+        &self.files
     }
 
     /// Check if current segment is null.
@@ -164,6 +186,11 @@ impl SegmentManager {
         matches!(self.states[self.current_segment], SegmentState::Null)
     }
 
+    /// Make a segment the current one.
+    pub const fn set_current(&mut self, index: usize) {
+        self.current_segment = index;
+    }
+
     /// Get current segment index.
     #[must_use]
     pub const fn current_index(&self) -> usize {
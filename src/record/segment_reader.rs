@@ -1,12 +1,28 @@
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
+
 use crate::record::segment::SegmentManager;
+use crate::record::source::RecordFiles;
+use crate::record::timing::seconds_to_sample;
+use crate::record::{FrameIndex, ReaderOptions, RecordClock};
+use crate::signal::INVALID_SAMPLE;
 use crate::{Error, MultiSignalReader, Result, Sample, SegmentInfo};
-use std::path::PathBuf;
 
 /// Reader for multi-segment records with seeking support.
 ///
 /// Handles automatic segment switching and provides unified access to signals
 /// across all segments in a multi-segment record.
 ///
+/// Null segments (`~`) mark gaps in the recording: their frames are read as
+/// [`INVALID_SAMPLE`] for every signal, so that frame numbers and times stay
+/// those of the master header. Segments whose signal files are shorter than
+/// their length in the master header are padded the same way.
+///
+/// In variable-layout records (whose first segment is a layout segment with
+/// no samples), frames are in the signal order of the layout segment: the
+/// signals of each segment are matched by description, and signals absent
+/// from a segment read as [`INVALID_SAMPLE`].
+///
 /// # Examples
 ///
 /// ```no_run
@@ -26,27 +42,61 @@ use std::path::PathBuf;
 pub struct SegmentReader {
     /// Segment manager for handling segment metadata and switching.
     segment_manager: SegmentManager,
-    /// Current multi-signal reader (for current segment).
-    current_reader: Option<MultiSignalReader>,
+    /// Current multi-signal reader (`None` in null segments).
+    current_reader: Option<Box<MultiSignalReader>>,
+    /// Whether a segment has been opened.
+    started: bool,
+    /// Frames left in the current segment.
+    remaining: u64,
+    /// Number of signals of the record.
+    num_signals: usize,
+    /// Signal descriptions of the layout segment (variable layout only).
+    layout: Option<Vec<String>>,
+    /// Index in the current segment of each layout signal (variable layout
+    /// only).
+    layout_map: Vec<Option<usize>>,
     /// Total samples read across all segments.
     samples_read: u64,
+    /// Sampling frequency of the record.
+    sampling_frequency: f64,
+    /// Options applied to the reader of each segment.
+    options: ReaderOptions,
 }
 
 impl SegmentReader {
     /// Create a new segment reader.
-    pub(crate) fn new(base_path: PathBuf, segments: Vec<SegmentInfo>) -> Self {
-        let segment_manager = SegmentManager::new(base_path, segments);
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout segment header cannot be loaded.
+    pub(crate) fn new(
+        files: RecordFiles,
+        segments: Vec<SegmentInfo>,
+        num_signals: usize,
+        sampling_frequency: f64,
+        options: ReaderOptions,
+    ) -> Result<Self> {
+        let mut segment_manager = SegmentManager::new(files, segments);
+        let layout = segment_manager.layout_descriptions()?;
 
-        Self {
+        Ok(Self {
             segment_manager,
             current_reader: None,
+            started: false,
+            remaining: 0,
+            num_signals: layout.as_ref().map_or(num_signals, Vec::len),
+            layout,
+            layout_map: Vec::new(),
             samples_read: 0,
-        }
+            sampling_frequency,
+            options,
+        })
     }
 
     /// Read one frame (one sample from each signal).
     ///
-    /// Returns `None` when all segments have been read.
+    /// Frames of null segments are filled with [`INVALID_SAMPLE`]. Returns
+    /// `None` when all segments have been read.
     ///
     /// # Errors
     ///
@@ -55,14 +105,29 @@ impl SegmentReader {
     /// - A frame cannot be read
     pub fn read_frame(&mut self) -> Result<Option<Vec<Sample>>> {
         loop {
-            // Try to read from current reader
-            if let Some(reader) = &mut self.current_reader {
-                let frame = reader.read_frame()?;
-                if !frame.is_empty() {
+            if self.remaining > 0 {
+                if let Some(reader) = &mut self.current_reader {
+                    let frame = reader.read_frame()?;
+                    if frame.is_empty() {
+                        // Signal files shorter than the segment: pad the rest
+                        self.current_reader = None;
+                        continue;
+                    }
+                    self.remaining -= 1;
                     self.samples_read += 1;
+                    if self.layout.is_none() {
+                        return Ok(Some(frame));
+                    }
+                    let frame = self
+                        .layout_map
+                        .iter()
+                        .map(|position| position.map_or(INVALID_SAMPLE, |k| frame[k]))
+                        .collect();
                     return Ok(Some(frame));
                 }
-                // Current segment exhausted, move to next
+                self.remaining -= 1;
+                self.samples_read += 1;
+                return Ok(Some(vec![INVALID_SAMPLE; self.num_signals]));
             }
 
             // Move to next segment
@@ -90,42 +155,116 @@ impl SegmentReader {
         Ok(frames)
     }
 
-    /// Seek to a specific sample number across all segments.
+    /// Read one frame converted to physical units, with the gains and
+    /// baselines of its segment.
+    ///
+    /// Invalid samples, including the frames of null segments, are `NaN`.
+    /// Returns `None` when all segments have been read.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_frame`](Self::read_frame).
+    pub fn read_frame_physical(&mut self) -> Result<Option<Vec<f64>>> {
+        let Some(frame) = self.read_frame()? else {
+            return Ok(None);
+        };
+        // The frame came from the current reader, unless it is a gap
+        let Some(reader) = &self.current_reader else {
+            return Ok(Some(vec![f64::NAN; frame.len()]));
+        };
+        let scales = reader.scales();
+        let values = frame
+            .iter()
+            .enumerate()
+            .map(|(index, &sample)| {
+                let position = if self.layout.is_some() {
+                    self.layout_map[index]
+                } else {
+                    Some(index)
+                };
+                position.map_or(f64::NAN, |k| scales[k].to_physical(sample))
+            })
+            .collect();
+        Ok(Some(values))
+    }
+
+    /// Seek to a specific frame number across all segments.
     ///
     /// Automatically switches to the appropriate segment and positions
-    /// the reader at the target sample.
+    /// the reader at the target frame.
     ///
-    /// Returns the actual sample position after seeking.
+    /// Returns the actual frame position after seeking. See [`FrameIndex`].
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The segment containing the target sample cannot be loaded
+    /// - The frame is beyond the end of the record
+    /// - The segment containing the target frame cannot be loaded
     /// - Seeking within the segment fails
-    pub fn seek_to_sample(&mut self, sample: u64) -> Result<u64> {
-        // Find which segment contains this sample
-        let segment_index = self.find_segment_for_sample(sample)?;
+    pub fn seek_to_frame(&mut self, frame: u64) -> Result<u64> {
+        // Find which segment contains this frame
+        let segment_index = self.find_segment_for_sample(frame)?;
 
         // Calculate offset within segment
-        let segment_start = if segment_index == 0 {
-            0
-        } else {
-            self.segment_manager
-                .segment_info(segment_index - 1)
-                .map_or(0, |s| s.num_samples)
-        };
-        let offset_in_segment = sample - segment_start;
+        let segment_start: u64 = (0..segment_index)
+            .filter_map(|i| self.segment_manager.segment_info(i))
+            .map(|s| s.num_samples)
+            .sum();
 
-        // Switch to target segment
-        self.switch_to_segment(segment_index)?;
+        // Switch to target segment, positioned at the frame
+        self.switch_to_segment(segment_index, frame - segment_start)?;
 
-        // Seek within segment
-        if let Some(reader) = &mut self.current_reader {
-            reader.seek_to_frame(offset_in_segment)?;
-        }
+        self.samples_read = frame;
+        Ok(frame)
+    }
+
+    /// Seek to a frame given as a [`FrameIndex`].
+    ///
+    /// Same as [`seek_to_frame`](Self::seek_to_frame), with a frame number
+    /// that cannot be mistaken for a sample number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_frame`](Self::seek_to_frame).
+    pub fn seek_to_frame_index(&mut self, frame: FrameIndex) -> Result<u64> {
+        self.seek_to_frame(frame.get())
+    }
+
+    /// Seek to a specific sample number across all segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_frame`](Self::seek_to_frame).
+    #[deprecated(since = "0.1.7", note = "renamed to `seek_to_frame`")]
+    pub fn seek_to_sample(&mut self, sample: u64) -> Result<u64> {
+        self.seek_to_frame(sample)
+    }
 
-        self.samples_read = sample;
-        Ok(sample)
+    /// Seek to a time from the start of the record, across all segments.
+    ///
+    /// Converts the time to the nearest frame at the sampling frequency of
+    /// the record. Returns the frame position after seeking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the time is negative, or the same errors as
+    /// [`seek_to_frame`](Self::seek_to_frame).
+    pub fn seek_to_time(&mut self, seconds: f64) -> Result<u64> {
+        self.seek_to_frame(seconds_to_sample(seconds, self.sampling_frequency)?)
+    }
+
+    /// Seek to a time from the start of the record, across all segments.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// See [`seek_to_time`](Self::seek_to_time).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_time`](Self::seek_to_time).
+    #[cfg(feature = "chrono")]
+    pub fn seek_to_duration(&mut self, duration: TimeDelta) -> Result<u64> {
+        self.seek_to_time(duration.as_seconds_f64())
     }
 
     /// Get current sample position across all segments.
@@ -134,12 +273,26 @@ impl SegmentReader {
         self.samples_read
     }
 
+    /// Get the current position in seconds from the start of the record.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn position_seconds(&self) -> f64 {
+        self.samples_read as f64 / self.sampling_frequency
+    }
+
+    /// Get the sampling frequency of the record.
+    #[must_use]
+    pub const fn sampling_frequency(&self) -> f64 {
+        self.sampling_frequency
+    }
+
     /// Get total number of samples across all segments.
     #[must_use]
     pub fn total_samples(&self) -> u64 {
-        self.segment_manager
-            .segment_info(self.segment_manager.num_segments() - 1)
-            .map_or(0, |s| s.num_samples)
+        (0..self.segment_manager.num_segments())
+            .filter_map(|index| self.segment_manager.segment_info(index))
+            .map(|segment| segment.num_samples)
+            .sum()
     }
 
     /// Get current segment index.
@@ -160,28 +313,60 @@ impl SegmentReader {
     ///
     /// Returns `true` if successfully advanced, `false` if no more segments.
     fn advance_segment(&mut self) -> Result<bool> {
-        let next_index = self.segment_manager.current_index() + 1;
+        // The first segment is opened by the first read
+        let next_index = if self.started {
+            self.segment_manager.current_index() + 1
+        } else {
+            0
+        };
         if next_index >= self.segment_manager.num_segments() {
             return Ok(false);
         }
 
-        self.switch_to_segment(next_index)?;
+        self.switch_to_segment(next_index, 0)?;
         Ok(true)
     }
 
-    /// Switch to a specific segment.
-    fn switch_to_segment(&mut self, index: usize) -> Result<()> {
+    /// Switch to a specific segment, positioned `offset` frames into it.
+    fn switch_to_segment(&mut self, index: usize, offset: u64) -> Result<()> {
+        let segment = self.segment_manager.segment_info(index);
+        let length = segment.map_or(0, |segment| segment.num_samples);
+        let is_null = segment.is_some_and(SegmentInfo::is_null_segment);
+
+        self.current_reader = None;
+        self.segment_manager.set_current(index);
+        self.started = true;
+        self.remaining = length.saturating_sub(offset);
+        if is_null || self.remaining == 0 {
+            // Gap, or a segment without frames (e.g. a layout segment)
+            return Ok(());
+        }
+
         // Load segment data
         self.segment_manager.load_segment(index)?;
+        if let Some(layout) = &self.layout {
+            self.layout_map = self.segment_manager.layout_map(index, layout)?;
+        }
 
-        // Get signals and base path for this segment
+        // Get signals and clock for this segment
         let signals = self.segment_manager.current_signals()?.to_vec();
-        let base_path = self.segment_manager.current_base_path()?.to_path_buf();
+        let metadata = &self.segment_manager.current_segment()?.header.metadata;
+        let num_samples = metadata.num_samples();
+        let clock = RecordClock::from_metadata(metadata);
 
         // Create new multi-signal reader for this segment
-        let reader = MultiSignalReader::new(&base_path, &signals)?;
+        let mut reader = MultiSignalReader::new(
+            self.segment_manager.files(),
+            &signals,
+            num_samples,
+            clock,
+            &self.options,
+        )?;
+        if offset > 0 {
+            reader.seek_to_frame(offset)?;
+        }
 
-        self.current_reader = Some(reader);
+        self.current_reader = Some(Box::new(reader));
         Ok(())
     }
 
@@ -192,7 +377,7 @@ impl SegmentReader {
             let segment_info = self
                 .segment_manager
                 .segment_info(i)
-                .ok_or_else(|| Error::InvalidHeader("Segment not found".to_string()))?;
+                .ok_or_else(|| Error::InvalidData("Segment not found".into()))?;
 
             if sample < cumulative + segment_info.num_samples {
                 return Ok(i);
@@ -200,7 +385,7 @@ impl SegmentReader {
             cumulative += segment_info.num_samples;
         }
 
-        Err(Error::InvalidHeader(format!(
+        Err(Error::InvalidData(format!(
             "Sample {sample} is beyond the end of the record"
         )))
     }
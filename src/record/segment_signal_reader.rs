@@ -0,0 +1,390 @@
+use crate::record::file_group::FileGroups;
+use crate::record::segment::SegmentManager;
+use crate::record::source::RecordFiles;
+use crate::record::{ReaderOptions, SampleIndex};
+use crate::signal::INVALID_SAMPLE;
+use crate::{Error, Result, Sample, SegmentInfo, SignalInfo, SignalReader};
+
+/// Reader for a single signal of a multi-segment record.
+///
+/// Reads one signal continuously across all segments, switching segments
+/// automatically. Samples of null segments, and of segments that do not
+/// contain the signal (in variable-layout records), read as
+/// [`INVALID_SAMPLE`] (`NaN` in physical units), so that positions stay
+/// aligned with the record. A segment whose signal file ends early is padded
+/// the same way.
+///
+/// In variable-layout records (whose first segment is a layout segment with
+/// no samples), the signal is identified by its description in the layout
+/// segment and looked up by description in each segment. Otherwise, the
+/// signal has the same index in every segment.
+///
+/// Physical values are converted with the gain and baseline of each segment.
+///
+/// Signals with several samples per frame are read in full, at
+/// [`sampling_frequency`](Self::sampling_frequency) samples per second;
+/// sample numbers and [`total_samples`](Self::total_samples) count samples,
+/// not frames.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/multi_segment_record")?;
+/// let mut reader = record.segment_signal_reader(0)?;
+///
+/// // Read the first minute of the signal, across segments
+/// let samples = reader.read_physical(7500)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct SegmentSignalReader {
+    /// Segment manager for handling segment metadata and switching.
+    segment_manager: SegmentManager,
+    /// Index of the signal in the record (or in the layout segment).
+    signal_index: usize,
+    /// Description of the signal in the layout segment (variable layout only).
+    signal_name: Option<String>,
+    /// Reader for the current segment (`None` if it has no data for the signal).
+    current_reader: Option<SignalReader>,
+    /// Whether a segment has been opened.
+    started: bool,
+    /// Samples per frame of the signal, scaling segment lengths (in frames)
+    /// to samples.
+    samples_per_frame: u64,
+    /// Samples left in the current segment.
+    remaining: u64,
+    /// Total samples read across all segments.
+    samples_read: u64,
+    /// Sampling frequency of the record (frames per second).
+    frame_frequency: f64,
+    /// Sampling frequency of the signal.
+    sampling_frequency: f64,
+    /// Options applied to the reader of each segment.
+    options: ReaderOptions,
+}
+
+impl SegmentSignalReader {
+    /// Create a new segment signal reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout segment header cannot be loaded, or the
+    /// signal is not in it.
+    pub(crate) fn new(
+        files: RecordFiles,
+        segments: Vec<SegmentInfo>,
+        signal_index: usize,
+        sampling_frequency: f64,
+        options: ReaderOptions,
+    ) -> Result<Self> {
+        let mut segment_manager = SegmentManager::new(files, segments);
+
+        let (signal_name, samples_per_frame) = if segment_manager.is_variable_layout() {
+            let layout = segment_manager.load_segment(0)?;
+            let signal = layout
+                .header
+                .signals()
+                .and_then(|signals| signals.get(signal_index))
+                .ok_or_else(|| {
+                    Error::InvalidData(format!(
+                        "Signal index {signal_index} out of bounds in layout segment"
+                    ))
+                })?;
+            (
+                Some(signal.description().unwrap_or_default().to_string()),
+                signal.samples_per_frame(),
+            )
+        } else {
+            (
+                None,
+                Self::first_samples_per_frame(&mut segment_manager, signal_index)?,
+            )
+        };
+
+        Ok(Self {
+            segment_manager,
+            signal_index,
+            signal_name,
+            current_reader: None,
+            started: false,
+            samples_per_frame: u64::from(samples_per_frame.max(1)),
+            remaining: 0,
+            samples_read: 0,
+            frame_frequency: sampling_frequency,
+            sampling_frequency: sampling_frequency * f64::from(samples_per_frame.max(1)),
+            options,
+        })
+    }
+
+    /// Read samples into a provided buffer (raw ADC values).
+    ///
+    /// Returns the number of samples read, which is less than the buffer
+    /// length only at the end of the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a segment cannot be loaded or read.
+    pub fn read_samples_buf(&mut self, buffer: &mut [Sample]) -> Result<usize> {
+        let mut count = 0;
+
+        while count < buffer.len() {
+            if self.remaining == 0 {
+                if !self.advance_segment()? {
+                    break;
+                }
+                continue;
+            }
+
+            let wanted = usize::try_from(self.remaining)
+                .unwrap_or(usize::MAX)
+                .min(buffer.len() - count);
+            let chunk = &mut buffer[count..count + wanted];
+            let n = if let Some(reader) = &mut self.current_reader {
+                reader.read_samples_buf(chunk)?
+            } else {
+                chunk.fill(INVALID_SAMPLE);
+                wanted
+            };
+            if n == 0 {
+                // The signal file ends early: pad the rest of the segment
+                self.current_reader = None;
+                continue;
+            }
+
+            count += n;
+            self.remaining -= n as u64;
+            self.samples_read += n as u64;
+        }
+
+        Ok(count)
+    }
+
+    /// Read a specified number of samples (raw ADC values).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a segment cannot be loaded or read.
+    pub fn read_samples(&mut self, count: usize) -> Result<Vec<Sample>> {
+        let mut buffer = vec![0; count];
+        let n = self.read_samples_buf(&mut buffer)?;
+        buffer.truncate(n);
+        Ok(buffer)
+    }
+
+    /// Read a specified number of samples (physical values).
+    ///
+    /// Missing samples read as `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a segment cannot be loaded or read.
+    pub fn read_physical(&mut self, count: usize) -> Result<Vec<f64>> {
+        let mut values = Vec::with_capacity(count);
+        let mut buffer = Vec::new();
+
+        while values.len() < count {
+            if self.remaining == 0 {
+                if !self.advance_segment()? {
+                    break;
+                }
+                continue;
+            }
+
+            // Read at most to the end of the segment, which sets the scale
+            let scale = self
+                .current_reader
+                .as_ref()
+                .map(|reader| reader.scale().clone());
+            buffer.resize(
+                usize::try_from(self.remaining)
+                    .unwrap_or(usize::MAX)
+                    .min(count - values.len()),
+                0,
+            );
+            let n = self.read_samples_buf(&mut buffer)?;
+            if n == 0 {
+                break;
+            }
+            match scale {
+                Some(scale) => values.extend(buffer[..n].iter().map(|&v| scale.to_physical(v))),
+                None => values.extend(std::iter::repeat_n(f64::NAN, n)),
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Seek to a specific sample number across all segments.
+    ///
+    /// Returns the actual sample position after seeking. See
+    /// [`SampleIndex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sample is beyond the end of the record, or the
+    /// segment containing it cannot be loaded or seeked.
+    pub fn seek_to_sample(&mut self, sample: u64) -> Result<u64> {
+        let mut segment_start = 0;
+        for index in 0..self.segment_manager.num_segments() {
+            let num_samples = self.segment_samples(index);
+            if sample < segment_start + num_samples {
+                self.open_segment(index, sample - segment_start)?;
+                self.samples_read = sample;
+                return Ok(sample);
+            }
+            segment_start += num_samples;
+        }
+
+        Err(Error::InvalidData(format!(
+            "Sample {sample} is beyond the end of the record"
+        )))
+    }
+
+    /// Seek to a sample given as a [`SampleIndex`].
+    ///
+    /// Same as [`seek_to_sample`](Self::seek_to_sample), with a sample number
+    /// that cannot be mistaken for a frame number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_sample`](Self::seek_to_sample).
+    pub fn seek_to_sample_index(&mut self, sample: SampleIndex) -> Result<u64> {
+        self.seek_to_sample(sample.get())
+    }
+
+    /// Get current sample position across all segments.
+    #[must_use]
+    pub const fn position(&self) -> u64 {
+        self.samples_read
+    }
+
+    /// Get total number of samples across all segments.
+    #[must_use]
+    pub fn total_samples(&self) -> u64 {
+        (0..self.segment_manager.num_segments())
+            .map(|index| self.segment_samples(index))
+            .sum()
+    }
+
+    /// Get current segment index.
+    #[must_use]
+    pub const fn current_segment(&self) -> usize {
+        self.segment_manager.current_index()
+    }
+
+    /// Get the sampling frequency of the signal: the frame rate of the
+    /// record times the samples per frame of the signal.
+    #[must_use]
+    pub const fn sampling_frequency(&self) -> f64 {
+        self.sampling_frequency
+    }
+
+    // [Private helper methods]
+
+    /// Advance to the next segment.
+    ///
+    /// Returns `true` if successfully advanced, `false` if no more segments.
+    fn advance_segment(&mut self) -> Result<bool> {
+        let next_index = if self.started {
+            self.segment_manager.current_index() + 1
+        } else {
+            0
+        };
+        if next_index >= self.segment_manager.num_segments() {
+            return Ok(false);
+        }
+
+        self.open_segment(next_index, 0)?;
+        Ok(true)
+    }
+
+    /// Get the samples per frame of the signal in the first segment holding
+    /// samples (1 if there is none).
+    fn first_samples_per_frame(
+        segment_manager: &mut SegmentManager,
+        signal_index: usize,
+    ) -> Result<u32> {
+        let first = (0..segment_manager.num_segments()).find(|&index| {
+            segment_manager
+                .segment_info(index)
+                .is_some_and(|segment| !segment.is_null_segment() && segment.num_samples > 0)
+        });
+        let Some(index) = first else {
+            return Ok(1);
+        };
+        Ok(segment_manager
+            .load_segment(index)?
+            .header
+            .signals()
+            .and_then(|signals| signals.get(signal_index))
+            .map_or(1, SignalInfo::samples_per_frame))
+    }
+
+    /// Get the number of samples of the signal in a segment.
+    fn segment_samples(&self, index: usize) -> u64 {
+        self.segment_manager
+            .segment_info(index)
+            .map_or(0, |segment| segment.num_samples * self.samples_per_frame)
+    }
+
+    /// Make a segment current, positioned `offset` samples into it.
+    fn open_segment(&mut self, index: usize, offset: u64) -> Result<()> {
+        let num_samples = self.segment_samples(index);
+
+        self.current_reader = None;
+        self.segment_manager.set_current(index);
+        self.started = true;
+        self.remaining = num_samples.saturating_sub(offset);
+
+        let mut reader = self.segment_signal_reader(index)?;
+        if let Some(reader) = &mut reader
+            && offset > 0
+        {
+            reader.seek_to_sample(offset)?;
+        }
+        self.current_reader = reader;
+        Ok(())
+    }
+
+    /// Open the signal in a segment.
+    ///
+    /// Returns `None` for null segments and segments without the signal.
+    fn segment_signal_reader(&mut self, index: usize) -> Result<Option<SignalReader>> {
+        if self
+            .segment_manager
+            .segment_info(index)
+            .is_none_or(|segment| segment.is_null_segment() || segment.num_samples == 0)
+        {
+            return Ok(None);
+        }
+
+        let data = self.segment_manager.load_segment(index)?;
+        let num_samples = data.header.metadata.num_samples();
+        let Some(signals) = data.header.signals().map(<[_]>::to_vec) else {
+            return Ok(None);
+        };
+        let position = match &self.signal_name {
+            Some(name) => signals
+                .iter()
+                .position(|signal| signal.description().unwrap_or_default() == name),
+            None => (self.signal_index < signals.len()).then_some(self.signal_index),
+        };
+        let Some(position) = position else {
+            return Ok(None);
+        };
+
+        SignalReader::new(
+            self.segment_manager.files(),
+            &signals,
+            position,
+            num_samples,
+            Some(self.frame_frequency),
+            &self.options,
+            &mut FileGroups::new(),
+        )
+        .map(Some)
+    }
+}
@@ -1,10 +1,24 @@
-use std::fs::File;
 use std::io::BufReader;
-use std::path::Path;
 
-use crate::signal::FormatDecoder;
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
+
+use crate::record::components::signal_byte_offsets;
+use crate::record::file_group::{FileGroup, FileGroups, SharedFileGroup};
+use crate::record::index::seek_position;
+use crate::record::source::{ReadSeek, RecordFiles};
+use crate::record::stats::StatsAccumulator;
+use crate::record::timing::seconds_to_sample;
+use crate::record::validation::add_to_checksum;
+use crate::record::{FrameIndex, ReaderOptions, SampleIndex, SignalStats};
+use crate::signal::filter::{Filter, FilterChain};
+use crate::signal::{FormatDecoder, INVALID_SAMPLE, RoundingMode, SignalScale};
 use crate::{Error, Result, Sample, SignalInfo};
 
+/// Requests closer than this many samples are served by a single read in
+/// [`SignalReader::read_at`].
+pub const READ_AT_COALESCE_GAP: u64 = 4096;
+
 /// Reader for a single signal with three-level API.
 ///
 /// Provides three ways to read signal data:
@@ -17,7 +31,16 @@ use crate::{Error, Result, Sample, SignalInfo};
 /// When multiple signals share the same file, they are stored interleaved
 /// (one frame = one sample from each signal). This reader automatically
 /// handles de-interleaving by reading entire frames and extracting only
-/// the requested signal's samples.
+/// the requested signal's samples. Readers created together by
+/// [`Record::signal_readers`](crate::Record::signal_readers) share the
+/// file and its decoded frames.
+///
+/// # Skew
+///
+/// A signal with a skew in the header is shifted back by that many samples,
+/// as `getvec()` does in the WFDB library. When the header gives the number
+/// of samples, the last samples past the stored data read as
+/// [`INVALID_SAMPLE`].
 ///
 /// # Examples
 ///
@@ -83,26 +106,45 @@ use crate::{Error, Result, Sample, SignalInfo};
 /// # }
 /// ```
 pub struct SignalReader {
-    /// Format decoder for this signal.
-    decoder: Box<dyn FormatDecoder>,
-    /// Buffered reader for the signal file.
-    reader: BufReader<File>,
-    /// Signal information (for physical units conversion).
+    /// Source of the samples of the signal.
+    source: Source,
+    /// Signal information.
     signal_info: SignalInfo,
-    /// Index of this signal within its file group (for interleaved reading).
+    /// Conversion between ADC values and physical units.
+    scale: SignalScale,
+    /// Position of the first sample of this signal within a frame of its
+    /// file group (for interleaved reading).
     signal_index_in_file: usize,
-    /// Total number of signals sharing this file (for interleaved reading).
-    signals_in_file: usize,
-    /// Buffer for reading interleaved frames.
-    frame_buffer: Vec<Sample>,
+    /// Samples per frame of this signal.
+    samples_per_frame: u64,
     /// Current sample position (for interleaved seeking).
     current_sample: u64,
-    /// Bytes per sample for this format (for seeking).
-    bytes_per_sample: usize,
     /// Initial file offset (to calculate absolute positions).
     initial_offset: u64,
+    /// Number of samples of the signal, when given in the header.
+    num_samples: Option<u64>,
+    /// Skew of the signal, in samples.
+    skew: u64,
     /// Sampling frequency (for time-based seeking).
     sampling_frequency: Option<f64>,
+    /// Filters applied to physical values.
+    filters: FilterChain,
+}
+
+/// Source of the samples of a [`SignalReader`].
+enum Source {
+    /// File holding only this signal, decoded sequentially.
+    Sequential {
+        /// Format decoder for this signal.
+        decoder: Box<dyn FormatDecoder>,
+        /// Buffered reader for the signal file.
+        reader: BufReader<Box<dyn ReadSeek>>,
+        /// Bytes per sample for this format (for seeking).
+        bytes_per_sample: usize,
+    },
+    /// File of interleaved signals, shared with the readers of the other
+    /// signals created together.
+    Interleaved(SharedFileGroup),
 }
 
 impl SignalReader {
@@ -114,70 +156,77 @@ impl SignalReader {
     /// - Signal file cannot be opened
     /// - Signal format is not supported
     pub(crate) fn new(
-        base_path: &Path,
-        signal_info: &SignalInfo,
+        files: &RecordFiles,
         all_signals: &[SignalInfo],
         signal_index: usize,
+        num_samples: Option<u64>,
         sampling_frequency: Option<f64>,
+        options: &ReaderOptions,
+        groups: &mut FileGroups,
     ) -> Result<Self> {
-        // Resolve signal file path
-        let signal_path = base_path.join(&signal_info.file_name);
-
-        // Open signal file
-        let file = File::open(&signal_path).map_err(|e| {
-            Error::InvalidPath(format!(
-                "Failed to open signal file '{}': {}",
-                signal_path.display(),
-                e
-            ))
-        })?;
-
-        let mut reader = BufReader::new(file);
-
-        // Create decoder for this signal's format
-        let initial_value = signal_info.initial_value.unwrap_or(0);
-        let decoder = crate::signal::get_decoder(signal_info.format, initial_value)?;
-
-        // Get bytes per sample for seeking
-        let bytes_per_sample = decoder.bytes_per_sample().unwrap_or(0);
+        let signal_info = &all_signals[signal_index];
 
-        // Handle byte offset if specified
-        let initial_offset = signal_info.byte_offset.unwrap_or(0);
-        if initial_offset > 0 {
-            use std::io::Seek;
-            reader.seek(std::io::SeekFrom::Start(initial_offset))?;
-        }
+        // Byte offset, given on this signal or the first of its group
+        let byte_offsets = signal_byte_offsets(all_signals);
+        let initial_offset = byte_offsets[signal_index];
 
-        // Determine interleaving: count how many signals share this file
+        // Determine interleaving: count how many signals (and samples per
+        // frame) share this file section
         let mut signal_index_in_file = 0;
-        let mut signals_in_file = 0;
+        let mut initial_values = Vec::new();
+        let mut frame_layout = Vec::new();
         for (idx, sig) in all_signals.iter().enumerate() {
-            if sig.file_name == signal_info.file_name && sig.format == signal_info.format {
+            if sig.file_name == signal_info.file_name && byte_offsets[idx] == initial_offset {
                 if idx == signal_index {
-                    signal_index_in_file = signals_in_file;
+                    signal_index_in_file = frame_layout.iter().sum();
                 }
-                signals_in_file += 1;
+                initial_values.push(sig.initial_value.unwrap_or(0));
+                frame_layout.push(sig.samples_per_frame().max(1) as usize);
             }
         }
 
-        // Create frame buffer if signals are interleaved
-        let frame_buffer = if signals_in_file > 1 {
-            vec![0; signals_in_file]
+        let samples_per_frame = u64::from(signal_info.samples_per_frame().max(1));
+        let skew = u64::from(signal_info.skew()) * samples_per_frame;
+
+        let source = if frame_layout.len() > 1 {
+            // Interleaved: share the file with the other readers created
+            // together
+            let key = (signal_info.file_name.clone(), initial_offset);
+            let group = if let Some(group) = groups.get(&key) {
+                group.clone()
+            } else {
+                let group = SharedFileGroup::new(FileGroup::open(
+                    files,
+                    &signal_info.file_name,
+                    signal_info.format,
+                    initial_offset,
+                    &initial_values,
+                    &frame_layout,
+                    options,
+                )?);
+                groups.insert(key, group.clone());
+                group
+            };
+            Source::Interleaved(group)
         } else {
-            Vec::new()
+            Self::open_sequential(files, signal_info, initial_offset, skew, options)?
         };
 
         Ok(Self {
-            decoder,
-            reader,
+            source,
             signal_info: signal_info.clone(),
+            scale: signal_info.scale().normalized(&options.target_units),
             signal_index_in_file,
-            signals_in_file,
-            frame_buffer,
+            samples_per_frame,
             current_sample: 0,
-            bytes_per_sample,
             initial_offset,
-            sampling_frequency,
+            num_samples: num_samples
+                .filter(|&n| n > 0 && !options.ignore_length)
+                .map(|n| n.saturating_mul(samples_per_frame)),
+            skew,
+            sampling_frequency: sampling_frequency
+                .map(|freq| freq * f64::from(signal_info.samples_per_frame().max(1))),
+            filters: FilterChain::new(),
         })
     }
 
@@ -186,7 +235,8 @@ impl SignalReader {
     /// Read samples into a provided buffer (raw ADC values).
     ///
     /// This is the zero-copy buffer API. The caller provides the buffer,
-    /// and this method fills it with as many samples as possible.
+    /// and this method fills it with as many samples as possible, up to the
+    /// number of samples given in the header (if any).
     ///
     /// For interleaved signals (multiple signals sharing one file), this
     /// automatically reads frames and extracts only this signal's samples.
@@ -195,93 +245,23 @@ impl SignalReader {
     ///
     /// Returns an error if reading from the signal file fails.
     pub fn read_samples_buf(&mut self, buffer: &mut [Sample]) -> Result<usize> {
-        if self.signals_in_file <= 1 {
-            // Non-interleaved: read directly
-            let count = self.decoder.decode_buf(&mut self.reader, buffer)?;
-            self.current_sample += count as u64;
-            Ok(count)
-        } else if self.bytes_per_sample == 0 {
-            // Interleaved with stateful format (e.g., Format212, Format310, Format311)
-            // These formats pack multiple samples into non-aligned byte sequences
-            // WARNING: This only works correctly if all signal readers for this file
-            // are created fresh or properly coordinated. For best results, use
-            // MultiSignalReader for interleaved stateful formats.
-
-            use std::io::Seek;
-
-            let mut count = 0;
-            for sample in buffer.iter_mut() {
-                // Reset decoder state before reading frame to ensure consistency
-                self.decoder.reset();
-
-                // Calculate byte position for this frame
-                let frame_number = self.current_sample;
-
-                // Use the decoder's bytes_per_frame method (format-specific logic)
-                let bytes_per_frame = self.decoder.bytes_per_frame(self.signals_in_file)
-                    .ok_or_else(|| Error::InvalidHeader(
-                        "Format does not support frame size calculation for interleaved reading".to_string()
-                    ))?;
-
-                let byte_offset = self.initial_offset + frame_number * bytes_per_frame as u64;
-                self.reader.seek(std::io::SeekFrom::Start(byte_offset))?;
-
-                // Read one frame sequentially
-                let n = self
-                    .decoder
-                    .decode_buf(&mut self.reader, &mut self.frame_buffer)?;
-                if n == 0 {
-                    break; // EOF
-                }
-                if n < self.signals_in_file {
-                    // Incomplete frame - shouldn't happen
-                    return Err(Error::InvalidHeader(
-                        "Incomplete frame in interleaved signal file".to_string(),
-                    ));
-                }
-
-                // Extract this signal's sample from the frame
-                *sample = self.frame_buffer[self.signal_index_in_file];
-                self.current_sample += 1;
-                count += 1;
-            }
-            Ok(count)
-        } else {
-            // Interleaved with fixed-size format - can seek for each frame
-            use std::io::Seek;
-
-            let mut count = 0;
-            for sample in buffer.iter_mut() {
-                // Calculate byte position for this frame
-                // Each frame contains signals_in_file samples
-                let frame_number = self.current_sample;
-                let byte_offset = self.initial_offset
-                    + frame_number * (self.signals_in_file * self.bytes_per_sample) as u64;
-
-                // Seek to the frame position
-                self.reader.seek(std::io::SeekFrom::Start(byte_offset))?;
-
-                // Read one frame
-                let n = self
-                    .decoder
-                    .decode_buf(&mut self.reader, &mut self.frame_buffer)?;
-                if n == 0 {
-                    break; // EOF
-                }
-                if n < self.signals_in_file {
-                    // Incomplete frame - shouldn't happen
-                    return Err(Error::InvalidHeader(
-                        "Incomplete frame in interleaved signal file".to_string(),
-                    ));
-                }
-
-                // Extract this signal's sample from the frame
-                *sample = self.frame_buffer[self.signal_index_in_file];
-                self.current_sample += 1;
-                count += 1;
-            }
-            Ok(count)
+        // Stop at the end of the signal, before any data of other records
+        let available = self.num_samples.map_or(buffer.len(), |num_samples| {
+            usize::try_from(num_samples.saturating_sub(self.current_sample))
+                .unwrap_or(usize::MAX)
+                .min(buffer.len())
+        });
+        let buffer = &mut buffer[..available];
+
+        let mut count = self.read_file_samples(buffer)?;
+
+        // Skewed signals run out of stored samples before the end of the record
+        if self.skew > 0 && self.num_samples.is_some() && count < buffer.len() {
+            buffer[count..].fill(INVALID_SAMPLE);
+            self.current_sample += (buffer.len() - count) as u64;
+            count = buffer.len();
         }
+        Ok(count)
     }
 
     /// Read a specified number of samples (raw ADC values).
@@ -323,10 +303,8 @@ impl SignalReader {
         let n = self.read_samples_buf(&mut adc_buffer)?;
 
         // Convert ADC values to physical values
-        for i in 0..n {
-            buffer[i] = self.to_physical(adc_buffer[i]);
-        }
-
+        let n = self.scale.to_physical_slice(&adc_buffer[..n], buffer);
+        self.filters.process_slice(&mut buffer[..n]);
         Ok(n)
     }
 
@@ -337,26 +315,72 @@ impl SignalReader {
     /// Returns an error if reading from the signal file fails.
     pub fn read_physical(&mut self, count: usize) -> Result<Vec<f64>> {
         let adc_values = self.read_samples(count)?;
-        Ok(adc_values.iter().map(|&v| self.to_physical(v)).collect())
+        let mut values: Vec<f64> = adc_values
+            .iter()
+            .map(|&v| self.scale.to_physical(v))
+            .collect();
+        self.filters.process_slice(&mut values);
+        Ok(values)
+    }
+
+    /// Filter the physical values read from now on.
+    ///
+    /// The filter applies to [`read_physical()`](Self::read_physical) and
+    /// [`read_physical_buf()`](Self::read_physical_buf), after conversion to
+    /// physical units; ADC values are read unfiltered. Filters attached by
+    /// several calls are applied in order. Seeking resets the filter state.
+    /// See [`filter`](crate::signal::filter) for the filters available.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    /// use wfdb::signal::filter;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let fs = record.metadata().sampling_frequency();
+    /// let mut reader = record
+    ///     .signal_reader(0)?
+    ///     .with_filter(filter::band_pass(fs, 0.5, 40.0)?);
+    ///
+    /// let cleaned = reader.read_physical(3600)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_filter<F: Filter + 'static>(mut self, filter: F) -> Self {
+        self.filters.push(filter);
+        self
     }
 
     // [Conversion utilities]
 
     /// Convert an ADC value to physical units.
+    ///
+    /// See [`SignalScale::to_physical`] for invalid and uncalibrated samples.
     #[must_use]
     pub fn to_physical(&self, adc_value: Sample) -> f64 {
-        let baseline = f64::from(self.signal_info.baseline());
-        let gain = self.signal_info.adc_gain();
-        (f64::from(adc_value) - baseline) / gain
+        self.scale.to_physical(adc_value)
     }
 
     /// Convert a physical value to ADC units.
+    ///
+    /// See [`SignalScale::to_adc`] for `NaN` and uncalibrated signals.
     #[must_use]
-    #[allow(clippy::cast_possible_truncation)]
     pub fn to_adc(&self, physical_value: f64) -> Sample {
-        let baseline = f64::from(self.signal_info.baseline());
-        let gain = self.signal_info.adc_gain();
-        physical_value.mul_add(gain, baseline).round() as Sample
+        self.scale.to_adc(physical_value)
+    }
+
+    /// Get the conversion between ADC values and physical units.
+    #[must_use]
+    pub const fn scale(&self) -> &SignalScale {
+        &self.scale
+    }
+
+    /// Set the rounding applied by [`to_adc`](Self::to_adc).
+    pub const fn set_rounding(&mut self, rounding: RoundingMode) {
+        self.scale.rounding = rounding;
     }
 
     // [Iterator API]
@@ -410,15 +434,18 @@ impl SignalReader {
     }
 
     /// Get the physical units for this signal.
+    ///
+    /// These are the header's units unless normalized by
+    /// [`ReaderOptions::target_units`].
     #[must_use]
     pub fn units(&self) -> &str {
-        self.signal_info.units()
+        &self.scale.units
     }
 
     /// Get the ADC gain (ADC units per physical unit).
     #[must_use]
-    pub fn gain(&self) -> f64 {
-        self.signal_info.adc_gain()
+    pub const fn gain(&self) -> f64 {
+        self.scale.gain
     }
 
     /// Get the baseline (ADC value at 0 physical units).
@@ -427,11 +454,19 @@ impl SignalReader {
         self.signal_info.baseline()
     }
 
+    /// Get the sampling frequency (Hz) of this signal, if known.
+    #[must_use]
+    pub const fn sampling_frequency(&self) -> Option<f64> {
+        self.sampling_frequency
+    }
+
     // [Seeking support]
 
     /// Seek to a specific sample number (0-indexed).
     ///
-    /// Returns the actual sample position after seeking.
+    /// The sample is counted in samples of this signal, which differ from
+    /// frames for signals with several samples per frame; see
+    /// [`SampleIndex`]. Returns the actual sample position after seeking.
     ///
     /// # Errors
     ///
@@ -442,47 +477,90 @@ impl SignalReader {
     /// # Performance Note
     ///
     /// For interleaved signals, seeking requires calculating frame boundaries.
-    /// For differential formats (Format 8), seeking resets the decoder state.
+    /// For differential formats (Format 8), the differences are accumulated
+    /// from the start of the file, so seeking reads through every sample
+    /// before the target.
     pub fn seek_to_sample(&mut self, sample: u64) -> Result<u64> {
         use std::io::Seek;
 
-        if self.signals_in_file <= 1 {
-            // Non-interleaved: calculate byte position directly
-            if self.bytes_per_sample > 0 {
-                let byte_offset = self.initial_offset + sample * self.bytes_per_sample as u64;
-                self.reader.seek(std::io::SeekFrom::Start(byte_offset))?;
-                self.decoder.reset();
-                self.current_sample = sample;
-                Ok(sample)
-            } else {
-                Err(Error::InvalidHeader(
-                    "Seeking not supported for this signal format".to_string(),
-                ))
+        match &mut self.source {
+            Source::Sequential {
+                decoder,
+                reader,
+                bytes_per_sample,
+            } => {
+                if decoder.is_differential() {
+                    // Difference formats: accumulate from the start of the file
+                    reader.seek(std::io::SeekFrom::Start(self.initial_offset))?;
+                    decoder.rewind();
+                    decoder.skip(reader, seek_position(self.skew, sample, 1)?)?;
+                } else if *bytes_per_sample > 0 {
+                    // Calculate byte position directly
+                    let byte_offset = seek_position(
+                        self.initial_offset,
+                        seek_position(self.skew, sample, 1)?,
+                        *bytes_per_sample as u64,
+                    )?;
+                    reader.seek(std::io::SeekFrom::Start(byte_offset))?;
+                    decoder.reset();
+                } else {
+                    return Err(Error::InvalidData(
+                        "Seeking not supported for this signal format".into(),
+                    ));
+                }
             }
-        } else {
-            // Interleaved: seek to frame containing the sample
-            if self.bytes_per_sample == 0 {
-                // Stateful format - need bytes_per_frame
-                let bytes_per_frame = self
-                    .decoder
-                    .bytes_per_frame(self.signals_in_file)
-                    .ok_or_else(|| {
-                        Error::InvalidHeader(
-                            "Seeking not supported for this signal format".to_string(),
-                        )
-                    })?;
-                let byte_offset = self.initial_offset + sample * bytes_per_frame as u64;
-                self.reader.seek(std::io::SeekFrom::Start(byte_offset))?;
-            } else {
-                // Fixed-size format
-                let byte_offset = self.initial_offset
-                    + sample * (self.signals_in_file * self.bytes_per_sample) as u64;
-                self.reader.seek(std::io::SeekFrom::Start(byte_offset))?;
+            Source::Interleaved(group) => {
+                // The frame is loaded by the next read, unless already in
+                // the current block
+                group.lock().frame_bytes()?;
             }
-            self.decoder.reset();
-            self.current_sample = sample;
-            Ok(sample)
         }
+        self.filters.reset();
+        self.current_sample = sample;
+        Ok(sample)
+    }
+
+    /// Seek to a sample given as a [`SampleIndex`].
+    ///
+    /// Same as [`seek_to_sample`](Self::seek_to_sample), with a sample number
+    /// that cannot be mistaken for a frame number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_sample`](Self::seek_to_sample).
+    pub fn seek_to_sample_index(&mut self, sample: SampleIndex) -> Result<u64> {
+        self.seek_to_sample(sample.get())
+    }
+
+    /// Seek to the first sample of a frame.
+    ///
+    /// Frame numbers are the sample numbers of the record line (as used by
+    /// [`MultiSignalReader`](crate::MultiSignalReader) and
+    /// [`Record::datetime_to_sample`](crate::Record::datetime_to_sample));
+    /// each frame holds [`SignalInfo::samples_per_frame`] samples of this
+    /// signal. Returns the sample position after seeking.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_sample`](Self::seek_to_sample).
+    pub fn seek_to_frame(&mut self, frame: u64) -> Result<u64> {
+        self.seek_to_sample(
+            FrameIndex(frame)
+                .to_sample(self.signal_info.samples_per_frame())
+                .get(),
+        )
+    }
+
+    /// Seek to a frame given as a [`FrameIndex`].
+    ///
+    /// Same as [`seek_to_frame`](Self::seek_to_frame), with a frame number
+    /// that cannot be mistaken for a sample number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_frame`](Self::seek_to_frame).
+    pub fn seek_to_frame_index(&mut self, frame: FrameIndex) -> Result<u64> {
+        self.seek_to_frame(frame.get())
     }
 
     /// Get current sample position.
@@ -491,26 +569,297 @@ impl SignalReader {
         self.current_sample
     }
 
+    /// Get the frame holding the current sample position.
+    #[must_use]
+    pub const fn current_frame(&self) -> u64 {
+        self.current_sample / self.samples_per_frame
+    }
+
+    /// Read windows of `window` samples starting at each of the given sample
+    /// numbers (raw ADC values).
+    ///
+    /// Requests are sorted and nearby windows (less than
+    /// [`READ_AT_COALESCE_GAP`] samples apart) are served by a single
+    /// sequential read, so extracting many short windows (e.g., around each
+    /// beat of a long record) touches each region of the file only once.
+    /// Windows are returned in the order of `starts`; windows reaching past
+    /// the end of the signal are truncated (possibly empty).
+    ///
+    /// The reader position is restored afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Seeking is not supported for this format
+    /// - Reading from the signal file fails
+    ///
+    /// # Performance Note
+    ///
+    /// For differential formats (Format 8), each group of windows is reached
+    /// by accumulating differences from the start of the file (see
+    /// [`seek_to_sample`](Self::seek_to_sample)).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut reader = record.signal_reader(0)?;
+    ///
+    /// // 200 samples around each beat
+    /// let beats = [370u64, 662, 946];
+    /// let starts: Vec<u64> = beats.iter().map(|&b| b.saturating_sub(100)).collect();
+    /// let windows = reader.read_at(&starts, 200)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_at(&mut self, starts: &[u64], window: usize) -> Result<Vec<Vec<Sample>>> {
+        let original_position = self.current_sample;
+        let window_len = window as u64;
+        let limit = self.num_samples.unwrap_or(u64::MAX);
+
+        let mut order: Vec<usize> = (0..starts.len()).collect();
+        order.sort_by_key(|&i| starts[i]);
+
+        let mut windows = vec![Vec::new(); starts.len()];
+        let mut group_begin = 0;
+        while group_begin < order.len() {
+            // Extend the group while the next window starts close enough
+            let read_start = starts[order[group_begin]];
+            let mut read_end = read_start.saturating_add(window_len);
+            let mut group_end = group_begin + 1;
+            while group_end < order.len()
+                && starts[order[group_end]] <= read_end.saturating_add(READ_AT_COALESCE_GAP)
+            {
+                read_end = read_end.max(starts[order[group_end]].saturating_add(window_len));
+                group_end += 1;
+            }
+
+            // Windows past the end of the signal stay empty
+            let read_end = read_end.min(limit);
+            if read_start >= read_end {
+                group_begin = group_end;
+                continue;
+            }
+
+            let count = usize::try_from(read_end - read_start).map_err(|_| {
+                Error::InvalidData("Requested window range is too large".to_string())
+            })?;
+            self.seek_to_sample(read_start)?;
+            let samples = self.read_samples(count)?;
+
+            for &index in &order[group_begin..group_end] {
+                let offset = usize::try_from(starts[index] - read_start).unwrap_or(usize::MAX);
+                let begin = offset.min(samples.len());
+                let end = offset.saturating_add(window).min(samples.len());
+                windows[index] = samples[begin..end].to_vec();
+            }
+            group_begin = group_end;
+        }
+
+        self.seek_to_sample(original_position)?;
+        Ok(windows)
+    }
+
     /// Seek to a specific time in the record.
     ///
-    /// Converts the time to a sample number using the signal's sampling frequency
-    /// and then seeks to that sample.
+    /// Converts the time to the nearest sample at the signal's sampling
+    /// frequency (the frequency of the record line times the samples per
+    /// frame), and then seeks to that sample. Use
+    /// [`seek_to_frame`](Self::seek_to_frame) for frame numbers.
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The time is negative
     /// - Sampling frequency is not available
     /// - Seeking is not supported for this format
     /// - The seek operation fails
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     pub fn seek_to_time(&mut self, seconds: f64) -> Result<u64> {
         let freq = self.sampling_frequency.ok_or_else(|| {
-            Error::InvalidHeader(
-                "Sampling frequency not available for time-based seeking".to_string(),
-            )
+            Error::InvalidHeader("Sampling frequency not available for time-based seeking".into())
         })?;
-        let sample = (seconds * freq).round() as u64;
-        self.seek_to_sample(sample)
+        self.seek_to_sample(seconds_to_sample(seconds, freq)?)
+    }
+
+    /// Seek to a time from the start of the record.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// See [`seek_to_time`](Self::seek_to_time).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`seek_to_time`](Self::seek_to_time).
+    #[cfg(feature = "chrono")]
+    pub fn seek_to_duration(&mut self, duration: TimeDelta) -> Result<u64> {
+        self.seek_to_time(duration.as_seconds_f64())
+    }
+
+    /// Get the current position in seconds from the start of the record.
+    ///
+    /// Returns `None` if the sampling frequency is not available.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn position_seconds(&self) -> Option<f64> {
+        self.sampling_frequency
+            .map(|freq| self.current_sample as f64 / freq)
+    }
+
+    // [Checksum verification]
+
+    /// Compute the 16-bit checksum of the samples left in the signal.
+    ///
+    /// Reads from the current position to the end of the signal: the length
+    /// given in the header, or the end of the file if the header has none or
+    /// [`ReaderOptions::ignore_length`] is set. On a new reader this is the
+    /// checksum of the whole signal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the signal file fails.
+    pub fn compute_checksum(&mut self) -> Result<i16> {
+        let mut checksum = 0;
+        let mut buffer = vec![0; 4096];
+        loop {
+            let n = self.read_samples_buf(&mut buffer)?;
+            if n == 0 {
+                return Ok(checksum);
+            }
+            checksum = buffer[..n]
+                .iter()
+                .fold(checksum, |sum, &sample| add_to_checksum(sum, sample));
+        }
+    }
+
+    /// Check the samples left in the signal against the header checksum.
+    ///
+    /// Returns `true` if the checksums match or the header has none. Like
+    /// [`compute_checksum`](Self::compute_checksum), this reads to the end of
+    /// the signal, using the length given in the header; use
+    /// [`Record::validate()`](crate::Record::validate) to check every signal
+    /// at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the signal file fails.
+    pub fn verify_checksum(&mut self) -> Result<bool> {
+        let computed = self.compute_checksum()?;
+        Ok(self
+            .signal_info
+            .checksum
+            .is_none_or(|expected| expected == computed))
+    }
+
+    // [Statistics]
+
+    /// Compute summary statistics of the samples left in the signal, in
+    /// physical units.
+    ///
+    /// Reads from the current position to the end of the signal in a single
+    /// pass, without holding the samples in memory. Invalid samples are
+    /// counted in [`SignalStats::invalid`] and left out of the other
+    /// statistics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the signal file fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut reader = record.signal_reader(0)?;
+    /// let stats = reader.stats()?;
+    /// println!("{} to {} {}", stats.min, stats.max, reader.units());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&mut self) -> Result<SignalStats> {
+        let mut accumulator = StatsAccumulator::default();
+        let mut buffer = vec![0; 4096];
+        loop {
+            let n = self.read_samples_buf(&mut buffer)?;
+            if n == 0 {
+                return Ok(accumulator.finish());
+            }
+            for &sample in &buffer[..n] {
+                accumulator.push_sample(&self.scale, sample);
+            }
+        }
+    }
+
+    // [Private helper methods]
+
+    /// Read samples from the signal file, shifted by the skew.
+    fn read_file_samples(&mut self, buffer: &mut [Sample]) -> Result<usize> {
+        let count = match &mut self.source {
+            Source::Sequential {
+                decoder, reader, ..
+            } => decoder.decode_buf(reader, buffer)?,
+            Source::Interleaved(group) => {
+                // Extract this signal's samples from the blocks of frames
+                // decoded by the file group
+                let mut group = group.lock();
+                let mut count = 0;
+                for sample in buffer.iter_mut() {
+                    let position = self.current_sample.saturating_add(count as u64 + self.skew);
+                    let frame = position / self.samples_per_frame;
+                    #[allow(clippy::cast_possible_truncation)]
+                    let offset =
+                        self.signal_index_in_file + (position % self.samples_per_frame) as usize;
+                    let Some(value) = group.sample(frame, offset)? else {
+                        break; // EOF
+                    };
+                    *sample = value;
+                    count += 1;
+                }
+                drop(group);
+                count
+            }
+        };
+        self.current_sample += count as u64;
+        Ok(count)
+    }
+
+    /// Open the file of a signal stored alone, positioned after its skew.
+    fn open_sequential(
+        files: &RecordFiles,
+        signal_info: &SignalInfo,
+        initial_offset: u64,
+        skew: u64,
+        options: &ReaderOptions,
+    ) -> Result<Source> {
+        use std::io::Seek;
+
+        let file = files.open_signal(&signal_info.file_name)?;
+        let mut reader = BufReader::with_capacity(options.effective_buffer_capacity(), file);
+        let initial_value = signal_info.initial_value.unwrap_or(0);
+        let mut decoder = crate::signal::get_decoder(signal_info.format, initial_value)?;
+        let bytes_per_sample = decoder.bytes_per_sample().unwrap_or(0);
+
+        // Skip the skew, as the file is read sequentially
+        if bytes_per_sample > 0 && !decoder.is_differential() {
+            reader.seek(std::io::SeekFrom::Start(
+                initial_offset + skew * bytes_per_sample as u64,
+            ))?;
+        } else {
+            if initial_offset > 0 {
+                reader.seek(std::io::SeekFrom::Start(initial_offset))?;
+            }
+            decoder.skip(&mut reader, skew)?;
+        }
+
+        Ok(Source::Sequential {
+            decoder,
+            reader,
+            bytes_per_sample,
+        })
     }
 }
 
@@ -520,9 +869,10 @@ impl SignalReader {
 ///
 /// # Performance
 ///
-/// For non-interleaved signals, this iterator efficiently reads samples
-/// sequentially. For interleaved signals, each call to `next()` may involve
-/// seeking and reading a full frame, which can be slower.
+/// Samples are read sequentially through the reader's buffers. For
+/// interleaved signals, frames are decoded in blocks (see
+/// [`ReaderOptions::interleaved_block_frames`]), so most calls to `next()`
+/// do no I/O.
 pub struct SampleIterator<'a> {
     reader: &'a mut SignalReader,
     buffer: [Sample; 1],
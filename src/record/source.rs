@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Seek};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::record::components::resolve_signal_path;
+use crate::{Error, Header, Result};
+
+/// Readable and seekable stream of bytes, such as a file or a cursor.
+///
+/// Implemented for every `Read + Seek + Send` type.
+pub trait ReadSeek: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> ReadSeek for T {}
+
+/// Source of the files of a record, for [`Record::from_sources`].
+///
+/// A source opens the files of a record by name, as written in the header:
+/// signal files (e.g. `100.dat`) and, for multi-segment records, segment
+/// headers (e.g. `seg_a.hea`). Each call must return an independent stream
+/// positioned at the start of the file, since several readers may read the
+/// same file at once.
+///
+/// Implemented by [`MemorySource`] and by closures, so that records can be
+/// read from archives, object stores or embedded fixtures without touching
+/// disk.
+///
+/// [`Record::from_sources`]: crate::Record::from_sources
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::Cursor;
+///
+/// use wfdb::Record;
+/// use wfdb::record::ReadSeek;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let header = std::fs::read_to_string("data/100.hea")?;
+/// let record = Record::from_sources(&header, |name: &str| {
+///     let bytes = std::fs::read(format!("data/{name}"))?;
+///     Ok(Box::new(Cursor::new(bytes)) as Box<dyn ReadSeek>)
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait SignalSource: Send + Sync {
+    /// Open a file of the record by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file does not exist or cannot be opened.
+    fn open(&self, file_name: &str) -> Result<Box<dyn ReadSeek>>;
+}
+
+impl<F> SignalSource for F
+where
+    F: Fn(&str) -> Result<Box<dyn ReadSeek>> + Send + Sync,
+{
+    fn open(&self, file_name: &str) -> Result<Box<dyn ReadSeek>> {
+        self(file_name)
+    }
+}
+
+/// Files of a record held in memory.
+///
+/// Each file is stored once and shared by every reader opening it.
+///
+/// # Examples
+///
+/// ```
+/// use wfdb::Record;
+/// use wfdb::record::MemorySource;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let mut source = MemorySource::new();
+/// source.insert("rec.dat", vec![1, 0, 2, 0, 3, 0]);
+///
+/// let record = Record::from_sources("rec 1 250 3\nrec.dat 16\n", source)?;
+/// assert_eq!(record.signal_reader(0)?.read_samples(3)?, vec![1, 2, 3]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemorySource {
+    /// Contents of each file, by file name.
+    files: BTreeMap<String, Arc<[u8]>>,
+}
+
+impl MemorySource {
+    /// Create an empty source.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Add a file, replacing any file with the same name.
+    pub fn insert<S: Into<String>, B: Into<Arc<[u8]>>>(&mut self, file_name: S, contents: B) {
+        self.files.insert(file_name.into(), contents.into());
+    }
+
+    /// Check if the source has a file.
+    #[must_use]
+    pub fn contains(&self, file_name: &str) -> bool {
+        self.files.contains_key(file_name)
+    }
+}
+
+impl<S: Into<String>, B: Into<Arc<[u8]>>> FromIterator<(S, B)> for MemorySource {
+    fn from_iter<I: IntoIterator<Item = (S, B)>>(iter: I) -> Self {
+        let mut source = Self::new();
+        for (file_name, contents) in iter {
+            source.insert(file_name, contents);
+        }
+        source
+    }
+}
+
+impl SignalSource for MemorySource {
+    fn open(&self, file_name: &str) -> Result<Box<dyn ReadSeek>> {
+        let contents = self.files.get(file_name).ok_or_else(|| {
+            Error::InvalidPath(format!("File '{file_name}' not found in memory source"))
+        })?;
+        Ok(Box::new(Cursor::new(Arc::clone(contents))))
+    }
+}
+
+/// Shared handle on the custom source of a record.
+#[derive(Clone)]
+pub struct SharedSource(pub Arc<dyn SignalSource>);
+
+impl fmt::Debug for SharedSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SignalSource")
+    }
+}
+
+/// Location of the files of a record, from which readers open them.
+#[derive(Debug, Clone)]
+pub enum RecordFiles {
+    /// Files in a directory, with per-file directory overrides.
+    Directory {
+        /// Directory of the header.
+        base_path: PathBuf,
+        /// Directory of each signal file whose location is overridden.
+        signal_dirs: BTreeMap<String, PathBuf>,
+    },
+    /// Files opened from a custom source.
+    Source(SharedSource),
+}
+
+impl RecordFiles {
+    /// Open a signal file.
+    pub fn open_signal(&self, file_name: &str) -> Result<Box<dyn ReadSeek>> {
+        match self {
+            Self::Directory {
+                base_path,
+                signal_dirs,
+            } => {
+                let signal_path = resolve_signal_path(base_path, signal_dirs, file_name);
+                let file = File::open(&signal_path).map_err(|source| Error::SignalFile {
+                    path: signal_path.clone(),
+                    source,
+                })?;
+                Ok(Box::new(file))
+            }
+            Self::Source(source) => source.0.open(file_name),
+        }
+    }
+
+    /// Open and parse the header of a segment.
+    pub fn load_segment_header(&self, record_name: &str) -> Result<Header> {
+        let file_name = format!("{record_name}.hea");
+        let file: Box<dyn ReadSeek> = match self {
+            Self::Directory { base_path, .. } => {
+                let header_path = base_path.join(&file_name);
+                Box::new(File::open(&header_path).map_err(|e| {
+                    Error::InvalidPath(format!(
+                        "Failed to open segment header '{}': {}",
+                        header_path.display(),
+                        e
+                    ))
+                })?)
+            }
+            Self::Source(source) => source.0.open(&file_name)?,
+        };
+        Header::from_reader(&mut BufReader::new(file))
+    }
+}
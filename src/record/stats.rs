@@ -0,0 +1,114 @@
+use crate::Sample;
+use crate::signal::{INVALID_SAMPLE, SignalScale};
+
+/// Summary statistics of a signal in physical units, like the `sigavg` and
+/// `sumstats` tools of the WFDB software package.
+///
+/// Computed in a single streaming pass by
+/// [`SignalReader::stats()`](crate::SignalReader::stats) and
+/// [`Record::signal_stats()`](crate::Record::signal_stats). Invalid samples
+/// are counted but left out of the other statistics, which are `NaN` when no
+/// sample is valid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalStats {
+    /// Number of samples read, including invalid samples.
+    pub count: u64,
+    /// Number of invalid samples.
+    pub invalid: u64,
+    /// Smallest valid value.
+    pub min: f64,
+    /// Largest valid value.
+    pub max: f64,
+    /// Mean of the valid values.
+    pub mean: f64,
+    /// Root mean square of the valid values.
+    pub rms: f64,
+}
+
+impl SignalStats {
+    /// Get the number of valid samples.
+    #[must_use]
+    pub const fn valid(&self) -> u64 {
+        self.count - self.invalid
+    }
+}
+
+/// Running sums from which [`SignalStats`] are computed.
+#[derive(Debug, Clone, Copy)]
+pub struct StatsAccumulator {
+    /// Number of samples pushed.
+    count: u64,
+    /// Number of invalid samples pushed.
+    invalid: u64,
+    /// Smallest valid value.
+    min: f64,
+    /// Largest valid value.
+    max: f64,
+    /// Sum of the valid values.
+    sum: f64,
+    /// Sum of the squares of the valid values.
+    sum_of_squares: f64,
+}
+
+impl Default for StatsAccumulator {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            invalid: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            sum: 0.0,
+            sum_of_squares: 0.0,
+        }
+    }
+}
+
+impl StatsAccumulator {
+    /// Add an ADC value, converted to physical units.
+    pub fn push_sample(&mut self, scale: &SignalScale, sample: Sample) {
+        if sample == INVALID_SAMPLE {
+            self.count += 1;
+            self.invalid += 1;
+        } else {
+            self.push(scale.to_physical(sample));
+        }
+    }
+
+    /// Add a physical value (`NaN` for invalid samples).
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        if value.is_nan() {
+            self.invalid += 1;
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value;
+        self.sum_of_squares = value.mul_add(value, self.sum_of_squares);
+    }
+
+    /// Compute the statistics of the values pushed.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn finish(&self) -> SignalStats {
+        let valid = self.count - self.invalid;
+        if valid == 0 {
+            return SignalStats {
+                count: self.count,
+                invalid: self.invalid,
+                min: f64::NAN,
+                max: f64::NAN,
+                mean: f64::NAN,
+                rms: f64::NAN,
+            };
+        }
+        SignalStats {
+            count: self.count,
+            invalid: self.invalid,
+            min: self.min,
+            max: self.max,
+            mean: self.sum / valid as f64,
+            rms: (self.sum_of_squares / valid as f64).sqrt(),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Asynchronous stream adapters for frame readers.
+//!
+//! Available with the `async` feature.
+
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use futures_core::Stream;
+
+use crate::record::SegmentReader;
+use crate::{Error, MultiSignalReader, Result, Sample};
+
+/// Number of frames decoded ahead of the consumer of a [`FrameStream`].
+pub const STREAM_BUFFER_FRAMES: usize = 64;
+
+/// Source of frames read by a [`FrameStream`].
+type FrameSource = Box<dyn FnMut() -> Result<Option<Vec<Sample>>> + Send>;
+
+/// Waker of the task polling a [`FrameStream`], shared with its thread.
+type SharedWaker = Arc<Mutex<Option<Waker>>>;
+
+/// A [`Stream`] of frames read from a record.
+///
+/// Created by [`MultiSignalReader::into_stream()`] or
+/// [`SegmentReader::into_stream()`].
+///
+/// Frames are decoded on a background thread (named `wfdb-stream`), so
+/// polling the stream never blocks the executor on file reads: a poll
+/// returns a decoded frame if one is ready, and otherwise registers the
+/// task to be woken when the next frame is.
+///
+/// # Back-pressure
+///
+/// The thread decodes at most [`STREAM_BUFFER_FRAMES`] frames ahead of the
+/// consumer, then waits for it, so a slow consumer (e.g., a bounded channel
+/// or a WebSocket sink) throttles reading.
+///
+/// # Cancellation
+///
+/// Dropping the stream (or the future polling it) at any point stops the
+/// thread after the frame it is decoding; frames decoded ahead are
+/// discarded. Dropping does not wait for the thread.
+pub struct FrameStream {
+    /// Frames decoded by the thread, `None` once the stream has ended.
+    receiver: Option<Receiver<Result<Vec<Sample>>>>,
+    /// Waker of the task waiting for the next frame.
+    waker: SharedWaker,
+    /// Error starting the thread, returned by the first poll.
+    spawn_error: Option<Error>,
+}
+
+impl FrameStream {
+    /// Create a stream decoding frames from a source on a new thread.
+    fn new(mut source: FrameSource) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(STREAM_BUFFER_FRAMES);
+        let waker = SharedWaker::default();
+        let thread_waker = Arc::clone(&waker);
+        let spawned = thread::Builder::new()
+            .name("wfdb-stream".to_string())
+            .spawn(move || {
+                loop {
+                    let item = source();
+                    let last = !matches!(item, Ok(Some(_)));
+                    // The consumer stops the thread by dropping the stream
+                    let sent = item
+                        .transpose()
+                        .is_some_and(|item| sender.send(item).is_ok());
+                    if !sent || last {
+                        // Disconnect before waking, so that the end is seen
+                        drop(sender);
+                        wake(&thread_waker);
+                        return;
+                    }
+                    wake(&thread_waker);
+                }
+            });
+
+        match spawned {
+            Ok(_) => Self {
+                receiver: Some(receiver),
+                waker,
+                spawn_error: None,
+            },
+            Err(e) => Self {
+                receiver: None,
+                waker,
+                spawn_error: Some(e.into()),
+            },
+        }
+    }
+
+    /// Take the next decoded frame, if ready.
+    fn try_next(&mut self) -> Poll<Option<Result<Vec<Sample>>>> {
+        let Some(receiver) = &self.receiver else {
+            return Poll::Ready(None);
+        };
+        match receiver.try_recv() {
+            Ok(item) => Poll::Ready(Some(item)),
+            Err(TryRecvError::Empty) => Poll::Pending,
+            Err(TryRecvError::Disconnected) => {
+                self.receiver = None;
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+impl Stream for FrameStream {
+    type Item = Result<Vec<Sample>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(e) = self.spawn_error.take() {
+            return Poll::Ready(Some(Err(e)));
+        }
+        if let Poll::Ready(item) = self.try_next() {
+            return Poll::Ready(item);
+        }
+
+        *self.waker.lock().unwrap_or_else(PoisonError::into_inner) = Some(cx.waker().clone());
+        // The thread may have sent a frame before the waker was registered
+        self.try_next()
+    }
+}
+
+/// Wake the task waiting for a frame, if any.
+fn wake(waker: &SharedWaker) {
+    let waker = waker.lock().unwrap_or_else(PoisonError::into_inner).take();
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+impl MultiSignalReader {
+    /// Convert this reader into an asynchronous [`Stream`] of frames.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use wfdb::Record;
+    ///
+    /// # async fn run() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let mut frames = record.multi_signal_reader()?.into_stream();
+    ///
+    /// while let Some(frame) = frames.next().await {
+    ///     let frame = frame?;
+    ///     // Forward frame to an async sink...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_stream(mut self) -> FrameStream {
+        FrameStream::new(Box::new(move || {
+            let frame = self.read_frame()?;
+            Ok((!frame.is_empty()).then_some(frame))
+        }))
+    }
+}
+
+impl SegmentReader {
+    /// Convert this reader into an asynchronous [`Stream`] of frames.
+    #[must_use]
+    pub fn into_stream(mut self) -> FrameStream {
+        FrameStream::new(Box::new(move || self.read_frame()))
+    }
+}
@@ -0,0 +1,332 @@
+//! Descriptions of a record, for printing.
+
+use std::fmt;
+
+use crate::header::{BaseDate, BaseTime};
+use crate::number::Number;
+use crate::record::timing::elapsed_time;
+use crate::record::waveform::segment_signals;
+use crate::{Record, Result, SignalFormat, SignalInfo};
+
+/// Summary of a record, like the first lines printed by the `wfdbdesc` tool
+/// of the WFDB software package.
+///
+/// Obtained with [`Record::summary()`]. Its [`Display`](fmt::Display)
+/// implementation prints the record name, starting time, length, sampling
+/// frequency and one line per signal.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::Record;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let record = Record::open("data/100")?;
+/// print!("{}", record.summary()?);
+/// // Record 100
+/// // Starting time: not specified
+/// // Length: 30:05.556 (650000 sample intervals)
+/// // Sampling frequency: 360 Hz
+/// // 2 signals
+/// //  0: MLII (format 212, 200 adu/mV)
+/// //  1: V5 (format 212, 200 adu/mV)
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordSummary {
+    /// Name of the record.
+    pub name: String,
+    /// Number of segments (`None` for single-segment records).
+    pub num_segments: Option<usize>,
+    /// Sampling frequency (Hz) of the record.
+    pub sampling_frequency: f64,
+    /// Time of the first sample, if in the header.
+    pub base_time: Option<BaseTime>,
+    /// Date of the first sample, if in the header.
+    pub base_date: Option<BaseDate>,
+    /// Number of frames, if known (see [`Record::total_samples()`]).
+    pub total_samples: Option<u64>,
+    /// Length of the record in seconds, if known.
+    pub duration: Option<f64>,
+    /// Signals of the record.
+    pub signals: Vec<SignalSummary>,
+}
+
+/// Summary of a signal, in a [`RecordSummary`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalSummary {
+    /// Description of the signal, if any.
+    pub description: Option<String>,
+    /// Physical units of the signal.
+    pub units: String,
+    /// Storage format of the signal.
+    pub format: SignalFormat,
+    /// Samples of the signal per frame.
+    pub samples_per_frame: u32,
+    /// ADC gain (ADC units per physical unit).
+    pub adc_gain: f64,
+}
+
+impl From<&SignalInfo> for SignalSummary {
+    fn from(signal: &SignalInfo) -> Self {
+        Self {
+            description: signal.description().map(ToString::to_string),
+            units: signal.units().to_string(),
+            format: signal.format(),
+            samples_per_frame: signal.samples_per_frame(),
+            adc_gain: signal.adc_gain(),
+        }
+    }
+}
+
+impl Record {
+    /// Summarize the record for printing, like the `wfdbdesc` tool.
+    ///
+    /// The signals of multi-segment records are those of the layout segment,
+    /// or of the first segment with signals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no segment header of a multi-segment record can
+    /// be read.
+    pub fn summary(&self) -> Result<RecordSummary> {
+        Ok(self.summarize(&self.described_signals()?))
+    }
+
+    /// Describe the record in full, like the `wfdbdesc` tool.
+    ///
+    /// After the lines of the [summary](Self::summary), every signal is
+    /// described (file, gain, initial value, format, ADC parameters and
+    /// checksum), followed by the segment table of multi-segment records and
+    /// the info strings of the header. The signals of multi-segment records
+    /// are those of the layout segment, or of the first segment with
+    /// signals.
+    ///
+    /// The [`Display`](fmt::Display) implementation of `Record` writes the
+    /// same description from the header alone, without the signals of
+    /// multi-segment records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no segment header of a multi-segment record can
+    /// be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// print!("{}", record.describe()?);
+    /// // Record 100
+    /// // ...
+    /// // Group 0, Signal 0:
+    /// //  File: 100.dat
+    /// //  Description: MLII
+    /// //  Gain: 200 adu/mV
+    /// //  Initial value: 995
+    /// //  Storage format: 212
+    /// //  I/O: can be unbuffered
+    /// //  ADC resolution: 11 bits
+    /// //  ADC zero: 1024
+    /// //  Baseline: 1024
+    /// //  Checksum: -22131
+    /// // ...
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn describe(&self) -> Result<String> {
+        let mut text = String::new();
+        let _ = self.write_description(&mut text, &self.described_signals()?);
+        Ok(text)
+    }
+
+    /// Get the signals of the record, or of the layout segment (or first
+    /// segment with signals) of multi-segment records.
+    fn described_signals(&self) -> Result<Vec<SignalInfo>> {
+        self.signal_info()
+            .map_or_else(|| segment_signals(self), |signals| Ok(signals.to_vec()))
+    }
+
+    /// Summarize the record with the given signals.
+    fn summarize(&self, signals: &[SignalInfo]) -> RecordSummary {
+        let metadata = self.metadata();
+        let total_samples = self.total_samples();
+        RecordSummary {
+            name: metadata.name().to_string(),
+            num_segments: metadata.num_segments(),
+            sampling_frequency: metadata.sampling_frequency(),
+            base_time: metadata.base_time,
+            base_date: metadata.base_date,
+            total_samples,
+            duration: total_samples.map(|samples| self.clock().elapsed(samples)),
+            signals: signals.iter().map(SignalSummary::from).collect(),
+        }
+    }
+
+    /// Write the full description of the record with the given signals.
+    fn write_description<W: fmt::Write>(&self, out: &mut W, signals: &[SignalInfo]) -> fmt::Result {
+        let metadata = self.metadata();
+        self.summarize(signals).write_overview(out)?;
+        if let Some(counter_frequency) = metadata.counter_frequency {
+            writeln!(
+                out,
+                "Counter frequency: {} ticks/second",
+                Number(counter_frequency)
+            )?;
+            if let Some(base_counter) = metadata.base_counter {
+                writeln!(out, "Base counter value: {}", Number(base_counter))?;
+            }
+        }
+
+        let count = metadata.num_signals();
+        writeln!(out, "{count} signal{}", if count == 1 { "" } else { "s" })?;
+        let mut files: Vec<&str> = Vec::new();
+        for (index, signal) in signals.iter().enumerate() {
+            let group = files
+                .iter()
+                .position(|&name| name == signal.file_name())
+                .unwrap_or_else(|| {
+                    files.push(signal.file_name());
+                    files.len() - 1
+                });
+            write_signal(out, group, index, signal)?;
+        }
+
+        if let Some(segments) = self.segment_info() {
+            writeln!(out, "{} segments", segments.len())?;
+            for (index, segment) in segments.iter().enumerate() {
+                let name = if segment.is_null_segment() {
+                    "(null)"
+                } else {
+                    &segment.record_name
+                };
+                writeln!(
+                    out,
+                    " Segment {index}: {name} ({} sample intervals)",
+                    segment.num_samples
+                )?;
+            }
+        }
+
+        if !self.info_strings().is_empty() {
+            writeln!(out, "Notes\n=====")?;
+            for info in self.info_strings() {
+                writeln!(out, "{info}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Record {
+    /// Write the description of [`Record::describe`] from the header alone.
+    ///
+    /// Multi-segment records list their segments but not their signals,
+    /// which are in the segment headers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_description(f, self.signal_info().unwrap_or_default())
+    }
+}
+
+impl RecordSummary {
+    /// Write the record name, starting time, length and sampling frequency.
+    fn write_overview<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        write!(out, "Record {}", self.name)?;
+        if let Some(num_segments) = self.num_segments {
+            write!(out, " ({num_segments} segments)")?;
+        }
+        writeln!(out)?;
+
+        match (self.base_time, self.base_date) {
+            (Some(time), Some(date)) => writeln!(out, "Starting time: [{time} {date}]")?,
+            (Some(time), None) => writeln!(out, "Starting time: [{time}]")?,
+            _ => writeln!(out, "Starting time: not specified")?,
+        }
+        match (self.duration, self.total_samples) {
+            (Some(duration), Some(samples)) => writeln!(
+                out,
+                "Length: {} ({samples} sample intervals)",
+                elapsed_time(duration)
+            )?,
+            _ => writeln!(out, "Length: not specified")?,
+        }
+        writeln!(
+            out,
+            "Sampling frequency: {} Hz",
+            Number(self.sampling_frequency)
+        )
+    }
+}
+
+impl fmt::Display for RecordSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_overview(f)?;
+
+        let count = self.signals.len();
+        writeln!(f, "{count} signal{}", if count == 1 { "" } else { "s" })?;
+        for (index, signal) in self.signals.iter().enumerate() {
+            write!(
+                f,
+                " {index}: {} (format {}",
+                signal.description.as_deref().unwrap_or("(no description)"),
+                u16::from(signal.format)
+            )?;
+            if signal.samples_per_frame > 1 {
+                write!(f, "x{}", signal.samples_per_frame)?;
+            }
+            writeln!(f, ", {} adu/{})", Number(signal.adc_gain), signal.units)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the description of a signal, as `wfdbdesc` does.
+fn write_signal<W: fmt::Write>(
+    out: &mut W,
+    group: usize,
+    index: usize,
+    signal: &SignalInfo,
+) -> fmt::Result {
+    writeln!(out, "Group {group}, Signal {index}:")?;
+    writeln!(out, " File: {}", signal.file_name())?;
+    writeln!(
+        out,
+        " Description: {}",
+        signal.description().unwrap_or_default()
+    )?;
+    if signal.is_calibrated() {
+        writeln!(
+            out,
+            " Gain: {} adu/{}",
+            Number(signal.adc_gain()),
+            signal.units()
+        )?;
+    } else {
+        writeln!(
+            out,
+            " Gain: uncalibrated; assume {} adu/{}",
+            Number(SignalInfo::DEFAULT_ADC_GAIN),
+            signal.units()
+        )?;
+    }
+    writeln!(out, " Initial value: {}", signal.initial_value())?;
+    write!(out, " Storage format: {}", u16::from(signal.format()))?;
+    if signal.samples_per_frame() > 1 {
+        write!(out, " ({} samples per frame)", signal.samples_per_frame())?;
+    }
+    writeln!(out)?;
+    match signal.block_size() {
+        0 => writeln!(out, " I/O: can be unbuffered")?,
+        block_size => writeln!(out, " I/O: {block_size}-byte blocks")?,
+    }
+    writeln!(out, " ADC resolution: {} bits", signal.adc_resolution())?;
+    writeln!(out, " ADC zero: {}", signal.adc_zero())?;
+    writeln!(out, " Baseline: {}", signal.baseline())?;
+    if let Some(checksum) = signal.checksum() {
+        writeln!(out, " Checksum: {checksum}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,234 @@
+//! Splitting a frame stream between concurrent consumers.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+use crate::record::SegmentReader;
+use crate::{Error, MultiSignalReader, Result, Sample};
+
+/// Source of frames shared by the branches of a tee.
+type FrameSource = Box<dyn FnMut() -> Result<Option<Vec<Sample>>> + Send>;
+
+/// State shared by the two branches.
+struct Shared {
+    /// Reads the next frame, returning `None` at end of record.
+    source: FrameSource,
+    /// Frames read but not yet returned, per branch.
+    queues: [VecDeque<Result<Vec<Sample>>>; 2],
+    /// Whether each branch has been dropped.
+    closed: [bool; 2],
+    /// Whether the end of the record (or an error) has been reached.
+    done: bool,
+}
+
+/// Synchronization around the shared state.
+struct Tee {
+    /// Shared state.
+    state: Mutex<Shared>,
+    /// Signaled whenever a branch takes a frame or is dropped.
+    progress: Condvar,
+    /// Maximum number of frames buffered for a lagging branch.
+    capacity: usize,
+}
+
+impl Tee {
+    /// Lock the shared state.
+    ///
+    /// A panicking consumer cannot leave the state inconsistent, so a
+    /// poisoned lock is recovered.
+    fn lock(&self) -> MutexGuard<'_, Shared> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// One of the two consumers of a frame stream split by `tee()`.
+///
+/// Created by [`MultiSignalReader::tee()`] or [`SegmentReader::tee()`].
+/// Each branch is an iterator yielding every frame of the record, in order,
+/// while the frames are decoded only once.
+///
+/// # Buffering
+///
+/// A branch running ahead of the other reads new frames, and keeps a copy
+/// for the lagging branch. Once `capacity` frames are waiting for the
+/// lagging branch, the leading branch blocks until the lagging one catches
+/// up, so memory stays bounded. The branches must therefore be consumed
+/// concurrently (e.g., from two threads, or alternately from one); a branch
+/// that is no longer needed should be dropped, which lets the other read
+/// the rest of the record without buffering.
+///
+/// Read errors are returned by both branches, after which both end.
+pub struct TeeBranch {
+    /// State shared with the other branch.
+    tee: Arc<Tee>,
+    /// Index of this branch (0 or 1).
+    index: usize,
+}
+
+impl TeeBranch {
+    /// Split a frame source into two branches.
+    fn pair(source: FrameSource, capacity: usize) -> (Self, Self) {
+        let tee = Arc::new(Tee {
+            state: Mutex::new(Shared {
+                source,
+                queues: [VecDeque::new(), VecDeque::new()],
+                closed: [false; 2],
+                done: false,
+            }),
+            progress: Condvar::new(),
+            capacity: capacity.max(1),
+        });
+        (
+            Self {
+                tee: Arc::clone(&tee),
+                index: 0,
+            },
+            Self { tee, index: 1 },
+        )
+    }
+
+    /// Get the number of frames read by the other branch but not yet
+    /// returned by this one.
+    #[must_use]
+    pub fn buffered(&self) -> usize {
+        self.tee.lock().queues[self.index].len()
+    }
+}
+
+impl Iterator for TeeBranch {
+    type Item = Result<Vec<Sample>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = 1 - self.index;
+        let mut state = self.tee.lock();
+        loop {
+            if let Some(item) = state.queues[self.index].pop_front() {
+                drop(state);
+                self.tee.progress.notify_all();
+                return Some(item);
+            }
+            if state.done {
+                return None;
+            }
+            if state.closed[other] || state.queues[other].len() < self.tee.capacity {
+                break;
+            }
+            state = self
+                .tee
+                .progress
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+
+        let item = match (state.source)() {
+            Ok(Some(frame)) => Ok(frame),
+            Ok(None) => {
+                state.done = true;
+                return None;
+            }
+            Err(e) => {
+                state.done = true;
+                Err(e)
+            }
+        };
+        if !state.closed[other] {
+            let copy = match &item {
+                Ok(frame) => Ok(frame.clone()),
+                Err(e) => Err(duplicate_error(e)),
+            };
+            state.queues[other].push_back(copy);
+        }
+        drop(state);
+        Some(item)
+    }
+}
+
+impl Drop for TeeBranch {
+    fn drop(&mut self) {
+        {
+            let mut state = self.tee.lock();
+            state.closed[self.index] = true;
+            state.queues[self.index].clear();
+        }
+        self.tee.progress.notify_all();
+    }
+}
+
+/// Copy an error for the other branch.
+fn duplicate_error(error: &Error) -> Error {
+    match error {
+        Error::UnsupportedSignalFormat(format) => Error::UnsupportedSignalFormat(*format),
+        Error::InvalidAnnotationCode(code) => Error::InvalidAnnotationCode(*code),
+        Error::UnsupportedAnnotationFormat(message) => {
+            Error::UnsupportedAnnotationFormat(message.clone())
+        }
+        Error::InvalidPath(message) => Error::InvalidPath(message.clone()),
+        Error::Io(e) => Error::Io(std::io::Error::new(e.kind(), e.to_string())),
+        Error::InvalidHeader(error) => Error::InvalidHeader(error.clone()),
+        Error::SignalFile { path, source } => Error::SignalFile {
+            path: path.clone(),
+            source: std::io::Error::new(source.kind(), source.to_string()),
+        },
+        Error::SignalDecode {
+            file,
+            byte_offset,
+            reason,
+        } => Error::SignalDecode {
+            file: file.clone(),
+            byte_offset: *byte_offset,
+            reason: reason.clone(),
+        },
+        Error::InvalidData(message) => Error::InvalidData(message.clone()),
+        Error::Remote(message) => Error::Remote(message.clone()),
+        Error::Locked(path) => Error::Locked(path.clone()),
+        Error::Cancelled => Error::Cancelled,
+        Error::BudgetExceeded(message) => Error::BudgetExceeded(message.clone()),
+    }
+}
+
+impl MultiSignalReader {
+    /// Split this reader into two branches consuming the same frames.
+    ///
+    /// Each frame is decoded once and returned by both branches. At most
+    /// `capacity` frames (at least 1) are buffered for the branch falling
+    /// behind; see [`TeeBranch`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    /// let (detector, monitor) = record.multi_signal_reader()?.tee(1024);
+    ///
+    /// let handle = std::thread::spawn(move || monitor.filter_map(Result::ok).count());
+    /// for frame in detector {
+    ///     let frame = frame?;
+    ///     // Run the QRS detector...
+    /// }
+    /// let frames_checked = handle.join().unwrap_or_default();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn tee(mut self, capacity: usize) -> (TeeBranch, TeeBranch) {
+        TeeBranch::pair(
+            Box::new(move || {
+                let frame = self.read_frame()?;
+                Ok((!frame.is_empty()).then_some(frame))
+            }),
+            capacity,
+        )
+    }
+}
+
+impl SegmentReader {
+    /// Split this reader into two branches consuming the same frames.
+    ///
+    /// See [`MultiSignalReader::tee()`].
+    #[must_use]
+    pub fn tee(mut self, capacity: usize) -> (TeeBranch, TeeBranch) {
+        TeeBranch::pair(Box::new(move || self.read_frame()), capacity)
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDateTime, TimeDelta};
+
+use crate::{Error, Metadata, Result};
+
+/// Clock of a record, converting sample numbers to times.
+///
+/// Built from the record line of the header with
+/// [`RecordClock::from_metadata`], or obtained with
+/// [`Record::clock()`](crate::Record::clock).
+///
+/// # Examples
+///
+/// ```
+/// use wfdb::Metadata;
+/// use wfdb::record::RecordClock;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let metadata = Metadata::from_record_line("100 2 360 650000 10:30:00 01/06/2020")?;
+/// let clock = RecordClock::from_metadata(&metadata);
+///
+/// let timing = clock.timing(720);
+/// assert_eq!(timing.elapsed, 2.0);
+/// assert_eq!(timing.counter, 720.0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecordClock {
+    /// Sampling frequency (Hz) of the record.
+    pub sampling_frequency: f64,
+    /// Frequency (Hz) of the counter.
+    pub counter_frequency: f64,
+    /// Counter value of the first sample.
+    pub base_counter: f64,
+    /// Date and time of the first sample, when both are in the header.
+    #[cfg(feature = "chrono")]
+    pub base_datetime: Option<NaiveDateTime>,
+}
+
+/// Timing of the first frame of a chunk of frames.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkTiming {
+    /// Sample number of the first frame.
+    pub start_sample: u64,
+    /// Seconds from the start of the record to the first frame.
+    pub elapsed: f64,
+    /// Counter value of the first frame.
+    pub counter: f64,
+    /// Date and time of the first frame, when the record has a base time and
+    /// date.
+    #[cfg(feature = "chrono")]
+    pub datetime: Option<NaiveDateTime>,
+}
+
+/// Consecutive frames together with the timing of the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedFrames<T> {
+    /// Timing of the first frame.
+    pub timing: ChunkTiming,
+    /// Frames, one value per signal each.
+    pub frames: Vec<Vec<T>>,
+}
+
+impl RecordClock {
+    /// Build the clock of a record from its metadata.
+    #[must_use]
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        Self {
+            sampling_frequency: metadata.sampling_frequency(),
+            counter_frequency: metadata.counter_frequency(),
+            base_counter: metadata.base_counter(),
+            #[cfg(feature = "chrono")]
+            base_datetime: metadata.base_datetime(),
+        }
+    }
+
+    /// Get the seconds from the start of the record to a sample.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn elapsed(&self, sample: u64) -> f64 {
+        sample as f64 / self.sampling_frequency
+    }
+
+    /// Get the counter value of a sample.
+    #[must_use]
+    pub fn counter(&self, sample: u64) -> f64 {
+        self.elapsed(sample)
+            .mul_add(self.counter_frequency, self.base_counter)
+    }
+
+    /// Get the date and time of a sample, rounded to the nanosecond.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// Returns `None` if the record has no base time and date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::Metadata;
+    /// use wfdb::record::RecordClock;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let metadata = Metadata::from_record_line("100 2 360 650000 10:30:00 01/06/2020")?;
+    /// let clock = RecordClock::from_metadata(&metadata);
+    ///
+    /// assert_eq!(clock.datetime(720).unwrap().to_string(), "2020-06-01 10:30:02");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn datetime(&self, sample: u64) -> Option<NaiveDateTime> {
+        let nanoseconds = (self.elapsed(sample) * 1e9).round() as i64;
+        self.base_datetime?
+            .checked_add_signed(TimeDelta::nanoseconds(nanoseconds))
+    }
+
+    /// Get the sample nearest to a date and time.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// Returns `None` if the record has no base time and date, or the date
+    /// and time is before the first sample.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn sample_at(&self, datetime: NaiveDateTime) -> Option<u64> {
+        let elapsed = datetime
+            .signed_duration_since(self.base_datetime?)
+            .as_seconds_f64();
+        let sample = (elapsed * self.sampling_frequency).round();
+        (sample >= 0.0).then_some(sample as u64)
+    }
+
+    /// Get the timing of a chunk starting at a sample.
+    #[must_use]
+    pub fn timing(&self, start_sample: u64) -> ChunkTiming {
+        ChunkTiming {
+            start_sample,
+            elapsed: self.elapsed(start_sample),
+            counter: self.counter(start_sample),
+            #[cfg(feature = "chrono")]
+            datetime: self.datetime(start_sample),
+        }
+    }
+}
+
+/// Convert a time in seconds to the nearest sample at a sampling frequency.
+///
+/// # Errors
+///
+/// Returns an error if the time is negative or not finite, or the sample
+/// number does not fit in an `i64` (the range of file offsets).
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn seconds_to_sample(seconds: f64, sampling_frequency: f64) -> Result<u64> {
+    if !seconds.is_finite() || seconds < 0.0 {
+        return Err(Error::InvalidData(format!(
+            "Cannot seek to {seconds} seconds"
+        )));
+    }
+    let sample = (seconds * sampling_frequency).round();
+    if sample.is_nan() || sample >= 2f64.powi(63) {
+        return Err(Error::InvalidData(format!(
+            "Cannot seek to {seconds} seconds: beyond the last addressable sample"
+        )));
+    }
+    Ok(sample as u64)
+}
+
+/// Format an elapsed time (seconds) as `h:mm:ss.mmm`, or `m:ss.mmm` in the
+/// first hour, like `mstimstr()` of the WFDB library.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn elapsed_time(seconds: f64) -> String {
+    let milliseconds = (seconds * 1000.0).round() as u64;
+    let (hours, rest) = (milliseconds / 3_600_000, milliseconds % 3_600_000);
+    let (minutes, rest) = (rest / 60_000, rest % 60_000);
+    let (seconds, milliseconds) = (rest / 1000, rest % 1000);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}.{milliseconds:03}")
+    } else {
+        format!("{minutes}:{seconds:02}.{milliseconds:03}")
+    }
+}
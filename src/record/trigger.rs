@@ -0,0 +1,237 @@
+//! Threshold and slope triggers evaluated while streaming a record.
+//!
+//! A [`TriggerSet`] holds per-channel [`Condition`]s. Frames of physical
+//! values are fed to it one by one (directly with
+//! [`process()`](TriggerSet::process) or from a reader with
+//! [`events()`](TriggerSet::events)), and an [`Event`] is emitted every time
+//! a condition becomes true.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use wfdb::Record;
+//! use wfdb::record::trigger::{Condition, TriggerSet};
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let record = Record::open("data/100")?;
+//! let mut reader = record.multi_signal_reader()?;
+//!
+//! let mut triggers = TriggerSet::new();
+//! let high = triggers.add(0, Condition::RisingAbove(1.5));
+//! triggers.add(1, Condition::SlopeBelow(-0.2));
+//!
+//! for event in triggers.events(&mut reader) {
+//!     let event = event?;
+//!     if event.trigger == high {
+//!         println!("Channel {} above 1.5 mV at sample {}", event.channel, event.sample);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::{MultiSignalReader, Result};
+
+/// Number of frames read at a time by [`Events`].
+const EVENT_CHUNK_SIZE: usize = 1024;
+
+/// Condition evaluated on consecutive samples of one channel.
+///
+/// All values are in physical units. Slopes are differences between
+/// consecutive samples (physical units per sample).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    /// The value crosses the threshold upwards (previous below, current at or above).
+    RisingAbove(f64),
+    /// The value crosses the threshold downwards (previous above, current at or below).
+    FallingBelow(f64),
+    /// The slope reaches or exceeds the limit.
+    SlopeAbove(f64),
+    /// The slope reaches or falls below the limit.
+    SlopeBelow(f64),
+}
+
+impl Condition {
+    /// Check whether the condition holds between two consecutive values.
+    fn holds(self, previous: f64, current: f64) -> bool {
+        match self {
+            Self::RisingAbove(threshold) => previous < threshold && current >= threshold,
+            Self::FallingBelow(threshold) => previous > threshold && current <= threshold,
+            Self::SlopeAbove(limit) => current - previous >= limit,
+            Self::SlopeBelow(limit) => current - previous <= limit,
+        }
+    }
+}
+
+/// An occurrence of a trigger condition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Event {
+    /// Identifier of the trigger, as returned by [`TriggerSet::add`].
+    pub trigger: usize,
+    /// Frame (sample) number at which the condition became true.
+    pub sample: u64,
+    /// Channel (signal index) the trigger watches.
+    pub channel: usize,
+    /// Physical value of the channel at `sample`.
+    pub value: f64,
+}
+
+/// A registered trigger and its evaluation state.
+#[derive(Debug, Clone)]
+struct Trigger {
+    /// Channel (signal index) watched by this trigger.
+    channel: usize,
+    /// Condition to evaluate.
+    condition: Condition,
+    /// Value of the channel in the previous frame.
+    previous: Option<f64>,
+    /// Whether the condition held for the previous frame.
+    active: bool,
+}
+
+/// A set of per-channel trigger conditions.
+///
+/// Each trigger fires once when its condition becomes true and re-arms once
+/// the condition no longer holds, so a sustained steep slope produces a single
+/// event rather than one per sample.
+#[derive(Debug, Clone, Default)]
+pub struct TriggerSet {
+    /// Registered triggers, indexed by trigger identifier.
+    triggers: Vec<Trigger>,
+}
+
+impl TriggerSet {
+    /// Create an empty trigger set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            triggers: Vec::new(),
+        }
+    }
+
+    /// Register a condition on a channel.
+    ///
+    /// Returns the trigger identifier reported in [`Event::trigger`].
+    pub fn add(&mut self, channel: usize, condition: Condition) -> usize {
+        self.triggers.push(Trigger {
+            channel,
+            condition,
+            previous: None,
+            active: false,
+        });
+        self.triggers.len() - 1
+    }
+
+    /// Get the number of registered triggers.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.triggers.len()
+    }
+
+    /// Check if no triggers are registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.triggers.is_empty()
+    }
+
+    /// Forget previous values so the next frame starts a fresh evaluation.
+    ///
+    /// Call this after seeking the underlying reader.
+    pub fn reset(&mut self) {
+        for trigger in &mut self.triggers {
+            trigger.previous = None;
+            trigger.active = false;
+        }
+    }
+
+    /// Evaluate all triggers on one frame of physical values.
+    ///
+    /// `sample` is the frame number reported in the events. Events are
+    /// appended to `events` in trigger registration order. Triggers watching
+    /// a channel missing from `frame` are skipped.
+    pub fn process(&mut self, sample: u64, frame: &[f64], events: &mut Vec<Event>) {
+        for (id, trigger) in self.triggers.iter_mut().enumerate() {
+            let Some(&value) = frame.get(trigger.channel) else {
+                continue;
+            };
+
+            let holds = trigger
+                .previous
+                .is_some_and(|previous| trigger.condition.holds(previous, value));
+            if holds && !trigger.active {
+                events.push(Event {
+                    trigger: id,
+                    sample,
+                    channel: trigger.channel,
+                    value,
+                });
+            }
+
+            trigger.active = holds;
+            trigger.previous = Some(value);
+        }
+    }
+
+    /// Stream the remaining frames of a reader through the triggers.
+    ///
+    /// Frames are read in physical units starting at the reader's current
+    /// position; event sample numbers are reader frame positions.
+    pub const fn events<'a>(&'a mut self, reader: &'a mut MultiSignalReader) -> Events<'a> {
+        Events {
+            triggers: self,
+            reader,
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+/// Iterator over the trigger events of a reader.
+///
+/// Created by [`TriggerSet::events`].
+pub struct Events<'a> {
+    /// Triggers being evaluated.
+    triggers: &'a mut TriggerSet,
+    /// Source of frames.
+    reader: &'a mut MultiSignalReader,
+    /// Events detected but not yet returned.
+    pending: VecDeque<Event>,
+    /// Whether the end of the record (or an error) has been reached.
+    done: bool,
+}
+
+impl Iterator for Events<'_> {
+    type Item = Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.done {
+                return None;
+            }
+
+            let start = self.reader.position();
+            let frames = match self.reader.read_frames_physical(EVENT_CHUNK_SIZE) {
+                Ok(frames) => frames,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            };
+            if frames.is_empty() {
+                self.done = true;
+                continue;
+            }
+
+            let mut detected = Vec::new();
+            for (offset, frame) in (0u64..).zip(&frames) {
+                self.triggers.process(start + offset, frame, &mut detected);
+            }
+            self.pending.extend(detected);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::record::progress::ProgressCounter;
+use crate::record::segment::SegmentManager;
+use crate::record::source::RecordFiles;
+use crate::record::{ReaderOptions, RecordClock};
+use crate::signal::INVALID_SAMPLE;
+use crate::{MultiSignalReader, Result, Sample, SignalInfo};
+
+/// Value counted in checksums for [`INVALID_SAMPLE`] (`WFDB_INVALID_SAMPLE`).
+const CHECKSUM_INVALID_SAMPLE: i16 = i16::MIN;
+
+/// Add a sample to a 16-bit WFDB checksum.
+///
+/// Checksums are the sum of all samples of a signal, modulo 2^16. Invalid
+/// samples count as `-32768`, as in the WFDB library.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn add_to_checksum(checksum: i16, sample: Sample) -> i16 {
+    let value = if sample == INVALID_SAMPLE {
+        CHECKSUM_INVALID_SAMPLE
+    } else {
+        sample as i16
+    };
+    checksum.wrapping_add(value)
+}
+
+/// Checksum of one signal, as computed and as written in the header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumCheck {
+    /// Index of the segment, for multi-segment records.
+    pub segment: Option<usize>,
+    /// Index of the signal (in its segment, for multi-segment records).
+    pub signal_index: usize,
+    /// Description of the signal.
+    pub description: Option<String>,
+    /// Checksum written in the header, if any.
+    pub expected: Option<i16>,
+    /// Checksum of the samples read.
+    pub computed: i16,
+    /// Number of samples read.
+    pub num_samples: u64,
+}
+
+impl ChecksumCheck {
+    /// Check if the computed checksum matches the header.
+    ///
+    /// Signals without a checksum in the header always match.
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        self.expected
+            .is_none_or(|expected| expected == self.computed)
+    }
+}
+
+/// Result of [`Record::validate()`](crate::Record::validate).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Checksum of every signal, in record (and segment) order.
+    pub checksums: Vec<ChecksumCheck>,
+}
+
+impl ValidationReport {
+    /// Check if every checksum matches its header.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.checksums.iter().all(ChecksumCheck::is_match)
+    }
+
+    /// Iterate over the signals whose checksum does not match.
+    pub fn mismatches(&self) -> impl Iterator<Item = &ChecksumCheck> {
+        self.checksums.iter().filter(|check| !check.is_match())
+    }
+}
+
+/// Compute the checksums of the signals of a single-segment record.
+///
+/// Reads `num_samples` frames, or up to the end of the signal files when the
+/// header gives no length. Checksums cover every sample of signals with
+/// several samples per frame.
+pub fn check_signals(
+    files: &RecordFiles,
+    signals: &[SignalInfo],
+    clock: RecordClock,
+    num_samples: Option<u64>,
+    segment: Option<usize>,
+    progress: &mut ProgressCounter<'_>,
+) -> Result<Vec<ChecksumCheck>> {
+    let mut computed = vec![0i16; signals.len()];
+    let mut count = 0;
+
+    if !signals.is_empty() {
+        let mut reader = MultiSignalReader::new(
+            files,
+            signals,
+            num_samples,
+            clock,
+            &ReaderOptions::default(),
+        )?;
+        let mut frame = Vec::with_capacity(signals.len());
+        while reader.read_frame_samples_into(&mut frame)? {
+            for (checksum, samples) in computed.iter_mut().zip(&frame) {
+                for &sample in samples {
+                    *checksum = add_to_checksum(*checksum, sample);
+                }
+            }
+            count += 1;
+            progress.advance(1)?;
+        }
+    }
+
+    Ok(signals
+        .iter()
+        .zip(computed)
+        .enumerate()
+        .map(|(signal_index, (signal, computed))| ChecksumCheck {
+            segment,
+            signal_index,
+            description: signal.description.clone(),
+            expected: signal.checksum,
+            computed,
+            num_samples: count,
+        })
+        .collect())
+}
+
+/// Compute the checksums of the signals of every segment of a record.
+///
+/// Null segments and layout segments have no samples and are skipped.
+pub fn check_segments(
+    segment_manager: &mut SegmentManager,
+    progress: &mut ProgressCounter<'_>,
+) -> Result<Vec<ChecksumCheck>> {
+    let mut checks = Vec::new();
+    for index in 0..segment_manager.num_segments() {
+        if let Some(segment) = segment_manager
+            .segment_info(index)
+            .filter(|segment| segment.is_null_segment() || segment.num_samples == 0)
+        {
+            progress.advance(segment.num_samples)?;
+            continue;
+        }
+        let header = segment_manager.load_segment(index)?.header.clone();
+        checks.extend(check_signals(
+            segment_manager.files(),
+            header.signals().unwrap_or_default(),
+            RecordClock::from_metadata(&header.metadata),
+            header.metadata.num_samples(),
+            Some(index),
+            progress,
+        )?);
+    }
+    Ok(checks)
+}
@@ -0,0 +1,153 @@
+use std::path::Path;
+
+use crate::{Error, Record, Result, SignalInfo};
+
+/// All signals of a record loaded in memory, in physical units.
+///
+/// The quickest way to get at the data of a record: one call opens the
+/// header, reads every signal (across segments, for multi-segment records)
+/// and converts it to physical units. Use [`Record`] and its readers instead
+/// to stream records too large for memory, or to read raw ADC values.
+///
+/// # Examples
+///
+/// ```no_run
+/// use wfdb::WaveformFile;
+///
+/// # fn main() -> wfdb::Result<()> {
+/// let waveform = WaveformFile::load("data/100")?;
+/// println!(
+///     "{} channels at {} Hz, {} s",
+///     waveform.num_channels(),
+///     waveform.sampling_frequency,
+///     waveform.duration()
+/// );
+///
+/// if let Some(mlii) = waveform.signal_by_name("MLII") {
+///     let mean = mlii.iter().sum::<f32>() / mlii.len() as f32;
+///     println!("Mean MLII: {mean} mV");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveformFile {
+    /// Name of the record.
+    pub record_name: String,
+    /// Sampling frequency (Hz) of the record.
+    pub sampling_frequency: f64,
+    /// Signal descriptions (or `None` when omitted in the header), in channel order.
+    pub channel_names: Vec<Option<String>>,
+    /// Units of each channel.
+    pub units: Vec<String>,
+    /// Physical values of each channel, all of the same length. Invalid
+    /// samples are `NaN`.
+    pub signals: Vec<Vec<f32>>,
+}
+
+impl WaveformFile {
+    /// Load every signal of the record at `path` (with or without `.hea`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header or a signal file cannot be read.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_record(&Record::open(path)?)
+    }
+
+    /// Load every signal of an opened record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a signal file or segment header cannot be read.
+    pub fn from_record(record: &Record) -> Result<Self> {
+        let metadata = record.metadata();
+        let (channel_names, units, signals) = if let Some(segments) = record.segment_info() {
+            let signals = segment_signals(record)?;
+            let num_samples = usize::try_from(segments.iter().map(|s| s.num_samples).sum::<u64>())
+                .map_err(|_| Error::InvalidData("Record too long to load".to_string()))?;
+            let columns = (0..metadata.num_signals())
+                .map(|index| {
+                    let mut reader = record.segment_signal_reader(index)?;
+                    Ok(to_f32(&reader.read_physical(num_samples)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            (
+                signals.iter().map(|s| s.description.clone()).collect(),
+                signals.iter().map(|s| s.units().to_string()).collect(),
+                columns,
+            )
+        } else {
+            let matrix = record.read_all_physical()?;
+            let columns = matrix.columns.iter().map(|column| to_f32(column)).collect();
+            (matrix.channel_names, matrix.units, columns)
+        };
+
+        Ok(Self {
+            record_name: metadata.name().to_string(),
+            sampling_frequency: metadata.sampling_frequency(),
+            channel_names,
+            units,
+            signals,
+        })
+    }
+
+    /// Get the number of channels.
+    #[must_use]
+    pub const fn num_channels(&self) -> usize {
+        self.signals.len()
+    }
+
+    /// Get the number of samples per channel.
+    #[must_use]
+    pub fn num_samples(&self) -> usize {
+        self.signals.first().map_or(0, Vec::len)
+    }
+
+    /// Get the duration of the record, in seconds.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn duration(&self) -> f64 {
+        self.num_samples() as f64 / self.sampling_frequency
+    }
+
+    /// Get the values of a channel by index.
+    #[must_use]
+    pub fn signal(&self, channel: usize) -> Option<&[f32]> {
+        self.signals.get(channel).map(Vec::as_slice)
+    }
+
+    /// Get the values of the first channel with the given description.
+    #[must_use]
+    pub fn signal_by_name(&self, name: &str) -> Option<&[f32]> {
+        let channel = self
+            .channel_names
+            .iter()
+            .position(|n| n.as_deref() == Some(name))?;
+        self.signal(channel)
+    }
+}
+
+/// Get the signal specifications of a multi-segment record, from its layout
+/// segment or its first segment with signals.
+pub fn segment_signals(record: &Record) -> Result<Vec<SignalInfo>> {
+    let files = record.files();
+    for segment in record.segment_info().unwrap_or_default() {
+        if segment.is_null_segment() {
+            continue;
+        }
+        let header = files.load_segment_header(&segment.record_name)?;
+        if let Some(signals) = header.signals() {
+            return Ok(signals.to_vec());
+        }
+    }
+    Err(Error::InvalidHeader(
+        "No segment of the record has signals".into(),
+    ))
+}
+
+/// Convert physical values to single precision.
+#[allow(clippy::cast_possible_truncation)]
+fn to_f32(values: &[f64]) -> Vec<f32> {
+    values.iter().map(|&value| value as f32).collect()
+}
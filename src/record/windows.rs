@@ -0,0 +1,271 @@
+//! Fixed-length windows of physical values over a record.
+
+use std::collections::VecDeque;
+
+use crate::record::timing::seconds_to_sample;
+use crate::record::waveform::segment_signals;
+use crate::record::{ReaderOptions, SegmentReader, SignalMatrix};
+use crate::{Error, MultiSignalReader, Record, Result, Sample, SignalInfo};
+
+/// Reader of the frames of a record in physical units, across segments.
+pub enum PhysicalReader {
+    /// Reader for a single-segment record.
+    Single(MultiSignalReader),
+    /// Reader for a multi-segment record.
+    Segmented(SegmentReader),
+}
+
+impl PhysicalReader {
+    /// Open a reader of every signal of a record, returning the signals of
+    /// its frames (those of the layout or first segment of multi-segment
+    /// records).
+    pub fn open(record: &Record) -> Result<(Self, Vec<SignalInfo>)> {
+        let signals = if record.is_multi_segment() {
+            segment_signals(record)?
+        } else {
+            record.signal_info().unwrap_or_default().to_vec()
+        };
+        Ok((
+            Self::with_options(record, &ReaderOptions::default())?,
+            signals,
+        ))
+    }
+
+    /// Open a reader of every signal of a record with custom reader
+    /// options.
+    pub fn with_options(record: &Record, options: &ReaderOptions) -> Result<Self> {
+        if record.is_multi_segment() {
+            Ok(Self::Segmented(
+                record.segment_reader_with_options(options)?,
+            ))
+        } else {
+            Ok(Self::Single(
+                record.multi_signal_reader_with_options(options)?,
+            ))
+        }
+    }
+
+    /// Read the next frame of ADC values, returning `None` at end of record.
+    pub fn read_adc_frame(&mut self) -> Result<Option<Vec<Sample>>> {
+        match self {
+            Self::Single(reader) => {
+                let frame = reader.read_frame()?;
+                Ok((!frame.is_empty()).then_some(frame))
+            }
+            Self::Segmented(reader) => reader.read_frame(),
+        }
+    }
+
+    /// Read the next frame in physical units, returning `None` at end of
+    /// record.
+    pub fn read_frame(&mut self) -> Result<Option<Vec<f64>>> {
+        match self {
+            Self::Single(reader) => {
+                let frame = reader.read_frame()?;
+                Ok((!frame.is_empty()).then(|| reader.frame_to_physical(&frame)))
+            }
+            Self::Segmented(reader) => reader.read_frame_physical(),
+        }
+    }
+
+    /// Move to a frame number, returning it.
+    #[cfg_attr(not(feature = "capi"), allow(dead_code))]
+    pub fn seek_to_frame(&mut self, frame: u64) -> Result<u64> {
+        match self {
+            Self::Single(reader) => reader.seek_to_frame(frame),
+            Self::Segmented(reader) => reader.seek_to_frame(frame),
+        }
+    }
+}
+
+/// Window of a record: the physical values of every signal over a fixed
+/// number of frames.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Window {
+    /// Sample number of the first frame of the window.
+    pub start_sample: u64,
+    /// Values of the window, one column per signal.
+    pub signals: SignalMatrix,
+}
+
+impl Window {
+    /// Get the time of the first frame of the window, in seconds from the
+    /// start of the record.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn start_seconds(&self) -> f64 {
+        self.start_sample as f64 / self.signals.sampling_frequency
+    }
+}
+
+/// Iterator over the windows of a record.
+///
+/// Created by [`Record::windows()`]. Frames are read once, in a single
+/// streaming pass: overlapping windows share the frames kept in a buffer
+/// of one window, and frames between non-overlapping windows are skipped.
+pub struct Windows {
+    /// Reader of the frames.
+    source: PhysicalReader,
+    /// Frames per window.
+    length: usize,
+    /// Frames between the starts of consecutive windows.
+    stride: u64,
+    /// Sampling frequency (Hz) of the record.
+    sampling_frequency: f64,
+    /// Signal descriptions, in channel order.
+    channel_names: Vec<Option<String>>,
+    /// Units of each channel.
+    units: Vec<String>,
+    /// Frames read for the next window.
+    buffer: VecDeque<Vec<f64>>,
+    /// Sample number of the first frame of the buffer.
+    start_sample: u64,
+    /// Frames to skip before filling the buffer.
+    skip: u64,
+    /// Whether the end of the record (or an error) has been reached.
+    done: bool,
+}
+
+impl Windows {
+    /// Get the number of frames per window.
+    #[must_use]
+    pub const fn window_len(&self) -> usize {
+        self.length
+    }
+
+    /// Get the number of frames between the starts of consecutive windows.
+    #[must_use]
+    pub const fn stride(&self) -> u64 {
+        self.stride
+    }
+
+    /// Read the next window, or `None` at the end of the record.
+    fn read_window(&mut self) -> Result<Option<Window>> {
+        while self.skip > 0 {
+            if self.source.read_frame()?.is_none() {
+                return Ok(None);
+            }
+            self.skip -= 1;
+            self.start_sample += 1;
+        }
+        while self.buffer.len() < self.length {
+            match self.source.read_frame()? {
+                Some(frame) => self.buffer.push_back(frame),
+                None => return Ok(None),
+            }
+        }
+
+        let columns = (0..self.channel_names.len())
+            .map(|channel| self.buffer.iter().map(|frame| frame[channel]).collect())
+            .collect();
+        let window = Window {
+            start_sample: self.start_sample,
+            signals: SignalMatrix {
+                sampling_frequency: self.sampling_frequency,
+                channel_names: self.channel_names.clone(),
+                units: self.units.clone(),
+                columns,
+            },
+        };
+
+        // Keep the frames shared with the next window
+        let kept =
+            usize::try_from(self.stride).map_or(0, |stride| self.length.saturating_sub(stride));
+        let dropped = self.buffer.len() - kept;
+        self.buffer.drain(..dropped);
+        self.start_sample += dropped as u64;
+        self.skip = self.stride - dropped as u64;
+        Ok(Some(window))
+    }
+}
+
+impl Iterator for Windows {
+    type Item = Result<Window>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.read_window() {
+            Ok(Some(window)) => Some(Ok(window)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl Record {
+    /// Iterate over windows of `duration` seconds of every signal, starting
+    /// every `stride` seconds, in physical units.
+    ///
+    /// Window lengths and strides are rounded to whole frames. A stride
+    /// shorter than the duration gives overlapping windows, and a longer one
+    /// skips the frames in between. The last frames of the record, too few
+    /// for a full window, are left out.
+    ///
+    /// Windows of multi-segment records run across segment boundaries, with
+    /// the gains of each segment; null segments and signals missing from a
+    /// segment are `NaN`, as are invalid samples. Signals with several
+    /// samples per frame are averaged over the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The duration or stride is less than one frame, or not finite
+    /// - The record's readers cannot be created
+    ///
+    /// Errors reading frames are returned by the iterator, which then ends.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wfdb::Record;
+    ///
+    /// # fn main() -> wfdb::Result<()> {
+    /// let record = Record::open("data/100")?;
+    ///
+    /// // Ten-second epochs with 50% overlap
+    /// for window in record.windows(10.0, 5.0)? {
+    ///     let window = window?;
+    ///     let mlii = window.signals.column_by_name("MLII").unwrap();
+    ///     let peak = mlii.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    ///     println!("{:.1} s: {peak} mV", window.start_seconds());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn windows(&self, duration: f64, stride: f64) -> Result<Windows> {
+        let sampling_frequency = self.metadata().sampling_frequency();
+        let length = seconds_to_sample(duration, sampling_frequency)?;
+        let step = seconds_to_sample(stride, sampling_frequency)?;
+        if length == 0 || step == 0 {
+            return Err(Error::InvalidData(format!(
+                "Windows must last and move at least one frame, got {duration} s every {stride} s"
+            )));
+        }
+        let length = usize::try_from(length)
+            .map_err(|_| Error::InvalidData(format!("Window of {length} frames too long")))?;
+
+        let (source, signals) = PhysicalReader::open(self)?;
+
+        Ok(Windows {
+            source,
+            length,
+            stride: step,
+            sampling_frequency,
+            channel_names: signals.iter().map(|s| s.description.clone()).collect(),
+            units: signals.iter().map(|s| s.units().to_string()).collect(),
+            // Grown as frames are read: the window may be longer than the record
+            buffer: VecDeque::new(),
+            start_sample: 0,
+            skip: 0,
+            done: false,
+        })
+    }
+}
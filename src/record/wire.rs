@@ -0,0 +1,448 @@
+//! Compact binary serialization of frame chunks.
+//!
+//! This module defines a small, self-describing wire format intended for
+//! streaming live or replayed records to other processes (e.g., browser
+//! clients over a WebSocket). A [`Chunk`] holds a run of consecutive frames
+//! and is encoded into a single binary message.
+//!
+//! # Wire format (version 1)
+//!
+//! The wire format is part of the crate's stable API: messages encoded by one
+//! version of this crate can be decoded by any later version. All multi-byte
+//! values are little-endian.
+//!
+//! | Offset | Size | Field                                             |
+//! |--------|------|---------------------------------------------------|
+//! | 0      | 4    | Magic bytes `WFCH`                                |
+//! | 4      | 1    | Format version (`1`)                              |
+//! | 5      | 1    | Payload encoding (see [`Encoding`])               |
+//! | 6      | 2    | Number of channels (`u16`)                        |
+//! | 8      | 8    | Sampling frequency in Hz (`f64`)                  |
+//! | 16     | 8    | Frame number of the first frame (`u64`)           |
+//! | 24     | 4    | Number of frames (`u32`)                          |
+//! | 28     | ...  | Payload                                           |
+//!
+//! The payload stores frames in order, each frame holding one value per
+//! channel:
+//!
+//! - [`Encoding::I16`] (`0`): one `i16` per value. [`INVALID_SAMPLE`] is
+//!   encoded as `i16::MIN`.
+//! - [`Encoding::Delta`] (`1`): for each value, the difference to the previous
+//!   value of the same channel (the first frame is relative to zero), as a
+//!   zigzag-encoded LEB128 varint. Differences wrap around in `i32`, so every
+//!   sample value round-trips exactly.
+//! - [`Encoding::F32`] (`2`): one `f32` per physical value.
+//!
+//! # Examples
+//!
+//! ```
+//! use wfdb::record::wire::{Chunk, Encoding};
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let frames = vec![vec![100, -20], vec![101, -21], vec![103, -19]];
+//! let chunk = Chunk::from_frames(&frames, 360.0, 0)?;
+//!
+//! let bytes = chunk.encode(Encoding::Delta)?;
+//! let decoded = Chunk::decode(&bytes)?;
+//! assert_eq!(decoded, chunk);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::signal::INVALID_SAMPLE;
+use crate::{Error, Result, Sample};
+
+/// Magic bytes at the start of every message.
+pub const MAGIC: [u8; 4] = *b"WFCH";
+
+/// Current version of the wire format.
+pub const VERSION: u8 = 1;
+
+/// Size in bytes of the fixed message header.
+pub const HEADER_SIZE: usize = 28;
+
+/// Encoding of the payload values in a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Raw ADC values as 16-bit integers.
+    I16,
+    /// Raw ADC values as zigzag varint differences per channel.
+    Delta,
+    /// Physical values as 32-bit floats.
+    F32,
+}
+
+impl TryFrom<u8> for Encoding {
+    type Error = Error;
+
+    /// Converts an encoding code to an `Encoding` enum.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoding code is unknown.
+    fn try_from(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(Self::I16),
+            1 => Ok(Self::Delta),
+            2 => Ok(Self::F32),
+            _ => Err(Error::InvalidData(format!(
+                "Unknown wire payload encoding: {code}"
+            ))),
+        }
+    }
+}
+
+impl From<Encoding> for u8 {
+    /// Converts an `Encoding` enum to its encoding code.
+    fn from(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::I16 => 0,
+            Encoding::Delta => 1,
+            Encoding::F32 => 2,
+        }
+    }
+}
+
+/// Values carried by a [`Chunk`], stored frame by frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// Raw ADC values.
+    Adc(Vec<Sample>),
+    /// Physical values.
+    Physical(Vec<f32>),
+}
+
+impl Payload {
+    /// Get the total number of values.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Adc(values) => values.len(),
+            Self::Physical(values) => values.len(),
+        }
+    }
+
+    /// Check if the payload contains no values.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A run of consecutive frames with the timing information needed to place
+/// them in a record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk {
+    /// Sampling frequency (Hz) of the frames.
+    pub sampling_frequency: f64,
+    /// Frame number of the first frame in the chunk.
+    pub start_frame: u64,
+    /// Number of channels (values per frame).
+    pub num_channels: u16,
+    /// Frame values, `num_channels` per frame.
+    pub payload: Payload,
+}
+
+impl Chunk {
+    // [Constructors]
+
+    /// Build a chunk of raw ADC values from frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frames have different lengths or more than
+    /// `u16::MAX` channels.
+    pub fn from_frames(
+        frames: &[Vec<Sample>],
+        sampling_frequency: f64,
+        start_frame: u64,
+    ) -> Result<Self> {
+        let num_channels = Self::channel_count(frames)?;
+        Ok(Self {
+            sampling_frequency,
+            start_frame,
+            num_channels,
+            payload: Payload::Adc(frames.concat()),
+        })
+    }
+
+    /// Build a chunk of physical values from frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frames have different lengths or more than
+    /// `u16::MAX` channels.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_physical_frames(
+        frames: &[Vec<f64>],
+        sampling_frequency: f64,
+        start_frame: u64,
+    ) -> Result<Self> {
+        let num_channels = Self::channel_count(frames)?;
+        Ok(Self {
+            sampling_frequency,
+            start_frame,
+            num_channels,
+            payload: Payload::Physical(frames.iter().flatten().map(|&v| v as f32).collect()),
+        })
+    }
+
+    /// Check that all frames have the same number of channels.
+    fn channel_count<T>(frames: &[Vec<T>]) -> Result<u16> {
+        let num_channels = frames.first().map_or(0, Vec::len);
+        if frames.iter().any(|frame| frame.len() != num_channels) {
+            return Err(Error::InvalidData(
+                "All frames in a chunk must have the same number of channels".to_string(),
+            ));
+        }
+        u16::try_from(num_channels).map_err(|_| {
+            Error::InvalidData(format!(
+                "Too many channels for a wire chunk: {num_channels}"
+            ))
+        })
+    }
+
+    // [Accessors]
+
+    /// Get the number of frames in the chunk.
+    #[must_use]
+    pub const fn num_frames(&self) -> usize {
+        if self.num_channels == 0 {
+            0
+        } else {
+            self.payload.len() / self.num_channels as usize
+        }
+    }
+
+    // [Encoding]
+
+    /// Encode the chunk into a binary message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The encoding does not match the payload (`F32` needs physical values,
+    ///   `I16` and `Delta` need ADC values)
+    /// - An ADC value does not fit in 16 bits with `I16` encoding
+    /// - The chunk has more than `u32::MAX` frames
+    /// - The payload is not a whole number of frames of `num_channels`
+    ///   values
+    pub fn encode(&self, encoding: Encoding) -> Result<Vec<u8>> {
+        let num_channels = usize::from(self.num_channels);
+        // Without channels, only an empty payload is a whole number of frames
+        if !self.payload.len().is_multiple_of(num_channels) {
+            return Err(Error::InvalidData(format!(
+                "Payload of {} values is not a whole number of frames of {num_channels} channels",
+                self.payload.len()
+            )));
+        }
+        let num_frames = u32::try_from(self.num_frames()).map_err(|_| {
+            Error::InvalidData(format!(
+                "Too many frames for a wire chunk: {}",
+                self.num_frames()
+            ))
+        })?;
+
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.payload.len() * 2);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        bytes.push(u8::from(encoding));
+        bytes.extend_from_slice(&self.num_channels.to_le_bytes());
+        bytes.extend_from_slice(&self.sampling_frequency.to_le_bytes());
+        bytes.extend_from_slice(&self.start_frame.to_le_bytes());
+        bytes.extend_from_slice(&num_frames.to_le_bytes());
+
+        match (encoding, &self.payload) {
+            (Encoding::I16, Payload::Adc(values)) => {
+                for &value in values {
+                    let value = if value == INVALID_SAMPLE {
+                        i16::MIN
+                    } else {
+                        i16::try_from(value).map_err(|_| {
+                            Error::InvalidData(format!(
+                                "Sample {value} does not fit in 16-bit wire encoding"
+                            ))
+                        })?
+                    };
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            (Encoding::Delta, Payload::Adc(values)) => {
+                let mut previous = vec![0 as Sample; usize::from(self.num_channels)];
+                for (i, &value) in values.iter().enumerate() {
+                    let channel = i % usize::from(self.num_channels);
+                    write_varint(&mut bytes, zigzag(value.wrapping_sub(previous[channel])));
+                    previous[channel] = value;
+                }
+            }
+            (Encoding::F32, Payload::Physical(values)) => {
+                for &value in values {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            (encoding, _) => {
+                return Err(Error::InvalidData(format!(
+                    "Encoding {encoding:?} does not match the chunk payload"
+                )));
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    /// Decode a chunk from a binary message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is truncated, has an unknown magic,
+    /// version or encoding, declares more values than can be addressed, or
+    /// contains trailing bytes.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(Error::InvalidData(format!(
+                "Wire chunk too short: {} bytes",
+                bytes.len()
+            )));
+        }
+        if bytes[0..4] != MAGIC {
+            return Err(Error::InvalidData(
+                "Invalid wire chunk magic bytes".to_string(),
+            ));
+        }
+        if bytes[4] != VERSION {
+            return Err(Error::InvalidData(format!(
+                "Unsupported wire format version: {}",
+                bytes[4]
+            )));
+        }
+
+        let encoding = Encoding::try_from(bytes[5])?;
+        let num_channels = u16::from_le_bytes([bytes[6], bytes[7]]);
+        let sampling_frequency = f64::from_le_bytes(read_array(&bytes[8..16]));
+        let start_frame = u64::from_le_bytes(read_array(&bytes[16..24]));
+        let num_frames = u32::from_le_bytes(read_array(&bytes[24..28]));
+        let num_values = usize::try_from(num_frames)
+            .ok()
+            .and_then(|frames| frames.checked_mul(usize::from(num_channels)))
+            .ok_or_else(|| Self::too_large(num_channels, num_frames))?;
+        let payload_bytes = &bytes[HEADER_SIZE..];
+
+        let payload = match encoding {
+            Encoding::I16 => {
+                let expected = num_values
+                    .checked_mul(2)
+                    .ok_or_else(|| Self::too_large(num_channels, num_frames))?;
+                Self::check_payload_size(payload_bytes, expected)?;
+                Payload::Adc(
+                    payload_bytes
+                        .chunks_exact(2)
+                        .map(|b| match i16::from_le_bytes([b[0], b[1]]) {
+                            i16::MIN => INVALID_SAMPLE,
+                            value => Sample::from(value),
+                        })
+                        .collect(),
+                )
+            }
+            Encoding::Delta => {
+                // Each value takes at least one byte, so a truncated payload
+                // cannot claim more values than it has bytes
+                let mut values = Vec::with_capacity(num_values.min(payload_bytes.len()));
+                let mut previous = vec![0 as Sample; usize::from(num_channels)];
+                let mut position = 0;
+                for i in 0..num_values {
+                    let channel = i % usize::from(num_channels);
+                    let delta = unzigzag(read_varint(payload_bytes, &mut position)?);
+                    previous[channel] = previous[channel].wrapping_add(delta);
+                    values.push(previous[channel]);
+                }
+                Self::check_payload_size(payload_bytes, position)?;
+                Payload::Adc(values)
+            }
+            Encoding::F32 => {
+                let expected = num_values
+                    .checked_mul(4)
+                    .ok_or_else(|| Self::too_large(num_channels, num_frames))?;
+                Self::check_payload_size(payload_bytes, expected)?;
+                Payload::Physical(
+                    payload_bytes
+                        .chunks_exact(4)
+                        .map(|b| f32::from_le_bytes(read_array(b)))
+                        .collect(),
+                )
+            }
+        };
+
+        Ok(Self {
+            sampling_frequency,
+            start_frame,
+            num_channels,
+            payload,
+        })
+    }
+
+    /// Build the error of a chunk whose size does not fit in memory.
+    fn too_large(num_channels: u16, num_frames: u32) -> Error {
+        Error::InvalidData(format!(
+            "Wire chunk of {num_frames} frames of {num_channels} channels is too large"
+        ))
+    }
+
+    /// Check that the payload has exactly the expected size.
+    fn check_payload_size(payload: &[u8], expected: usize) -> Result<()> {
+        if payload.len() == expected {
+            Ok(())
+        } else {
+            Err(Error::InvalidData(format!(
+                "Wire chunk payload has {} bytes, expected {expected}",
+                payload.len()
+            )))
+        }
+    }
+}
+
+/// Copy a slice of known length into a fixed-size array.
+fn read_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut array = [0u8; N];
+    array.copy_from_slice(&bytes[..N]);
+    array
+}
+
+/// Map a signed value to unsigned so that small magnitudes stay small.
+#[allow(clippy::cast_sign_loss)]
+const fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Inverse of [`zigzag`].
+#[allow(clippy::cast_possible_wrap)]
+const fn unzigzag(value: u32) -> i32 {
+    ((value >> 1) as i32) ^ -((value & 1) as i32)
+}
+
+/// Append an unsigned LEB128 varint.
+#[allow(clippy::cast_possible_truncation)]
+fn write_varint(bytes: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Read an unsigned LEB128 varint starting at `position`.
+fn read_varint(bytes: &[u8], position: &mut usize) -> Result<u32> {
+    let mut value = 0u32;
+    for shift in (0..35).step_by(7) {
+        let byte = *bytes.get(*position).ok_or_else(|| {
+            Error::InvalidData("Truncated varint in wire chunk payload".to_string())
+        })?;
+        *position += 1;
+        value |= u32::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(Error::InvalidData(
+        "Varint too long in wire chunk payload".to_string(),
+    ))
+}
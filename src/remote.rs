@@ -0,0 +1,389 @@
+//! Reading records from remote sources.
+//!
+//! This module is only available with the `remote` feature. Records are
+//! opened from a URL with [`Record::open_url`](crate::Record::open_url),
+//! which downloads the record files into a local cache directory.
+//!
+//! Every HTTP request made by this crate is bounded by a [`RemoteOptions`]:
+//! the number of redirects followed, the size of the response body, the time
+//! spent on a single attempt and the number of attempts. Failed attempts
+//! caused by transient errors (timeouts, dropped connections, `429` and `5xx`
+//! responses) are retried with exponential backoff and random jitter, so that
+//! services embedding remote reads fail predictably instead of hanging.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use wfdb::Record;
+//! use wfdb::remote::RemoteOptions;
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let options = RemoteOptions {
+//!     timeout: Some(Duration::from_secs(30)),
+//!     max_retries: 1,
+//!     ..RemoteOptions::default()
+//! };
+//! let record = Record::open_url_with_options(
+//!     "https://physionet.org/files/mitdb/1.0.0/100",
+//!     "target/wfdb-cache",
+//!     &options,
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeSet;
+use std::collections::hash_map::RandomState;
+use std::fs::File;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{Error, Header, Result};
+
+/// Default maximum number of redirects followed per request.
+pub const DEFAULT_MAX_REDIRECTS: u32 = 5;
+
+/// Default maximum size (bytes) of a response body (1 GiB).
+pub const DEFAULT_MAX_BYTES: u64 = 1 << 30;
+
+/// Default time limit of a single request attempt.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_mins(5);
+
+/// Default number of retries after a failed attempt.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default delay before the first retry.
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Limits and retry policy applied to remote requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteOptions {
+    /// Maximum number of redirects followed per request.
+    ///
+    /// Exceeding it fails the request. `0` disables redirects.
+    pub max_redirects: u32,
+    /// Maximum size (bytes) of a response body.
+    ///
+    /// Larger responses fail without being retried.
+    pub max_bytes: u64,
+    /// Time limit of a single attempt, from connecting to reading the last
+    /// byte of the body (`None` for no limit).
+    pub timeout: Option<Duration>,
+    /// Number of retries after a transient failure (`0` for a single attempt).
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    ///
+    /// The delay doubles after each retry, and each wait is drawn uniformly
+    /// between half and the full delay to spread out concurrent clients.
+    pub retry_delay: Duration,
+}
+
+impl Default for RemoteOptions {
+    fn default() -> Self {
+        Self {
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            max_bytes: DEFAULT_MAX_BYTES,
+            timeout: Some(DEFAULT_TIMEOUT),
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: DEFAULT_RETRY_DELAY,
+        }
+    }
+}
+
+impl RemoteOptions {
+    /// Get the wait before retry number `retry` (starting at 0), with jitter.
+    fn backoff(&self, retry: u32) -> Duration {
+        let delay = self
+            .retry_delay
+            .saturating_mul(2u32.saturating_pow(retry.min(16)));
+        // Uniform in [delay / 2, delay]
+        let jitter = RandomState::new().build_hasher().finish() % 1024;
+        delay / 2 + delay / 2 * u32::try_from(jitter).unwrap_or(0) / 1023
+    }
+}
+
+/// HTTP client enforcing a [`RemoteOptions`].
+pub(crate) struct HttpClient<'a> {
+    agent: ureq::Agent,
+    options: &'a RemoteOptions,
+}
+
+impl<'a> HttpClient<'a> {
+    /// Create a client applying the given options.
+    pub(crate) fn new(options: &'a RemoteOptions) -> Self {
+        let config = ureq::Agent::config_builder()
+            .max_redirects(options.max_redirects)
+            .timeout_global(options.timeout)
+            .build();
+        Self {
+            agent: config.into(),
+            options,
+        }
+    }
+
+    /// Download a URL into memory as text.
+    #[cfg_attr(not(feature = "fetch-examples"), allow(dead_code))]
+    pub(crate) fn get_string(&self, url: &str) -> Result<String> {
+        self.get_with(url, |reader| {
+            let mut text = String::new();
+            reader.read_to_string(&mut text)?;
+            Ok(text)
+        })
+    }
+
+    /// Download a URL, passing the size-limited response body to `consume`.
+    ///
+    /// `consume` is called again from scratch on each retry, so it must not
+    /// depend on state left over by a failed attempt.
+    #[cfg_attr(not(feature = "fetch-examples"), allow(dead_code))]
+    pub(crate) fn get_with<T, F>(&self, url: &str, consume: F) -> Result<T>
+    where
+        F: FnMut(&mut dyn Read) -> std::io::Result<T>,
+    {
+        self.get_optional_with(url, consume)?.ok_or_else(|| {
+            Error::Remote(format!(
+                "Failed to fetch '{url}': {}",
+                ureq::Error::StatusCode(404)
+            ))
+        })
+    }
+
+    /// Download a URL like [`get_with`](Self::get_with), returning `None`
+    /// if the server answers `404 Not Found`.
+    pub(crate) fn get_optional_with<T, F>(&self, url: &str, mut consume: F) -> Result<Option<T>>
+    where
+        F: FnMut(&mut dyn Read) -> std::io::Result<T>,
+    {
+        let mut retry = 0;
+        loop {
+            let error = match self.attempt(url, &mut consume) {
+                Ok(value) => return Ok(Some(value)),
+                Err(ureq::Error::StatusCode(404)) => return Ok(None),
+                Err(error) => error,
+            };
+
+            if retry >= self.options.max_retries || !is_transient(&error) {
+                let attempts = retry + 1;
+                return Err(Error::Remote(match error {
+                    ureq::Error::BodyExceedsLimit(limit) => {
+                        format!("Response from '{url}' exceeds the limit of {limit} bytes")
+                    }
+                    _ if attempts > 1 => {
+                        format!("Failed to fetch '{url}' after {attempts} attempts: {error}")
+                    }
+                    _ => format!("Failed to fetch '{url}': {error}"),
+                }));
+            }
+
+            std::thread::sleep(self.options.backoff(retry));
+            retry += 1;
+        }
+    }
+
+    /// Perform a single request attempt.
+    fn attempt<T, F>(&self, url: &str, consume: &mut F) -> std::result::Result<T, ureq::Error>
+    where
+        F: FnMut(&mut dyn Read) -> std::io::Result<T>,
+    {
+        let response = self.agent.get(url).call()?;
+        let mut reader = response
+            .into_body()
+            .into_with_config()
+            .limit(self.options.max_bytes)
+            .reader();
+        consume(&mut reader).map_err(ureq::Error::from)
+    }
+}
+
+/// Check whether a failed attempt is worth retrying.
+const fn is_transient(error: &ureq::Error) -> bool {
+    match error {
+        ureq::Error::StatusCode(code) => *code == 429 || *code >= 500,
+        ureq::Error::Io(_)
+        | ureq::Error::Timeout(_)
+        | ureq::Error::HostNotFound
+        | ureq::Error::ConnectionFailed => true,
+        _ => false,
+    }
+}
+
+// [Remote records]
+
+/// Environment variable overriding the default cache directory of
+/// [`Record::open_url`](crate::Record::open_url).
+pub const CACHE_DIR_ENV: &str = "WFDB_CACHE";
+
+/// Name of the annotator listing published with `PhysioNet` databases.
+const ANNOTATORS_FILE: &str = "ANNOTATORS";
+
+/// Get the default cache directory of remote records.
+///
+/// This is the directory named by the `WFDB_CACHE` environment variable, or
+/// `wfdb-cache` in the system temporary directory.
+#[must_use]
+pub fn default_cache_dir() -> PathBuf {
+    std::env::var_os(CACHE_DIR_ENV)
+        .map_or_else(|| std::env::temp_dir().join("wfdb-cache"), PathBuf::from)
+}
+
+/// Download a remote record into a cache directory.
+///
+/// `url` is the URL of the record without extension (a trailing `.hea` is
+/// accepted). The header, the signal files it names (and, for multi-segment
+/// records, the segment headers and their signal files) are downloaded, as
+/// well as the annotation files of the annotators listed in the database's
+/// `ANNOTATORS` file, when the server has them. Files already in the cache
+/// are not downloaded again.
+///
+/// Returns the local path of the record (without extension).
+pub(crate) fn fetch_record(
+    url: &str,
+    cache_dir: &Path,
+    options: &RemoteOptions,
+) -> Result<PathBuf> {
+    let url = url.strip_suffix(".hea").unwrap_or(url);
+    let (base_url, record_name) = url
+        .rsplit_once('/')
+        .filter(|(base_url, name)| base_url.contains("://") && !name.is_empty())
+        .ok_or_else(|| {
+            Error::InvalidPath(format!(
+                "Invalid record URL '{url}', expected 'scheme://host/path/record'"
+            ))
+        })?;
+    check_file_name(record_name)?;
+
+    let target_dir = cache_dir.join(cache_subdir(base_url));
+    std::fs::create_dir_all(&target_dir)?;
+    let client = HttpClient::new(options);
+    let fetch = |file_name: &str| fetch_file(&client, base_url, file_name, &target_dir);
+
+    let header = fetch_header(&client, base_url, record_name, &target_dir)?;
+    let mut file_names = signal_files(&header)?;
+    if let Some(segments) = header.segments() {
+        for segment in segments.iter().filter(|s| !s.is_null_segment()) {
+            let segment_header =
+                fetch_header(&client, base_url, &segment.record_name, &target_dir)?;
+            file_names.extend(signal_files(&segment_header)?);
+        }
+    }
+    for file_name in &file_names {
+        if !fetch(file_name)? {
+            return Err(Error::Remote(format!(
+                "Signal file '{base_url}/{file_name}' not found"
+            )));
+        }
+    }
+
+    // Annotation files are optional: fetch those the server has
+    let annotators = client
+        .get_optional_with(&format!("{base_url}/{ANNOTATORS_FILE}"), |reader| {
+            let mut text = String::new();
+            reader.read_to_string(&mut text)?;
+            Ok(text)
+        })?
+        .unwrap_or_default();
+    for annotator in annotators
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+    {
+        let file_name = format!("{record_name}.{annotator}");
+        if check_file_name(&file_name).is_ok() {
+            fetch(&file_name)?;
+        }
+    }
+
+    Ok(target_dir.join(record_name))
+}
+
+/// Fetch and parse the header of a record.
+fn fetch_header(
+    client: &HttpClient,
+    base_url: &str,
+    record_name: &str,
+    target_dir: &Path,
+) -> Result<Header> {
+    check_file_name(record_name)?;
+    let file_name = format!("{record_name}.hea");
+    if !fetch_file(client, base_url, &file_name, target_dir)? {
+        return Err(Error::Remote(format!(
+            "Header '{base_url}/{file_name}' not found"
+        )));
+    }
+    let file = File::open(target_dir.join(file_name))?;
+    Header::from_reader(&mut BufReader::new(file))
+}
+
+/// Get the distinct signal file names of a header.
+fn signal_files(header: &Header) -> Result<BTreeSet<String>> {
+    let signals = header.signals().unwrap_or_default();
+    let file_names: BTreeSet<String> = signals
+        .iter()
+        .map(|signal| signal.file_name.clone())
+        .filter(|file_name| file_name != "~")
+        .collect();
+    for file_name in &file_names {
+        check_file_name(file_name)?;
+    }
+    Ok(file_names)
+}
+
+/// Download a file into the cache, unless already cached.
+///
+/// Returns `false` if the server does not have the file. Files are written
+/// under a temporary name and renamed once complete, so an interrupted
+/// download never leaves a truncated file in the cache.
+fn fetch_file(
+    client: &HttpClient,
+    base_url: &str,
+    file_name: &str,
+    target_dir: &Path,
+) -> Result<bool> {
+    let path = target_dir.join(file_name);
+    if path.exists() {
+        return Ok(true);
+    }
+
+    let partial = target_dir.join(format!("{file_name}.part"));
+    let found = client
+        .get_optional_with(&format!("{base_url}/{file_name}"), |reader| {
+            let mut writer = BufWriter::new(File::create(&partial)?);
+            std::io::copy(reader, &mut writer)?;
+            writer.flush()
+        })?
+        .is_some();
+    if found {
+        std::fs::rename(&partial, &path)?;
+    }
+    Ok(found)
+}
+
+/// Reject file names that would escape the cache directory.
+fn check_file_name(file_name: &str) -> Result<()> {
+    if file_name.is_empty()
+        || file_name == "."
+        || file_name == ".."
+        || file_name.contains(['/', '\\'])
+    {
+        return Err(Error::InvalidPath(format!(
+            "Invalid remote file name '{file_name}'"
+        )));
+    }
+    Ok(())
+}
+
+/// Get the cache subdirectory of a base URL (`host/path`).
+fn cache_subdir(base_url: &str) -> PathBuf {
+    let location = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, location)| location);
+    let location = location.split(['?', '#']).next().unwrap_or_default();
+    location
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != "." && *part != "..")
+        .map(|part| part.replace(':', "_"))
+        .collect()
+}
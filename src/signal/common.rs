@@ -1,7 +1,7 @@
 //! Common traits and types for signal format decoders and encoders.
 
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Invalid sample marker used by WFDB library.
 ///
@@ -111,6 +111,44 @@ pub trait FormatDecoder: Send {
     /// to a new position in the input stream.
     fn reset(&mut self);
 
+    /// Reset the decoder to its state at the start of the signal file.
+    ///
+    /// Differs from [`reset()`](FormatDecoder::reset) only for difference
+    /// formats, which restart from the initial values of their signals.
+    fn rewind(&mut self) {
+        self.reset();
+    }
+
+    /// Whether each sample depends on the samples before it in the file.
+    ///
+    /// Decoding such formats (first differences) can only start at the
+    /// beginning of the file, so seeking rewinds and decodes through the
+    /// samples before the target.
+    fn is_differential(&self) -> bool {
+        false
+    }
+
+    /// Decode and discard up to `count` samples, returning the number of
+    /// samples skipped (fewer only at the end of the input).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input data is malformed or I/O fails.
+    fn skip(&mut self, reader: &mut dyn BufRead, count: u64) -> Result<u64> {
+        let mut scratch = vec![0; usize::try_from(count).unwrap_or(usize::MAX).min(4096)];
+        let mut skipped = 0;
+        while skipped < count {
+            let len =
+                usize::try_from(count - skipped).map_or(scratch.len(), |n| n.min(scratch.len()));
+            let n = self.decode_buf(reader, &mut scratch[..len])?;
+            skipped += n as u64;
+            if n < len {
+                break;
+            }
+        }
+        Ok(skipped)
+    }
+
     /// Get the number of bytes required to decode one sample.
     ///
     /// Returns `None` for variable-size formats or formats where the size
@@ -166,6 +204,85 @@ pub trait FormatDecoder: Send {
     }
 }
 
+/// Trait for encoding WFDB signal data to a byte stream.
+///
+/// Format encoders are the inverse of [`FormatDecoder`]s: they convert
+/// `Sample` values to raw bytes according to the WFDB format specification
+/// and write them to a `Write` sink. Decoding the output with the matching
+/// decoder yields the encoded samples.
+///
+/// Samples outside the range of the format are clamped to it, and
+/// [`INVALID_SAMPLE`] is written as the format's invalid sample marker.
+///
+/// # Examples
+///
+/// ```
+/// # use wfdb::signal::{Format212Decoder, Format212Encoder, FormatDecoder, FormatEncoder};
+/// # use std::io::Cursor;
+/// # fn main() -> wfdb::Result<()> {
+/// let mut encoder = Format212Encoder::new();
+/// let mut bytes = Vec::new();
+/// encoder.encode(&mut bytes, &[1, -1, 2047])?;
+/// encoder.finish(&mut bytes)?;
+///
+/// let samples = Format212Decoder::new().decode(&mut Cursor::new(bytes), 3)?;
+/// assert_eq!(samples, vec![1, -1, 2047]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait FormatEncoder: Send {
+    /// Encode samples and write them to `writer`.
+    ///
+    /// Formats packing several samples together buffer an incomplete group
+    /// until the next call completes it or [`finish()`](FormatEncoder::finish)
+    /// writes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()>;
+
+    /// Write any buffered samples, then reset the encoder.
+    ///
+    /// An incomplete group is written as the shortest byte sequence the
+    /// matching decoder reads it from, with unused bits set to zero. Formats
+    /// 310 and 311 cannot always end mid-group, so decoding their output may
+    /// yield up to two extra zero samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    fn finish(&mut self, writer: &mut dyn Write) -> Result<()> {
+        let _ = writer;
+        self.reset();
+        Ok(())
+    }
+
+    /// Reset the encoder to its initial state, discarding buffered samples.
+    fn reset(&mut self);
+}
+
+/// Clamp a sample to the valid range of a `bits`-wide two's complement
+/// format, keeping [`INVALID_SAMPLE`] unchanged.
+///
+/// The most negative value of the format is excluded since it is reserved
+/// as the invalid sample marker.
+#[inline]
+#[must_use]
+pub const fn clamp_sample(sample: Sample, bits: u32) -> Sample {
+    if sample == INVALID_SAMPLE || bits >= 32 {
+        return sample;
+    }
+    let max = (1 << (bits - 1)) - 1;
+    if sample > max {
+        max
+    } else if sample < -max {
+        -max
+    } else {
+        sample
+    }
+}
+
 /// Sign-extend a value from a specific bit position.
 ///
 /// # Examples
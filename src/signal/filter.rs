@@ -0,0 +1,383 @@
+//! Streaming digital filters for physical values.
+//!
+//! Filters process one value at a time and keep their state between calls,
+//! so a signal can be filtered chunk by chunk while it is read. The usual
+//! ECG cleanup steps are provided ready-made:
+//!
+//! - [`baseline_wander()`]: 0.5 Hz high-pass removing respiration and
+//!   electrode drift
+//! - [`powerline_notch()`]: 50 or 60 Hz notch removing mains interference
+//! - [`band_pass()`]: high-pass and low-pass in cascade
+//!
+//! Other filters are built from [`Biquad`] (second-order IIR sections, from
+//! the Audio EQ Cookbook) and [`Fir`], and combined with [`FilterChain`].
+//!
+//! `NaN` values (invalid samples) pass through unchanged without disturbing
+//! the filter state. To avoid a long transient from the signal's DC offset,
+//! filters start in the steady state of their first value.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use wfdb::Record;
+//! use wfdb::signal::filter::{self, FilterChain};
+//!
+//! # fn main() -> wfdb::Result<()> {
+//! let record = Record::open("data/100")?;
+//! let fs = record.metadata().sampling_frequency();
+//! let mut reader = record.signal_reader(0)?.with_filter(
+//!     FilterChain::new()
+//!         .then(filter::baseline_wander(fs)?)
+//!         .then(filter::powerline_notch(fs, 60.0)?),
+//! );
+//!
+//! let cleaned = reader.read_physical(3600)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use crate::{Error, Result};
+
+/// Cutoff frequency (Hz) of [`baseline_wander()`].
+pub const BASELINE_WANDER_CUTOFF: f64 = 0.5;
+
+/// Quality factor of [`powerline_notch()`] (bandwidth of about 2 Hz at 60 Hz).
+pub const POWERLINE_NOTCH_Q: f64 = 30.0;
+
+/// Quality factor of a second-order Butterworth section.
+pub const BUTTERWORTH_Q: f64 = FRAC_1_SQRT_2;
+
+/// Streaming filter over physical values.
+pub trait Filter: Send {
+    /// Filter the next value.
+    ///
+    /// `NaN` values are returned unchanged and do not update the state.
+    fn process(&mut self, value: f64) -> f64;
+
+    /// Forget the values seen so far, as after a seek.
+    fn reset(&mut self);
+
+    /// Filter values in place.
+    fn process_slice(&mut self, values: &mut [f64]) {
+        for value in values {
+            *value = self.process(*value);
+        }
+    }
+}
+
+/// Second-order IIR section (biquad), in transposed direct form II.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Biquad {
+    /// Feed-forward coefficients, normalized by `a0`.
+    b: [f64; 3],
+    /// Feedback coefficients `a1` and `a2`, normalized by `a0`.
+    a: [f64; 2],
+    /// Delay line.
+    state: [f64; 2],
+    /// Whether a value has been processed since the last reset.
+    primed: bool,
+}
+
+impl Biquad {
+    /// Create a section from its transfer function coefficients
+    /// `(b0 + b1 z⁻¹ + b2 z⁻²) / (a0 + a1 z⁻¹ + a2 z⁻²)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a coefficient is not finite or `a0` is 0.
+    pub fn new(b: [f64; 3], a: [f64; 3]) -> Result<Self> {
+        if b.iter().chain(&a).any(|c| !c.is_finite()) || a[0] == 0.0 {
+            return Err(Error::InvalidData(format!(
+                "Invalid biquad coefficients b = {b:?}, a = {a:?}"
+            )));
+        }
+        Ok(Self {
+            b: [b[0] / a[0], b[1] / a[0], b[2] / a[0]],
+            a: [a[1] / a[0], a[2] / a[0]],
+            state: [0.0; 2],
+            primed: false,
+        })
+    }
+
+    /// Create a low-pass section with cutoff `frequency` (Hz) for signals
+    /// sampled at `sampling_frequency` (Hz).
+    ///
+    /// [`BUTTERWORTH_Q`] gives a maximally flat pass band.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frequency` is not between 0 and the Nyquist
+    /// frequency, or `q` is not positive.
+    pub fn low_pass(sampling_frequency: f64, frequency: f64, q: f64) -> Result<Self> {
+        let (cos, alpha) = cookbook(sampling_frequency, frequency, q)?;
+        let b0 = (1.0 - cos) / 2.0;
+        Self::new([b0, 1.0 - cos, b0], [1.0 + alpha, -2.0 * cos, 1.0 - alpha])
+    }
+
+    /// Create a high-pass section with cutoff `frequency` (Hz) for signals
+    /// sampled at `sampling_frequency` (Hz).
+    ///
+    /// [`BUTTERWORTH_Q`] gives a maximally flat pass band.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frequency` is not between 0 and the Nyquist
+    /// frequency, or `q` is not positive.
+    pub fn high_pass(sampling_frequency: f64, frequency: f64, q: f64) -> Result<Self> {
+        let (cos, alpha) = cookbook(sampling_frequency, frequency, q)?;
+        let b0 = f64::midpoint(1.0, cos);
+        Self::new(
+            [b0, -(1.0 + cos), b0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    /// Create a band-pass section centered on `frequency` (Hz), with unit
+    /// gain at the center and a bandwidth of `frequency / q`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frequency` is not between 0 and the Nyquist
+    /// frequency, or `q` is not positive.
+    pub fn band_pass(sampling_frequency: f64, frequency: f64, q: f64) -> Result<Self> {
+        let (cos, alpha) = cookbook(sampling_frequency, frequency, q)?;
+        Self::new([alpha, 0.0, -alpha], [1.0 + alpha, -2.0 * cos, 1.0 - alpha])
+    }
+
+    /// Create a notch section rejecting `frequency` (Hz), with a bandwidth
+    /// of `frequency / q`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frequency` is not between 0 and the Nyquist
+    /// frequency, or `q` is not positive.
+    pub fn notch(sampling_frequency: f64, frequency: f64, q: f64) -> Result<Self> {
+        let (cos, alpha) = cookbook(sampling_frequency, frequency, q)?;
+        Self::new(
+            [1.0, -2.0 * cos, 1.0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    /// Get the gain of the section at DC.
+    fn dc_gain(&self) -> f64 {
+        let denominator = 1.0 + self.a[0] + self.a[1];
+        if denominator == 0.0 {
+            0.0
+        } else {
+            (self.b[0] + self.b[1] + self.b[2]) / denominator
+        }
+    }
+}
+
+impl Filter for Biquad {
+    fn process(&mut self, value: f64) -> f64 {
+        if value.is_nan() {
+            return value;
+        }
+        if !self.primed {
+            // Start in the steady state of a constant input
+            let output = self.dc_gain() * value;
+            self.state[1] = self.b[2].mul_add(value, -self.a[1] * output);
+            self.state[0] =
+                (self.b[1] + self.b[2]).mul_add(value, -(self.a[0] + self.a[1]) * output);
+            self.primed = true;
+        }
+        let output = self.b[0].mul_add(value, self.state[0]);
+        self.state[0] = self.b[1].mul_add(value, -self.a[0] * output) + self.state[1];
+        self.state[1] = self.b[2].mul_add(value, -self.a[1] * output);
+        output
+    }
+
+    fn reset(&mut self) {
+        self.state = [0.0; 2];
+        self.primed = false;
+    }
+}
+
+/// Finite impulse response filter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fir {
+    /// Coefficients, applied to the newest value first.
+    taps: Vec<f64>,
+    /// Latest values, newest first.
+    history: VecDeque<f64>,
+}
+
+impl Fir {
+    /// Create a filter from its coefficients, `taps[k]` weighting the value
+    /// `k` samples back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `taps` is empty or holds a non-finite value.
+    pub fn new(taps: Vec<f64>) -> Result<Self> {
+        if taps.is_empty() || taps.iter().any(|tap| !tap.is_finite()) {
+            return Err(Error::InvalidData(
+                "FIR taps must be finite and non-empty".to_string(),
+            ));
+        }
+        Ok(Self {
+            history: VecDeque::with_capacity(taps.len()),
+            taps,
+        })
+    }
+
+    /// Create a moving average over `length` samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length` is 0.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn moving_average(length: usize) -> Result<Self> {
+        Self::new(vec![1.0 / length as f64; length])
+    }
+}
+
+impl Filter for Fir {
+    fn process(&mut self, value: f64) -> f64 {
+        if value.is_nan() {
+            return value;
+        }
+        if self.history.is_empty() {
+            // Start in the steady state of a constant input
+            self.history.resize(self.taps.len(), value);
+        }
+        self.history.pop_back();
+        self.history.push_front(value);
+        self.taps
+            .iter()
+            .zip(&self.history)
+            .fold(0.0, |sum, (&tap, &value)| tap.mul_add(value, sum))
+    }
+
+    fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+/// Filters applied one after the other.
+#[derive(Default)]
+pub struct FilterChain {
+    /// Filters, in order of application.
+    filters: Vec<Box<dyn Filter>>,
+}
+
+impl FilterChain {
+    /// Create an empty chain, passing values unchanged.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a filter to the chain.
+    #[must_use]
+    pub fn then<F: Filter + 'static>(mut self, filter: F) -> Self {
+        self.push(filter);
+        self
+    }
+
+    /// Append a filter to the chain.
+    pub fn push<F: Filter + 'static>(&mut self, filter: F) {
+        self.filters.push(Box::new(filter));
+    }
+
+    /// Get the number of filters in the chain.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.filters.len()
+    }
+
+    /// Check whether the chain has no filter.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+}
+
+impl std::fmt::Debug for FilterChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilterChain")
+            .field("len", &self.filters.len())
+            .finish()
+    }
+}
+
+impl Filter for FilterChain {
+    fn process(&mut self, value: f64) -> f64 {
+        self.filters
+            .iter_mut()
+            .fold(value, |value, filter| filter.process(value))
+    }
+
+    fn reset(&mut self) {
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+    }
+}
+
+/// Create a high-pass filter removing baseline wander below
+/// [`BASELINE_WANDER_CUTOFF`] (second-order Butterworth).
+///
+/// # Errors
+///
+/// Returns an error if `sampling_frequency` is too low for the cutoff.
+pub fn baseline_wander(sampling_frequency: f64) -> Result<Biquad> {
+    Biquad::high_pass(sampling_frequency, BASELINE_WANDER_CUTOFF, BUTTERWORTH_Q)
+}
+
+/// Create a notch filter removing power line interference at
+/// `mains_frequency` (usually 50 or 60 Hz), with [`POWERLINE_NOTCH_Q`].
+///
+/// # Errors
+///
+/// Returns an error if `mains_frequency` is not below the Nyquist
+/// frequency.
+pub fn powerline_notch(sampling_frequency: f64, mains_frequency: f64) -> Result<Biquad> {
+    Biquad::notch(sampling_frequency, mains_frequency, POWERLINE_NOTCH_Q)
+}
+
+/// Create a band-pass filter between `low` and `high` (Hz), as a
+/// second-order Butterworth high-pass followed by a second-order
+/// Butterworth low-pass.
+///
+/// # Errors
+///
+/// Returns an error if `low` is not below `high`, or either is not between
+/// 0 and the Nyquist frequency.
+pub fn band_pass(sampling_frequency: f64, low: f64, high: f64) -> Result<FilterChain> {
+    if low >= high {
+        return Err(Error::InvalidData(format!(
+            "Band-pass low cutoff {low} Hz must be below high cutoff {high} Hz"
+        )));
+    }
+    Ok(FilterChain::new()
+        .then(Biquad::high_pass(sampling_frequency, low, BUTTERWORTH_Q)?)
+        .then(Biquad::low_pass(sampling_frequency, high, BUTTERWORTH_Q)?))
+}
+
+/// Compute `cos(w0)` and `alpha` of the Audio EQ Cookbook formulas.
+fn cookbook(sampling_frequency: f64, frequency: f64, q: f64) -> Result<(f64, f64)> {
+    if !(sampling_frequency.is_finite() && sampling_frequency > 0.0) {
+        return Err(Error::InvalidData(format!(
+            "Filter sampling frequency must be a positive number, got {sampling_frequency}"
+        )));
+    }
+    if !(frequency > 0.0 && frequency < sampling_frequency / 2.0) {
+        return Err(Error::InvalidData(format!(
+            "Filter frequency {frequency} Hz must be between 0 and {} Hz",
+            sampling_frequency / 2.0
+        )));
+    }
+    if !(q.is_finite() && q > 0.0) {
+        return Err(Error::InvalidData(format!(
+            "Filter quality factor must be a positive number, got {q}"
+        )));
+    }
+    let w0 = 2.0 * PI * frequency / sampling_frequency;
+    Ok((w0.cos(), w0.sin() / (2.0 * q)))
+}
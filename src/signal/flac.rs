@@ -0,0 +1,140 @@
+use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::{Error, Result, Sample};
+use std::io::{BufRead, Cursor};
+
+/// FLAC stream decoded from an in-memory copy of the signal file.
+type FlacStream = claxon::FlacReader<Cursor<Vec<u8>>>;
+
+/// Decoder for WFDB FLAC formats (508, 516 and 524).
+///
+/// The signal file is a FLAC stream with one channel per signal of the file.
+/// Samples are returned interleaved in channel order, one sample per channel
+/// per frame, like the uncompressed formats. The most negative value of the
+/// format's bit depth (e.g. -32768 for format 516) indicates an invalid
+/// sample.
+///
+/// The compressed stream is read into memory on the first call to
+/// [`decode_buf()`](FormatDecoder::decode_buf), and blocks are decompressed
+/// on demand. FLAC signals cannot be seeked.
+///
+/// Requires the `flac` feature.
+pub struct FlacDecoder {
+    /// Bits per sample of the format (8, 16 or 24)
+    bits_per_sample: u32,
+    /// Stream being decoded (`None` until the first read)
+    stream: Option<FlacStream>,
+    /// Interleaved samples of the current block
+    block: Vec<Sample>,
+    /// Position of the next sample in the current block
+    position: usize,
+    /// Whether the end of the stream was reached
+    done: bool,
+}
+
+impl FlacDecoder {
+    /// Create a new FLAC decoder for samples of the given bit depth.
+    ///
+    /// `bits_per_sample` is 8, 16 or 24 for formats 508, 516 and 524.
+    #[must_use]
+    pub const fn new(bits_per_sample: u32) -> Self {
+        Self {
+            bits_per_sample,
+            stream: None,
+            block: Vec::new(),
+            position: 0,
+            done: false,
+        }
+    }
+
+    /// Decompress the next block into `self.block`.
+    ///
+    /// Returns `false` at the end of the stream.
+    fn next_block(&mut self, reader: &mut dyn BufRead) -> Result<bool> {
+        if self.done {
+            return Ok(false);
+        }
+
+        if self.stream.is_none() {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+            if bytes.is_empty() {
+                self.done = true;
+                return Ok(false);
+            }
+            self.stream = Some(claxon::FlacReader::new(Cursor::new(bytes)).map_err(flac_error)?);
+        }
+        let Some(stream) = &mut self.stream else {
+            return Ok(false);
+        };
+
+        let buffer = std::mem::take(&mut self.block);
+        let Some(block) = stream
+            .blocks()
+            .read_next_or_eof(buffer)
+            .map_err(flac_error)?
+        else {
+            self.done = true;
+            return Ok(false);
+        };
+
+        // Blocks store channels one after another: interleave them
+        let invalid = -(1 << (self.bits_per_sample - 1));
+        let mut samples = Vec::with_capacity(block.len() as usize);
+        for index in 0..block.duration() {
+            for channel in 0..block.channels() {
+                let value = block.sample(channel, index);
+                samples.push(if value == invalid {
+                    INVALID_SAMPLE
+                } else {
+                    value
+                });
+            }
+        }
+        self.block = samples;
+        self.position = 0;
+        Ok(true)
+    }
+}
+
+impl std::fmt::Debug for FlacDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlacDecoder")
+            .field("bits_per_sample", &self.bits_per_sample)
+            .field("position", &self.position)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FormatDecoder for FlacDecoder {
+    fn decode_buf(&mut self, reader: &mut dyn BufRead, output: &mut [Sample]) -> Result<usize> {
+        let mut count = 0;
+
+        while count < output.len() {
+            if self.position == self.block.len() && !self.next_block(reader)? {
+                break;
+            }
+            let n = (output.len() - count).min(self.block.len() - self.position);
+            output[count..count + n].copy_from_slice(&self.block[self.position..self.position + n]);
+            self.position += n;
+            count += n;
+        }
+
+        Ok(count)
+    }
+
+    fn reset(&mut self) {
+        self.stream = None;
+        self.block.clear();
+        self.position = 0;
+        self.done = false;
+    }
+}
+
+/// Convert a FLAC decoding error.
+fn flac_error(error: claxon::Error) -> Error {
+    match error {
+        claxon::Error::IoError(error) => Error::Io(error),
+        error => Error::InvalidData(format!("Invalid FLAC signal data: {error}")),
+    }
+}
@@ -1,6 +1,6 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::signal::common::{FormatDecoder, FormatEncoder, INVALID_SAMPLE};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 0 (null signal).
 ///
@@ -32,3 +32,27 @@ impl FormatDecoder for Format0Decoder {
         Some(0) // No bytes per sample
     }
 }
+
+/// Encoder for WFDB Format 0 (null signal).
+///
+/// Null signals store no data, so samples are discarded.
+#[derive(Debug, Clone, Default)]
+pub struct Format0Encoder;
+
+impl Format0Encoder {
+    /// Create a new Format 0 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl FormatEncoder for Format0Encoder {
+    fn encode(&mut self, _writer: &mut dyn Write, _samples: &[Sample]) -> Result<()> {
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        // No state need to be reset
+    }
+}
@@ -1,12 +1,13 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::signal::common::{FormatDecoder, FormatEncoder, INVALID_SAMPLE, clamp_sample};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 160 (16-bit offset binary, little-endian).
 #[derive(Debug, Clone, Default)]
 pub struct Format160Decoder;
 
 impl Format160Decoder {
+    /// Create a new Format 160 decoder.
     #[must_use]
     pub const fn new() -> Self {
         Self
@@ -44,3 +45,38 @@ impl FormatDecoder for Format160Decoder {
         Some(2)
     }
 }
+
+/// Encoder for WFDB Format 160 (16-bit offset binary, little-endian).
+///
+/// Samples are clamped to -32767..=32767, the most negative value being
+/// reserved for [`INVALID_SAMPLE`].
+#[derive(Debug, Clone, Default)]
+pub struct Format160Encoder;
+
+impl Format160Encoder {
+    /// Create a new Format 160 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl FormatEncoder for Format160Encoder {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len() * 2);
+        for &sample in samples {
+            let value = if sample == INVALID_SAMPLE {
+                0
+            } else {
+                // Add 32768 to convert to offset binary
+                (clamp_sample(sample, 16) + 32768) as u16
+            };
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn reset(&mut self) {}
+}
@@ -1,6 +1,7 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE, sign_extend};
+use crate::signal::common::{FormatDecoder, FormatEncoder, clamp_sample};
+use crate::signal::packing::{pack_212, unpack_212};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 212 (packed 12-bit samples).
 ///
@@ -9,10 +10,13 @@ use std::io::BufRead;
 /// - Byte 2: Contains sample 1's low 8 bits
 ///
 /// The value 0x800 (-2048 in 12-bit two's complement) indicates an invalid sample.
+///
+/// See [`packing::unpack_212`](crate::signal::packing::unpack_212) for the
+/// bit layout.
 #[derive(Debug, Clone)]
 pub struct Format212Decoder {
-    /// Buffer for partial data when reading pairs
-    buffer: Option<u16>,
+    /// First two bytes of the pair being read
+    buffer: Option<[u8; 2]>,
     /// Whether we're reading the first or second sample of a pair
     is_second: bool,
 }
@@ -44,22 +48,13 @@ impl FormatDecoder for Format212Decoder {
                 let mut buf = [0u8; 1];
                 match reader.read_exact(&mut buf) {
                     Ok(()) => {
-                        let Some(word) = self.buffer else {
+                        let Some([byte0, byte1]) = self.buffer else {
                             // Should not happen - reset state and skip
                             self.is_second = false;
                             continue;
                         };
-                        // Sample 1: high 4 bits from word (bits 12-15), low 8 bits from new byte
-                        let high_bits = (word >> 12) & 0x0F;
-                        let low_bits = u16::from(buf[0]);
-                        let raw_value = (high_bits << 8) | low_bits;
-                        let value = sign_extend(u32::from(raw_value), 12);
-
-                        if value == (-1 << 11) {
-                            *sample = INVALID_SAMPLE;
-                        } else {
-                            *sample = value;
-                        }
+                        // Sample 1: high 4 bits from byte 1, low 8 bits from new byte
+                        [_, *sample] = unpack_212([byte0, byte1, buf[0]]);
 
                         self.buffer = None;
                         self.is_second = false;
@@ -78,18 +73,11 @@ impl FormatDecoder for Format212Decoder {
                 let mut buf = [0u8; 2];
                 match reader.read_exact(&mut buf) {
                     Ok(()) => {
-                        let word = u16::from_le_bytes(buf);
-                        // Sample 0: bits 0-11
-                        let value = sign_extend(u32::from(word & 0x0FFF), 12);
-
-                        if value == (-1 << 11) {
-                            *sample = INVALID_SAMPLE;
-                        } else {
-                            *sample = value;
-                        }
+                        // Sample 0: bits 0-11 (independent of byte 2)
+                        [*sample, _] = unpack_212([buf[0], buf[1], 0]);
 
                         // Save bits 12-15 for second sample
-                        self.buffer = Some(word);
+                        self.buffer = Some(buf);
                         self.is_second = true;
                         count += 1;
                     }
@@ -120,3 +108,49 @@ impl FormatDecoder for Format212Decoder {
         Some((num_signals * 3).div_ceil(2))
     }
 }
+
+/// Encoder for WFDB Format 212 (packed 12-bit samples).
+///
+/// Samples are clamped to -2047..=2047 and packed in pairs with
+/// [`packing::pack_212`](crate::signal::packing::pack_212). A trailing
+/// unpaired sample is written as 2 bytes by
+/// [`finish()`](FormatEncoder::finish).
+#[derive(Debug, Clone, Default)]
+pub struct Format212Encoder {
+    /// First sample of the pair being written
+    pending: Option<Sample>,
+}
+
+impl Format212Encoder {
+    /// Create a new Format 212 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { pending: None }
+    }
+}
+
+impl FormatEncoder for Format212Encoder {
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len() * 3 / 2 + 2);
+        for &sample in samples {
+            let sample = clamp_sample(sample, 12);
+            match self.pending.take() {
+                Some(first) => bytes.extend_from_slice(&pack_212([first, sample])),
+                None => self.pending = Some(sample),
+            }
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn finish(&mut self, writer: &mut dyn Write) -> Result<()> {
+        if let Some(first) = self.pending.take() {
+            writer.write_all(&pack_212([first, 0])[..2])?;
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.pending = None;
+    }
+}
@@ -1,6 +1,6 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::signal::common::{FormatDecoder, FormatEncoder, INVALID_SAMPLE, clamp_sample};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 24 (24-bit two's complement, little-endian).
 #[derive(Debug, Clone, Default)]
@@ -53,3 +53,37 @@ impl FormatDecoder for Format24Decoder {
         Some(3)
     }
 }
+
+/// Encoder for WFDB Format 24 (24-bit two's complement, little-endian).
+///
+/// Samples are clamped to -8388607..=8388607, the most negative value being
+/// reserved for [`INVALID_SAMPLE`].
+#[derive(Debug, Clone, Default)]
+pub struct Format24Encoder;
+
+impl Format24Encoder {
+    /// Create a new Format 24 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl FormatEncoder for Format24Encoder {
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len() * 3);
+        for &sample in samples {
+            let value = if sample == INVALID_SAMPLE {
+                -1 << 23
+            } else {
+                clamp_sample(sample, 24)
+            };
+            bytes.extend_from_slice(&value.to_le_bytes()[..3]);
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn reset(&mut self) {}
+}
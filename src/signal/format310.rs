@@ -1,14 +1,17 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE, sign_extend};
+use crate::signal::common::{FormatDecoder, FormatEncoder, clamp_sample};
+use crate::signal::packing::{pack_310, unpack_310};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 310 (packed 10-bit samples).
 ///
-/// Three 10-bit samples are bit-packed into 4 bytes.
+/// Three 10-bit samples are bit-packed into 4 bytes. See
+/// [`packing::unpack_310`](crate::signal::packing::unpack_310) for the bit
+/// layout.
 #[derive(Debug, Clone)]
 pub struct Format310Decoder {
-    /// Buffer for reading sample groups
-    buffer: [u16; 2],
+    /// Bytes of the sample group being read
+    buffer: [u8; 4],
     /// Current position in the group (0, 1, or 2)
     position: u8,
 }
@@ -23,7 +26,7 @@ impl Format310Decoder {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            buffer: [0; 2],
+            buffer: [0; 4],
             position: 0,
         }
     }
@@ -37,19 +40,10 @@ impl FormatDecoder for Format310Decoder {
             match self.position {
                 0 => {
                     // Read first 16-bit word
-                    let mut buf = [0u8; 2];
-                    match reader.read_exact(&mut buf) {
+                    match reader.read_exact(&mut self.buffer[..2]) {
                         Ok(()) => {
-                            self.buffer[0] = u16::from_le_bytes(buf);
-                            // Sample 0: bits 1-10 of first word (discard bit 0)
-                            let raw = (self.buffer[0] >> 1) & 0x3FF;
-                            let value = sign_extend(u32::from(raw), 10);
-
-                            *sample = if value == (-1 << 9) {
-                                INVALID_SAMPLE
-                            } else {
-                                value
-                            };
+                            // Sample 0: bits 1-10 of first word (independent of second word)
+                            *sample = unpack_310(self.buffer)[0];
 
                             self.position = 1;
                             count += 1;
@@ -60,19 +54,10 @@ impl FormatDecoder for Format310Decoder {
                 }
                 1 => {
                     // Read second 16-bit word
-                    let mut buf = [0u8; 2];
-                    match reader.read_exact(&mut buf) {
+                    match reader.read_exact(&mut self.buffer[2..]) {
                         Ok(()) => {
-                            self.buffer[1] = u16::from_le_bytes(buf);
-                            // Sample 1: bits 1-10 of second word (discard bit 0)
-                            let raw = (self.buffer[1] >> 1) & 0x3FF;
-                            let value = sign_extend(u32::from(raw), 10);
-
-                            *sample = if value == (-1 << 9) {
-                                INVALID_SAMPLE
-                            } else {
-                                value
-                            };
+                            // Sample 1: bits 1-10 of second word
+                            *sample = unpack_310(self.buffer)[1];
 
                             self.position = 2;
                             count += 1;
@@ -86,16 +71,7 @@ impl FormatDecoder for Format310Decoder {
                 }
                 _ => {
                     // Sample 2: bits 11-15 from first word, bits 11-15 from second word
-                    let high0 = (self.buffer[0] >> 11) & 0x1F;
-                    let high1 = (self.buffer[1] >> 11) & 0x1F;
-                    let raw = (high1 << 5) | high0;
-                    let value = sign_extend(u32::from(raw), 10);
-
-                    *sample = if value == (-1 << 9) {
-                        INVALID_SAMPLE
-                    } else {
-                        value
-                    };
+                    *sample = unpack_310(self.buffer)[2];
 
                     self.position = 0;
                     count += 1;
@@ -107,7 +83,7 @@ impl FormatDecoder for Format310Decoder {
     }
 
     fn reset(&mut self) {
-        self.buffer = [0; 2];
+        self.buffer = [0; 4];
         self.position = 0;
     }
 
@@ -122,3 +98,60 @@ impl FormatDecoder for Format310Decoder {
         Some(num_signals.div_ceil(3) * 4)
     }
 }
+
+/// Encoder for WFDB Format 310 (packed 10-bit samples).
+///
+/// Samples are clamped to -511..=511 and packed in groups of three with
+/// [`packing::pack_310`](crate::signal::packing::pack_310). A trailing group of one sample is written as 2 bytes and
+/// a group of two samples as 4 bytes by [`finish()`](FormatEncoder::finish).
+#[derive(Debug, Clone, Default)]
+pub struct Format310Encoder {
+    /// Samples of the group being written
+    group: [Sample; 3],
+    /// Number of samples in the group (0, 1, or 2)
+    len: usize,
+}
+
+impl Format310Encoder {
+    /// Create a new Format 310 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            group: [0; 3],
+            len: 0,
+        }
+    }
+}
+
+impl FormatEncoder for Format310Encoder {
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len().div_ceil(3) * 4);
+        for &sample in samples {
+            self.group[self.len] = clamp_sample(sample, 10);
+            self.len += 1;
+            if self.len == 3 {
+                bytes.extend_from_slice(&pack_310(self.group));
+                self.len = 0;
+            }
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn finish(&mut self, writer: &mut dyn Write) -> Result<()> {
+        if self.len > 0 {
+            let mut group = [0; 3];
+            group[..self.len].copy_from_slice(&self.group[..self.len]);
+            let bytes = pack_310(group);
+            let len = if self.len == 1 { 2 } else { 4 };
+            writer.write_all(&bytes[..len])?;
+        }
+        self.reset();
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.group = [0; 3];
+        self.len = 0;
+    }
+}
@@ -1,14 +1,17 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE, sign_extend};
+use crate::signal::common::{FormatDecoder, FormatEncoder, clamp_sample};
+use crate::signal::packing::{pack_311, unpack_311};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 311 (packed 10-bit samples, alternative layout).
 ///
 /// Three 10-bit samples are packed into a 32-bit word in little-endian order.
+/// See [`packing::unpack_311`](crate::signal::packing::unpack_311) for the
+/// bit layout.
 #[derive(Debug, Clone)]
 pub struct Format311Decoder {
-    /// Buffered 32-bit word containing 3 samples
-    buffer: u32,
+    /// Samples unpacked from the current 32-bit word
+    buffer: [Sample; 3],
     /// Current position in the group (0, 1, or 2)
     position: u8,
 }
@@ -23,7 +26,7 @@ impl Format311Decoder {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            buffer: 0,
+            buffer: [0; 3],
             position: 0,
         }
     }
@@ -38,59 +41,23 @@ impl FormatDecoder for Format311Decoder {
                 // Read 4 bytes as little-endian 32-bit word
                 let mut buf = [0u8; 4];
                 match reader.read_exact(&mut buf) {
-                    Ok(()) => {
-                        self.buffer = u32::from_le_bytes(buf);
-
-                        // Sample 0: bits 0-9
-                        let raw = self.buffer & 0x3FF;
-                        let value = sign_extend(raw, 10);
-
-                        *sample = if value == (-1 << 9) {
-                            INVALID_SAMPLE
-                        } else {
-                            value
-                        };
-
-                        self.position = 1;
-                        count += 1;
-                    }
+                    Ok(()) => self.buffer = unpack_311(buf),
                     Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
                     Err(e) => return Err(e.into()),
                 }
-            } else if self.position == 1 {
-                // Sample 1: bits 10-19
-                let raw = (self.buffer >> 10) & 0x3FF;
-                let value = sign_extend(raw, 10);
-
-                *sample = if value == (-1 << 9) {
-                    INVALID_SAMPLE
-                } else {
-                    value
-                };
-
-                self.position = 2;
-                count += 1;
-            } else {
-                // Sample 2: bits 20-29
-                let raw = (self.buffer >> 20) & 0x3FF;
-                let value = sign_extend(raw, 10);
-
-                *sample = if value == (-1 << 9) {
-                    INVALID_SAMPLE
-                } else {
-                    value
-                };
-
-                self.position = 0;
-                count += 1;
             }
+
+            // Samples 0, 1 and 2: bits 0-9, 10-19 and 20-29
+            *sample = self.buffer[usize::from(self.position)];
+            self.position = (self.position + 1) % 3;
+            count += 1;
         }
 
         Ok(count)
     }
 
     fn reset(&mut self) {
-        self.buffer = 0;
+        self.buffer = [0; 3];
         self.position = 0;
     }
 
@@ -105,3 +72,58 @@ impl FormatDecoder for Format311Decoder {
         Some(num_signals.div_ceil(3) * 4)
     }
 }
+
+/// Encoder for WFDB Format 311 (packed 10-bit samples).
+///
+/// Samples are clamped to -511..=511 and packed in groups of three with
+/// [`packing::pack_311`](crate::signal::packing::pack_311). A trailing incomplete group is written as 4 bytes by
+/// [`finish()`](FormatEncoder::finish).
+#[derive(Debug, Clone, Default)]
+pub struct Format311Encoder {
+    /// Samples of the group being written
+    group: [Sample; 3],
+    /// Number of samples in the group (0, 1, or 2)
+    len: usize,
+}
+
+impl Format311Encoder {
+    /// Create a new Format 311 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            group: [0; 3],
+            len: 0,
+        }
+    }
+}
+
+impl FormatEncoder for Format311Encoder {
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len().div_ceil(3) * 4);
+        for &sample in samples {
+            self.group[self.len] = clamp_sample(sample, 10);
+            self.len += 1;
+            if self.len == 3 {
+                bytes.extend_from_slice(&pack_311(self.group));
+                self.len = 0;
+            }
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn finish(&mut self, writer: &mut dyn Write) -> Result<()> {
+        if self.len > 0 {
+            let mut group = [0; 3];
+            group[..self.len].copy_from_slice(&self.group[..self.len]);
+            writer.write_all(&pack_311(group))?;
+        }
+        self.reset();
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.group = [0; 3];
+        self.len = 0;
+    }
+}
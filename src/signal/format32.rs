@@ -1,6 +1,6 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::signal::common::{FormatDecoder, FormatEncoder, INVALID_SAMPLE};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 32 (32-bit two's complement, little-endian).
 #[derive(Debug, Clone, Default)]
@@ -42,3 +42,31 @@ impl FormatDecoder for Format32Decoder {
         Some(4)
     }
 }
+
+/// Encoder for WFDB Format 32 (32-bit two's complement, little-endian).
+///
+/// Every `Sample` value fits in the format.
+#[derive(Debug, Clone, Default)]
+pub struct Format32Encoder;
+
+impl Format32Encoder {
+    /// Create a new Format 32 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl FormatEncoder for Format32Encoder {
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len() * 4);
+        for &sample in samples {
+            // INVALID_SAMPLE is the format's invalid marker
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn reset(&mut self) {}
+}
@@ -1,12 +1,13 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::signal::common::{FormatDecoder, FormatEncoder, INVALID_SAMPLE, clamp_sample};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 61 (16-bit two's complement, big-endian).
 #[derive(Debug, Clone, Default)]
 pub struct Format61Decoder;
 
 impl Format61Decoder {
+    /// Create a new Format 61 decoder.
     #[must_use]
     pub const fn new() -> Self {
         Self
@@ -43,3 +44,37 @@ impl FormatDecoder for Format61Decoder {
         Some(2)
     }
 }
+
+/// Encoder for WFDB Format 61 (16-bit two's complement, big-endian).
+///
+/// Samples are clamped to -32767..=32767, the most negative value being
+/// reserved for [`INVALID_SAMPLE`].
+#[derive(Debug, Clone, Default)]
+pub struct Format61Encoder;
+
+impl Format61Encoder {
+    /// Create a new Format 61 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl FormatEncoder for Format61Encoder {
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len() * 2);
+        for &sample in samples {
+            let value = if sample == INVALID_SAMPLE {
+                i16::MIN
+            } else {
+                clamp_sample(sample, 16) as i16
+            };
+            bytes.extend_from_slice(&value.to_be_bytes());
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn reset(&mut self) {}
+}
@@ -1,6 +1,6 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::signal::common::{FormatDecoder, FormatEncoder, INVALID_SAMPLE};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 8 (8-bit first differences).
 ///
@@ -9,18 +9,52 @@ use std::io::BufRead;
 ///
 /// When differences exceed the representable range (-128 to +127), the maximum
 /// difference is stored and subsequent differences adjust to reach the target.
+///
+/// Each signal of a file accumulates its own differences: a decoder for a
+/// file of interleaved signals is created with
+/// [`interleaved()`](Self::interleaved), and keeps one running value per
+/// signal.
 #[derive(Debug, Clone)]
 pub struct Format8Decoder {
-    /// Current accumulated sample value
-    current_value: Sample,
+    /// Initial value of each signal, restored on rewind
+    initial_values: Vec<Sample>,
+    /// Current accumulated value of each signal
+    current_values: Vec<Sample>,
+    /// Signal of each sample of a frame
+    frame_signals: Vec<usize>,
+    /// Position of the next sample within its frame
+    position: usize,
 }
 
 impl Format8Decoder {
     /// Create a new Format 8 decoder with the specified initial value.
     #[must_use]
-    pub const fn new(initial_value: Sample) -> Self {
+    pub fn new(initial_value: Sample) -> Self {
+        Self::interleaved(&[initial_value], &[1])
+    }
+
+    /// Create a Format 8 decoder for a file of interleaved signals.
+    ///
+    /// `initial_values` and `samples_per_frame` give the initial value and
+    /// the number of samples per frame of each signal of the file, in file
+    /// order. Decoding must start at the beginning of a frame.
+    #[must_use]
+    pub fn interleaved(initial_values: &[Sample], samples_per_frame: &[usize]) -> Self {
+        let mut frame_signals: Vec<usize> = samples_per_frame
+            .iter()
+            .enumerate()
+            .flat_map(|(signal, &count)| std::iter::repeat_n(signal, count.max(1)))
+            .collect();
+        if frame_signals.is_empty() {
+            frame_signals.push(0);
+        }
+        let mut initial_values = initial_values.to_vec();
+        initial_values.resize(frame_signals.iter().max().map_or(1, |&max| max + 1), 0);
         Self {
-            current_value: initial_value,
+            current_values: initial_values.clone(),
+            initial_values,
+            frame_signals,
+            position: 0,
         }
     }
 }
@@ -36,14 +70,16 @@ impl FormatDecoder for Format8Decoder {
                     // Read signed 8-bit difference
                     let diff = i8::from_le_bytes(buf);
 
-                    // Accumulate the difference
-                    self.current_value = self.current_value.saturating_add(i32::from(diff));
+                    // Accumulate the difference on the value of its signal
+                    let value = &mut self.current_values[self.frame_signals[self.position]];
+                    *value = value.saturating_add(i32::from(diff));
+                    self.position = (self.position + 1) % self.frame_signals.len();
 
                     // Check for invalid sample marker (not typically used in format 8)
-                    if diff == i8::MIN && self.current_value == i32::from(i8::MIN) {
+                    if diff == i8::MIN && *value == i32::from(i8::MIN) {
                         *sample = INVALID_SAMPLE;
                     } else {
-                        *sample = self.current_value;
+                        *sample = *value;
                     }
 
                     count += 1;
@@ -59,10 +95,93 @@ impl FormatDecoder for Format8Decoder {
     }
 
     fn reset(&mut self) {
-        self.current_value = 0;
+        self.current_values.fill(0);
+        self.position = 0;
+    }
+
+    fn rewind(&mut self) {
+        self.current_values.clone_from(&self.initial_values);
+        self.position = 0;
+    }
+
+    fn is_differential(&self) -> bool {
+        true
     }
 
     fn bytes_per_sample(&self) -> Option<usize> {
         Some(1)
     }
 }
+
+/// Encoder for WFDB Format 8 (8-bit first differences).
+///
+/// Each sample is stored as its difference from the previously encoded
+/// value of its signal. Differences are clamped to -127..=127, so a signal
+/// changing faster than that lags behind and catches up over the following
+/// samples, as with the reference WFDB library. The format has no invalid
+/// sample marker: [`INVALID_SAMPLE`] repeats the previous value.
+#[derive(Debug, Clone)]
+pub struct Format8Encoder {
+    /// Initial value of each signal, restored on reset
+    initial_values: Vec<Sample>,
+    /// Value of each signal reconstructed from the differences written so far
+    current_values: Vec<Sample>,
+    /// Signal of each sample of a frame
+    frame_signals: Vec<usize>,
+    /// Position of the next sample within its frame
+    position: usize,
+}
+
+impl Format8Encoder {
+    /// Create a new Format 8 encoder with the specified initial value.
+    ///
+    /// The initial value must match the one in the signal specification.
+    #[must_use]
+    pub fn new(initial_value: Sample) -> Self {
+        Self::interleaved(&[initial_value], &[1])
+    }
+
+    /// Create a Format 8 encoder for a file of interleaved signals.
+    ///
+    /// `initial_values` and `samples_per_frame` give the initial value and
+    /// the number of samples per frame of each signal of the file, in file
+    /// order, as for [`Format8Decoder::interleaved`]. Samples must be
+    /// written in whole frames.
+    #[must_use]
+    pub fn interleaved(initial_values: &[Sample], samples_per_frame: &[usize]) -> Self {
+        let decoder = Format8Decoder::interleaved(initial_values, samples_per_frame);
+        Self {
+            current_values: decoder.initial_values.clone(),
+            initial_values: decoder.initial_values,
+            frame_signals: decoder.frame_signals,
+            position: 0,
+        }
+    }
+}
+
+impl FormatEncoder for Format8Encoder {
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len());
+        for &sample in samples {
+            let value = &mut self.current_values[self.frame_signals[self.position]];
+            self.position = (self.position + 1) % self.frame_signals.len();
+            let diff = if sample == INVALID_SAMPLE {
+                0
+            } else {
+                i64::from(sample) - i64::from(*value)
+            };
+            // -128 is avoided since the decoder may read it as invalid
+            let diff = diff.clamp(-127, 127) as i8;
+            *value = value.saturating_add(i32::from(diff));
+            bytes.extend_from_slice(&diff.to_le_bytes());
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.current_values.clone_from(&self.initial_values);
+        self.position = 0;
+    }
+}
@@ -1,6 +1,6 @@
-use crate::signal::common::{FormatDecoder, INVALID_SAMPLE};
+use crate::signal::common::{FormatDecoder, FormatEncoder, INVALID_SAMPLE, clamp_sample};
 use crate::{Result, Sample};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 
 /// Decoder for WFDB Format 80 (8-bit offset binary).
 #[derive(Debug, Clone, Default)]
@@ -43,3 +43,38 @@ impl FormatDecoder for Format80Decoder {
         Some(1)
     }
 }
+
+/// Encoder for WFDB Format 80 (8-bit offset binary).
+///
+/// Samples are clamped to -127..=127, the most negative value being
+/// reserved for [`INVALID_SAMPLE`].
+#[derive(Debug, Clone, Default)]
+pub struct Format80Encoder;
+
+impl Format80Encoder {
+    /// Create a new Format 80 encoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl FormatEncoder for Format80Encoder {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn encode(&mut self, writer: &mut dyn Write, samples: &[Sample]) -> Result<()> {
+        let mut bytes = Vec::with_capacity(samples.len());
+        for &sample in samples {
+            let value = if sample == INVALID_SAMPLE {
+                0
+            } else {
+                // Add 128 to convert to offset binary
+                (clamp_sample(sample, 8) + 128) as u8
+            };
+            bytes.push(value);
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn reset(&mut self) {}
+}
@@ -3,6 +3,8 @@
 //! This module provides low-level signal format decoders that work with any
 //! `BufRead` source. Each format decoder is responsible for reading raw bytes
 //! and converting them to `Sample` values according to the WFDB specification.
+//! Each format also has an encoder implementing [`FormatEncoder`], writing
+//! `Sample` values back to any `Write` sink.
 //!
 //! # Hybrid APIs
 //!
@@ -25,7 +27,7 @@
 //!
 //! # Format Support
 //!
-//! Each WFDB signal format has a dedicated decoder:
+//! Each WFDB signal format has a dedicated decoder and encoder:
 //! - Format 0: Null signals (no data)
 //! - Format 8: 8-bit first differences
 //! - Format 16: 16-bit two's complement (little-endian)
@@ -38,7 +40,32 @@
 //! - Format 310: Packed 10-bit samples (3 samples in 4 bytes)
 //! - Format 311: Packed 10-bit samples (alternative layout)
 //!
-//! > _FLAC-based formats are not supported yet._
+//! The bit layouts of the packed formats are available as pure functions in
+//! [`packing`].
+//!
+//! Physical values are converted by [`SignalScale`], and between units with
+//! the [`units`] registry. Streaming digital filters for physical values are
+//! in [`filter`].
+//!
+//! - Formats 508, 516 and 524: FLAC-compressed 8, 16 and 24-bit samples
+//!   (decoding only, requires the `flac` feature)
+//!
+//! # Random Access
+//!
+//! Readers seek by computing byte offsets from
+//! [`FormatDecoder::bytes_per_sample()`] and
+//! [`FormatDecoder::bytes_per_frame()`], starting at the signal's byte offset:
+//! - Formats 16, 24, 32, 61, 80 and 160 have a fixed sample size and support
+//!   seeking to any sample, including within interleaved files.
+//! - Formats 212, 310 and 311 pack several samples together and support
+//!   seeking to any frame boundary.
+//! - Format 8 stores first differences, so a sample's value depends on every
+//!   sample before it. Seeking rewinds to the start of the signal and
+//!   accumulates the differences up to the target (see
+//!   [`FormatDecoder::skip()`]): the cost of a seek is a linear scan over
+//!   every sample before the target.
+//! - Format 0 stores no data and cannot be seeked.
+//! - FLAC formats are decompressed sequentially and cannot be seeked.
 //!
 //! # Examples
 //!
@@ -107,6 +134,9 @@
 //! ```
 
 mod common;
+pub mod filter;
+#[cfg(feature = "flac")]
+mod flac;
 mod format0;
 mod format16;
 mod format160;
@@ -118,19 +148,28 @@ mod format32;
 mod format61;
 mod format8;
 mod format80;
+pub mod packing;
+mod scale;
+pub mod units;
 
-pub use common::{DecoderConfig, FormatDecoder, INVALID_SAMPLE, SampleIter, sign_extend};
-pub use format0::Format0Decoder;
-pub use format8::Format8Decoder;
-pub use format16::Format16Decoder;
-pub use format24::Format24Decoder;
-pub use format32::Format32Decoder;
-pub use format61::Format61Decoder;
-pub use format80::Format80Decoder;
-pub use format160::Format160Decoder;
-pub use format212::Format212Decoder;
-pub use format310::Format310Decoder;
-pub use format311::Format311Decoder;
+pub use common::{
+    DecoderConfig, FormatDecoder, FormatEncoder, INVALID_SAMPLE, SampleIter, clamp_sample,
+    sign_extend,
+};
+#[cfg(feature = "flac")]
+pub use flac::FlacDecoder;
+pub use format0::{Format0Decoder, Format0Encoder};
+pub use format8::{Format8Decoder, Format8Encoder};
+pub use format16::{Format16Decoder, Format16Encoder};
+pub use format24::{Format24Decoder, Format24Encoder};
+pub use format32::{Format32Decoder, Format32Encoder};
+pub use format61::{Format61Decoder, Format61Encoder};
+pub use format80::{Format80Decoder, Format80Encoder};
+pub use format160::{Format160Decoder, Format160Encoder};
+pub use format212::{Format212Decoder, Format212Encoder};
+pub use format310::{Format310Decoder, Format310Encoder};
+pub use format311::{Format311Decoder, Format311Encoder};
+pub use scale::{RoundingMode, SignalScale};
 
 use crate::{Error, Result, Sample, SignalFormat};
 
@@ -152,6 +191,81 @@ pub fn get_decoder(format: SignalFormat, initial_value: Sample) -> Result<Box<dy
         SignalFormat::Format212 => Ok(Box::new(Format212Decoder::new())),
         SignalFormat::Format310 => Ok(Box::new(Format310Decoder::new())),
         SignalFormat::Format311 => Ok(Box::new(Format311Decoder::new())),
+        #[cfg(feature = "flac")]
+        SignalFormat::Flac8 => Ok(Box::new(FlacDecoder::new(8))),
+        #[cfg(feature = "flac")]
+        SignalFormat::Flac16 => Ok(Box::new(FlacDecoder::new(16))),
+        #[cfg(feature = "flac")]
+        SignalFormat::Flac24 => Ok(Box::new(FlacDecoder::new(24))),
+        #[cfg(not(feature = "flac"))]
         _ => Err(Error::UnsupportedSignalFormat(u16::from(format))),
     }
 }
+
+/// Create a decoder for a file of interleaved signals.
+///
+/// `initial_values` and `samples_per_frame` give the initial value and the
+/// number of samples per frame of each signal of the file, in file order.
+/// Format 8 decoders accumulate the differences of each signal separately;
+/// other formats are decoded as by [`get_decoder`].
+///
+/// # Errors
+///
+/// Returns `Error::UnsupportedSignalFormat` if the format is not supported.
+pub fn get_interleaved_decoder(
+    format: SignalFormat,
+    initial_values: &[Sample],
+    samples_per_frame: &[usize],
+) -> Result<Box<dyn FormatDecoder>> {
+    match format {
+        SignalFormat::Format8 => Ok(Box::new(Format8Decoder::interleaved(
+            initial_values,
+            samples_per_frame,
+        ))),
+        _ => get_decoder(format, initial_values.first().copied().unwrap_or(0)),
+    }
+}
+
+/// Create an encoder for given signal format.
+///
+/// # Errors
+///
+/// Returns `Error::UnsupportedSignalFormat` if the format is not supported.
+pub fn get_encoder(format: SignalFormat, initial_value: Sample) -> Result<Box<dyn FormatEncoder>> {
+    match format {
+        SignalFormat::Format0 => Ok(Box::new(Format0Encoder::new())),
+        SignalFormat::Format8 => Ok(Box::new(Format8Encoder::new(initial_value))),
+        SignalFormat::Format16 => Ok(Box::new(Format16Encoder::new())),
+        SignalFormat::Format24 => Ok(Box::new(Format24Encoder::new())),
+        SignalFormat::Format32 => Ok(Box::new(Format32Encoder::new())),
+        SignalFormat::Format61 => Ok(Box::new(Format61Encoder::new())),
+        SignalFormat::Format80 => Ok(Box::new(Format80Encoder::new())),
+        SignalFormat::Format160 => Ok(Box::new(Format160Encoder::new())),
+        SignalFormat::Format212 => Ok(Box::new(Format212Encoder::new())),
+        SignalFormat::Format310 => Ok(Box::new(Format310Encoder::new())),
+        SignalFormat::Format311 => Ok(Box::new(Format311Encoder::new())),
+        _ => Err(Error::UnsupportedSignalFormat(u16::from(format))),
+    }
+}
+
+/// Create an encoder for a file of interleaved signals.
+///
+/// The counterpart of [`get_interleaved_decoder`]: format 8 encoders take
+/// the differences of each signal separately.
+///
+/// # Errors
+///
+/// Returns `Error::UnsupportedSignalFormat` if the format is not supported.
+pub fn get_interleaved_encoder(
+    format: SignalFormat,
+    initial_values: &[Sample],
+    samples_per_frame: &[usize],
+) -> Result<Box<dyn FormatEncoder>> {
+    match format {
+        SignalFormat::Format8 => Ok(Box::new(Format8Encoder::interleaved(
+            initial_values,
+            samples_per_frame,
+        ))),
+        _ => get_encoder(format, initial_values.first().copied().unwrap_or(0)),
+    }
+}
@@ -0,0 +1,143 @@
+//! Bit packing of the WFDB packed sample formats.
+//!
+//! These pure functions convert between groups of samples and the bytes of
+//! formats 212, 310 and 311. They are the routines used by the decoders and
+//! do not depend on any reader, so other tools (e.g. firmware producing WFDB
+//! files) can reuse them directly.
+//!
+//! Each format reserves its most negative value (`-2048` for 12-bit samples,
+//! `-512` for 10-bit samples) as the invalid sample marker: unpacking maps it
+//! to [`INVALID_SAMPLE`], and packing maps [`INVALID_SAMPLE`] back to it.
+//! Other values are packed as their low 12 or 10 bits, so callers must clamp
+//! samples to the format's range beforehand.
+//!
+//! # Examples
+//!
+//! ```
+//! use wfdb::signal::packing::{pack_212, unpack_212};
+//!
+//! let bytes = pack_212([-1, 1000]);
+//! assert_eq!(unpack_212(bytes), [-1, 1000]);
+//! ```
+
+use crate::Sample;
+use crate::signal::common::{INVALID_SAMPLE, sign_extend};
+
+/// Most negative 12-bit value, marking an invalid format 212 sample.
+pub const INVALID_212: Sample = -(1 << 11);
+
+/// Most negative 10-bit value, marking an invalid format 310/311 sample.
+pub const INVALID_10_BIT: Sample = -(1 << 9);
+
+/// Decode a `bits`-wide field, mapping the invalid marker to [`INVALID_SAMPLE`].
+const fn decode_field(raw: u32, bits: u32) -> Sample {
+    let value = sign_extend(raw, bits);
+    if value == -(1 << (bits - 1)) {
+        INVALID_SAMPLE
+    } else {
+        value
+    }
+}
+
+/// Encode a sample as a `bits`-wide field, mapping [`INVALID_SAMPLE`] to the
+/// invalid marker.
+#[allow(clippy::cast_sign_loss)]
+const fn encode_field(sample: Sample, bits: u32) -> u32 {
+    let value = if sample == INVALID_SAMPLE {
+        -(1 << (bits - 1))
+    } else {
+        sample
+    };
+    (value as u32) & ((1 << bits) - 1)
+}
+
+// [Format 212]
+
+/// Unpack two format 212 samples from 3 bytes.
+///
+/// The first sample is the low 12 bits of the little-endian word in bytes
+/// 0-1. The second sample has its high 4 bits in the high nibble of byte 1
+/// and its low 8 bits in byte 2.
+#[must_use]
+pub const fn unpack_212(bytes: [u8; 3]) -> [Sample; 2] {
+    let first = (bytes[0] as u32) | ((bytes[1] as u32 & 0x0F) << 8);
+    let second = (bytes[2] as u32) | ((bytes[1] as u32 & 0xF0) << 4);
+    [decode_field(first, 12), decode_field(second, 12)]
+}
+
+/// Pack two samples into 3 format 212 bytes.
+///
+/// See [`unpack_212`] for the layout.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn pack_212(samples: [Sample; 2]) -> [u8; 3] {
+    let first = encode_field(samples[0], 12);
+    let second = encode_field(samples[1], 12);
+    [
+        first as u8,
+        ((first >> 8) | ((second >> 8) << 4)) as u8,
+        second as u8,
+    ]
+}
+
+// [Format 310]
+
+/// Unpack three format 310 samples from 4 bytes.
+///
+/// Bytes 0-1 and 2-3 form two little-endian words. The first and second
+/// samples are bits 1-10 of the first and second word. The third sample has
+/// its low 5 bits in bits 11-15 of the first word and its high 5 bits in
+/// bits 11-15 of the second word. Bit 0 of each word is unused.
+#[must_use]
+pub const fn unpack_310(bytes: [u8; 4]) -> [Sample; 3] {
+    let word0 = u16::from_le_bytes([bytes[0], bytes[1]]) as u32;
+    let word1 = u16::from_le_bytes([bytes[2], bytes[3]]) as u32;
+    [
+        decode_field((word0 >> 1) & 0x3FF, 10),
+        decode_field((word1 >> 1) & 0x3FF, 10),
+        decode_field((word0 >> 11) | ((word1 >> 11) << 5), 10),
+    ]
+}
+
+/// Pack three samples into 4 format 310 bytes.
+///
+/// See [`unpack_310`] for the layout. Unused bits are written as zero.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn pack_310(samples: [Sample; 3]) -> [u8; 4] {
+    let first = encode_field(samples[0], 10);
+    let second = encode_field(samples[1], 10);
+    let third = encode_field(samples[2], 10);
+    let word0 = ((first << 1) | ((third & 0x1F) << 11)) as u16;
+    let word1 = ((second << 1) | ((third >> 5) << 11)) as u16;
+    let [b0, b1] = word0.to_le_bytes();
+    let [b2, b3] = word1.to_le_bytes();
+    [b0, b1, b2, b3]
+}
+
+// [Format 311]
+
+/// Unpack three format 311 samples from 4 bytes.
+///
+/// The bytes form a little-endian 32-bit word holding the samples in bits
+/// 0-9, 10-19 and 20-29. Bits 30-31 are unused.
+#[must_use]
+pub const fn unpack_311(bytes: [u8; 4]) -> [Sample; 3] {
+    let word = u32::from_le_bytes(bytes);
+    [
+        decode_field(word & 0x3FF, 10),
+        decode_field((word >> 10) & 0x3FF, 10),
+        decode_field((word >> 20) & 0x3FF, 10),
+    ]
+}
+
+/// Pack three samples into 4 format 311 bytes.
+///
+/// See [`unpack_311`] for the layout. Unused bits are written as zero.
+#[must_use]
+pub const fn pack_311(samples: [Sample; 3]) -> [u8; 4] {
+    let word = encode_field(samples[0], 10)
+        | (encode_field(samples[1], 10) << 10)
+        | (encode_field(samples[2], 10) << 20);
+    word.to_le_bytes()
+}
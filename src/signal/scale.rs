@@ -0,0 +1,192 @@
+use crate::signal::INVALID_SAMPLE;
+use crate::signal::units::conversion_factor;
+use crate::{Sample, SignalInfo};
+
+/// Rounding applied when converting values to integers.
+///
+/// Reproducing the output of another tool bit for bit requires using the
+/// same convention as that tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, ties away from zero (`2.5` → `3`,
+    /// `-2.5` → `-3`).
+    #[default]
+    HalfAwayFromZero,
+    /// Round to the nearest integer, ties to even (`2.5` → `2`, `3.5` → `4`).
+    HalfEven,
+    /// Round toward zero (`2.7` → `2`, `-2.7` → `-2`).
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Round a value to an integral value.
+    #[must_use]
+    pub const fn round(self, value: f64) -> f64 {
+        match self {
+            Self::HalfAwayFromZero => value.round(),
+            Self::HalfEven => value.round_ties_even(),
+            Self::Truncate => value.trunc(),
+        }
+    }
+}
+
+/// Conversion between ADC values and physical units for one signal.
+///
+/// This is the single place where gain and baseline are applied. It also
+/// fixes the policies shared by every reader and exporter:
+///
+/// - [`INVALID_SAMPLE`] converts to `NaN`, and `NaN` converts back to
+///   [`INVALID_SAMPLE`].
+/// - Uncalibrated signals (gain 0) convert values unchanged.
+/// - Physical values are rounded to ADC units with the scale's
+///   [`RoundingMode`] (half away from zero by default).
+///
+/// # Examples
+///
+/// ```
+/// use wfdb::signal::SignalScale;
+///
+/// let scale = SignalScale::new(200.0, 1024, "mV");
+/// assert_eq!(scale.to_physical(1224), 1.0);
+/// assert_eq!(scale.to_adc(-0.5), 924);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalScale {
+    /// ADC gain in ADC units per physical unit (0 when uncalibrated).
+    pub gain: f64,
+    /// ADC value corresponding to 0 physical units.
+    pub baseline: i32,
+    /// Physical unit name.
+    pub units: String,
+    /// Rounding applied by [`to_adc`](Self::to_adc).
+    pub rounding: RoundingMode,
+}
+
+impl SignalScale {
+    /// Create a scale from its gain, baseline and units.
+    ///
+    /// The scale uses the default [`RoundingMode`].
+    #[must_use]
+    pub fn new(gain: f64, baseline: i32, units: impl Into<String>) -> Self {
+        Self {
+            gain,
+            baseline,
+            units: units.into(),
+            rounding: RoundingMode::default(),
+        }
+    }
+
+    /// Set the rounding applied by [`to_adc`](Self::to_adc).
+    #[must_use]
+    pub const fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Get the equivalent scale producing physical values in other units.
+    ///
+    /// The gain is adjusted so that physical values are expressed in
+    /// `units` (e.g. `mV` to `uV` multiplies them by 1000). Returns `None` if
+    /// the units are unknown to the [`units`](crate::signal::units) registry
+    /// or measure different quantities, and for uncalibrated scales, whose
+    /// values are raw ADC values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wfdb::signal::SignalScale;
+    ///
+    /// let scale = SignalScale::new(200.0, 0, "mV").in_units("uV").unwrap();
+    /// assert_eq!(scale.units, "uV");
+    /// assert_eq!(scale.to_physical(100), 500.0);
+    /// ```
+    #[must_use]
+    pub fn in_units(&self, units: &str) -> Option<Self> {
+        if self.units == units {
+            return Some(self.clone());
+        }
+        if !self.is_calibrated() {
+            return None;
+        }
+        let factor = conversion_factor(&self.units, units)?;
+        Some(Self {
+            gain: self.gain / factor,
+            units: units.to_string(),
+            ..self.clone()
+        })
+    }
+
+    /// Convert the scale to the first of `targets` its units convert to.
+    ///
+    /// Returns the scale unchanged if none applies. See
+    /// [`in_units`](Self::in_units).
+    #[must_use]
+    pub fn normalized<S: AsRef<str>>(&self, targets: &[S]) -> Self {
+        targets
+            .iter()
+            .find_map(|target| self.in_units(target.as_ref()))
+            .unwrap_or_else(|| self.clone())
+    }
+
+    /// Check if the scale is calibrated (non-zero gain).
+    #[must_use]
+    pub fn is_calibrated(&self) -> bool {
+        self.gain > 0.0
+    }
+
+    /// Convert an ADC value to physical units.
+    #[must_use]
+    pub fn to_physical(&self, adc_value: Sample) -> f64 {
+        if adc_value == INVALID_SAMPLE {
+            f64::NAN
+        } else if self.is_calibrated() {
+            (f64::from(adc_value) - f64::from(self.baseline)) / self.gain
+        } else {
+            f64::from(adc_value)
+        }
+    }
+
+    /// Convert a physical value to ADC units.
+    ///
+    /// The value is rounded with the scale's [`RoundingMode`]. Values outside
+    /// the `Sample` range saturate.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_adc(&self, physical_value: f64) -> Sample {
+        if physical_value.is_nan() {
+            INVALID_SAMPLE
+        } else if self.is_calibrated() {
+            self.rounding
+                .round(physical_value.mul_add(self.gain, f64::from(self.baseline)))
+                as Sample
+        } else {
+            self.rounding.round(physical_value) as Sample
+        }
+    }
+
+    /// Convert ADC values to physical units into a caller-provided buffer.
+    ///
+    /// Converts `min(adc_values.len(), output.len())` values and returns that count.
+    pub fn to_physical_slice(&self, adc_values: &[Sample], output: &mut [f64]) -> usize {
+        for (out, &value) in output.iter_mut().zip(adc_values) {
+            *out = self.to_physical(value);
+        }
+        adc_values.len().min(output.len())
+    }
+
+    /// Convert physical values to ADC units into a caller-provided buffer.
+    ///
+    /// Converts `min(physical_values.len(), output.len())` values and returns that count.
+    pub fn to_adc_slice(&self, physical_values: &[f64], output: &mut [Sample]) -> usize {
+        for (out, &value) in output.iter_mut().zip(physical_values) {
+            *out = self.to_adc(value);
+        }
+        physical_values.len().min(output.len())
+    }
+}
+
+impl From<&SignalInfo> for SignalScale {
+    fn from(signal: &SignalInfo) -> Self {
+        Self::new(signal.adc_gain(), signal.baseline(), signal.units())
+    }
+}
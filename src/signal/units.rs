@@ -0,0 +1,123 @@
+//! Registry of physical units found in WFDB headers.
+//!
+//! Headers name units freely (`mV`, `uV`, `mmHg`, `kPa`, ...), so records
+//! from different datasets may store the same kind of signal at different
+//! magnitudes. This registry knows the common units of physiologic signals
+//! and the factor between units measuring the same quantity, so that values
+//! can be converted with [`conversion_factor`] or
+//! [`SignalScale::in_units`](crate::signal::SignalScale::in_units).
+//!
+//! Unit names are case sensitive (`mV` is not `MV`). Only units related by a
+//! plain factor are supported; offset scales such as degrees Celsius are not.
+//!
+//! # Examples
+//!
+//! ```
+//! use wfdb::signal::units::{Dimension, Unit, conversion_factor};
+//!
+//! let unit = Unit::parse("uV").unwrap();
+//! assert_eq!(unit.dimension, Dimension::Voltage);
+//!
+//! assert_eq!(conversion_factor("mV", "uV"), Some(1000.0));
+//! assert_eq!(conversion_factor("mV", "mmHg"), None);
+//! ```
+
+/// Physical quantity measured by a unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    /// Electric potential (e.g. ECG, EEG).
+    Voltage,
+    /// Pressure (e.g. blood pressure).
+    Pressure,
+    /// Time (e.g. RR intervals).
+    Time,
+    /// Frequency (e.g. heart rate).
+    Frequency,
+}
+
+/// Physical unit known to the registry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit {
+    /// Canonical symbol of the unit.
+    pub symbol: &'static str,
+    /// Quantity measured by the unit.
+    pub dimension: Dimension,
+    /// Value of one unit in the smallest unit of its dimension (nV, Pa, us
+    /// and beats per minute), so that decimal prefixes convert exactly.
+    factor: f64,
+}
+
+/// Units of the registry.
+const UNITS: &[Unit] = &[
+    unit("kV", Dimension::Voltage, 1e12),
+    unit("V", Dimension::Voltage, 1e9),
+    unit("mV", Dimension::Voltage, 1e6),
+    unit("uV", Dimension::Voltage, 1e3),
+    unit("nV", Dimension::Voltage, 1.0),
+    unit("kPa", Dimension::Pressure, 1e3),
+    unit("mmHg", Dimension::Pressure, 133.322_387_415),
+    unit("cmH2O", Dimension::Pressure, 98.0665),
+    unit("mbar", Dimension::Pressure, 100.0),
+    unit("Pa", Dimension::Pressure, 1.0),
+    unit("s", Dimension::Time, 1e6),
+    unit("ms", Dimension::Time, 1e3),
+    unit("us", Dimension::Time, 1.0),
+    unit("Hz", Dimension::Frequency, 60.0),
+    unit("bpm", Dimension::Frequency, 1.0),
+];
+
+/// Alternative spellings, as (alias, canonical symbol) pairs.
+const ALIASES: &[(&str, &str)] = &[
+    ("volt", "V"),
+    ("mv", "mV"),
+    ("\u{b5}V", "uV"),
+    ("\u{3bc}V", "uV"),
+    ("mmhg", "mmHg"),
+    ("cmh2o", "cmH2O"),
+    ("sec", "s"),
+    ("msec", "ms"),
+    ("\u{b5}s", "us"),
+    ("\u{3bc}s", "us"),
+    ("BPM", "bpm"),
+    ("beats/min", "bpm"),
+];
+
+const fn unit(symbol: &'static str, dimension: Dimension, factor: f64) -> Unit {
+    Unit {
+        symbol,
+        dimension,
+        factor,
+    }
+}
+
+impl Unit {
+    /// Look up a unit by symbol or alias (e.g. `µV` for `uV`).
+    ///
+    /// Returns `None` for units unknown to the registry.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim();
+        let symbol = ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == name)
+            .map_or(name, |&(_, symbol)| symbol);
+        UNITS.iter().find(|unit| unit.symbol == symbol).copied()
+    }
+
+    /// Get the factor converting values in this unit to `other`.
+    ///
+    /// Returns `None` if the units measure different quantities.
+    #[must_use]
+    pub fn factor_to(&self, other: &Self) -> Option<f64> {
+        (self.dimension == other.dimension).then(|| self.factor / other.factor)
+    }
+}
+
+/// Get the factor converting values in units `from` to units `to`.
+///
+/// Multiply a value in `from` by the factor to express it in `to`. Returns
+/// `None` if either unit is unknown or they measure different quantities.
+#[must_use]
+pub fn conversion_factor(from: &str, to: &str) -> Option<f64> {
+    Unit::parse(from)?.factor_to(&Unit::parse(to)?)
+}
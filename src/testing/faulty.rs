@@ -0,0 +1,143 @@
+use std::io::{self, BufRead, ErrorKind, Read, Seek, SeekFrom};
+use std::thread;
+use std::time::Duration;
+
+/// Byte stream wrapper injecting I/O faults, for resilience tests.
+///
+/// Requires the `testing` feature.
+///
+/// Real files, pipes and network streams do not always fill a read buffer
+/// or succeed at the first attempt. A `FaultyReader` reproduces this
+/// deterministically over any reader:
+///
+/// - Short reads: each read returns at most [`max_chunk`](Self::max_chunk)
+///   bytes
+/// - Interruptions: every [`interrupt_every`](Self::interrupt_every)-th read
+///   fails with [`ErrorKind::Interrupted`] before returning any byte
+/// - Delays: each read sleeps for [`delay`](Self::delay) first
+///
+/// Code handling these correctly reads the same bytes as from the wrapped
+/// reader. Seeking is passed through, so a faulty reader can stand in for a
+/// signal file behind [`Record::from_sources`](crate::Record::from_sources).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Cursor, Read};
+///
+/// use wfdb::testing::FaultyReader;
+///
+/// let mut reader = FaultyReader::new(Cursor::new(vec![1, 2, 3, 4, 5]))
+///     .max_chunk(2)
+///     .interrupt_every(3);
+///
+/// let mut bytes = Vec::new();
+/// reader.read_to_end(&mut bytes).unwrap();
+/// assert_eq!(bytes, vec![1, 2, 3, 4, 5]);
+/// assert!(reader.interruptions() > 0);
+/// ```
+#[derive(Debug)]
+pub struct FaultyReader<R> {
+    /// Wrapped reader.
+    inner: R,
+    /// Maximum number of bytes returned by one read.
+    max_chunk: usize,
+    /// Period (in reads) of the injected interruptions, 0 for none.
+    interrupt_every: usize,
+    /// Sleep before each read.
+    delay: Duration,
+    /// Number of reads attempted so far.
+    reads: usize,
+    /// Number of interruptions injected so far.
+    interruptions: usize,
+}
+
+impl<R> FaultyReader<R> {
+    /// Wrap a reader, injecting no fault until configured.
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            max_chunk: usize::MAX,
+            interrupt_every: 0,
+            delay: Duration::ZERO,
+            reads: 0,
+            interruptions: 0,
+        }
+    }
+
+    /// Return at most `max_chunk` bytes (at least 1) per read.
+    #[must_use]
+    pub fn max_chunk(mut self, max_chunk: usize) -> Self {
+        self.max_chunk = max_chunk.max(1);
+        self
+    }
+
+    /// Fail every `period`-th read with [`ErrorKind::Interrupted`] (0 never
+    /// fails).
+    #[must_use]
+    pub const fn interrupt_every(mut self, period: usize) -> Self {
+        self.interrupt_every = period;
+        self
+    }
+
+    /// Sleep for `delay` before each read.
+    #[must_use]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Get the number of interruptions injected so far.
+    #[must_use]
+    pub const fn interruptions(&self) -> usize {
+        self.interruptions
+    }
+
+    /// Get the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Count a read attempt, returning an error if it is to be interrupted.
+    fn fault(&mut self) -> io::Result<()> {
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+        self.reads += 1;
+        if self.interrupt_every > 0 && self.reads.is_multiple_of(self.interrupt_every) {
+            self.interruptions += 1;
+            return Err(io::Error::new(
+                ErrorKind::Interrupted,
+                "injected interruption",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for FaultyReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fault()?;
+        let len = buf.len().min(self.max_chunk);
+        self.inner.read(&mut buf[..len])
+    }
+}
+
+impl<R: BufRead> BufRead for FaultyReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.fault()?;
+        let max_chunk = self.max_chunk;
+        let buffer = self.inner.fill_buf()?;
+        Ok(&buffer[..buffer.len().min(max_chunk)])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.inner.consume(amount);
+    }
+}
+
+impl<R: Seek> Seek for FaultyReader<R> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        self.inner.seek(position)
+    }
+}
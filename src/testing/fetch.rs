@@ -0,0 +1,151 @@
+use std::fs::File;
+use std::io::{BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::remote::{HttpClient, RemoteOptions};
+use crate::{Error, Result};
+
+/// Base URL of the `PhysioNet` file server.
+pub const PHYSIONET_URL: &str = "https://physionet.org/files";
+
+/// Database version used when the record path omits it.
+pub const DEFAULT_DATABASE_VERSION: &str = "1.0.0";
+
+/// Name of the checksum listing published with every `PhysioNet` database.
+const CHECKSUM_FILE: &str = "SHA256SUMS.txt";
+
+/// Fetch a `PhysioNet` record into a local cache directory.
+///
+/// `record` is either `database/record` (e.g. `mitdb/100`, using
+/// [`DEFAULT_DATABASE_VERSION`]) or `database/version/record`
+/// (e.g. `mitdb/1.0.0/100`). Every file belonging to the record (header,
+/// signal and annotation files) is downloaded to
+/// `cache_dir/database/version/` unless a cached copy with a matching
+/// checksum already exists.
+///
+/// Returns the path of the record (without extension), ready to be passed
+/// to [`Record::open`](crate::Record::open).
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The record path is malformed
+/// - The record is not listed in the database checksums
+/// - A download fails or its checksum does not match
+/// - The cache directory cannot be written
+pub fn fetch_physionet_record<P: AsRef<Path>>(record: &str, cache_dir: P) -> Result<PathBuf> {
+    fetch_physionet_record_with_options(record, cache_dir, &RemoteOptions::default())
+}
+
+/// Fetch a `PhysioNet` record into a local cache directory, with custom
+/// limits and retry policy for the downloads.
+///
+/// See [`fetch_physionet_record`] for the record path format.
+///
+/// # Errors
+///
+/// Returns the same errors as [`fetch_physionet_record`], and an error if a
+/// download exceeds the limits in `options`.
+pub fn fetch_physionet_record_with_options<P: AsRef<Path>>(
+    record: &str,
+    cache_dir: P,
+    options: &RemoteOptions,
+) -> Result<PathBuf> {
+    let (database, version, record_name) = parse_record_path(record)?;
+    let client = HttpClient::new(options);
+
+    let base_url = format!("{PHYSIONET_URL}/{database}/{version}");
+    let target_dir = cache_dir.as_ref().join(database).join(version);
+    std::fs::create_dir_all(&target_dir)?;
+
+    let checksums = client.get_string(&format!("{base_url}/{CHECKSUM_FILE}"))?;
+    let files = record_files(&checksums, record_name);
+    if files.is_empty() {
+        return Err(Error::Remote(format!(
+            "Record '{record_name}' not found in {base_url}/{CHECKSUM_FILE}"
+        )));
+    }
+
+    for (file_name, expected) in files {
+        let path = target_dir.join(file_name);
+        if path.exists() && sha256_file(&path)? == expected {
+            continue;
+        }
+
+        let actual = download_to_file(&client, &format!("{base_url}/{file_name}"), &path)?;
+        if actual != expected {
+            std::fs::remove_file(&path)?;
+            return Err(Error::Remote(format!(
+                "Checksum mismatch for '{file_name}': expected {expected}, got {actual}"
+            )));
+        }
+    }
+
+    Ok(target_dir.join(record_name))
+}
+
+/// Split a record path into (database, version, record name).
+fn parse_record_path(record: &str) -> Result<(&str, &str, &str)> {
+    let parts: Vec<&str> = record.split('/').collect();
+    match parts.as_slice() {
+        [database, record_name] if !database.is_empty() && !record_name.is_empty() => {
+            Ok((database, DEFAULT_DATABASE_VERSION, record_name))
+        }
+        [database, version, record_name]
+            if !database.is_empty() && !version.is_empty() && !record_name.is_empty() =>
+        {
+            Ok((database, version, record_name))
+        }
+        _ => Err(Error::InvalidPath(format!(
+            "Invalid PhysioNet record path '{record}', expected 'database[/version]/record'"
+        ))),
+    }
+}
+
+/// Collect `(file name, checksum)` pairs of a record from a `SHA256SUMS.txt` listing.
+///
+/// Only files at the top level of the database whose stem is the record
+/// name are returned.
+fn record_files<'a>(checksums: &'a str, record_name: &str) -> Vec<(&'a str, &'a str)> {
+    checksums
+        .lines()
+        .filter_map(|line| {
+            let (hash, file_name) = line.split_once(char::is_whitespace)?;
+            let file_name = file_name.trim_start().trim_start_matches('*');
+            let (stem, _) = file_name.rsplit_once('.')?;
+            (stem == record_name).then_some((file_name, hash))
+        })
+        .collect()
+}
+
+/// Download a URL into a file, returning the SHA-256 of the written bytes.
+fn download_to_file(client: &HttpClient, url: &str, path: &Path) -> Result<String> {
+    client.get_with(url, |reader| {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let mut hasher = Sha256::new();
+        let mut buffer = [0u8; 8192];
+        loop {
+            let n = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            hasher.update(&buffer[..n]);
+            writer.write_all(&buffer[..n])?;
+        }
+        writer.flush()?;
+
+        Ok(format!("{:x}", hasher.finalize()))
+    })
+}
+
+/// Compute the SHA-256 of a file on disk.
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
@@ -0,0 +1,38 @@
+//! Helpers for examples and tests.
+//!
+//! With the `fetch-examples` feature, small public records are downloaded
+//! from [PhysioNet](https://physionet.org) into a local cache directory and
+//! verified against the database's `SHA256SUMS.txt` before use.
+//!
+//! With the `testing` feature, [`FaultyReader`] wraps a byte stream to
+//! inject the short reads, interruptions and delays of real devices and
+//! networks, to test code reading records under adverse I/O.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[cfg(feature = "fetch-examples")]
+//! # fn main() -> wfdb::Result<()> {
+//! use wfdb::Record;
+//! use wfdb::testing::fetch_physionet_record;
+//!
+//! let path = fetch_physionet_record("mitdb/100", "target/wfdb-cache")?;
+//! let record = Record::open(path)?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "fetch-examples"))]
+//! # fn main() {}
+//! ```
+
+#[cfg(feature = "testing")]
+mod faulty;
+#[cfg(feature = "fetch-examples")]
+mod fetch;
+
+#[cfg(feature = "testing")]
+pub use faulty::FaultyReader;
+#[cfg(feature = "fetch-examples")]
+pub use fetch::{
+    DEFAULT_DATABASE_VERSION, PHYSIONET_URL, fetch_physionet_record,
+    fetch_physionet_record_with_options,
+};
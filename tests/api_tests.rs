@@ -0,0 +1,199 @@
+//! Checks of the stable public API.
+//!
+//! Each item of the core read path is named here with its signature, so that
+//! removing, renaming or changing one of them fails to compile. Changes to
+//! this file are breaking changes and must follow the deprecation policy in
+//! the crate documentation.
+
+#![allow(clippy::unwrap_used)]
+
+use std::io::BufRead;
+use std::ops::Range;
+use std::path::PathBuf;
+
+use wfdb::header::Specifications;
+use wfdb::record::{
+    FrameIndex, MultiSignalReader, ReaderOptions, SampleIndex, SegmentReader, SegmentSignalReader,
+    SignalMatrix, SignalReader,
+};
+use wfdb::signal::{FormatDecoder, INVALID_SAMPLE, SignalScale};
+use wfdb::{
+    Error, Header, Metadata, Record, Result, Sample, SegmentInfo, SignalFormat, SignalInfo, Time,
+};
+
+// [Core types]
+
+#[test]
+fn test_core_type_aliases() {
+    let _: Sample = 0i32;
+    let _: Time = 0i64;
+    let _: Result<()> = Ok(());
+    assert_eq!(INVALID_SAMPLE, i32::MIN);
+}
+
+#[test]
+fn test_error_variants() {
+    let errors = [
+        Error::UnsupportedSignalFormat(0),
+        Error::InvalidAnnotationCode(0),
+        Error::UnsupportedAnnotationFormat(String::new()),
+        Error::InvalidPath(String::new()),
+        Error::Io(std::io::Error::other("")),
+        Error::InvalidHeader(String::new().into()),
+        Error::SignalFile {
+            path: std::path::PathBuf::new(),
+            source: std::io::Error::other(""),
+        },
+        Error::SignalDecode {
+            file: String::new(),
+            byte_offset: 0,
+            reason: String::new(),
+        },
+        Error::InvalidData(String::new()),
+        Error::Remote(String::new()),
+        Error::Cancelled,
+        Error::BudgetExceeded(String::new()),
+    ];
+    for error in errors {
+        match error {
+            Error::UnsupportedSignalFormat(_)
+            | Error::InvalidAnnotationCode(_)
+            | Error::UnsupportedAnnotationFormat(_)
+            | Error::InvalidPath(_)
+            | Error::Io(_)
+            | Error::InvalidHeader(_)
+            | Error::SignalFile { .. }
+            | Error::SignalDecode { .. }
+            | Error::InvalidData(_)
+            | Error::Remote(_)
+            | Error::Cancelled
+            | Error::BudgetExceeded(_) => {}
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn test_signal_format_codes() {
+    for code in [0, 8, 16, 24, 32, 61, 80, 160, 212, 310, 311, 508, 516, 524] {
+        assert!(SignalFormat::try_from(code).is_ok());
+    }
+}
+
+// [Headers]
+
+fn from_any_reader<R: BufRead>(reader: &mut R) -> Result<Header> {
+    Header::from_reader(reader)
+}
+
+#[test]
+fn test_header_api() {
+    let _: fn(&Header) -> &Metadata = Header::metadata;
+    let _: fn(&Header) -> &Specifications = Header::specifications;
+    let _: fn(&Header) -> Option<&[SignalInfo]> = Header::signals;
+    let _: fn(&Header) -> Option<&[SegmentInfo]> = Header::segments;
+    let _: fn(&Header) -> &[String] = Header::info_strings;
+    let _: fn(&str) -> Result<Metadata> = Metadata::from_record_line;
+    let _: fn(&str) -> Result<SignalInfo> = SignalInfo::from_signal_line;
+    let _: fn(&str) -> Result<SegmentInfo> = SegmentInfo::from_segment_line;
+    let _: fn(&Metadata) -> f64 = Metadata::sampling_frequency;
+    let _: fn(&Metadata) -> Option<u64> = Metadata::num_samples;
+    let _: fn(&SignalInfo) -> SignalFormat = SignalInfo::format;
+    let _: fn(&SignalInfo) -> f64 = SignalInfo::adc_gain;
+    let _: fn(&SignalInfo) -> SignalScale = SignalInfo::scale;
+    let _: fn(&SignalInfo) -> Option<&str> = SignalInfo::description;
+    assert!(from_any_reader(&mut b"r 0\n".as_slice()).is_ok());
+}
+
+// [Records and readers]
+
+#[test]
+fn test_record_api() {
+    let _: fn(PathBuf) -> Result<Record> = Record::open::<PathBuf>;
+    let _: fn(Header, PathBuf) -> Record = Record::from_header;
+    let _: fn(&Record) -> &Metadata = Record::metadata;
+    let _: fn(&Record) -> Option<&[SignalInfo]> = Record::signal_info;
+    let _: fn(&Record) -> Option<&[SegmentInfo]> = Record::segment_info;
+    let _: fn(&Record) -> bool = Record::is_multi_segment;
+    let _: fn(&Record) -> usize = Record::signal_count;
+    let _: fn(&Record, usize) -> Result<SignalReader> = Record::signal_reader;
+    let _: fn(&Record, usize, &ReaderOptions) -> Result<SignalReader> =
+        Record::signal_reader_with_options;
+    let _: fn(&Record) -> Result<MultiSignalReader> = Record::multi_signal_reader;
+    let _: fn(&Record, &ReaderOptions) -> Result<MultiSignalReader> =
+        Record::multi_signal_reader_with_options;
+    let _: fn(&Record) -> Result<SegmentReader> = Record::segment_reader;
+    let _: fn(&Record, usize) -> Result<SegmentSignalReader> = Record::segment_signal_reader;
+    let _: fn(&Record) -> Result<SignalMatrix> = Record::read_all_physical;
+    let _: fn(&Record, Range<u64>) -> Result<SignalMatrix> =
+        Record::read_physical_frames::<Range<u64>>;
+    let _: fn(&Record, u64, u64) -> Result<SignalMatrix> = Record::read_range_physical;
+}
+
+#[test]
+fn test_reader_api() {
+    let _: fn(&mut SignalReader, &mut [Sample]) -> Result<usize> = SignalReader::read_samples_buf;
+    let _: fn(&mut SignalReader, usize) -> Result<Vec<Sample>> = SignalReader::read_samples;
+    let _: fn(&mut SignalReader, usize) -> Result<Vec<f64>> = SignalReader::read_physical;
+    let _: fn(&mut SignalReader, u64) -> Result<u64> = SignalReader::seek_to_sample;
+    let _: fn(&mut SignalReader, SampleIndex) -> Result<u64> = SignalReader::seek_to_sample_index;
+    let _: fn(&SignalReader) -> u64 = SignalReader::position;
+
+    let _: fn(&mut MultiSignalReader) -> Result<Vec<Sample>> = MultiSignalReader::read_frame;
+    let _: fn(&mut MultiSignalReader, &mut [Sample]) -> Result<bool> =
+        MultiSignalReader::read_frame_into;
+    let _: fn(&mut MultiSignalReader, usize) -> Result<Vec<Vec<Sample>>> =
+        MultiSignalReader::read_frames;
+    let _: fn(&mut MultiSignalReader, u64) -> Result<u64> = MultiSignalReader::seek_to_frame;
+    let _: fn(&mut MultiSignalReader, FrameIndex) -> Result<u64> =
+        MultiSignalReader::seek_to_frame_index;
+
+    let _: fn(&mut SegmentReader) -> Result<Option<Vec<Sample>>> = SegmentReader::read_frame;
+    let _: fn(&mut SegmentReader, u64) -> Result<u64> = SegmentReader::seek_to_frame;
+    let _: fn(&mut SegmentReader, FrameIndex) -> Result<u64> = SegmentReader::seek_to_frame_index;
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_shims() {
+    let _: fn(&mut SegmentReader, u64) -> Result<u64> = SegmentReader::seek_to_sample;
+}
+
+// [Decoders]
+
+#[test]
+fn test_decoder_api() {
+    let mut decoder: Box<dyn FormatDecoder> =
+        wfdb::signal::get_decoder(SignalFormat::Format16, 0).unwrap();
+    let mut output = [0; 2];
+    let n = decoder
+        .decode_buf(&mut [1u8, 0, 2, 0].as_slice(), &mut output)
+        .unwrap();
+    assert_eq!(n, 2);
+    assert_eq!(output, [1, 2]);
+}
+
+// [Prelude]
+
+#[test]
+fn test_prelude() {
+    use wfdb::prelude::*;
+
+    let record: Record = Record::from_sources(
+        "rec 1 250 2\nrec.dat 16\n",
+        wfdb::record::MemorySource::new(),
+    )
+    .unwrap();
+    let _: Option<&[SignalInfo]> = record.signal_info();
+    let _: &Metadata = record.metadata();
+    let _: Result<SignalReader> = record.signal_reader_with_options(0, &ReaderOptions::default());
+    let _: Result<MultiSignalReader> = record.multi_signal_reader();
+    let _: (FrameIndex, SampleIndex) = (FrameIndex(1), SampleIndex(2));
+
+    let decoder: Box<dyn FormatDecoder> =
+        wfdb::signal::get_decoder(SignalFormat::Format16, 0).unwrap();
+    assert_eq!(decoder.bytes_per_sample(), Some(2));
+
+    let mut filter = wfdb::signal::filter::baseline_wander(250.0).unwrap();
+    assert!(filter.process(f64::NAN).is_nan());
+}
@@ -1,5 +1,5 @@
 use std::io::Cursor;
-use wfdb::{Error, Header};
+use wfdb::{Error, Header, HeaderField};
 
 // [Basic Parsing Tests]
 
@@ -178,7 +178,7 @@ fn test_missing_record_line() {
 
     assert!(result.is_err());
     if let Err(Error::InvalidHeader(msg)) = result {
-        assert!(msg.contains("Missing record line"));
+        assert!(msg.reason().contains("Missing record line"));
     } else {
         panic!("Expected InvalidHeader error");
     }
@@ -194,7 +194,7 @@ fn test_insufficient_signal_specifications() {
 
     assert!(result.is_err());
     if let Err(Error::InvalidHeader(msg)) = result {
-        assert!(msg.contains("Expected 2 signal specifications"));
+        assert!(msg.reason().contains("Expected 2 signal specifications"));
     } else {
         panic!("Expected InvalidHeader error");
     }
@@ -210,7 +210,7 @@ fn test_insufficient_segment_specifications() {
 
     assert!(result.is_err());
     if let Err(Error::InvalidHeader(msg)) = result {
-        assert!(msg.contains("Expected 3 segment specifications"));
+        assert!(msg.reason().contains("Expected 3 segment specifications"));
     } else {
         panic!("Expected InvalidHeader error");
     }
@@ -236,6 +236,53 @@ fn test_only_whitespace() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_error_position_of_signal_field() {
+    let header_text = "# Comment\n\
+                      100 2 360\n\
+                      100.dat 212 200\n\
+                      100.dat 212 1e999/mV 11 0\n";
+
+    let result = Header::from_reader(&mut Cursor::new(header_text));
+
+    let Err(Error::InvalidHeader(error)) = result else {
+        panic!("Expected InvalidHeader error, got {result:?}");
+    };
+    assert_eq!(error.line(), Some(4));
+    assert_eq!(error.field(), Some(HeaderField::Gain));
+    assert_eq!(
+        Error::InvalidHeader(error).to_string(),
+        "Invalid header: Invalid ADC gain: expected a finite number, got '1e999'"
+    );
+}
+
+#[test]
+fn test_error_position_of_record_and_segment_fields() {
+    let result = Header::from_reader(&mut Cursor::new("100 2 0\n"));
+    let Err(Error::InvalidHeader(error)) = result else {
+        panic!("Expected InvalidHeader error, got {result:?}");
+    };
+    assert_eq!(error.line(), Some(1));
+    assert_eq!(error.field(), Some(HeaderField::SamplingFrequency));
+
+    let result = Header::from_reader(&mut Cursor::new("multi/2 2 360\n100s 10\n100t x\n"));
+    let Err(Error::InvalidHeader(error)) = result else {
+        panic!("Expected InvalidHeader error, got {result:?}");
+    };
+    assert_eq!(error.line(), Some(3));
+    assert_eq!(error.field(), Some(HeaderField::SegmentLength));
+}
+
+#[test]
+fn test_error_position_unknown() {
+    let result = Header::from_reader(&mut Cursor::new("100 2 360\n100.dat 212 200\n"));
+    let Err(Error::InvalidHeader(error)) = result else {
+        panic!("Expected InvalidHeader error, got {result:?}");
+    };
+    assert_eq!(error.line(), None);
+    assert_eq!(error.field(), None);
+}
+
 // [Accessor Tests]
 
 #[test]
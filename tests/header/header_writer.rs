@@ -0,0 +1,110 @@
+use std::io::Cursor;
+use wfdb::{Header, Metadata, SegmentInfo, SignalInfo};
+
+// [Lines that round-trip unchanged]
+
+#[test]
+fn test_record_lines_round_trip() {
+    for line in [
+        "100 2 360 650000",
+        "100 2 360 650000 12:00:00 01/01/2000",
+        "my_record_0 12 500/100(50) 675000 14:49:37 07/06/2025",
+        "24_record/2 4 102400",
+        "rec 2 12:30:45 01/01/2000",
+        "rec 1 0.5",
+        "rec 1",
+    ] {
+        let metadata = Metadata::from_record_line(line).unwrap();
+        assert_eq!(metadata.to_string(), line);
+    }
+}
+
+#[test]
+fn test_signal_lines_round_trip() {
+    for line in [
+        "100.dat 212 200 11 1024 995 -22131 0 MLII",
+        "data0 8 100 10 0 -53 -1279 0 ECG signal 0",
+        "- 16",
+        "sig.dat 16x2:100+512 200(500)/uV 12 2048 0 0 0 Channel A",
+        "sig.dat 16 0/mV 12",
+        "sig.dat 24 1.5(-3)",
+    ] {
+        let signal = SignalInfo::from_signal_line(line).unwrap();
+        assert_eq!(signal.to_string(), line);
+    }
+}
+
+#[test]
+fn test_segment_lines_round_trip() {
+    for line in ["100s 21600", "~ 1800"] {
+        let segment = SegmentInfo::from_segment_line(line).unwrap();
+        assert_eq!(segment.to_string(), line);
+    }
+}
+
+// [Positional defaults]
+
+#[test]
+fn test_metadata_fills_positional_defaults() {
+    let mut metadata = Metadata::from_record_line("rec 1").unwrap();
+    metadata.num_samples = Some(1000);
+    assert_eq!(metadata.to_string(), "rec 1 250 1000");
+
+    let mut metadata = Metadata::from_record_line("rec 1 360").unwrap();
+    metadata.base_date = Metadata::from_record_line("rec 1 0:0:0 02/03/2004")
+        .unwrap()
+        .base_date;
+    assert_eq!(metadata.to_string(), "rec 1 360 00:00:00 02/03/2004");
+}
+
+#[test]
+fn test_signal_fills_positional_defaults() {
+    let mut signal = SignalInfo::from_signal_line("sig.dat 16").unwrap();
+    signal.description = Some("Lead II".to_string());
+    assert_eq!(signal.to_string(), "sig.dat 16 200 12 0 0 0 0 Lead II");
+    assert_eq!(
+        SignalInfo::from_signal_line(&signal.to_string()).unwrap(),
+        SignalInfo {
+            adc_gain: Some(200.0),
+            adc_resolution: Some(12),
+            adc_zero: Some(0),
+            initial_value: Some(0),
+            checksum: Some(0),
+            block_size: Some(0),
+            ..signal
+        }
+    );
+
+    let mut signal = SignalInfo::from_signal_line("d.dat 8").unwrap();
+    signal.adc_zero = Some(-5);
+    assert_eq!(signal.to_string(), "d.dat 8 200 10 -5");
+}
+
+#[test]
+fn test_unsigned_checksum_written_signed() {
+    let signal = SignalInfo::from_signal_line("100.dat 212 200 11 1024 995 43405 0").unwrap();
+    assert_eq!(signal.to_string(), "100.dat 212 200 11 1024 995 -22131 0");
+}
+
+// [Whole headers]
+
+#[test]
+fn test_header_round_trip() {
+    let text = "100 2 360 650000 00:00:00 01/01/2000\n\
+                100.dat 212 200 11 1024 995 -22131 0 MLII\n\
+                100.dat 212 200 11 1024 1011 20052 0 V5\n\
+                # 69 M 1085 1629 x1\n\
+                # Aldomet, Inderal\n";
+    let header = Header::from_reader(&mut Cursor::new(text)).unwrap();
+    assert_eq!(header.to_string(), text);
+}
+
+#[test]
+fn test_multi_segment_header_round_trip() {
+    let text = "multi/3 2 360 45000\n100s 21600\n~ 1800\n100s 21600\n";
+    let header = Header::from_reader(&mut Cursor::new(text)).unwrap();
+    assert_eq!(header.to_string(), text);
+
+    let reparsed = Header::from_reader(&mut Cursor::new(header.to_string())).unwrap();
+    assert_eq!(reparsed, header);
+}
@@ -0,0 +1,148 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::header::ParseOptions;
+use wfdb::limits::{
+    MAX_DESCRIPTION_LEN, MAX_RECORD_NAME_LEN, MAX_SAMPLES, MAX_SAMPLES_PER_FRAME, MAX_UNITS_LEN,
+};
+use wfdb::{Error, Header, HeaderField, Metadata, SegmentInfo, SignalInfo};
+
+// [Record Line Limits]
+
+#[test]
+fn test_record_name_at_limit() {
+    let name = "a".repeat(MAX_RECORD_NAME_LEN);
+    let metadata = Metadata::from_record_line(&format!("{name} 2")).unwrap();
+    assert_eq!(metadata.name, name);
+}
+
+#[test]
+fn test_record_name_too_long() {
+    let name = "a".repeat(MAX_RECORD_NAME_LEN + 1);
+    let result = Metadata::from_record_line(&format!("{name} 2"));
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("longer than"))
+    );
+}
+
+#[test]
+fn test_num_samples_too_large() {
+    assert!(Metadata::from_record_line(&format!("100 2 360 {MAX_SAMPLES}")).is_ok());
+    let result = Metadata::from_record_line(&format!("100 2 360 {}", MAX_SAMPLES + 1));
+    assert!(matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("exceeds")));
+}
+
+#[test]
+fn test_num_signals_too_large() {
+    let result = Metadata::from_record_line("100 4294967296");
+    assert!(matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("exceeds")));
+}
+
+#[test]
+fn test_num_segments_too_large() {
+    let result = Metadata::from_record_line("multi/4294967296 2");
+    assert!(matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("exceeds")));
+}
+
+// [Segment Line Limits]
+
+#[test]
+fn test_segment_name_too_long() {
+    let name = "s".repeat(MAX_RECORD_NAME_LEN + 1);
+    let result = SegmentInfo::from_segment_line(&format!("{name} 1800"));
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("longer than"))
+    );
+}
+
+#[test]
+fn test_segment_num_samples_too_large() {
+    let result = SegmentInfo::from_segment_line(&format!("seg {}", MAX_SAMPLES + 1));
+    assert!(matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("exceeds")));
+}
+
+// [Signal Line Limits]
+
+#[test]
+fn test_samples_per_frame_too_large() {
+    let line = format!("100.dat 16x{MAX_SAMPLES_PER_FRAME}");
+    assert!(SignalInfo::from_signal_line(&line).is_ok());
+    let line = format!("100.dat 16x{}", MAX_SAMPLES_PER_FRAME + 1);
+    let result = SignalInfo::from_signal_line(&line);
+    assert!(matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("exceeds")));
+}
+
+#[test]
+fn test_units_too_long() {
+    let units = "u".repeat(MAX_UNITS_LEN);
+    let signal = SignalInfo::from_signal_line(&format!("100.dat 16 200/{units}")).unwrap();
+    assert_eq!(signal.units(), units);
+
+    let units = "u".repeat(MAX_UNITS_LEN + 1);
+    let result = SignalInfo::from_signal_line(&format!("100.dat 16 200/{units}"));
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("longer than"))
+    );
+}
+
+#[test]
+fn test_description_too_long() {
+    let description = "d".repeat(MAX_DESCRIPTION_LEN);
+    let line = format!("100.dat 16 200 12 0 0 0 0 {description}");
+    let signal = SignalInfo::from_signal_line(&line).unwrap();
+    assert_eq!(signal.description(), Some(description.as_str()));
+
+    let line = format!("100.dat 16 200 12 0 0 0 0 {description} d");
+    let result = SignalInfo::from_signal_line(&line);
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(msg)) if msg.reason().contains("longer than"))
+    );
+}
+
+// [Plausible Ranges]
+
+#[test]
+fn test_implausible_values_rejected() {
+    for (text, field) in [
+        ("100 1 1e-300\n100.dat 16\n", HeaderField::SamplingFrequency),
+        (
+            "100 1 360/1e12\n100.dat 16\n",
+            HeaderField::CounterFrequency,
+        ),
+        ("100 1 360/1(1e20)\n100.dat 16\n", HeaderField::BaseCounter),
+        ("100 1 360\n100.dat 16 1e300\n", HeaderField::Gain),
+        ("100 1 360\n100.dat 16 1e-20/mV\n", HeaderField::Gain),
+    ] {
+        let result = Header::from_reader(&mut text.as_bytes());
+        let Err(Error::InvalidHeader(error)) = result else {
+            panic!("Expected InvalidHeader error for {text:?}, got {result:?}");
+        };
+        assert_eq!(error.field(), Some(field));
+        assert!(error.reason().contains("plausible range"));
+    }
+
+    // Uncalibrated signals have a gain of zero
+    let text = "100 1 360\n100.dat 16 0\n";
+    assert!(Header::from_reader(&mut text.as_bytes()).is_ok());
+}
+
+#[test]
+fn test_lenient_parsing_warns() {
+    let text = "100 1 1e-300\n100.dat 16 1e300/mV\n";
+    let options = ParseOptions::default().lenient();
+
+    let (header, warnings) =
+        Header::from_reader_with_options(&mut text.as_bytes(), &options).unwrap();
+    assert!((header.metadata().sampling_frequency() - 1e-300).abs() < f64::EPSILON);
+    let positions: Vec<_> = warnings.iter().map(|w| (w.line(), w.field())).collect();
+    assert_eq!(
+        positions,
+        vec![
+            (Some(1), Some(HeaderField::SamplingFrequency)),
+            (Some(2), Some(HeaderField::Gain)),
+        ]
+    );
+
+    let (_, warnings) =
+        Header::from_reader_with_options(&mut &b"100 1 360\n100.dat 16\n"[..], &options).unwrap();
+    assert!(warnings.is_empty());
+}
@@ -1,4 +1,4 @@
-use chrono::{NaiveDate, NaiveTime};
+use wfdb::header::{BaseDate, BaseTime};
 use wfdb::{Error, header::Metadata};
 
 // [Basic Parsing Tests]
@@ -33,8 +33,8 @@ fn test_full_record_line() {
         counter_frequency: Some(72.0),
         base_counter: Some(0.0),
         num_samples: Some(650_000),
-        base_time: Some(NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
-        base_date: Some(NaiveDate::from_ymd_opt(1990, 5, 1).unwrap()),
+        base_time: Some(BaseTime::new(9, 30, 0)),
+        base_date: Some(BaseDate::new(1990, 5, 1)),
     };
     assert_eq!(metadata, expected);
 }
@@ -199,7 +199,7 @@ fn test_time_only() {
         counter_frequency: None,
         base_counter: None,
         num_samples: None,
-        base_time: Some(NaiveTime::from_hms_opt(9, 15, 30).unwrap()),
+        base_time: Some(BaseTime::new(9, 15, 30)),
         base_date: None,
     };
     assert_eq!(metadata, expected);
@@ -217,8 +217,8 @@ fn test_time_and_date_only() {
         counter_frequency: None,
         base_counter: None,
         num_samples: None,
-        base_time: Some(NaiveTime::from_hms_opt(12, 30, 45).unwrap()),
-        base_date: Some(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()),
+        base_time: Some(BaseTime::new(12, 30, 45)),
+        base_date: Some(BaseDate::new(2000, 1, 1)),
     };
     assert_eq!(metadata, expected);
 }
@@ -235,7 +235,7 @@ fn test_frequency_and_time_no_samples() {
         counter_frequency: None,
         base_counter: None,
         num_samples: None,
-        base_time: Some(NaiveTime::from_hms_opt(12, 30, 45).unwrap()),
+        base_time: Some(BaseTime::new(12, 30, 45)),
         base_date: None,
     };
     assert_eq!(metadata, expected);
@@ -253,7 +253,7 @@ fn test_samples_and_time_no_frequency() {
         counter_frequency: None,
         base_counter: None,
         num_samples: None,
-        base_time: Some(NaiveTime::from_hms_opt(12, 30, 45).unwrap()),
+        base_time: Some(BaseTime::new(12, 30, 45)),
         base_date: None,
     };
     assert_eq!(metadata, expected);
@@ -412,24 +412,26 @@ fn test_num_samples_accessor() {
 }
 
 #[test]
+#[cfg(feature = "chrono")]
 fn test_base_time_accessor() {
     let metadata = create_minimal_metadata();
     assert_eq!(metadata.base_time(), None);
     let metadata = create_full_metadata();
     assert_eq!(
-        metadata.base_time(),
-        Some(NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+        metadata.base_time().map(BaseTime::from),
+        Some(BaseTime::new(9, 30, 0))
     );
 }
 
 #[test]
+#[cfg(feature = "chrono")]
 fn test_base_date_accessor() {
     let metadata = create_minimal_metadata();
     assert_eq!(metadata.base_date(), None);
     let metadata = create_full_metadata();
     assert_eq!(
-        metadata.base_date(),
-        Some(NaiveDate::from_ymd_opt(1990, 5, 1).unwrap())
+        metadata.base_date().map(BaseDate::from),
+        Some(BaseDate::new(1990, 5, 1))
     );
 }
 
@@ -701,3 +703,118 @@ fn test_date_only_without_time() {
         "Expected InvalidHeader error, got {result:?}"
     );
 }
+
+// [Non-finite Numeric Fields]
+
+#[test]
+fn test_nan_sampling_frequency() {
+    let result = Metadata::from_record_line("rec 2 NaN");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
+
+#[test]
+fn test_infinite_sampling_frequency() {
+    let result = Metadata::from_record_line("rec 2 inf");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
+
+#[test]
+fn test_overflowing_sampling_frequency() {
+    let result = Metadata::from_record_line("rec 2 1e999 1000");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
+
+#[test]
+fn test_non_finite_counter_frequency() {
+    let result = Metadata::from_record_line("rec 2 360/inf");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
+
+#[test]
+fn test_non_finite_base_counter() {
+    let result = Metadata::from_record_line("rec 2 360/72(NaN)");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
+
+// [Base Time and Date]
+
+#[test]
+fn test_base_time_and_date_fallbacks() {
+    let metadata = Metadata::from_record_line("rec 2 360 1000 9:05:03 29/02/2000").unwrap();
+    assert_eq!(metadata.base_time_hms(), Some((9, 5, 3)));
+    assert_eq!(metadata.base_date_ymd(), Some((2000, 2, 29)));
+    assert_eq!(metadata.to_string(), "rec 2 360 1000 09:05:03 29/02/2000");
+
+    let metadata = Metadata::from_record_line("rec 2 360").unwrap();
+    assert_eq!(metadata.base_time_hms(), None);
+    assert_eq!(metadata.base_date_ymd(), None);
+}
+
+#[test]
+fn test_invalid_base_dates() {
+    for line in [
+        "rec 2 12:00:00 29/02/2001",
+        "rec 2 12:00:00 31/04/2020",
+        "rec 2 12:00:00 00/01/2020",
+        "rec 2 12:00:00 01/13/2020",
+        "rec 2 12:00:60",
+        "rec 2 12:00",
+        "rec 2 12:00:00:00",
+    ] {
+        let result = Metadata::from_record_line(line);
+        assert!(
+            matches!(result, Err(Error::InvalidHeader(_))),
+            "Expected InvalidHeader error for '{line}', got {result:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_base_datetime_chrono() {
+    let metadata = Metadata::from_record_line("rec 2 360 1000 10:30:00 01/06/2020").unwrap();
+    let base_time = metadata.base_time().unwrap();
+    let base_date = metadata.base_date().unwrap();
+    assert_eq!(
+        metadata.base_datetime().unwrap().to_string(),
+        "2020-06-01 10:30:00"
+    );
+    assert_eq!(BaseTime::from(base_time), BaseTime::new(10, 30, 0));
+    assert_eq!(BaseDate::from(base_date), BaseDate::new(2020, 6, 1));
+}
+
+// [Counter Conversions]
+
+#[test]
+fn test_counter_conversions() {
+    let metadata = Metadata::from_record_line("rec 1 250/10(-20) 5000").unwrap();
+    assert!((metadata.sample_to_counter(0) + 20.0).abs() < 1e-9);
+    assert!((metadata.sample_to_counter(1000) + 20.0 - 40.0).abs() < 1e-9);
+    assert_eq!(metadata.counter_to_sample(20.0), Some(1000));
+    assert_eq!(metadata.counter_to_sample(-20.0), Some(0));
+    // Nearest sample, and none before the first one
+    assert_eq!(metadata.counter_to_sample(-19.99), Some(0));
+    assert_eq!(metadata.counter_to_sample(-19.9), Some(3));
+    assert_eq!(metadata.counter_to_sample(-21.0), None);
+    assert_eq!(metadata.counter_to_sample(f64::NAN), None);
+
+    // Without a counter frequency, the counter counts samples
+    let metadata = Metadata::from_record_line("rec 1 360").unwrap();
+    assert!((metadata.sample_to_counter(720) - 720.0).abs() < 1e-9);
+    assert_eq!(metadata.counter_to_sample(720.0), Some(720));
+}
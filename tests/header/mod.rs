@@ -1,4 +1,10 @@
 pub mod header_parser;
+pub mod header_writer;
+pub mod limits;
 pub mod metadata_parser;
+pub mod number;
 pub mod segment_info_parser;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod signal_info_parser;
+pub mod signal_type;
@@ -0,0 +1,71 @@
+use wfdb::number::{Number, format_number};
+use wfdb::{Metadata, SignalInfo};
+
+// [Formatting conventions]
+
+#[test]
+fn test_format_number_conventions() {
+    for (value, text) in [
+        (200.0, "200"),
+        (360.0, "360"),
+        (-3.0, "-3"),
+        (0.0, "0"),
+        (-0.0, "0"),
+        (0.5, "0.5"),
+        (1.5e-5, "0.000015"),
+        (1e-7, "1e-7"),
+        (2.5e20, "2.5e20"),
+        (0.1 + 0.2, "0.30000000000000004"),
+        (f64::NAN, "NaN"),
+        (f64::INFINITY, "inf"),
+        (f64::NEG_INFINITY, "-inf"),
+    ] {
+        assert_eq!(format_number(value), text, "formatting {value:?}");
+        assert_eq!(Number(value).to_string(), text);
+    }
+}
+
+// [Round trips]
+
+#[test]
+fn test_format_number_round_trips() {
+    // Values spread over the whole finite range, from a fixed bit pattern sequence
+    let mut bits = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut checked = 0;
+    while checked < 10_000 {
+        bits = bits
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let value = f64::from_bits(bits);
+        if !value.is_finite() {
+            continue;
+        }
+        let parsed: f64 = format_number(value).parse().unwrap();
+        assert_eq!(parsed.to_bits(), value.to_bits(), "round trip of {value:?}");
+        checked += 1;
+    }
+}
+
+#[test]
+fn test_header_numbers_round_trip() {
+    for frequency in [360.0, 0.1 + 0.2, 1.0 / 3.0, 128.000_000_1, 1e-7] {
+        let metadata = Metadata {
+            sampling_frequency: Some(frequency),
+            num_samples: Some(10),
+            ..Metadata::from_record_line("rec 1").unwrap()
+        };
+        let parsed = Metadata::from_record_line(&metadata.to_string()).unwrap();
+        assert_eq!(parsed.sampling_frequency, Some(frequency));
+    }
+
+    for gain in [200.0, 0.1 + 0.2, 1.0 / 3.0, 6.553_5e4, 1e-7] {
+        let signal = SignalInfo {
+            adc_gain: Some(gain),
+            ..SignalInfo::from_signal_line("sig.dat 16").unwrap()
+        };
+        let line = signal.to_string();
+        assert_eq!(line, format!("sig.dat 16 {}", format_number(gain)));
+        let parsed = SignalInfo::from_signal_line(&line).unwrap();
+        assert_eq!(parsed.adc_gain, Some(gain));
+    }
+}
@@ -0,0 +1,64 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::header::Specifications;
+use wfdb::{Header, SignalFormat};
+
+fn parse(text: &str) -> Header {
+    Header::from_reader(&mut text.as_bytes()).unwrap()
+}
+
+#[test]
+fn test_header_json_round_trip() {
+    let header = parse(
+        "100 2 360 650000 12:00:00 01/01/2000\n\
+         100.dat 212 200 11 1024 995 -22131 0 MLII\n\
+         100.dat 212x2:5+512 200(10)/uV 11 1024 1011 20052 0 V5\n\
+         # 69 M 1085 1629 x1\n",
+    );
+
+    let json = serde_json::to_string(&header).unwrap();
+    let decoded: Header = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, header);
+}
+
+#[test]
+fn test_multi_segment_json_round_trip() {
+    let header = parse("multi/2 2 360 45000\n100s 21600\nnull 23400\n");
+
+    let json = serde_json::to_value(&header).unwrap();
+    assert_eq!(
+        json["specifications"]["multi_segment"]["segments"][1]["record_name"],
+        "null"
+    );
+    let decoded: Header = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded, header);
+}
+
+#[test]
+fn test_json_layout() {
+    let header = parse("rec 1 250 10 08:30:00 02/03/2004\nrec.dat 16 200/mV\n");
+    let json = serde_json::to_value(&header).unwrap();
+
+    assert_eq!(json["metadata"]["name"], "rec");
+    assert_eq!(json["metadata"]["sampling_frequency"], 250.0);
+    assert_eq!(json["metadata"]["base_time"]["hours"], 8);
+    assert_eq!(json["metadata"]["base_date"]["year"], 2004);
+    let signal = &json["specifications"]["single_segment"]["signals"][0];
+    assert_eq!(signal["format"], 16);
+    assert_eq!(signal["units"], "mV");
+    assert!(signal["description"].is_null());
+}
+
+#[test]
+fn test_signal_format_codes() {
+    assert_eq!(
+        serde_json::to_string(&SignalFormat::Format212).unwrap(),
+        "212"
+    );
+    assert_eq!(
+        serde_json::from_str::<SignalFormat>("311").unwrap(),
+        SignalFormat::Format311
+    );
+    assert!(serde_json::from_str::<SignalFormat>("7").is_err());
+    assert!(serde_json::from_str::<Specifications>("{\"unknown\": {}}").is_err());
+}
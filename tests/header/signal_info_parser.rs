@@ -41,7 +41,7 @@ fn test_mit_bih_signal_line() {
         adc_resolution: Some(11),
         adc_zero: Some(1024),
         initial_value: Some(995),
-        checksum: Some(43405),
+        checksum: Some(-22131),
         block_size: Some(0),
         description: Some("MLII".to_string()),
     };
@@ -64,7 +64,7 @@ fn test_aha_signal_line() {
         adc_resolution: Some(10),
         adc_zero: Some(0),
         initial_value: Some(-53),
-        checksum: Some(64257),
+        checksum: Some(-1279),
         block_size: Some(0),
         description: Some("ECG signal 0".to_string()),
     };
@@ -239,7 +239,7 @@ fn test_complete_adc_spec() {
     assert_eq!(signal.adc_resolution, Some(12));
     assert_eq!(signal.adc_zero, Some(2048));
     assert_eq!(signal.initial_value, Some(100));
-    assert_eq!(signal.checksum, Some(60536));
+    assert_eq!(signal.checksum, Some(-5000));
     assert_eq!(signal.block_size, Some(0));
 }
 
@@ -251,7 +251,7 @@ fn test_adc_spec_with_description() {
     assert_eq!(signal.adc_resolution, Some(12));
     assert_eq!(signal.adc_zero, Some(2048));
     assert_eq!(signal.initial_value, Some(100));
-    assert_eq!(signal.checksum, Some(60536));
+    assert_eq!(signal.checksum, Some(-5000));
     assert_eq!(signal.block_size, Some(0));
     assert_eq!(signal.description, Some("Lead II".to_string()));
 }
@@ -465,7 +465,7 @@ fn test_checksum_accessor() {
     let signal = create_minimal_signal();
     assert_eq!(signal.checksum(), None);
     let signal = create_full_signal();
-    assert_eq!(signal.checksum(), Some(60536));
+    assert_eq!(signal.checksum(), Some(-5000));
 }
 
 #[test]
@@ -491,15 +491,16 @@ fn test_valid_gain_format_errors() {
     // When gain is successfully parsed but invalid, it should error
     // This happens when the field format matches gain pattern but value is invalid
 
-    // Test with integer that parses as block_size instead
-    let line = "sig.dat 16 0";
+    // Negative gains are never detected as a gain field
+    let line = "sig.dat 16 -200/mV";
     let signal = SignalInfo::from_signal_line(line).unwrap();
-    assert_eq!(signal.block_size, Some(0));
+    assert_eq!(signal.adc_gain, None);
+    assert_eq!(signal.description, Some("-200/mV".to_string()));
 
     // Fields that look like descriptions due to failed gain parsing
-    let line = "sig.dat 16 0.0/mV";
+    let line = "sig.dat 16 abc/mV";
     let signal = SignalInfo::from_signal_line(line).unwrap();
-    assert_eq!(signal.description, Some("0.0/mV".to_string()));
+    assert_eq!(signal.description, Some("abc/mV".to_string()));
 }
 
 #[test]
@@ -581,10 +582,34 @@ fn test_invalid_byte_offset() {
 
 #[test]
 fn test_zero_value_after_format() {
-    // A zero after format could be block_size or description, not gain
+    // A zero gain after format marks an uncalibrated signal
     let line = "sig.dat 16 0";
     let signal = SignalInfo::from_signal_line(line).unwrap();
-    assert_eq!(signal.block_size, Some(0));
+    assert_eq!(signal.adc_gain, Some(0.0));
+    assert_eq!(signal.block_size, None);
+    assert!(!signal.is_calibrated());
+}
+
+#[test]
+fn test_uncalibrated_full_signal_line() {
+    let line = "sig.dat 16 0.0(100)/mV 12 0 0 0 0 Marker";
+    let signal = SignalInfo::from_signal_line(line).unwrap();
+    assert_eq!(signal.adc_gain, Some(0.0));
+    assert_eq!(signal.baseline, Some(100));
+    assert_eq!(signal.units, Some("mV".to_string()));
+    assert_eq!(signal.adc_resolution, Some(12));
+    assert_eq!(signal.description, Some("Marker".to_string()));
+    assert!(!signal.is_calibrated());
+}
+
+#[test]
+fn test_calibrated_signal() {
+    let signal = SignalInfo::from_signal_line("sig.dat 16 200").unwrap();
+    assert!(signal.is_calibrated());
+
+    // Omitted gain falls back to the (calibrated) default
+    let signal = SignalInfo::from_signal_line("sig.dat 16").unwrap();
+    assert!(signal.is_calibrated());
 }
 
 #[test]
@@ -691,7 +716,34 @@ fn test_invalid_block_size_after_checksum() {
 fn test_negative_checksum() {
     let line = "sig.dat 16 200 12 2048 100 32768 0";
     let signal = SignalInfo::from_signal_line(line).unwrap();
-    assert_eq!(signal.checksum, Some(32768));
+    assert_eq!(signal.checksum, Some(-32768));
+}
+
+#[test]
+fn test_signed_and_unsigned_checksum_agree() {
+    let unsigned = SignalInfo::from_signal_line("sig.dat 16 200 12 2048 100 64257 0").unwrap();
+    let signed = SignalInfo::from_signal_line("sig.dat 16 200 12 2048 100 -1279 0").unwrap();
+    assert_eq!(unsigned.checksum, Some(-1279));
+    assert_eq!(unsigned.checksum, signed.checksum);
+
+    let min = SignalInfo::from_signal_line("sig.dat 16 200 12 2048 100 -32768 0").unwrap();
+    assert_eq!(min.checksum, Some(i16::MIN));
+    let max = SignalInfo::from_signal_line("sig.dat 16 200 12 2048 100 65535 0").unwrap();
+    assert_eq!(max.checksum, Some(-1));
+}
+
+#[test]
+fn test_out_of_range_checksum() {
+    for line in [
+        "sig.dat 16 200 12 2048 100 65536 0",
+        "sig.dat 16 200 12 2048 100 -32769 0",
+    ] {
+        let result = SignalInfo::from_signal_line(line);
+        assert!(
+            matches!(result, Err(Error::InvalidHeader(_))),
+            "Expected InvalidHeader error, got {result:?}"
+        );
+    }
 }
 
 #[test]
@@ -757,3 +809,32 @@ fn test_mixed_whitespace() {
     assert_eq!(signal.format, SignalFormat::Format16);
     assert_eq!(signal.adc_gain, Some(200.0));
 }
+
+// [Non-finite Numeric Fields]
+
+#[test]
+fn test_infinite_gain() {
+    let result = SignalInfo::from_signal_line("sig.dat 16 inf 12 0");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
+
+#[test]
+fn test_nan_gain() {
+    let result = SignalInfo::from_signal_line("sig.dat 16 NaN 12 0");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
+
+#[test]
+fn test_overflowing_gain_with_units() {
+    let result = SignalInfo::from_signal_line("sig.dat 16 1e999(0)/mV 12 0");
+    assert!(
+        matches!(result, Err(Error::InvalidHeader(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+}
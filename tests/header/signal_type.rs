@@ -0,0 +1,81 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::SignalInfo;
+use wfdb::header::SignalType;
+
+fn signal_type(line: &str) -> SignalType {
+    SignalInfo::from_signal_line(line).unwrap().signal_type()
+}
+
+#[test]
+fn test_signal_type_by_description() {
+    assert_eq!(
+        signal_type("100.dat 212 200 11 1024 995 -22131 0 MLII"),
+        SignalType::Ecg
+    );
+    assert_eq!(
+        signal_type("100.dat 212 200 11 1024 1011 20052 0 V5"),
+        SignalType::Ecg
+    );
+    assert_eq!(
+        signal_type("r.dat 16 200/mV 12 0 0 0 0 ECG1"),
+        SignalType::Ecg
+    );
+    assert_eq!(
+        signal_type("r.dat 16 200/mV 12 0 0 0 0 Lead II"),
+        SignalType::Ecg
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/mmHg 12 0 0 0 0 ABP"),
+        SignalType::Abp
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/mmHg 12 0 0 0 0 ART"),
+        SignalType::Abp
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/NU 12 0 0 0 0 PLETH"),
+        SignalType::Ppg
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/NU 12 0 0 0 0 RESP"),
+        SignalType::Resp
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/uV 12 0 0 0 0 Resp (abdomen)"),
+        SignalType::Resp
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/% 12 0 0 0 0 SpO2"),
+        SignalType::SpO2
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/uV 12 0 0 0 0 EEG Fpz-Cz"),
+        SignalType::Eeg
+    );
+    assert_eq!(
+        signal_type("r.dat 16 100/uV 12 0 0 0 0 C3-A2"),
+        SignalType::Eeg
+    );
+}
+
+#[test]
+fn test_signal_type_by_units() {
+    assert_eq!(SignalType::classify(None, "mmHg"), SignalType::Abp);
+    assert_eq!(SignalType::classify(Some("ch1"), "uV"), SignalType::Eeg);
+    assert_eq!(SignalType::classify(None, "%"), SignalType::SpO2);
+    assert_eq!(SignalType::classify(None, "mV"), SignalType::Unknown);
+    assert_eq!(
+        SignalType::classify(Some("Temp"), "degC"),
+        SignalType::Unknown
+    );
+}
+
+#[test]
+fn test_signal_type_description_takes_precedence() {
+    // A respiration signal recorded in mV is not an ECG
+    assert_eq!(SignalType::classify(Some("Thor"), "mV"), SignalType::Resp);
+    // O2 is an EEG electrode, but O2sat is not a derivation
+    assert_eq!(SignalType::classify(Some("O2sat"), "%"), SignalType::SpO2);
+    assert_eq!(SignalType::classify(Some("O2-A1"), "uV"), SignalType::Eeg);
+}
@@ -0,0 +1,129 @@
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use std::fs::File;
+
+use arrow_array::{Array, Float64Array, RecordBatch, UInt64Array};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use wfdb::Record;
+use wfdb::interop::arrow::{self, ArrowOptions};
+
+use super::common::{format16_bytes, two_signal_record, write_files, write_record};
+
+/// Signals I and an undescribed one, in mV.
+const SIGNALS: [&str; 2] = ["100/mV 16 0 0 0 0 I", "100/mV 16 0 0 0 0"];
+
+/// Five frames at 10 Hz, the second signal invalid in frame 2.
+const FRAMES: [[i16; 2]; 5] = [
+    [0, 0],
+    [100, -100],
+    [200, i16::MIN],
+    [300, -300],
+    [400, -400],
+];
+
+#[test]
+fn test_to_arrow() {
+    let dir = two_signal_record(10, SIGNALS, &FRAMES);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let batches = record.to_arrow().unwrap();
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+    assert_eq!(batch.num_rows(), 5);
+
+    let schema = batch.schema();
+    let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(names, vec!["sample", "time", "I", "signal_1"]);
+    assert_eq!(schema.field(2).metadata()["units"], "mV");
+    assert_eq!(schema.metadata()["record"], "pair");
+    assert_eq!(schema.metadata()["sampling_frequency"], "10");
+
+    let samples = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    assert_eq!(samples.values(), &[0, 1, 2, 3, 4]);
+    let times = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap();
+    assert_eq!(times.value(3), 0.3);
+    let first = batch
+        .column(2)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap();
+    assert_eq!(first.values(), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+
+    // Invalid samples are nulls
+    let second = batch
+        .column(3)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap();
+    assert_eq!(second.null_count(), 1);
+    assert!(second.is_null(2));
+    assert_eq!(second.value(4), -4.0);
+}
+
+#[test]
+fn test_batches_split_and_gaps() {
+    let dir = write_record("multi", "multi/2 1 10 5\nseg_a 3\n~ 2\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 10 3\nseg_a.dat 16 100/mV 12 0 0 0 0 ECG\n",
+        &[("seg_a.dat", &format16_bytes(&[100, 200, 300]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let options = ArrowOptions::default().with_batch_size(2);
+    let batches = arrow::batches(&record, &options)
+        .unwrap()
+        .collect::<wfdb::Result<Vec<_>>>()
+        .unwrap();
+    let rows: Vec<usize> = batches.iter().map(RecordBatch::num_rows).collect();
+    assert_eq!(rows, vec![2, 2, 1]);
+
+    let last = batches[2]
+        .column(0)
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    assert_eq!(last.value(0), 4);
+    let ecg = batches[1]
+        .column(2)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap();
+    assert_eq!(ecg.value(0), 3.0);
+    assert!(ecg.is_null(1));
+
+    let options = ArrowOptions::default().with_batch_size(0);
+    assert!(arrow::batches(&record, &options).is_err());
+}
+
+#[test]
+fn test_write_parquet() {
+    let dir = two_signal_record(10, SIGNALS, &FRAMES);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let path = dir.path().join("pair.parquet");
+
+    let options = ArrowOptions::default().with_batch_size(3);
+    assert_eq!(arrow::write_parquet(&record, &path, &options).unwrap(), 5);
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+    assert_eq!(builder.schema().metadata()["record"], "pair");
+    let batches = builder
+        .build()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 5);
+    assert_eq!(
+        batches[0].columns(),
+        record.to_arrow().unwrap()[0].columns()
+    );
+}
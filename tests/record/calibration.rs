@@ -0,0 +1,72 @@
+use wfdb::Record;
+use wfdb::record::export::{ChannelFormat, per_channel};
+
+use super::common::{format16_bytes, two_signal_record, write_record};
+
+/// Two signals: a calibrated ECG and an uncalibrated (gain 0) marker channel.
+fn mixed_record() -> tempfile::TempDir {
+    two_signal_record(
+        100,
+        ["100(0)/mV 12 0 0 0 0 ECG", "0(5)/mV 12 0 0 0 0 Marker"],
+        &[[100, 7], [200, -3], [300, 12]],
+    )
+}
+
+#[test]
+fn test_uncalibrated_physical_reads_return_adc() {
+    let dir = mixed_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let mut ecg = record.signal_reader(0).unwrap();
+    assert_eq!(ecg.read_physical(3).unwrap(), vec![1.0, 2.0, 3.0]);
+
+    let mut marker = record.signal_reader(1).unwrap();
+    assert!(!marker.signal_info().is_calibrated());
+    assert_eq!(marker.read_physical(3).unwrap(), vec![7.0, -3.0, 12.0]);
+    assert_eq!(marker.to_adc(-3.0), -3);
+
+    let mut frames = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        frames.read_frames_physical(1).unwrap(),
+        vec![vec![1.0, 7.0]]
+    );
+
+    let matrix = record.pipeline().physical().to_matrix().unwrap();
+    assert_eq!(matrix.columns[1], vec![7.0, -3.0, 12.0]);
+}
+
+#[test]
+fn test_uncalibrated_export_manifest() {
+    let dir = mixed_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let manifest = per_channel(&record, dir.path().join("out"), ChannelFormat::Csv).unwrap();
+    assert!(manifest.channels[0].calibrated);
+    assert!(!manifest.channels[1].calibrated);
+    assert!(manifest.to_json().contains("\"calibrated\": false"));
+
+    let marker = std::fs::read_to_string(dir.path().join("out").join("pair_1.csv")).unwrap();
+    assert_eq!(marker, "7\n-3\n12\n");
+}
+
+#[test]
+fn test_invalid_samples_read_as_nan() {
+    let data = format16_bytes(&[100, i16::MIN, 300]);
+    let dir = write_record(
+        "rec",
+        "rec 1 100 3\nrec.dat 16 100/mV 12 0 0 0 0 ECG\n",
+        &[("rec.dat", &data)],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let physical = record.signal_reader(0).unwrap().read_physical(3).unwrap();
+    assert!(physical[1].is_nan());
+
+    let frames = record
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames_physical(3)
+        .unwrap();
+    assert!(frames[1][0].is_nan());
+    assert!((frames[2][0] - 3.0).abs() < f64::EPSILON);
+}
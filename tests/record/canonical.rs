@@ -0,0 +1,112 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::record::MemorySource;
+
+#[test]
+fn test_canonical_json_single_segment() {
+    let record = Record::from_sources(
+        "rec 2 360/1.5(10) 650000 08:30:00 01/02/2003\n\
+         rec.dat 212 200/mV 11 1024 995 -22131 0 MLII\n\
+         rec.dat 212x2 0.5 11 1024 1011 20052 0 \"V5\"\n\
+         # age: 69 \"sex\": M\n",
+        MemorySource::new(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        record.to_canonical_json(),
+        r#"{
+  "info": [
+    " age: 69 \"sex\": M"
+  ],
+  "metadata": {
+    "base_counter": 10,
+    "base_date": "01/02/2003",
+    "base_time": "08:30:00",
+    "counter_frequency": 1.5,
+    "name": "rec",
+    "num_samples": 650000,
+    "num_segments": null,
+    "num_signals": 2,
+    "sampling_frequency": 360
+  },
+  "segments": null,
+  "signals": [
+    {
+      "adc_gain": 200,
+      "adc_resolution": 11,
+      "adc_zero": 1024,
+      "baseline": null,
+      "block_size": 0,
+      "byte_offset": null,
+      "checksum": -22131,
+      "description": "MLII",
+      "file_name": "rec.dat",
+      "format": 212,
+      "initial_value": 995,
+      "samples_per_frame": null,
+      "skew": null,
+      "units": "mV"
+    },
+    {
+      "adc_gain": 0.5,
+      "adc_resolution": 11,
+      "adc_zero": 1024,
+      "baseline": null,
+      "block_size": 0,
+      "byte_offset": null,
+      "checksum": 20052,
+      "description": "\"V5\"",
+      "file_name": "rec.dat",
+      "format": 212,
+      "initial_value": 1011,
+      "samples_per_frame": 2,
+      "skew": null,
+      "units": null
+    }
+  ]
+}
+"#
+    );
+}
+
+#[test]
+fn test_canonical_json_multi_segment() {
+    let record = Record::from_sources(
+        "multi/2 2 125 1500\n\
+         layout 0\n\
+         seg_1 1500\n",
+        MemorySource::new(),
+    )
+    .unwrap();
+
+    let json = record.to_canonical_json();
+    assert!(json.contains("\"num_segments\": 2,"));
+    assert!(json.contains("\"signals\": null\n"));
+    assert!(json.contains(
+        "  \"segments\": [\n    {\n      \"num_samples\": 0,\n      \"record_name\": \"layout\"\n    },"
+    ));
+    assert!(json.contains("  \"info\": [],\n"));
+}
+
+#[test]
+fn test_canonical_json_diff_is_local() {
+    let header = "rec 1 250 1000\nrec.dat 16 200/mV 12 0 0 0 0 I\n";
+    let before = Record::from_sources(header, MemorySource::new()).unwrap();
+    let after =
+        Record::from_sources(&header.replace("200/mV", "400/mV"), MemorySource::new()).unwrap();
+
+    let before = before.to_canonical_json();
+    let after = after.to_canonical_json();
+    let changed: Vec<(&str, &str)> = before
+        .lines()
+        .zip(after.lines())
+        .filter(|(a, b)| a != b)
+        .collect();
+    assert_eq!(before.lines().count(), after.lines().count());
+    assert_eq!(
+        changed,
+        vec![("      \"adc_gain\": 200,", "      \"adc_gain\": 400,")]
+    );
+}
@@ -0,0 +1,147 @@
+#![allow(unsafe_code, clippy::unwrap_used, clippy::float_cmp)]
+
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use wfdb::capi::{
+    WFDB_CAPI_VERSION, WfdbRecordInfo, WfdbSignalInfo, wfdb_capi_version, wfdb_last_error,
+    wfdb_reader_free, wfdb_reader_open, wfdb_reader_read_frame, wfdb_reader_read_physical,
+    wfdb_reader_seek, wfdb_record_free, wfdb_record_info, wfdb_record_open,
+    wfdb_signal_description, wfdb_signal_info, wfdb_signal_units,
+};
+
+use super::common::two_signal_record;
+
+/// Two signals of 4 frames at 250 Hz: I = 0..4 (mV, gain 100) and an undescribed one.
+fn two_channel_record() -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..4).map(|i| [i * 100, -i]).collect();
+    two_signal_record(250, ["100/mV 12 0 0 0 0 I", "200 12 0 0 0 0"], &frames)
+}
+
+/// Get the message of the last error.
+fn last_error() -> String {
+    unsafe { CStr::from_ptr(wfdb_last_error()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn test_capi_record() {
+    let dir = two_channel_record();
+    let path = CString::new(dir.path().join("pair").to_str().unwrap()).unwrap();
+    assert_eq!(wfdb_capi_version(), WFDB_CAPI_VERSION);
+
+    unsafe {
+        let record = wfdb_record_open(path.as_ptr());
+        assert!(!record.is_null());
+
+        let mut info = WfdbRecordInfo::default();
+        assert_eq!(wfdb_record_info(record, &raw mut info), 0);
+        assert_eq!(
+            info,
+            WfdbRecordInfo {
+                num_signals: 2,
+                num_segments: 0,
+                sampling_frequency: 250.0,
+                num_samples: 4,
+            }
+        );
+
+        let mut signal = WfdbSignalInfo::default();
+        assert_eq!(wfdb_signal_info(record, 0, &raw mut signal), 0);
+        assert_eq!(signal.format, 16);
+        assert_eq!(signal.adc_gain, 100.0);
+        assert_eq!(signal.adc_resolution, 12);
+        assert_eq!(
+            CStr::from_ptr(wfdb_signal_description(record, 0)).to_str(),
+            Ok("I")
+        );
+        assert_eq!(
+            CStr::from_ptr(wfdb_signal_units(record, 0)).to_str(),
+            Ok("mV")
+        );
+        assert!(wfdb_signal_description(record, 1).is_null());
+
+        assert_eq!(wfdb_signal_info(record, 2, &raw mut signal), -1);
+        assert!(last_error().contains("out of bounds"));
+
+        wfdb_record_free(record);
+    }
+}
+
+#[test]
+fn test_capi_reader() {
+    let dir = two_channel_record();
+    let path = CString::new(dir.path().join("pair").to_str().unwrap()).unwrap();
+
+    unsafe {
+        let record = wfdb_record_open(path.as_ptr());
+        let reader = wfdb_reader_open(record);
+        // Readers outlive their record
+        wfdb_record_free(record);
+        assert!(!reader.is_null());
+
+        let mut samples = [0; 2];
+        assert_eq!(wfdb_reader_read_frame(reader, samples.as_mut_ptr(), 2), 2);
+        assert_eq!(samples, [0, 0]);
+        assert_eq!(wfdb_reader_read_frame(reader, samples.as_mut_ptr(), 2), 2);
+        assert_eq!(samples, [100, -1]);
+
+        let mut values = [0.0; 3];
+        assert_eq!(wfdb_reader_read_physical(reader, values.as_mut_ptr(), 3), 2);
+        assert_eq!(values, [2.0, -0.01, 0.0]);
+
+        assert_eq!(wfdb_reader_seek(reader, 3), 0);
+        assert_eq!(wfdb_reader_read_frame(reader, samples.as_mut_ptr(), 2), 2);
+        assert_eq!(samples, [300, -3]);
+        assert_eq!(wfdb_reader_read_frame(reader, samples.as_mut_ptr(), 2), 0);
+
+        // Short buffers are rejected
+        assert_eq!(wfdb_reader_seek(reader, 0), 0);
+        assert_eq!(wfdb_reader_read_frame(reader, samples.as_mut_ptr(), 1), -1);
+        assert!(last_error().contains("too small"));
+
+        wfdb_reader_free(reader);
+    }
+}
+
+#[test]
+fn test_capi_seek_out_of_range() {
+    let dir = two_channel_record();
+    let path = CString::new(dir.path().join("pair").to_str().unwrap()).unwrap();
+
+    unsafe {
+        let record = wfdb_record_open(path.as_ptr());
+        let reader = wfdb_reader_open(record);
+
+        // Failures deep in the library, panics included, reach the caller
+        // as an error instead of aborting the process
+        assert_eq!(wfdb_reader_seek(reader, u64::MAX), -1);
+        assert!(!last_error().is_empty());
+
+        // The reader stays usable
+        assert_eq!(wfdb_reader_seek(reader, 1), 0);
+        let mut samples = [0; 2];
+        assert_eq!(wfdb_reader_read_frame(reader, samples.as_mut_ptr(), 2), 2);
+        assert_eq!(samples, [100, -1]);
+
+        wfdb_reader_free(reader);
+        wfdb_record_free(record);
+    }
+}
+
+#[test]
+fn test_capi_errors() {
+    let missing = CString::new("no/such/record").unwrap();
+    unsafe {
+        assert!(wfdb_record_open(missing.as_ptr()).is_null());
+        assert!(!last_error().is_empty());
+        assert!(wfdb_record_open(ptr::null()).is_null());
+        assert!(last_error().contains("'path' is NULL"));
+        assert!(wfdb_reader_open(ptr::null()).is_null());
+
+        // Freeing NULL is a no-op
+        wfdb_record_free(ptr::null_mut());
+        wfdb_reader_free(ptr::null_mut());
+    }
+}
@@ -0,0 +1,56 @@
+#![allow(clippy::unwrap_used)]
+
+use std::path::Path;
+
+use tempfile::TempDir;
+
+/// Write a header and its signal files into a fresh temporary directory.
+pub fn write_record(header_name: &str, header_text: &str, files: &[(&str, &[u8])]) -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    write_files(dir.path(), header_name, header_text, files);
+    dir
+}
+
+/// Write a header and its signal files into an existing directory.
+pub fn write_files(dir: &Path, header_name: &str, header_text: &str, files: &[(&str, &[u8])]) {
+    std::fs::write(dir.join(format!("{header_name}.hea")), header_text).unwrap();
+    for (name, data) in files {
+        std::fs::write(dir.join(name), data).unwrap();
+    }
+}
+
+/// Write a record `pair` of two signals sharing the format 16 file
+/// `pair.dat`.
+///
+/// `signals` gives the fields of each signal line after the file name and
+/// format (gain, ADC resolution, ...), and `frames` the samples of each
+/// frame.
+pub fn two_signal_record(frequency: u32, signals: [&str; 2], frames: &[[i16; 2]]) -> TempDir {
+    let header = format!(
+        "pair 2 {frequency} {}\npair.dat 16 {}\npair.dat 16 {}\n",
+        frames.len(),
+        signals[0],
+        signals[1]
+    );
+    let samples: Vec<i16> = frames.iter().flatten().copied().collect();
+    write_record("pair", &header, &[("pair.dat", &format16_bytes(&samples))])
+}
+
+/// Encode samples as format 16 bytes.
+pub fn format16_bytes(samples: &[i16]) -> Vec<u8> {
+    samples.iter().flat_map(|s| s.to_le_bytes()).collect()
+}
+
+/// Encode samples as format 61 (big-endian) bytes.
+pub fn format61_bytes(samples: &[i16]) -> Vec<u8> {
+    samples.iter().flat_map(|s| s.to_be_bytes()).collect()
+}
+
+/// Encode samples as format 160 (offset binary) bytes.
+#[allow(clippy::cast_sign_loss)]
+pub fn format160_bytes(samples: &[i16]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|&s| ((s as u16) ^ 0x8000).to_le_bytes())
+        .collect()
+}
@@ -0,0 +1,78 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::record::RecordComponents;
+
+use super::common::{format16_bytes, write_files};
+
+const HEADER: &str = "split 2 100 3\n\
+                      split_a.dat 16 200/mV 12 0 0 0 0 A\n\
+                      split_b.dat 16 200/mV 12 0 0 0 0 B\n";
+
+#[test]
+fn test_open_components_with_signal_overrides() {
+    let dir = tempfile::tempdir().unwrap();
+    let headers = dir.path().join("headers");
+    let signals = dir.path().join("signals");
+    std::fs::create_dir_all(&headers).unwrap();
+    std::fs::create_dir_all(&signals).unwrap();
+
+    // `split_a.dat` lives next to the header, `split_b.dat` elsewhere
+    write_files(
+        &headers,
+        "split",
+        HEADER,
+        &[("split_a.dat", &format16_bytes(&[1, 2, 3]))],
+    );
+    std::fs::write(signals.join("split_b.dat"), format16_bytes(&[4, 5, 6])).unwrap();
+
+    let mut components = RecordComponents::new(headers.join("split"));
+    components
+        .signal_dir_overrides
+        .insert("split_b.dat".to_string(), signals.clone());
+    let record = Record::open_components(&components).unwrap();
+
+    assert_eq!(
+        record.signal_path("split_a.dat"),
+        headers.join("split_a.dat")
+    );
+    assert_eq!(
+        record.signal_path("split_b.dat"),
+        signals.join("split_b.dat")
+    );
+    assert_eq!(record.annotation_dir(), headers.as_path());
+
+    let mut reader = record.signal_reader(1).unwrap();
+    assert_eq!(reader.read_samples(3).unwrap(), vec![4, 5, 6]);
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(3).unwrap(),
+        vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+    );
+
+    // Without the override the second file is not found
+    let record = Record::open(headers.join("split")).unwrap();
+    assert!(record.signal_reader(1).is_err());
+}
+
+#[test]
+fn test_open_components_annotation_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    write_files(
+        dir.path(),
+        "split",
+        HEADER,
+        &[
+            ("split_a.dat", &format16_bytes(&[0; 3])),
+            ("split_b.dat", &format16_bytes(&[0; 3])),
+        ],
+    );
+
+    let mut components = RecordComponents::new(dir.path().join("split.hea"));
+    components.annotation_dir = Some(dir.path().join("local"));
+    let record = Record::open_components(&components).unwrap();
+
+    assert_eq!(record.annotation_dir(), dir.path().join("local").as_path());
+    assert_eq!(record.signal_count(), 2);
+}
@@ -0,0 +1,99 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+use wfdb::header::Specifications;
+use wfdb::{Error, Header, Record};
+
+use super::common::format16_bytes;
+
+fn header(text: &str) -> Header {
+    Header::from_reader(&mut Cursor::new(text)).unwrap()
+}
+
+#[test]
+fn test_create_writes_readable_header() {
+    let dir = tempfile::tempdir().unwrap();
+    let text = "rec 2 500 3\n\
+                rec.dat 16 200/mV 12 0 0 0 0 ECG\n\
+                rec.dat 16 100/mmHg 12 0 0 0 0 ABP\n\
+                # device 7\n";
+
+    let record = Record::create(dir.path().join("rec"), &header(text)).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("rec.hea")).unwrap(),
+        text
+    );
+    assert_eq!(record.signal_count(), 2);
+
+    // The record is readable once the signal file exists
+    std::fs::write(
+        dir.path().join("rec.dat"),
+        format16_bytes(&[1, 2, 3, 4, 5, 6]),
+    )
+    .unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(3).unwrap(),
+        vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+    );
+
+    let reopened = Record::open(dir.path().join("rec")).unwrap();
+    assert_eq!(reopened.info_strings(), [" device 7".to_string()]);
+}
+
+#[test]
+fn test_create_rejects_mismatched_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let result = Record::create(dir.path().join("other"), &header("rec 0\n"));
+    assert!(matches!(result, Err(Error::InvalidPath(_))), "{result:?}");
+    assert!(!dir.path().join("other.hea").exists());
+}
+
+#[test]
+fn test_create_rejects_inconsistent_counts() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut header = header("rec 1 250\nrec.dat 16\n");
+    header.metadata.num_signals = 2;
+
+    let result = Record::create(dir.path().join("rec"), &header);
+    assert!(matches!(result, Err(Error::InvalidHeader(_))), "{result:?}");
+
+    header.metadata.num_signals = 1;
+    header.metadata.num_segments = Some(1);
+    let result = Record::create(dir.path().join("rec"), &header);
+    assert!(matches!(result, Err(Error::InvalidHeader(_))), "{result:?}");
+    assert!(!dir.path().join("rec.hea").exists());
+}
+
+#[test]
+fn test_create_multi_segment() {
+    let dir = tempfile::tempdir().unwrap();
+    let text = "multi/2 1 125 30\nseg_0 10\n~ 20\n";
+    let record = Record::create(dir.path().join("multi.hea"), &header(text)).unwrap();
+
+    assert!(record.is_multi_segment());
+    assert!(matches!(
+        record.specifications(),
+        Specifications::MultiSegment { segments } if segments.len() == 2
+    ));
+}
+
+#[test]
+fn test_create_fails_while_locked() {
+    let dir = tempfile::tempdir().unwrap();
+    let text = "rec 1 250\nrec.dat 16\n";
+
+    // Another writer holds the lock of the header file
+    let other = std::fs::File::create(dir.path().join("rec.hea")).unwrap();
+    other.lock().unwrap();
+    let result = Record::create(dir.path().join("rec"), &header(text));
+    assert!(matches!(result, Err(Error::Locked(_))), "{result:?}");
+    assert!(dir.path().join("rec.hea").exists());
+
+    other.unlock().unwrap();
+    Record::create(dir.path().join("rec"), &header(text)).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("rec.hea")).unwrap(),
+        text
+    );
+}
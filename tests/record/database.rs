@@ -0,0 +1,136 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Database;
+
+use super::common::{format16_bytes, write_files};
+
+/// Build a small database tree:
+/// - `100`: single-segment record with an `atr` annotation file
+/// - `sub/200`: single-segment record
+/// - `sub/multi`: multi-segment record with a layout and one data segment
+/// - `sub/broken`: unparsable header
+fn database_tree() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let sub = dir.path().join("sub");
+    std::fs::create_dir(&sub).unwrap();
+
+    write_files(
+        dir.path(),
+        "100",
+        "100 2 100 4\n\
+         100.dat 16 200/mV 12 0 0 0 0 MLII\n\
+         100.dat 16 200/mV 12 0 0 0 0 V5\n",
+        &[("100.dat", &format16_bytes(&[0; 8])), ("100.atr", &[0, 0])],
+    );
+    write_files(
+        &sub,
+        "200",
+        "200 1 250\n200.dat 16 200 12 0 0 0 0 ECG\n",
+        &[("200.dat", &format16_bytes(&[0; 10]))],
+    );
+    write_files(
+        &sub,
+        "multi",
+        "multi/2 1 125 30\nmulti_layout 0\nmulti_1 30\n",
+        &[],
+    );
+    write_files(
+        &sub,
+        "multi_layout",
+        "multi_layout 1 125 0\n~ 0 200 12 0 0 0 0 PLETH\n",
+        &[],
+    );
+    write_files(
+        &sub,
+        "multi_1",
+        "multi_1 1 125 30\nmulti_1.dat 16 200 12 0 0 0 0 PLETH\n",
+        &[("multi_1.dat", &format16_bytes(&[0; 30]))],
+    );
+    write_files(&sub, "broken", "broken x y z\n", &[]);
+    dir
+}
+
+#[test]
+fn test_records_exclude_segments() {
+    let dir = database_tree();
+    let database = Database::open(dir.path()).unwrap();
+
+    let records: Vec<_> = database
+        .records()
+        .unwrap()
+        .into_iter()
+        .map(|path| path.strip_prefix(dir.path()).unwrap().to_path_buf())
+        .collect();
+    assert_eq!(
+        records,
+        ["100", "sub/200", "sub/broken", "sub/multi"]
+            .iter()
+            .map(std::path::PathBuf::from)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_catalog() {
+    let dir = database_tree();
+    let catalog = Database::open(dir.path()).unwrap().catalog().unwrap();
+
+    let paths: Vec<&str> = catalog.entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, ["100", "sub/200", "sub/multi"]);
+    assert_eq!(catalog.failures.len(), 1);
+    assert_eq!(catalog.failures[0].0, "sub/broken");
+
+    let entry = &catalog.entries[0];
+    assert_eq!(entry.record_name, "100");
+    assert_eq!(entry.num_samples, Some(4));
+    assert_eq!(entry.duration, Some(0.04));
+    assert_eq!(
+        entry.channel_names,
+        vec![Some("MLII".to_string()), Some("V5".to_string())]
+    );
+    assert_eq!(entry.annotators, vec!["atr".to_string()]);
+    let names: Vec<&str> = entry.files.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["100.hea", "100.dat", "100.atr"]);
+    assert_eq!(entry.files[1].1, 16);
+    assert_eq!(entry.fingerprint.len(), 16);
+
+    let unknown_length = &catalog.entries[1];
+    assert_eq!(unknown_length.num_samples, None);
+    assert_eq!(unknown_length.duration, None);
+    assert!(unknown_length.annotators.is_empty());
+
+    let multi = &catalog.entries[2];
+    assert_eq!(multi.channel_names, vec![Some("PLETH".to_string())]);
+    let names: Vec<&str> = multi.files.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "multi.hea",
+            "multi_layout.hea",
+            "multi_1.hea",
+            "multi_1.dat"
+        ]
+    );
+    assert_eq!(multi.files[3].1, 60);
+
+    let json = catalog.to_json();
+    assert!(json.contains("\"path\": \"sub/multi\""));
+    assert!(json.contains("\"annotators\": [\"atr\"]"));
+    assert!(json.contains("\"num_samples\": null"));
+}
+
+#[test]
+fn test_fingerprint_tracks_file_sizes() {
+    let dir = database_tree();
+    let database = Database::open(dir.path()).unwrap();
+    let before = database.catalog().unwrap().entries[0].fingerprint.clone();
+
+    std::fs::write(dir.path().join("100.dat"), format16_bytes(&[0; 10])).unwrap();
+    let after = database.catalog().unwrap().entries[0].fingerprint.clone();
+    assert_ne!(before, after);
+}
+
+#[test]
+fn test_open_missing_directory() {
+    assert!(Database::open("/nonexistent/wfdb/database").is_err());
+}
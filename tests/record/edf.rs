@@ -0,0 +1,210 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::interop::edf::{self, EdfOptions, EdfVariant};
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, write_record};
+
+/// Two signals at 4 Hz, 6 frames, with a base time and date.
+///
+/// In frame i, I = [i, -i] (2 samples per frame) and II = 100 * i. The last
+/// sample of I is invalid.
+fn source_record() -> tempfile::TempDir {
+    let mut samples: Vec<i16> = (0..6).flat_map(|i| [i, -i, 100 * i]).collect();
+    samples[16] = i16::MIN;
+    write_record(
+        "rec",
+        "rec 2 4 6 08:30:05 02/03/2004\n\
+         rec.dat 16x2 200/mV 12 0 0 0 0 I\n\
+         rec.dat 16 100(10)/uV 12 1024 0 0 0 II\n",
+        &[("rec.dat", &format16_bytes(&samples))],
+    )
+}
+
+/// Get a trimmed text field of an EDF file.
+fn field(bytes: &[u8], start: usize, len: usize) -> &str {
+    std::str::from_utf8(&bytes[start..start + len])
+        .unwrap()
+        .trim_end()
+}
+
+/// Get the 16-bit samples of an EDF file starting at byte `start`.
+fn samples(bytes: &[u8], start: usize, count: usize) -> Vec<i16> {
+    bytes[start..start + 2 * count]
+        .chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+        .collect()
+}
+
+#[test]
+fn test_edf_plus_header_and_records() {
+    let dir = source_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let path = dir.path().join("rec.edf");
+
+    let num_records = edf::to_edf(&record, &path, &EdfOptions::default()).unwrap();
+    assert_eq!(num_records, 2);
+
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(field(&bytes, 0, 8), "0");
+    assert_eq!(field(&bytes, 8, 80), "X X X X");
+    assert_eq!(field(&bytes, 88, 80), "Startdate 02-MAR-2004 X X X");
+    assert_eq!(field(&bytes, 168, 8), "02.03.04");
+    assert_eq!(field(&bytes, 176, 8), "08.30.05");
+    assert_eq!(field(&bytes, 184, 8), "1024");
+    assert_eq!(field(&bytes, 192, 44), "EDF+C");
+    assert_eq!(field(&bytes, 236, 8), "2");
+    assert_eq!(field(&bytes, 244, 8), "1");
+    assert_eq!(field(&bytes, 252, 4), "3");
+
+    // Signal fields are stored field by field, for the 3 signals
+    let column = |offset: usize, width: usize| -> Vec<&str> {
+        (0..3)
+            .map(|k| field(&bytes, 256 + offset * 3 + k * width, width))
+            .collect()
+    };
+    assert_eq!(column(0, 16), vec!["I", "II", "EDF Annotations"]);
+    assert_eq!(column(96, 8), vec!["mV", "uV", ""]);
+    assert_eq!(column(104, 8), vec!["-10.24", "-10.34", "-1"]);
+    assert_eq!(column(112, 8), vec!["10.235", "30.61", "1"]);
+    assert_eq!(column(120, 8), vec!["-2048", "-1024", "-32768"]);
+    assert_eq!(column(128, 8), vec!["2047", "3071", "32767"]);
+    assert_eq!(column(216, 8), vec!["8", "4", "16"]);
+
+    // Data record: 8 samples of I, 4 of II, then 32 bytes of annotations
+    let record_size = 2 * (8 + 4 + 16);
+    assert_eq!(bytes.len(), 1024 + 2 * record_size);
+    assert_eq!(
+        samples(&bytes, 1024, 12),
+        vec![0, 0, 1, -1, 2, -2, 3, -3, 0, 100, 200, 300]
+    );
+    assert!(bytes[1048..].starts_with(b"+0\x14\x14\0"));
+
+    // The second data record is padded with the digital minimum
+    let second = 1024 + record_size;
+    assert_eq!(
+        samples(&bytes, second, 12),
+        vec![
+            4, -4, 5, -2048, -2048, -2048, -2048, -2048, 400, 500, -1024, -1024
+        ]
+    );
+    assert!(bytes[second + 24..].starts_with(b"+1\x14\x14\0"));
+}
+
+#[test]
+fn test_edf_signals_and_record_duration() {
+    let values: Vec<i16> = (0..5).collect();
+    let dir = write_record(
+        "slow",
+        "slow 1 2.5 5\nslow.dat 16 0 16 0 0 0 0 resp\n",
+        &[("slow.dat", &format16_bytes(&values))],
+    );
+    let record = Record::open(dir.path().join("slow")).unwrap();
+    let path = dir.path().join("slow.edf");
+    let options = EdfOptions::default()
+        .with_variant(EdfVariant::Edf)
+        .with_signals(&[0])
+        .with_patient("P1");
+
+    let num_records = edf::to_edf(&record, &path, &options).unwrap();
+    assert_eq!(num_records, 1);
+
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(field(&bytes, 8, 80), "P1");
+    assert_eq!(field(&bytes, 88, 80), "slow");
+    assert_eq!(field(&bytes, 168, 8), "01.01.85");
+    assert_eq!(field(&bytes, 184, 8), "512");
+    assert_eq!(field(&bytes, 192, 44), "");
+    // 2 s records hold 5 frames at 2.5 Hz
+    assert_eq!(field(&bytes, 244, 8), "2");
+    assert_eq!(field(&bytes, 252, 4), "1");
+    // Uncalibrated signals keep their ADC values
+    assert_eq!(field(&bytes, 256 + 104, 8), "-32768");
+    assert_eq!(field(&bytes, 256 + 112, 8), "32767");
+    assert_eq!(samples(&bytes, 512, 5), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_edf_invalid_signal() {
+    let dir = source_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let options = EdfOptions::default().with_signals(&[2]);
+    let result = edf::to_edf(&record, dir.path().join("bad.edf"), &options);
+    assert!(matches!(result, Err(Error::InvalidData(_))));
+}
+
+#[test]
+fn test_edf_read_round_trip() {
+    let dir = source_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let path = dir.path().join("copy.edf");
+    edf::to_edf(&record, &path, &EdfOptions::default()).unwrap();
+
+    let copy = edf::read(&path).unwrap();
+    let metadata = copy.metadata();
+    assert_eq!(metadata.name(), "copy");
+    assert_eq!(metadata.num_signals(), 2);
+    assert_eq!(metadata.num_samples(), Some(2));
+    assert!((metadata.sampling_frequency() - 1.0).abs() < 1e-9);
+    assert_eq!(metadata.base_time_hms(), Some((8, 30, 5)));
+    assert_eq!(metadata.base_date_ymd(), Some((2004, 3, 2)));
+    assert_eq!(copy.info_strings()[0], " patient: X X X X");
+
+    // The annotation signal is skipped
+    let signals = copy.signal_info().unwrap();
+    assert_eq!(signals[0].description(), Some("I"));
+    assert_eq!(signals[0].samples_per_frame(), 8);
+    assert_eq!(signals[0].units(), "mV");
+    assert!((signals[0].adc_gain() - 200.0).abs() < 1e-9);
+    assert_eq!(signals[0].baseline(), 0);
+    assert_eq!(signals[1].description(), Some("II"));
+    assert_eq!(signals[1].samples_per_frame(), 4);
+    assert!((signals[1].adc_gain() - 100.0).abs() < 1e-9);
+    assert_eq!(signals[1].baseline(), 10);
+
+    let i = copy.signal_reader(0).unwrap().read_samples(16).unwrap();
+    assert_eq!(
+        i,
+        vec![
+            0, 0, 1, -1, 2, -2, 3, -3, 4, -4, 5, -2048, -2048, -2048, -2048, -2048
+        ]
+    );
+    let mut ii = copy.signal_reader(1).unwrap();
+    ii.seek_to_sample(5).unwrap();
+    assert_eq!(ii.read_samples(3).unwrap(), vec![500, -1024, -1024]);
+    let physical = copy.signal_reader(1).unwrap().read_physical(2).unwrap();
+    assert!((physical[1] - 0.9).abs() < 1e-9);
+}
+
+#[test]
+fn test_edf_read_plain_edf() {
+    let dir = source_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let path = dir.path().join("plain.edf");
+    let options = EdfOptions::default()
+        .with_variant(EdfVariant::Edf)
+        .with_signals(&[1]);
+    edf::to_edf(&record, &path, &options).unwrap();
+
+    let copy = edf::read(&path).unwrap();
+    assert_eq!(copy.metadata().num_signals(), 1);
+    assert_eq!(copy.metadata().base_date_ymd(), Some((2004, 3, 2)));
+    let mut reader = copy.multi_signal_reader().unwrap();
+    let mut frame = Vec::new();
+    let mut frames = Vec::new();
+    while reader.read_frame_samples_into(&mut frame).unwrap() {
+        frames.push(frame[0].clone());
+    }
+    assert_eq!(
+        frames,
+        vec![vec![0, 100, 200, 300], vec![400, 500, -1024, -1024]]
+    );
+}
+
+#[test]
+fn test_edf_read_not_edf() {
+    let dir = source_record();
+    let result = edf::read(dir.path().join("rec.hea"));
+    assert!(result.is_err());
+}
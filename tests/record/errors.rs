@@ -0,0 +1,69 @@
+use wfdb::{Error, HeaderField, Record};
+
+use super::common::{format16_bytes, write_record};
+
+#[test]
+fn test_missing_signal_file() {
+    let dir = write_record("rec", "rec 1 250 3\nrec.dat 16 200 12 0 0 0 0 I\n", &[]);
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let result = record.signal_reader(0);
+    let Err(Error::SignalFile { path, source }) = result else {
+        panic!("Expected SignalFile error");
+    };
+    assert_eq!(path, dir.path().join("rec.dat"));
+    assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+    assert!(matches!(
+        record.multi_signal_reader(),
+        Err(Error::SignalFile { .. })
+    ));
+}
+
+#[test]
+fn test_malformed_gain_field() {
+    let dir = write_record(
+        "rec",
+        "rec 1 250 3\nrec.dat 16 1e999/mV 12 0 0 0 0 I\n",
+        &[("rec.dat", &format16_bytes(&[1, 2, 3]))],
+    );
+
+    let result = Record::open(dir.path().join("rec"));
+    let Err(Error::InvalidHeader(error)) = result else {
+        panic!("Expected InvalidHeader error");
+    };
+    assert_eq!(error.line(), Some(2));
+    assert_eq!(error.field(), Some(HeaderField::Gain));
+}
+
+#[test]
+fn test_incomplete_frame() {
+    let dir = write_record(
+        "rec",
+        "rec 2 250\nrec.dat 16 200 12 0 0 0 0 I\nrec.dat 16 200 12 0 0 0 0 II\n",
+        &[("rec.dat", &format16_bytes(&[0, 1, 2]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(reader.read_frame().unwrap(), vec![0, 1]);
+    let error = reader.read_frame().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Signal decode error in 'rec.dat' at byte 6: Incomplete frame read from signal group"
+    );
+    let Error::SignalDecode {
+        file, byte_offset, ..
+    } = error
+    else {
+        panic!("Expected SignalDecode error");
+    };
+    assert_eq!(file, "rec.dat");
+    assert_eq!(byte_offset, 6);
+
+    let mut reader = record.signal_reader(1).unwrap();
+    assert_eq!(reader.read_samples(1).unwrap(), vec![1]);
+    assert!(matches!(
+        reader.read_samples(1),
+        Err(Error::SignalDecode { .. })
+    ));
+}
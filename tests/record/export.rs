@@ -0,0 +1,361 @@
+use wfdb::record::export::{
+    ChannelFormat, CsvOptions, CsvTime, ExportOptions, Manifest, per_channel,
+    per_channel_with_options, to_wav, windows_npy,
+};
+use wfdb::signal::RoundingMode;
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, two_signal_record, write_record};
+
+fn two_channel_record() -> tempfile::TempDir {
+    two_signal_record(
+        100,
+        ["200/mV 12 0 0 0 0 ECG", "100/mmHg 12 0 0 0 0 ABP"],
+        &[[0, 100], [200, -200], [400, 50]],
+    )
+}
+
+#[test]
+fn test_per_channel_csv() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let out = dir.path().join("out");
+
+    let manifest = per_channel(&record, &out, ChannelFormat::Csv).unwrap();
+
+    assert_eq!(manifest.record_name, "pair");
+    assert_eq!(manifest.channels.len(), 2);
+    assert_eq!(manifest.channels[0].file_name, "pair_0.csv");
+    assert_eq!(manifest.channels[0].name.as_deref(), Some("ECG"));
+    assert_eq!(manifest.channels[1].units, "mmHg");
+    assert_eq!(manifest.channels[1].num_samples, 3);
+
+    let ecg = std::fs::read_to_string(out.join("pair_0.csv")).unwrap();
+    assert_eq!(ecg, "0\n1\n2\n");
+    let abp = std::fs::read_to_string(out.join("pair_1.csv")).unwrap();
+    assert_eq!(abp, "1\n-2\n0.5\n");
+
+    let json = std::fs::read_to_string(out.join(Manifest::FILE_NAME)).unwrap();
+    assert_eq!(json, manifest.to_json());
+    assert!(json.contains("\"sampling_frequency\": 100"));
+    assert!(json.contains("\"name\": \"ABP\""));
+}
+
+#[test]
+fn test_per_channel_rates_and_many_channels() {
+    // 40 channels (more than the export threads) at 100 Hz, the first with
+    // 2 samples per frame
+    let header: String = std::iter::once("rec 40 100 2\nrec.dat 16x2 200 12 0 0 0 0 HF\n".into())
+        .chain((1..40).map(|index| format!("rec.dat 16 200 12 0 0 0 0 S{index}\n")))
+        .collect();
+    let samples: Vec<i16> = (0..82).collect();
+    let dir = write_record("rec", &header, &[("rec.dat", &format16_bytes(&samples))]);
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let out = dir.path().join("out");
+
+    let manifest = per_channel(&record, &out, ChannelFormat::F32).unwrap();
+
+    assert!((manifest.sampling_frequency - 100.0).abs() < f64::EPSILON);
+    let indices: Vec<_> = manifest.channels.iter().map(|c| c.index).collect();
+    assert_eq!(indices, (0..40).collect::<Vec<_>>());
+    assert!((manifest.channels[0].sampling_frequency - 200.0).abs() < f64::EPSILON);
+    assert_eq!(manifest.channels[0].num_samples, 4);
+    assert!((manifest.channels[39].sampling_frequency - 100.0).abs() < f64::EPSILON);
+    assert_eq!(manifest.channels[39].num_samples, 2);
+    assert!(manifest.to_json().contains("\"sampling_frequency\": 200"));
+}
+
+#[test]
+fn test_per_channel_csv_decimals() {
+    // 1/8, 3/8 and -1/8 mV are exact halfway cases at two decimals
+    let dir = write_record(
+        "half",
+        "half 1 100 3
+half.dat 16 8/mV 12 0 0 0 0 ECG
+",
+        &[("half.dat", &format16_bytes(&[1, 3, -1]))],
+    );
+    let record = Record::open(dir.path().join("half")).unwrap();
+
+    for (rounding, expected) in [
+        (RoundingMode::HalfAwayFromZero, "0.13\n0.38\n-0.13\n"),
+        (RoundingMode::HalfEven, "0.12\n0.38\n-0.12\n"),
+        (RoundingMode::Truncate, "0.12\n0.37\n-0.12\n"),
+    ] {
+        let out = dir.path().join(format!("{rounding:?}"));
+        let options = ExportOptions {
+            decimals: Some(2),
+            rounding,
+        };
+        per_channel_with_options(&record, &out, ChannelFormat::Csv, &options).unwrap();
+        let csv = std::fs::read_to_string(out.join("half_0.csv")).unwrap();
+        assert_eq!(csv, expected, "{rounding:?}");
+    }
+}
+
+#[test]
+fn test_per_channel_csv_round_trips() {
+    // A gain of 3 gives values with no short decimal representation
+    let dir = write_record(
+        "third",
+        "third 1 100 3\nthird.dat 16 3/mV 12 0 0 0 0 ECG\n",
+        &[("third.dat", &format16_bytes(&[1, -2, 3]))],
+    );
+    let record = Record::open(dir.path().join("third")).unwrap();
+    let out = dir.path().join("out");
+
+    per_channel(&record, &out, ChannelFormat::Csv).unwrap();
+    let csv = std::fs::read_to_string(out.join("third_0.csv")).unwrap();
+    assert_eq!(csv, "0.3333333333333333\n-0.6666666666666666\n1\n");
+
+    let expected = record.signal_reader(0).unwrap().read_physical(3).unwrap();
+    let parsed: Vec<f64> = csv.lines().map(|line| line.parse().unwrap()).collect();
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn test_per_channel_f32() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let out = dir.path().join("out");
+
+    per_channel(&record, &out, ChannelFormat::F32).unwrap();
+
+    let bytes = std::fs::read(out.join("pair_1.f32")).unwrap();
+    let values: Vec<f32> = bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+        .collect();
+    assert_eq!(values, vec![1.0, -2.0, 0.5]);
+}
+
+#[test]
+fn test_windows_npy() {
+    // 5 frames of two channels, gains 200 and 100
+    let data = format16_bytes(&[0, 100, 200, -200, 400, 50, 600, 0, 800, 100]);
+    let dir = write_record(
+        "rec",
+        "rec 2 100 5\n\
+         rec.dat 16 200/mV 12 0 0 0 0 ECG\n\
+         rec.dat 16 100/mmHg 12 0 0 0 0 ABP\n",
+        &[("rec.dat", &data)],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let out = dir.path().join("out");
+
+    let manifest = windows_npy(&record, &out, 2, 2).unwrap();
+    assert_eq!(manifest.num_windows, 2);
+    assert_eq!(manifest.shape(), (2, 2, 2));
+
+    let bytes = std::fs::read(out.join(&manifest.file_name)).unwrap();
+    assert_eq!(&bytes[..6], b"\x93NUMPY");
+    let header_len = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+    let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+    assert!(header.contains("'shape': (2, 2, 2)"));
+    assert_eq!((10 + header_len) % 64, 0);
+
+    let values: Vec<f32> = bytes[10 + header_len..]
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+        .collect();
+    // Window 0: ECG [0, 1], ABP [1, -2]; window 1: ECG [2, 3], ABP [0.5, 0]
+    assert_eq!(values, vec![0.0, 1.0, 1.0, -2.0, 2.0, 3.0, 0.5, 0.0]);
+
+    let json = std::fs::read_to_string(out.join("rec_windows.json")).unwrap();
+    assert!(json.contains("\"shape\": [2, 2, 2]"));
+}
+
+#[test]
+fn test_windows_npy_overlapping_and_sparse() {
+    let data = format16_bytes(&[0, 200, 400, 600, 800]);
+    let dir = write_record(
+        "rec",
+        "rec 1 100 5\nrec.dat 16 200 12 0 0 0 0 ECG\n",
+        &[("rec.dat", &data)],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    // Overlapping windows: [0,1,2], [1,2,3], [2,3,4]
+    let manifest = windows_npy(&record, dir.path().join("overlap"), 3, 1).unwrap();
+    assert_eq!(manifest.num_windows, 3);
+
+    // Stride larger than window: [0], [3]
+    let manifest = windows_npy(&record, dir.path().join("sparse"), 1, 3).unwrap();
+    assert_eq!(manifest.num_windows, 2);
+
+    assert!(matches!(
+        windows_npy(&record, dir.path().join("bad"), 0, 1),
+        Err(Error::InvalidData(_))
+    ));
+}
+
+#[test]
+fn test_to_wav() {
+    let data = format16_bytes(&[0, 200, 400, 200, 0]);
+    let dir = write_record(
+        "rec",
+        "rec 1 100 5\nrec.dat 16 200 12 0 0 0 0 ECG\n",
+        &[("rec.dat", &data)],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let path = dir.path().join("rec.wav");
+
+    assert_eq!(to_wav(&record, &path, 0, 100).unwrap(), 5);
+
+    let wav = std::fs::read(&path).unwrap();
+    assert_eq!(wav.len(), 44 + 10);
+    assert_eq!(&wav[..4], b"RIFF");
+    assert_eq!(&wav[8..16], b"WAVEfmt ");
+    assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 100);
+    assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 10);
+
+    // Mean (0.8 mV) removed, peak (2 mV) at full scale
+    let pcm: Vec<i16> = wav[44..]
+        .chunks_exact(2)
+        .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+        .collect();
+    assert_eq!(pcm, vec![-21845, 5461, 32767, 5461, -21845]);
+
+    // Upsampled 4 times
+    assert_eq!(to_wav(&record, &path, 0, 400).unwrap(), 17);
+
+    assert!(matches!(
+        to_wav(&record, &path, 1, 100),
+        Err(Error::InvalidData(_))
+    ));
+    assert!(to_wav(&record, &path, 0, 0).is_err());
+}
+
+#[test]
+fn test_export_csv_rdsamp_layout() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let mut csv = Vec::new();
+    let frames = record.export_csv(&mut csv, &CsvOptions::default()).unwrap();
+
+    assert_eq!(frames, 3);
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "'Elapsed time','ECG','ABP'\n\
+         'hh:mm:ss.mmm','mV','mmHg'\n\
+         '0:00.000',0.000,1.000\n\
+         '0:00.010',1.000,-2.000\n\
+         '0:00.020',2.000,0.500\n"
+    );
+}
+
+#[test]
+fn test_export_csv_high_resolution() {
+    // ECG has 2 samples per frame, ABP 1: frames (0, 200 | 100), (400, 600 | -200)
+    let dir = write_record(
+        "hr",
+        "hr 2 100 2\n\
+         hr.dat 16x2 200/mV 12 0 0 0 0 ECG\n\
+         hr.dat 16 100/mmHg 12 0 0 0 0 ABP\n",
+        &[("hr.dat", &format16_bytes(&[0, 200, 100, 400, 600, -200]))],
+    );
+    let record = Record::open(dir.path().join("hr")).unwrap();
+
+    // Output of `rdsamp -r hr -c -H -p -v`
+    let mut csv = Vec::new();
+    assert_eq!(
+        record.export_csv(&mut csv, &CsvOptions::default()).unwrap(),
+        2
+    );
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "'Elapsed time','ECG','ABP'\n\
+         'hh:mm:ss.mmm','mV','mmHg'\n\
+         '0:00.000',0.000,1.000\n\
+         '0:00.005',1.000,1.000\n\
+         '0:00.010',2.000,-2.000\n\
+         '0:00.015',3.000,-2.000\n"
+    );
+
+    // Output of `rdsamp -r hr -c -p -v`
+    let options = CsvOptions::default().with_high_resolution(false);
+    let mut csv = Vec::new();
+    assert_eq!(record.export_csv(&mut csv, &options).unwrap(), 2);
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "'Elapsed time','ECG','ABP'\n\
+         'hh:mm:ss.mmm','mV','mmHg'\n\
+         '0:00.000',0.500,1.000\n\
+         '0:00.010',2.500,-2.000\n"
+    );
+}
+
+#[test]
+fn test_export_csv_options() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let options = CsvOptions::default()
+        .with_time(CsvTime::Seconds)
+        .with_signals(&[1, 0])
+        .with_frames(1..)
+        .with_decimals(1)
+        .with_header(false);
+    let mut csv = Vec::new();
+    assert_eq!(record.export_csv(&mut csv, &options).unwrap(), 2);
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "0.010,-2.0,1.0\n0.020,0.5,2.0\n"
+    );
+
+    let bad = CsvOptions::default().with_signals(&[2]);
+    assert!(record.export_csv(Vec::new(), &bad).is_err());
+}
+
+#[test]
+fn test_export_csv_hours_and_invalid_samples() {
+    let mut samples: Vec<i16> = (0..3602).collect();
+    samples[3601] = i16::MIN;
+    let dir = write_record(
+        "long",
+        "long 1 1 3602\nlong.dat 16 1 12 0 0 0 0\n",
+        &[("long.dat", &format16_bytes(&samples))],
+    );
+    let record = Record::open(dir.path().join("long")).unwrap();
+
+    let options = CsvOptions::default().with_frames(3599..);
+    let mut csv = Vec::new();
+    record.export_csv(&mut csv, &options).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "'Elapsed time','signal 0'\n\
+         'hh:mm:ss.mmm','mV'\n\
+         '59:59.000',3599.000\n\
+         '1:00:00.000',3600.000\n\
+         '1:00:01.000',-\n"
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_export_csv_datetime() {
+    let dir = write_record(
+        "rec",
+        "rec 1 4 4 23:59:59 31/12/2003\nrec.dat 16 200/mV 12 0 0 0 0 ECG\n",
+        &[("rec.dat", &format16_bytes(&[200, 400, 600, 800]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let options = CsvOptions::default()
+        .with_time(CsvTime::DateTime)
+        .with_frames(2..);
+    let mut csv = Vec::new();
+    record.export_csv(&mut csv, &options).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "'Time and date','ECG'\n\
+         'hh:mm:ss.mmm dd/mm/yyyy','mV'\n\
+         '[23:59:59.500 31/12/2003]',3.000\n\
+         '[23:59:59.750 31/12/2003]',4.000\n"
+    );
+
+    let undated = two_channel_record();
+    let record = Record::open(undated.path().join("pair")).unwrap();
+    assert!(record.export_csv(Vec::new(), &options).is_err());
+}
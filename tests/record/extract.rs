@@ -0,0 +1,138 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, write_record};
+
+/// Three channels at 100 Hz over two files, with a base time and counter.
+///
+/// In frame i, I = [10 * i, 10 * i + 1] (2 samples per frame), II = i and
+/// III = -i, for i in 0..10.
+fn source_record() -> tempfile::TempDir {
+    let a: Vec<i16> = (0..10).flat_map(|i| [10 * i, 10 * i + 1, i]).collect();
+    let b: Vec<i16> = (0..10).map(|i| -i).collect();
+    write_record(
+        "src",
+        "src 3 100/50(1000) 10 08:30:00\n\
+         a.dat 16x2 10/mV 12 0 0 0 0 I\n\
+         a.dat 16 10/mV 12 0 0 0 0 II\n\
+         b.dat 16 20/mV 12 0 0 0 0 III\n\
+         # age: 60\n",
+        &[
+            ("a.dat", &format16_bytes(&a)),
+            ("b.dat", &format16_bytes(&b)),
+        ],
+    )
+}
+
+#[test]
+fn test_extract_range_and_signals() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+
+    let snippet = source
+        .extract(dir.path().join("snip"), 2..6, Some(&[2, 0]))
+        .unwrap();
+
+    assert_eq!(snippet.metadata().name(), "snip");
+    assert_eq!(snippet.metadata().num_samples(), Some(4));
+    assert!(snippet.metadata().base_time_hms().is_none());
+    assert!((snippet.metadata().base_counter() - 1001.0).abs() < 1e-9);
+
+    let signals = snippet.signal_info().unwrap();
+    assert_eq!(signals.len(), 2);
+    assert_eq!(signals[0].description.as_deref(), Some("I"));
+    assert_eq!(signals[0].file_name, "snip_0.dat");
+    assert_eq!(signals[0].samples_per_frame, Some(2));
+    assert_eq!(signals[0].initial_value, Some(20));
+    assert_eq!(signals[1].description.as_deref(), Some("III"));
+    assert_eq!(signals[1].file_name, "snip_1.dat");
+    assert_eq!(signals[1].initial_value, Some(-2));
+    assert!(snippet.validate().unwrap().is_valid());
+
+    let i = snippet.signal_reader(0).unwrap().read_samples(20).unwrap();
+    assert_eq!(i, vec![20, 21, 30, 31, 40, 41, 50, 51]);
+    let iii = snippet.signal_reader(1).unwrap().read_samples(20).unwrap();
+    assert_eq!(iii, vec![-2, -3, -4, -5]);
+
+    assert_eq!(snippet.info_strings()[0], " age: 60");
+    let provenance = snippet.provenance();
+    assert_eq!(provenance.len(), 1);
+    assert_eq!(provenance[0].source, "src");
+    assert_eq!(provenance[0].transforms, vec!["slice 2..6", "signals 0, 2"]);
+}
+
+#[test]
+fn test_extract_whole_record_is_identical() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+
+    let copy = source.extract(dir.path().join("copy"), .., None).unwrap();
+
+    assert_eq!(copy.metadata().num_samples(), Some(10));
+    assert!(copy.metadata().base_time_hms().is_some());
+    assert!((copy.metadata().base_counter() - 1000.0).abs() < 1e-9);
+    assert!(copy.provenance()[0].transforms.is_empty());
+    for signal in 0..3 {
+        assert_eq!(
+            copy.signal_reader(signal)
+                .unwrap()
+                .read_samples(20)
+                .unwrap(),
+            source
+                .signal_reader(signal)
+                .unwrap()
+                .read_samples(20)
+                .unwrap()
+        );
+    }
+    assert!(copy.validate().unwrap().is_valid());
+}
+
+#[test]
+fn test_extract_single_file_and_truncated_range() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+
+    let snippet = source
+        .extract(dir.path().join("ii"), 7..=20, Some(&[1, 1]))
+        .unwrap();
+
+    assert_eq!(snippet.metadata().num_samples(), Some(3));
+    assert_eq!(snippet.signal_info().unwrap()[0].file_name, "ii.dat");
+    let ii = snippet.signal_reader(0).unwrap().read_samples(10).unwrap();
+    assert_eq!(ii, vec![7, 8, 9]);
+    assert_eq!(
+        snippet.provenance()[0].transforms,
+        vec!["slice 7..21", "signals 1"]
+    );
+}
+
+#[test]
+fn test_extract_invalid_signal() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+
+    let result = source.extract(dir.path().join("bad"), .., Some(&[3]));
+    assert!(matches!(result, Err(Error::InvalidData(_))));
+}
+
+#[test]
+fn test_extract_locking() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+
+    // Another writer holds the lock: no signal file is written
+    let other = std::fs::File::create(dir.path().join("out.hea")).unwrap();
+    other.lock().unwrap();
+    let result = source.extract(dir.path().join("out"), .., None);
+    assert!(matches!(result, Err(Error::Locked(_))), "{result:?}");
+    assert!(!dir.path().join("out_1.dat").exists());
+    drop(other);
+    std::fs::remove_file(dir.path().join("out.hea")).unwrap();
+
+    // A failed extract leaves no empty header behind
+    std::fs::remove_file(dir.path().join("b.dat")).unwrap();
+    assert!(source.extract(dir.path().join("out"), .., None).is_err());
+    assert!(!dir.path().join("out.hea").exists());
+}
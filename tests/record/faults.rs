@@ -0,0 +1,119 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+use std::time::Duration;
+
+use wfdb::record::{MemorySource, ReadSeek};
+use wfdb::signal::{get_decoder, get_encoder};
+use wfdb::testing::FaultyReader;
+use wfdb::{Header, Record, Sample, SignalFormat};
+
+/// Wrap bytes in a reader returning one byte per read and interrupting
+/// every other read.
+fn faulty(bytes: Vec<u8>) -> FaultyReader<Cursor<Vec<u8>>> {
+    FaultyReader::new(Cursor::new(bytes))
+        .max_chunk(1)
+        .interrupt_every(2)
+}
+
+const HEADER: &str = "rec 2 250 50\n\
+                      rec.dat 212 200 12 0 0 0 0 I\n\
+                      rec.dat 212 200 12 0 0 0 0 II\n";
+
+/// 50 frames of two format 212 signals.
+fn signal_bytes() -> Vec<u8> {
+    let samples: Vec<Sample> = (0..100).map(|i| (i * 37) % 2000 - 1000).collect();
+    let mut bytes = Vec::new();
+    let mut encoder = get_encoder(SignalFormat::Format212, 0).unwrap();
+    encoder.encode(&mut bytes, &samples).unwrap();
+    encoder.finish(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn test_decoders_handle_short_and_interrupted_reads() {
+    let samples: Vec<Sample> = (-50..50).map(|i| i * 13).collect();
+    for format in [
+        SignalFormat::Format8,
+        SignalFormat::Format16,
+        SignalFormat::Format24,
+        SignalFormat::Format32,
+        SignalFormat::Format61,
+        SignalFormat::Format80,
+        SignalFormat::Format160,
+        SignalFormat::Format212,
+        SignalFormat::Format310,
+        SignalFormat::Format311,
+    ] {
+        let mut bytes = Vec::new();
+        let mut encoder = get_encoder(format, samples[0]).unwrap();
+        encoder.encode(&mut bytes, &samples).unwrap();
+        encoder.finish(&mut bytes).unwrap();
+
+        let expected = get_decoder(format, samples[0])
+            .unwrap()
+            .decode(&mut Cursor::new(bytes.clone()), 100)
+            .unwrap();
+
+        let mut reader = faulty(bytes);
+        let mut decoder = get_decoder(format, samples[0]).unwrap();
+        assert_eq!(
+            decoder.decode(&mut reader, 100).unwrap(),
+            expected,
+            "{format:?}"
+        );
+        assert!(reader.interruptions() > 0);
+    }
+}
+
+#[test]
+fn test_header_parsing_handles_faults() {
+    let mut reader = faulty(HEADER.as_bytes().to_vec());
+    let header = Header::from_reader(&mut reader).unwrap();
+    assert_eq!(header.metadata.num_signals, 2);
+    assert!(reader.interruptions() > 0);
+}
+
+#[test]
+fn test_readers_handle_faulty_sources() {
+    let bytes = signal_bytes();
+    let mut source = MemorySource::new();
+    source.insert("rec.dat", bytes.clone());
+    let clean = Record::from_sources(HEADER, source).unwrap();
+    let record = Record::from_sources(HEADER, move |_: &str| {
+        Ok(Box::new(faulty(bytes.clone())) as Box<dyn ReadSeek>)
+    })
+    .unwrap();
+
+    let expected = clean
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames(100)
+        .unwrap();
+    let frames = record
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames(100)
+        .unwrap();
+    assert_eq!(frames.len(), 50);
+    assert_eq!(frames, expected);
+
+    let mut reader = record.signal_reader(1).unwrap();
+    reader.seek_to_sample(20).unwrap();
+    let mut clean_reader = clean.signal_reader(1).unwrap();
+    clean_reader.seek_to_sample(20).unwrap();
+    assert_eq!(
+        reader.read_samples(100).unwrap(),
+        clean_reader.read_samples(100).unwrap()
+    );
+    assert_eq!(record.validate().unwrap(), clean.validate().unwrap());
+}
+
+#[test]
+fn test_delayed_reads() {
+    let mut reader = FaultyReader::new(Cursor::new(vec![1, 0, 2, 0]))
+        .max_chunk(2)
+        .delay(Duration::from_millis(1));
+    let mut decoder = get_decoder(SignalFormat::Format16, 0).unwrap();
+    assert_eq!(decoder.decode(&mut reader, 4).unwrap(), vec![1, 2]);
+}
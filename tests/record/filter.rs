@@ -0,0 +1,49 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::signal::filter;
+
+use super::common::{format16_bytes, write_record};
+
+/// One channel at 360 Hz: a square wave of period 36 around an offset of
+/// 1000 (100 mV).
+fn offset_record() -> tempfile::TempDir {
+    let samples: Vec<i16> = (0..720)
+        .map(|i| if i % 36 < 18 { 1010 } else { 990 })
+        .collect();
+    write_record(
+        "offset",
+        "offset 1 360 720\n\
+         offset.dat 16 10/mV 12 0 0 0 0 ECG\n",
+        &[("offset.dat", &format16_bytes(&samples))],
+    )
+}
+
+#[test]
+fn test_with_filter_applies_to_physical_reads() {
+    let dir = offset_record();
+    let record = Record::open(dir.path().join("offset")).unwrap();
+
+    let mut raw = record.signal_reader(0).unwrap();
+    let mut filtered = record
+        .signal_reader(0)
+        .unwrap()
+        .with_filter(filter::baseline_wander(360.0).unwrap());
+
+    let raw_values = raw.read_physical(720).unwrap();
+    let mut filtered_values = filtered.read_physical(360).unwrap();
+    let mut buffer = vec![0.0; 360];
+    assert_eq!(filtered.read_physical_buf(&mut buffer).unwrap(), 360);
+    filtered_values.extend(buffer);
+
+    let raw_mean = raw_values.iter().sum::<f64>() / 720.0;
+    let filtered_mean = filtered_values[360..].iter().sum::<f64>() / 360.0;
+    assert!((raw_mean - 100.0).abs() < 1e-9);
+    assert!(filtered_mean.abs() < 0.1);
+
+    // ADC values are not filtered, and seeking restarts the filter
+    filtered.seek_to_sample(0).unwrap();
+    assert_eq!(filtered.read_samples(1).unwrap(), vec![1010]);
+    filtered.seek_to_sample(0).unwrap();
+    assert!((filtered.read_physical(1).unwrap()[0] - filtered_values[0]).abs() < 1e-9);
+}
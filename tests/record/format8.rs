@@ -0,0 +1,111 @@
+use wfdb::Record;
+
+use super::common::write_record;
+
+/// Two format 8 signals interleaved in one file, with different initial
+/// values:
+///
+/// | frame | I   | II  |
+/// |-------|-----|-----|
+/// | 0     | 101 | -52 |
+/// | 1     | 104 | -48 |
+/// | 2     | 99  | -42 |
+/// | 3     | 101 | -43 |
+fn interleaved_record() -> tempfile::TempDir {
+    let differences: [i8; 8] = [1, -2, 3, 4, -5, 6, 2, -1];
+    let bytes: Vec<u8> = differences.iter().flat_map(|d| d.to_le_bytes()).collect();
+    write_record(
+        "rec",
+        "rec 2 100 4\n\
+         rec.dat 8 200 10 0 100 0 0 I\n\
+         rec.dat 8 200 10 0 -50 0 0 II\n",
+        &[("rec.dat", &bytes)],
+    )
+}
+
+#[test]
+fn test_format8_interleaved_frames() {
+    let dir = interleaved_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![
+            vec![101, -52],
+            vec![104, -48],
+            vec![99, -42],
+            vec![101, -43]
+        ]
+    );
+}
+
+#[test]
+fn test_format8_interleaved_signal_readers() {
+    let dir = interleaved_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut first = record.signal_reader(0).unwrap();
+    assert_eq!(first.read_samples(10).unwrap(), vec![101, 104, 99, 101]);
+    let mut second = record.signal_reader(1).unwrap();
+    assert_eq!(second.read_samples(10).unwrap(), vec![-52, -48, -42, -43]);
+}
+
+#[test]
+fn test_format8_interleaved_seek() {
+    let dir = interleaved_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    reader.read_frames(3).unwrap();
+    reader.seek_to_frame(1).unwrap();
+    assert_eq!(reader.read_frame().unwrap(), vec![104, -48]);
+    reader.seek_to_frame(3).unwrap();
+    assert_eq!(reader.read_frame().unwrap(), vec![101, -43]);
+
+    let mut second = record.signal_reader(1).unwrap();
+    second.seek_to_sample(2).unwrap();
+    assert_eq!(second.read_samples(2).unwrap(), vec![-42, -43]);
+    second.seek_to_sample(0).unwrap();
+    assert_eq!(second.read_samples(1).unwrap(), vec![-52]);
+}
+
+#[test]
+fn test_format8_interleaved_read_at() {
+    let dir = interleaved_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.signal_reader(1).unwrap();
+
+    let windows = reader.read_at(&[3, 1], 1).unwrap();
+    assert_eq!(windows, vec![vec![-43], vec![-48]]);
+    assert_eq!(reader.read_samples(1).unwrap(), vec![-52]);
+}
+
+#[test]
+fn test_format8_single_signal_seek() {
+    let dir = write_record(
+        "rec",
+        "rec 1 100 4\nrec.dat 8 200 10 0 10 0 0 I\n",
+        &[("rec.dat", &[1, 2, 3, 4])],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.signal_reader(0).unwrap();
+
+    reader.seek_to_sample(2).unwrap();
+    assert_eq!(reader.read_samples(2).unwrap(), vec![16, 20]);
+    reader.seek_to_sample(0).unwrap();
+    assert_eq!(reader.read_samples(1).unwrap(), vec![11]);
+}
+
+#[test]
+fn test_format8_interleaved_extract() {
+    let dir = interleaved_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let snippet = record.extract(dir.path().join("out"), 1..4, None).unwrap();
+    let mut reader = snippet.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![vec![104, -48], vec![99, -42], vec![101, -43]]
+    );
+}
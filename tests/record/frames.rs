@@ -0,0 +1,181 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::signal::INVALID_SAMPLE;
+
+use super::common::{format16_bytes, format61_bytes, write_record};
+
+/// Three signals in two files: a.dat holds (i, -i), b.dat holds 100 + i.
+fn two_file_record() -> tempfile::TempDir {
+    let a: Vec<i16> = (0..5).flat_map(|i| [i, -i]).collect();
+    let b: Vec<i16> = (100..105).collect();
+    write_record(
+        "rec",
+        "rec 3 250 5\n\
+         a.dat 16 200 12 0 0 0 0 I\n\
+         b.dat 61 200 12 0 0 0 0 II\n\
+         a.dat 16 200 12 0 0 0 0 III\n",
+        &[
+            ("a.dat", &format16_bytes(&a)),
+            ("b.dat", &format61_bytes(&b)),
+        ],
+    )
+}
+
+#[test]
+fn test_read_frame_into_matches_read_frame() {
+    let dir = two_file_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let expected = record
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames(10)
+        .unwrap();
+    assert_eq!(expected.len(), 5);
+    assert_eq!(expected[2], vec![2, 102, -2]);
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    let mut frame = [0; 3];
+    let mut frames = Vec::new();
+    while reader.read_frame_into(&mut frame).unwrap() {
+        frames.push(frame.to_vec());
+    }
+    assert_eq!(frames, expected);
+    assert_eq!(frame, [4, 104, -4]);
+    assert_eq!(reader.position(), 5);
+
+    // The buffer must hold one sample per signal
+    reader.seek_to_frame(0).unwrap();
+    assert!(reader.read_frame_into(&mut [0; 2]).is_err());
+    assert!(reader.read_frame_into(&mut [0; 4]).is_err());
+    assert_eq!(reader.position(), 0);
+}
+
+#[test]
+fn test_read_frame_into_averages_multi_frequency_signals() {
+    let dir = write_record(
+        "rec",
+        "rec 2 250 2\nrec.dat 16x2 200 12 0 0 0 0 I\nrec.dat 16 200 12 0 0 0 0 II\n",
+        &[("rec.dat", &format16_bytes(&[1, 3, 10, 5, 7, 20]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+
+    let mut frame = [0; 2];
+    assert!(reader.read_frame_into(&mut frame).unwrap());
+    assert_eq!(frame, [2, 10]);
+    assert!(reader.read_frame_into(&mut frame).unwrap());
+    assert_eq!(frame, [6, 20]);
+    assert!(!reader.read_frame_into(&mut frame).unwrap());
+}
+
+#[test]
+fn test_read_labeled_frame() {
+    let a: Vec<i16> = vec![0, 10, -32768, 20];
+    let dir = write_record(
+        "rec",
+        "rec 2 250 2\n\
+         a.dat 16 200 12 0 0 0 0 I\n\
+         a.dat 16 0 12 0 0 0 0\n",
+        &[("a.dat", &format16_bytes(&a))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+    reader.seek_to_frame(1).unwrap();
+
+    let frame = reader.read_labeled_frame().unwrap().unwrap();
+    assert_eq!(frame.sample_number(), 1);
+    assert_eq!(frame.len(), 2);
+    assert_eq!(frame.raw(), &[INVALID_SAMPLE, 20]);
+    assert_eq!(frame["I"], INVALID_SAMPLE);
+    assert_eq!(frame[1], 20);
+    assert_eq!(frame.get_by_name("I"), Some(INVALID_SAMPLE));
+    assert_eq!(frame.get_by_name("II"), None);
+    assert_eq!(frame.channel_name(0), Some("I"));
+    assert_eq!(frame.channel_name(1), None);
+    assert!(frame.is_invalid(0));
+    assert!(!frame.is_invalid(1));
+    assert!(frame.has_invalid());
+
+    // Invalid samples are NaN, uncalibrated signals keep their ADC values
+    let physical = frame.to_physical();
+    assert!(physical[0].is_nan());
+    assert!((physical[1] - 20.0).abs() < 1e-12);
+    assert_eq!(frame.physical(2), None);
+
+    assert!(reader.read_labeled_frame().unwrap().is_none());
+}
+
+#[test]
+fn test_read_labeled_frame_matches_read_frame() {
+    let dir = two_file_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let expected = record
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames(10)
+        .unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    let mut frames = Vec::new();
+    while let Some(frame) = reader.read_labeled_frame().unwrap() {
+        assert_eq!(frame.sample_number(), frames.len() as u64);
+        assert!((frame.physical(1).unwrap() - f64::from(frame[1]) / 200.0).abs() < 1e-12);
+        frames.push(frame.into_raw());
+    }
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn test_read_block() {
+    let dir = two_file_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+    reader.seek_to_frame(1).unwrap();
+
+    let block = reader.read_block(3).unwrap();
+    assert_eq!(block.start_sample(), 1);
+    assert_eq!(block.num_frames(), 3);
+    assert_eq!(block.num_channels(), 3);
+    assert_eq!(block.as_slice(), &[1, 2, 3, 101, 102, 103, -1, -2, -3]);
+    assert_eq!(block.channel(1), Some(&[101, 102, 103][..]));
+    assert_eq!(block.channel_by_name("III"), Some(&[-1, -2, -3][..]));
+    assert_eq!(block.channel(3), None);
+    assert_eq!(block.channel_name(0), Some("I"));
+    assert_eq!(block.channels().count(), 3);
+    let physical = block.channel_physical(0).unwrap();
+    assert!((physical[2] - 0.015).abs() < 1e-12);
+}
+
+#[test]
+fn test_read_block_into_end_of_record() {
+    let dir = two_file_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+
+    let mut block = reader.read_block(3).unwrap();
+    assert_eq!(block.channel(0), Some(&[0, 1, 2][..]));
+
+    // The last block is short, then empty
+    assert_eq!(reader.read_block_into(&mut block, 3).unwrap(), 2);
+    assert_eq!(block.start_sample(), 3);
+    assert_eq!(block.as_slice(), &[3, 4, 103, 104, -3, -4]);
+    assert_eq!(reader.read_block_into(&mut block, 3).unwrap(), 0);
+    assert!(block.is_empty());
+    assert_eq!(block.channel(0), Some(&[][..]));
+}
+
+#[test]
+fn test_read_block_without_length() {
+    // Without a length in the header, the block is compacted at the end
+    let a: Vec<i16> = (0..3).flat_map(|i| [i, 10 * i]).collect();
+    let dir = write_record(
+        "rec",
+        "rec 2 250\na.dat 16 200 12 0 0 0 0 I\na.dat 16 200 12 0 0 0 0 II\n",
+        &[("a.dat", &format16_bytes(&a))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let block = record.multi_signal_reader().unwrap().read_block(5).unwrap();
+    assert_eq!(block.num_frames(), 3);
+    assert_eq!(block.into_vec(), vec![0, 1, 2, 0, 10, 20]);
+}
@@ -0,0 +1,111 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::record::{FrameIndex, SampleIndex};
+use wfdb::signal::INVALID_SAMPLE;
+
+use super::common::{format16_bytes, write_record};
+
+/// Write a record whose first signal has 2 samples per frame, interleaved
+/// with a second signal in the same file.
+fn high_resolution_record() -> tempfile::TempDir {
+    write_record(
+        "rec",
+        "rec 2 125 3\nrec.dat 16x2 200 12 0 0 0 0 I\nrec.dat 16 200 12 0 0 0 0 II\n",
+        &[(
+            "rec.dat",
+            &format16_bytes(&[1, 2, 100, 3, 6, 200, -5, i16::MIN, 300]),
+        )],
+    )
+}
+
+#[test]
+fn test_high_resolution_read_frame_averages() {
+    let dir = high_resolution_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(reader.samples_per_frame(), &[2, 1]);
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![vec![2, 100], vec![5, 200], vec![INVALID_SAMPLE, 300]]
+    );
+}
+
+#[test]
+fn test_high_resolution_read_frame_samples() {
+    let dir = high_resolution_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frame_samples().unwrap(),
+        vec![vec![1, 2], vec![100]]
+    );
+
+    reader.seek_to_frame(2).unwrap();
+    let mut frame = Vec::new();
+    assert!(reader.read_frame_samples_into(&mut frame).unwrap());
+    assert_eq!(frame, vec![vec![-5, INVALID_SAMPLE], vec![300]]);
+    assert!(!reader.read_frame_samples_into(&mut frame).unwrap());
+    assert!(frame.is_empty());
+}
+
+#[test]
+fn test_high_resolution_checksums() {
+    let dir = write_record(
+        "rec",
+        "rec 2 125 2\nrec.dat 16x2 200 12 0 0 10 0 I\nrec.dat 16 200 12 0 0 300 0 II\n",
+        &[("rec.dat", &format16_bytes(&[1, 2, 100, 3, 4, 200]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    assert!(record.validate().unwrap().is_valid());
+}
+
+#[test]
+fn test_high_resolution_frame_and_sample_indices() {
+    let dir = high_resolution_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let samples_per_frame = record.signal_info().unwrap()[0].samples_per_frame();
+
+    // Frame 1 starts at sample 2 of the 2-samples-per-frame signal
+    let start = FrameIndex(1).to_sample(samples_per_frame);
+    assert_eq!(start, SampleIndex(2));
+    assert_eq!(start.to_frame(samples_per_frame), FrameIndex(1));
+
+    let mut reader = record.signal_reader(0).unwrap();
+    assert_eq!(reader.seek_to_sample_index(start).unwrap(), 2);
+    assert_eq!(
+        reader.read_samples(10).unwrap(),
+        vec![3, 6, -5, INVALID_SAMPLE]
+    );
+    assert_eq!(
+        record.signal_reader(1).unwrap().read_samples(10).unwrap(),
+        vec![100, 200, 300]
+    );
+
+    // The signal is sampled at 250 Hz, twice the frame rate
+    assert_eq!(reader.sampling_frequency(), Some(250.0));
+    assert_eq!(reader.seek_to_time(0.008).unwrap(), 2);
+    assert_eq!(reader.read_samples(1).unwrap(), vec![3]);
+
+    let mut frames = record.multi_signal_reader().unwrap();
+    assert_eq!(frames.seek_to_frame_index(FrameIndex(1)).unwrap(), 1);
+    assert_eq!(frames.read_frame().unwrap(), vec![5, 200]);
+}
+
+#[test]
+fn test_high_resolution_length_near_limit() {
+    // The header length times the samples per frame exceeds u64
+    let dir = write_record(
+        "r",
+        "r 1 250 9223372036854775807\nr.dat 16x4 200 12 0 0 0 0 I\n",
+        &[("r.dat", &format16_bytes(&[1, 2, 3, 4]))],
+    );
+    let record = Record::open(dir.path().join("r")).unwrap();
+
+    let mut reader = record.signal_reader(0).unwrap();
+    assert_eq!(reader.read_samples(10).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(FrameIndex(u64::MAX).to_sample(4), SampleIndex(u64::MAX));
+}
@@ -0,0 +1,81 @@
+#![allow(clippy::unwrap_used)]
+
+use std::path::PathBuf;
+
+use wfdb::Error;
+use wfdb::locator::{Locator, SearchEntry};
+
+use super::common::{format16_bytes, write_files};
+
+#[test]
+fn test_locator_parse() {
+    let locator = Locator::parse(" .  /data/wfdb\thttps://physionet.org/files/\n");
+    assert_eq!(
+        locator.entries(),
+        &[
+            SearchEntry::Directory(PathBuf::from(".")),
+            SearchEntry::Directory(PathBuf::from("/data/wfdb")),
+            SearchEntry::Url("https://physionet.org/files".to_string()),
+        ]
+    );
+    assert!(Locator::parse("").entries().is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_locator_parse_colon_separated() {
+    let locator = Locator::parse(".:/data/wfdb:http://localhost/db::/mirror");
+    assert_eq!(
+        locator.entries(),
+        &[
+            SearchEntry::Directory(PathBuf::from(".")),
+            SearchEntry::Directory(PathBuf::from("/data/wfdb")),
+            SearchEntry::Url("http://localhost/db".to_string()),
+            SearchEntry::Directory(PathBuf::from("/mirror")),
+        ]
+    );
+}
+
+#[test]
+fn test_locator_search_order() {
+    let first = tempfile::tempdir().unwrap();
+    let second = tempfile::tempdir().unwrap();
+    std::fs::create_dir(first.path().join("db")).unwrap();
+    std::fs::create_dir(second.path().join("db")).unwrap();
+    let data = format16_bytes(&[1, 2, 3]);
+    write_files(
+        &second.path().join("db"),
+        "rec",
+        "rec 1 250 3\nrec.dat 16\n",
+        &[("rec.dat", &data)],
+    );
+    write_files(
+        &first.path().join("db"),
+        "other",
+        "other 1 250 3\nrec.dat 16\n",
+        &[("rec.dat", &data)],
+    );
+    let locator = Locator::new()
+        .with_directory(first.path())
+        .with_directory(second.path());
+
+    assert_eq!(
+        locator.find("db/rec"),
+        Some(second.path().join("db/rec.hea"))
+    );
+    assert_eq!(
+        locator.find("db/other.hea"),
+        Some(first.path().join("db/other.hea"))
+    );
+
+    let record = locator.open("db/rec").unwrap();
+    let samples = record.signal_reader(0).unwrap().read_samples(3).unwrap();
+    assert_eq!(samples, vec![1, 2, 3]);
+
+    assert!(locator.find("db/missing").is_none());
+    assert!(matches!(
+        locator.open("db/missing"),
+        Err(Error::InvalidPath(_))
+    ));
+    assert!(Locator::new().find("db/rec").is_none());
+}
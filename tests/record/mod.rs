@@ -0,0 +1,53 @@
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod calibration;
+pub mod canonical;
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod common;
+pub mod components;
+pub mod create;
+pub mod database;
+pub mod edf;
+pub mod errors;
+pub mod export;
+pub mod extract;
+#[cfg(feature = "testing")]
+pub mod faults;
+pub mod filter;
+pub mod format8;
+pub mod frames;
+pub mod high_resolution;
+pub mod locator;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+pub mod options;
+pub mod pipeline;
+pub mod progress;
+pub mod provenance;
+#[cfg(feature = "unstable")]
+pub mod qc;
+pub mod read_at;
+#[cfg(feature = "remote")]
+pub mod remote;
+pub mod replay;
+pub mod resampling;
+pub mod seek;
+pub mod segment_signal;
+pub mod shared_file;
+pub mod skew;
+pub mod source;
+pub mod stats;
+#[cfg(feature = "async")]
+pub mod stream;
+pub mod summary;
+pub mod tee;
+#[cfg(feature = "chrono")]
+pub mod timing;
+#[cfg(feature = "unstable")]
+pub mod trigger;
+pub mod validation;
+pub mod waveform;
+pub mod windows;
+pub mod wire;
+pub mod workflows;
@@ -0,0 +1,66 @@
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use ndarray::array;
+use wfdb::Record;
+
+use super::common::{format16_bytes, two_signal_record, write_files, write_record};
+
+/// Two signals of 5 frames at 10 Hz: 0..5 and 0..-10 (mV).
+fn two_channel_record() -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..5).map(|i| [i * 100, -i * 400]).collect();
+    two_signal_record(10, ["100/mV 12 0 0 0 0 I", "200/mV 12 0 0 0 0 II"], &frames)
+}
+
+#[test]
+fn test_record_to_ndarray() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let data = record.to_ndarray().unwrap();
+    assert_eq!(data.dim(), (5, 2));
+    assert_eq!(
+        data,
+        array![
+            [0.0, 0.0],
+            [1.0, -2.0],
+            [2.0, -4.0],
+            [3.0, -6.0],
+            [4.0, -8.0]
+        ]
+    );
+    // Channels are contiguous
+    assert_eq!(
+        data.column(1).as_slice().unwrap(),
+        &[0.0, -2.0, -4.0, -6.0, -8.0]
+    );
+}
+
+#[test]
+fn test_read_array() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+
+    let first = reader.read_array(3).unwrap();
+    assert_eq!(first, array![[0.0, 0.0], [1.0, -2.0], [2.0, -4.0]]);
+    let rest = reader.read_array(3).unwrap();
+    assert_eq!(rest, array![[3.0, -6.0], [4.0, -8.0]]);
+    assert_eq!(reader.read_array(3).unwrap().dim(), (0, 2));
+}
+
+#[test]
+fn test_multi_segment_to_ndarray() {
+    let dir = write_record("multi", "multi/2 1 10 4\nseg_a 2\n~ 2\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 10 2\nseg_a.dat 16 100/mV 12 0 0 0 0 ECG\n",
+        &[("seg_a.dat", &format16_bytes(&[100, 200]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let data = record.to_ndarray().unwrap();
+    assert_eq!(data.dim(), (4, 1));
+    assert_eq!(data[[1, 0]], 2.0);
+    assert!(data[[2, 0]].is_nan() && data[[3, 0]].is_nan());
+}
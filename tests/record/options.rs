@@ -0,0 +1,268 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use wfdb::record::{DEFAULT_BUFFER_CAPACITY, ReadSeek, ReaderOptions};
+use wfdb::signal::get_encoder;
+use wfdb::{Record, Result, SignalFormat};
+
+use super::common::{format16_bytes, two_signal_record, write_record};
+
+/// Signals I and II at 250 Hz, holding 0..200 interleaved over 100 frames.
+fn two_channel_record() -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..100).map(|i| [2 * i, 2 * i + 1]).collect();
+    two_signal_record(250, ["200 12 0 0 0 0 I", "200 12 0 0 0 0 II"], &frames)
+}
+
+#[test]
+fn test_default_options() {
+    assert_eq!(
+        ReaderOptions::default().buffer_capacity,
+        DEFAULT_BUFFER_CAPACITY
+    );
+    assert!(ReaderOptions::low_latency().buffer_capacity < DEFAULT_BUFFER_CAPACITY);
+}
+
+#[test]
+fn test_low_latency_reads_same_frames() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let expected = record
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames(100)
+        .unwrap();
+    let mut reader = record
+        .multi_signal_reader_with_options(&ReaderOptions::low_latency())
+        .unwrap();
+    let frames = reader.read_frames(100).unwrap();
+
+    assert_eq!(frames, expected);
+    assert_eq!(frames[99], vec![198, 199]);
+    assert!(reader.read_frame().unwrap().is_empty());
+}
+
+#[test]
+fn test_zero_buffer_capacity_is_clamped() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let options = ReaderOptions {
+        buffer_capacity: 0,
+        ..ReaderOptions::default()
+    };
+
+    let mut reader = record.signal_reader_with_options(1, &options).unwrap();
+    assert_eq!(reader.read_samples(3).unwrap(), vec![1, 3, 5]);
+}
+
+#[test]
+fn test_target_units_normalize_physical_values() {
+    let samples: Vec<i16> = vec![200, 100, 400, 50];
+    let dir = write_record(
+        "rec",
+        "rec 2 250 2\n\
+         rec.dat 16 200/mV 12 0 0 0 0 I\n\
+         rec.dat 16 0.2/uV 12 0 0 0 0 II\n",
+        &[("rec.dat", &format16_bytes(&samples))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    // Without normalization, channels report different magnitudes
+    let frames = record
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames_physical(2)
+        .unwrap();
+    assert_eq!(frames[0], vec![1.0, 500.0]);
+
+    let options = ReaderOptions::default().with_target_units(&["mV", "mmHg"]);
+    let mut reader = record.multi_signal_reader_with_options(&options).unwrap();
+    let frames = reader.read_frames_physical(2).unwrap();
+    assert!((frames[0][1] - 0.5).abs() < 1e-12);
+    assert!((frames[1][1] - 0.25).abs() < 1e-12);
+    assert!((frames[1][0] - 2.0).abs() < 1e-12);
+    assert!(reader.scales().iter().all(|scale| scale.units == "mV"));
+
+    let mut reader = record.signal_reader_with_options(1, &options).unwrap();
+    assert_eq!(reader.units(), "mV");
+    assert!((reader.gain() - 200.0).abs() < 1e-9);
+    assert!((reader.read_physical(1).unwrap()[0] - 0.5).abs() < 1e-12);
+    assert_eq!(reader.to_adc(0.25), 50);
+}
+
+#[test]
+fn test_target_units_keep_unrelated_signals() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let options = ReaderOptions::default().with_target_units(&["mmHg"]);
+
+    let reader = record.signal_reader_with_options(0, &options).unwrap();
+    assert_eq!(reader.units(), "mV");
+    assert!((reader.gain() - 200.0).abs() < f64::EPSILON);
+}
+
+/// In-memory file counting the seeks made on it.
+struct SeekCounter {
+    inner: Cursor<Vec<u8>>,
+    seeks: Arc<AtomicUsize>,
+}
+
+impl Read for SeekCounter {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl Seek for SeekCounter {
+    fn seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        self.seeks.fetch_add(1, Ordering::Relaxed);
+        self.inner.seek(position)
+    }
+}
+
+#[test]
+fn test_interleaved_reads_do_not_seek_per_sample() {
+    // 12 channels, 1000 frames: channel c of frame i holds 100 * c + i % 100
+    let samples: Vec<i16> = (0..1000)
+        .flat_map(|i| (0..12).map(move |c| 100 * c + i % 100))
+        .collect();
+    let bytes = format16_bytes(&samples);
+    let header: String = std::iter::once("rec 12 500 1000\n".to_string())
+        .chain((0..12).map(|c| format!("rec.dat 16 200 12 0 0 0 0 lead{c}\n")))
+        .collect();
+    let seeks = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&seeks);
+    let record = Record::from_sources(&header, move |_: &str| -> Result<Box<dyn ReadSeek>> {
+        Ok(Box::new(SeekCounter {
+            inner: Cursor::new(bytes.clone()),
+            seeks: Arc::clone(&counter),
+        }))
+    })
+    .unwrap();
+
+    let options = ReaderOptions::default().with_interleaved_block_frames(64);
+    let mut reader = record.signal_reader_with_options(7, &options).unwrap();
+    let values = reader.read_samples(2000).unwrap();
+    let expected: Vec<i32> = (0..1000).map(|i| 700 + i % 100).collect();
+    assert_eq!(values, expected);
+    assert_eq!(seeks.load(Ordering::Relaxed), 0);
+
+    // Seeking within the current block needs no I/O; elsewhere, one seek
+    reader.seek_to_sample(990).unwrap();
+    assert_eq!(reader.read_samples(2).unwrap(), vec![790, 791]);
+    assert_eq!(seeks.load(Ordering::Relaxed), 0);
+    reader.seek_to_sample(10).unwrap();
+    assert_eq!(reader.read_samples(100).unwrap(), expected[10..110]);
+    assert_eq!(seeks.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_interleaved_block_sizes_read_same_samples() {
+    // Three signals in format 212, the first with 2 samples per frame
+    let mut bytes = Vec::new();
+    let mut encoder = get_encoder(SignalFormat::Format212, 0).unwrap();
+    encoder
+        .encode(&mut bytes, &(0..20).collect::<Vec<_>>())
+        .unwrap();
+    encoder.finish(&mut bytes).unwrap();
+    let dir = write_record(
+        "rec",
+        "rec 3 250 5\n\
+         rec.dat 212x2 200 12 0 0 0 0 I\n\
+         rec.dat 212 200 12 0 0 0 0 II\n\
+         rec.dat 212 200 12 0 0 0 0 III\n",
+        &[("rec.dat", &bytes)],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    for frames in [0, 1, 2, 3, 1024] {
+        let options = ReaderOptions::default().with_interleaved_block_frames(frames);
+        let mut first = record.signal_reader_with_options(0, &options).unwrap();
+        assert_eq!(
+            first.read_samples(20).unwrap(),
+            vec![0, 1, 4, 5, 8, 9, 12, 13, 16, 17]
+        );
+        let mut third = record.signal_reader_with_options(2, &options).unwrap();
+        assert_eq!(third.read_samples(20).unwrap(), vec![3, 7, 11, 15, 19]);
+        third.seek_to_sample(3).unwrap();
+        assert_eq!(third.read_samples(20).unwrap(), vec![15, 19]);
+    }
+}
+
+#[test]
+fn test_prefetch_reads_same_frames() {
+    // Two files, one with a skewed signal and two samples per frame
+    let dir = write_record(
+        "rec",
+        "rec 3 250 40\n\
+         a.dat 16 200 12 0 0 0 0 I\n\
+         b.dat 16x2 200 12 0 0 0 0 II\n\
+         b.dat 16:1 200 12 0 0 0 0 III\n",
+        &[
+            ("a.dat", &format16_bytes(&(0..40).collect::<Vec<_>>())),
+            ("b.dat", &format16_bytes(&(100..220).collect::<Vec<_>>())),
+        ],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut plain = record.multi_signal_reader().unwrap();
+    let expected = plain.read_frames(100).unwrap();
+
+    for frames in [1, 3, 40, 1000] {
+        let options = ReaderOptions::default().with_prefetch_frames(frames);
+        let mut reader = record.multi_signal_reader_with_options(&options).unwrap();
+        assert_eq!(reader.read_frames(100).unwrap(), expected);
+
+        // Seeking discards the frames decoded ahead
+        reader.seek_to_frame(5).unwrap();
+        assert_eq!(reader.read_frame().unwrap(), expected[5]);
+        reader.seek_to_frame(38).unwrap();
+        assert_eq!(reader.read_frames(10).unwrap(), expected[38..]);
+        assert!(reader.read_frame().unwrap().is_empty());
+    }
+}
+
+#[test]
+fn test_prefetch_dropped_mid_record() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let options = ReaderOptions::default().with_prefetch_frames(8);
+
+    let mut reader = record.multi_signal_reader_with_options(&options).unwrap();
+    assert_eq!(reader.read_frame().unwrap(), vec![0, 1]);
+    let reader = std::thread::spawn(move || {
+        assert_eq!(reader.read_frame().unwrap(), vec![2, 3]);
+        reader
+    })
+    .join()
+    .unwrap();
+    drop(reader);
+}
+
+#[test]
+fn test_readers_stop_at_record_length() {
+    // Three frames, padded to a block of five
+    let dir = write_record(
+        "rec",
+        "rec 2 250 3\n\
+         rec.dat 16 200 12 0 0 0 0 I\n\
+         rec.dat 16 200 12 0 0 0 0 II\n",
+        &[(
+            "rec.dat",
+            &format16_bytes(&[1, -1, 2, -2, 3, -3, 0, 0, 0, 0]),
+        )],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(reader.read_frames(10).unwrap().len(), 3);
+    let mut reader = record.signal_reader(1).unwrap();
+    assert_eq!(reader.read_samples(10).unwrap(), vec![-1, -2, -3]);
+
+    // The padding is read when the length is ignored
+    let options = ReaderOptions::default().ignore_length();
+    let mut reader = record.multi_signal_reader_with_options(&options).unwrap();
+    assert_eq!(reader.read_frames(10).unwrap().len(), 5);
+    let mut reader = record.signal_reader_with_options(1, &options).unwrap();
+    assert_eq!(reader.read_samples(10).unwrap(), vec![-1, -2, -3, 0, 0]);
+}
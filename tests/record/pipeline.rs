@@ -0,0 +1,185 @@
+// Values below are exact: small integers divided by a power-of-ten gain.
+#![allow(clippy::float_cmp)]
+
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, write_record};
+
+/// Three channels at 100 Hz: I = i, II = 10 * i, V5 = -i, for i in 0..10.
+fn three_channel_record() -> tempfile::TempDir {
+    let samples: Vec<i16> = (0..10).flat_map(|i| [i, 10 * i, -i]).collect();
+    write_record(
+        "rec",
+        "rec 3 100 10\n\
+         rec.dat 16 10/mV 12 0 0 0 0 I\n\
+         rec.dat 16 10/mV 12 0 0 0 0 II\n\
+         rec.dat 16 10/mV 12 0 0 0 0 V5\n",
+        &[("rec.dat", &format16_bytes(&samples))],
+    )
+}
+
+#[test]
+fn test_pipeline_default_reads_everything() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let matrix = record.pipeline().to_matrix().unwrap();
+
+    assert_eq!(matrix.num_channels(), 3);
+    assert_eq!(matrix.num_samples(), 10);
+    assert_eq!(matrix.column(1).unwrap()[9], 90.0);
+    assert!((matrix.duration() - 0.1).abs() < 1e-12);
+}
+
+#[test]
+fn test_pipeline_select_slice_physical() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let matrix = record
+        .pipeline()
+        .select(&["V5", "II"])
+        .frames(2..5)
+        .physical()
+        .to_matrix()
+        .unwrap();
+
+    assert_eq!(
+        matrix.channel_names,
+        vec![Some("V5".to_string()), Some("II".to_string())]
+    );
+    assert_eq!(matrix.units, vec!["mV", "mV"]);
+    assert_eq!(matrix.column_by_name("V5").unwrap(), &[-0.2, -0.3, -0.4]);
+    assert_eq!(matrix.column_by_name("II").unwrap(), &[2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn test_pipeline_resample() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    // Upsampling to 200 Hz interpolates halfway between input samples
+    let up = record
+        .pipeline()
+        .select(&["I"])
+        .resample(200.0)
+        .to_matrix()
+        .unwrap();
+    assert!((up.sampling_frequency - 200.0).abs() < f64::EPSILON);
+    let expected: Vec<f64> = (0..19).map(|i| f64::from(i) / 2.0).collect();
+    assert_eq!(up.columns[0], expected);
+
+    // Downsampling to 50 Hz keeps every other sample
+    let down = record
+        .pipeline()
+        .select(&["II"])
+        .frames(1..10)
+        .resample(50.0)
+        .to_matrix()
+        .unwrap();
+    assert_eq!(down.columns[0], vec![10.0, 30.0, 50.0, 70.0, 90.0]);
+}
+
+#[test]
+fn test_pipeline_for_each_frame() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut frames = Vec::new();
+    record
+        .pipeline()
+        .frames(8..100)
+        .for_each_frame(|frame| frames.push(frame.to_vec()))
+        .unwrap();
+
+    assert_eq!(frames, vec![vec![8.0, 80.0, -8.0], vec![9.0, 90.0, -9.0]]);
+}
+
+#[test]
+fn test_pipeline_errors() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let result = record.pipeline().select(&["aVR"]).to_matrix();
+    assert!(
+        matches!(result, Err(Error::InvalidData(_))),
+        "Expected InvalidHeader error, got {result:?}"
+    );
+
+    let (start, end) = (5, 2);
+    let result = record.pipeline().frames(start..end).to_matrix();
+    assert!(matches!(result, Err(Error::InvalidData(_))));
+
+    let result = record.pipeline().resample(0.0).to_matrix();
+    assert!(matches!(result, Err(Error::InvalidData(_))));
+}
+
+#[test]
+fn test_read_all_physical() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let matrix = record.read_all_physical().unwrap();
+    assert_eq!(matrix, record.pipeline().physical().to_matrix().unwrap());
+    assert_eq!(matrix.num_channels(), 3);
+    assert_eq!(matrix.num_samples(), 10);
+    assert_eq!(matrix.sampling_frequency, 100.0);
+    assert_eq!(matrix.units, vec!["mV", "mV", "mV"]);
+    assert_eq!(matrix.column_by_name("II").unwrap()[9], 9.0);
+}
+
+#[test]
+fn test_read_physical_frames() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let matrix = record.read_physical_frames(7..20).unwrap();
+    assert_eq!(matrix.num_samples(), 3);
+    assert_eq!(matrix.column(2).unwrap(), &[-0.7, -0.8, -0.9]);
+    let (start, end) = (5, 2);
+    assert!(matches!(
+        record.read_physical_frames(start..end),
+        Err(Error::InvalidData(_))
+    ));
+
+    // Ranges past the end of the record are empty
+    let matrix = record.read_physical_frames(u64::MAX - 1..u64::MAX).unwrap();
+    assert_eq!(matrix.num_samples(), 0);
+    assert_eq!(matrix.num_channels(), 3);
+}
+
+#[test]
+fn test_range_forms() {
+    let dir = three_channel_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let column = |matrix: wfdb::record::SignalMatrix| matrix.column(1).unwrap().to_vec();
+
+    let half_open = column(record.read_physical_frames(2..5).unwrap());
+    assert_eq!(half_open, &[2.0, 3.0, 4.0]);
+    assert_eq!(
+        column(record.read_physical_frames(2..=4).unwrap()),
+        half_open
+    );
+    assert_eq!(
+        column(record.read_physical_frames(..2).unwrap()),
+        &[0.0, 1.0]
+    );
+    assert_eq!(
+        column(record.read_physical_frames(8..).unwrap()),
+        &[8.0, 9.0]
+    );
+    assert_eq!(record.read_physical_frames(..).unwrap().num_samples(), 10);
+
+    assert_eq!(column(record.read_range_physical(2, 5).unwrap()), half_open);
+    assert_eq!(
+        column(
+            record
+                .pipeline()
+                .slice(2, 5)
+                .physical()
+                .to_matrix()
+                .unwrap()
+        ),
+        half_open
+    );
+}
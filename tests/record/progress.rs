@@ -0,0 +1,192 @@
+#![allow(clippy::unwrap_used)]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use wfdb::record::export::CsvOptions;
+use wfdb::record::{Budget, CancelToken, Progress};
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, write_files, write_record};
+
+/// One signal of 10,000 frames.
+fn long_record() -> tempfile::TempDir {
+    let values: Vec<i16> = (0..10_000).map(|i| (i % 100) as i16).collect();
+    write_record(
+        "long",
+        "long 1 250 10000\nlong.dat 16 200 12 0 0 0 0 I\n",
+        &[("long.dat", &format16_bytes(&values))],
+    )
+}
+
+/// Reports received by a progress callback.
+type Reports = Arc<Mutex<Vec<(u64, Option<u64>)>>>;
+
+/// Collect the reports of a progress callback.
+fn recorder() -> (
+    Reports,
+    impl FnMut(u64, Option<u64>) -> bool + Send + 'static,
+) {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reports);
+    (reports, move |done, total| {
+        sink.lock().unwrap().push((done, total));
+        true
+    })
+}
+
+#[test]
+fn test_validate_with_progress() {
+    let dir = long_record();
+    let record = Record::open(dir.path().join("long")).unwrap();
+
+    let (reports, callback) = recorder();
+    let report = record.validate_with_progress(callback).unwrap();
+    assert_eq!(report.checksums.len(), 1);
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![
+            (4096, Some(10_000)),
+            (8192, Some(10_000)),
+            (10_000, Some(10_000))
+        ]
+    );
+}
+
+#[test]
+fn test_validate_with_progress_multi_segment() {
+    let dir = write_record("multi", "multi/2 1 250 5\nseg_a 3\n~ 2\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 3\nseg_a.dat 16 200 12 0 0 0 0 I\n",
+        &[("seg_a.dat", &format16_bytes(&[1, 2, 3]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    // Null segments are counted
+    let (reports, callback) = recorder();
+    record.validate_with_progress(callback).unwrap();
+    assert_eq!(*reports.lock().unwrap(), vec![(5, Some(5))]);
+}
+
+#[test]
+fn test_progress_cancels() {
+    let dir = long_record();
+    let record = Record::open(dir.path().join("long")).unwrap();
+
+    let result = record.validate_with_progress(|done, _| done < 4096);
+    assert!(matches!(result, Err(Error::Cancelled)));
+
+    let result = record.read_all_physical_with_progress(|_, _| false);
+    assert!(matches!(result, Err(Error::Cancelled)));
+
+    let mut output = Vec::new();
+    let options = CsvOptions::default().with_progress(Progress::new(|done, _| done < 8192));
+    let result = record.export_csv(&mut output, &options);
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
+
+#[test]
+fn test_pipeline_progress_counts_slice() {
+    let dir = long_record();
+    let record = Record::open(dir.path().join("long")).unwrap();
+
+    let (reports, callback) = recorder();
+    let matrix = record
+        .pipeline()
+        .frames(1000..6000)
+        .on_progress(Progress::new(callback))
+        .to_matrix()
+        .unwrap();
+    assert_eq!(matrix.num_samples(), 5000);
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![(4096, Some(5000)), (5000, Some(5000))]
+    );
+
+    let (reports, callback) = recorder();
+    let mut output = Vec::new();
+    let options = CsvOptions::default()
+        .with_frames(..10)
+        .with_progress(Progress::new(callback));
+    assert_eq!(record.export_csv(&mut output, &options).unwrap(), 10);
+    assert_eq!(*reports.lock().unwrap(), vec![(10, Some(10))]);
+}
+
+#[test]
+fn test_budget_max_frames() {
+    let dir = long_record();
+    let record = Record::open(dir.path().join("long")).unwrap();
+    let budget = Budget::new().with_max_frames(5000);
+
+    // Known lengths are checked before reading
+    let result = record.validate_with_budget(&budget);
+    assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+    let result = record.pipeline().with_budget(budget.clone()).to_matrix();
+    assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+
+    // Slices within the budget are read
+    let matrix = record
+        .pipeline()
+        .frames(..5000)
+        .with_budget(budget.clone())
+        .to_matrix()
+        .unwrap();
+    assert_eq!(matrix.num_samples(), 5000);
+
+    let mut output = Vec::new();
+    let options = CsvOptions::default()
+        .with_frames(2000..8000)
+        .with_budget(budget);
+    let result = record.export_csv(&mut output, &options);
+    assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+}
+
+#[test]
+fn test_budget_max_frames_unknown_length() {
+    let values: Vec<i16> = (0..10_000).map(|i| (i % 100) as i16).collect();
+    let dir = write_record(
+        "open",
+        "open 1 250\nopen.dat 16 200 12 0 0 0 0 I\n",
+        &[("open.dat", &format16_bytes(&values))],
+    );
+    let record = Record::open(dir.path().join("open")).unwrap();
+
+    // Unknown lengths fail once the limit is passed
+    let budget = Budget::new().with_max_frames(5000);
+    let result = record.pipeline().with_budget(budget).to_matrix();
+    assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+
+    let budget = Budget::new().with_max_frames(10_000);
+    let matrix = record.pipeline().with_budget(budget).to_matrix().unwrap();
+    assert_eq!(matrix.num_samples(), 10_000);
+}
+
+#[test]
+fn test_budget_deadline_and_token() {
+    let dir = long_record();
+    let record = Record::open(dir.path().join("long")).unwrap();
+
+    let budget = Budget::new().with_deadline(Instant::now());
+    let result = record.validate_with_budget(&budget);
+    assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+
+    let budget = Budget::new().with_timeout(Duration::from_hours(1));
+    assert!(record.validate_with_budget(&budget).is_ok());
+
+    // Cancelled from the progress callback, as another thread would
+    let token = CancelToken::new();
+    let canceller = token.clone();
+    let budget = Budget::new().with_cancel_token(token.clone());
+    let result = record
+        .pipeline()
+        .with_budget(budget)
+        .on_progress(Progress::new(move |_, _| {
+            canceller.cancel();
+            true
+        }))
+        .to_matrix();
+    assert!(token.is_cancelled());
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
@@ -0,0 +1,113 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::record::Provenance;
+
+use super::common::{format16_bytes, write_record};
+
+/// Two channels at 100 Hz with a base time: I = i, II = -2 * i, for i in 0..10.
+fn source_record() -> tempfile::TempDir {
+    let samples: Vec<i16> = (0..10).flat_map(|i| [i, -2 * i]).collect();
+    write_record(
+        "src",
+        "src 2 100 10 08:30:00\n\
+         src.dat 16 10/mV 12 0 0 0 0 I\n\
+         src.dat 16 10/mV 12 0 0 0 0 II\n\
+         # age: 60\n",
+        &[("src.dat", &format16_bytes(&samples))],
+    )
+}
+
+#[test]
+fn test_to_record_writes_samples_and_provenance() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+
+    let derived = source
+        .pipeline()
+        .select(&["II"])
+        .frames(2..6)
+        .to_record(dir.path().join("derived"))
+        .unwrap();
+
+    assert_eq!(derived.metadata().name(), "derived");
+    assert_eq!(derived.metadata().num_samples(), Some(4));
+    assert!(derived.metadata().base_time_hms().is_none());
+    let samples = derived.signal_reader(0).unwrap().read_samples(10).unwrap();
+    assert_eq!(samples, vec![-4, -6, -8, -10]);
+    assert!(derived.validate().unwrap().is_valid());
+
+    assert_eq!(derived.info_strings()[0], " age: 60");
+    let provenance = derived.provenance();
+    assert_eq!(provenance.len(), 1);
+    assert_eq!(provenance[0].source, "src");
+    assert_eq!(provenance[0].fingerprint, source.fingerprint());
+    assert_eq!(
+        provenance[0].crate_version,
+        format!("wfdb {}", env!("CARGO_PKG_VERSION"))
+    );
+    assert_eq!(provenance[0].transforms, vec!["slice 2..6", "select II"]);
+}
+
+#[test]
+fn test_to_record_resample_keeps_base_time() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+
+    let derived = source
+        .pipeline()
+        .resample(50.0)
+        .to_record(dir.path().join("half"))
+        .unwrap();
+
+    assert!((derived.metadata().sampling_frequency() - 50.0).abs() < 1e-12);
+    assert_eq!(derived.metadata().num_samples(), Some(5));
+    assert!(derived.metadata().base_time_hms().is_some());
+    let frames = derived
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames(10)
+        .unwrap();
+    assert_eq!(frames[1], vec![2, -4]);
+    assert_eq!(derived.provenance()[0].transforms, vec!["resample 50 Hz"]);
+}
+
+#[test]
+fn test_provenance_chain() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+    let first = source
+        .pipeline()
+        .frames(0..8)
+        .to_record(dir.path().join("first"))
+        .unwrap();
+    let second = first
+        .pipeline()
+        .invert(&["I"])
+        .to_record(dir.path().join("second"))
+        .unwrap();
+
+    let chain = second.provenance();
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain[0].source, "src");
+    assert_eq!(chain[1].source, "first");
+    assert_eq!(chain[1].fingerprint, first.fingerprint());
+    assert_eq!(chain[1].transforms, vec!["invert I"]);
+    assert_ne!(first.fingerprint(), source.fingerprint());
+}
+
+#[test]
+fn test_provenance_info_strings_round_trip() {
+    let dir = source_record();
+    let source = Record::open(dir.path().join("src")).unwrap();
+    let provenance = Provenance::from_record(&source).with_transform("custom filter 0.5-40 Hz");
+
+    let mut info_strings = vec![" unrelated comment".to_string()];
+    info_strings.extend(provenance.to_info_strings());
+    assert_eq!(info_strings[1], format!(" wfdb-provenance: source src"));
+    assert_eq!(
+        Provenance::from_info_strings(&info_strings),
+        vec![provenance]
+    );
+    assert!(source.provenance().is_empty());
+}
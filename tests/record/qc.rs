@@ -0,0 +1,111 @@
+use wfdb::Record;
+use wfdb::qc::{MIN_INVERSION_BEATS, detect_inversion};
+
+use super::common::{format16_bytes, two_signal_record, write_record};
+
+/// Synthetic ECG at 250 Hz: a beat every 200 samples with a small Q wave,
+/// a tall R wave and a shallow S wave, over a slow baseline wander.
+fn synthetic_ecg(polarity: i16) -> Vec<i16> {
+    const QRS: [(usize, i16); 9] = [
+        (0, -10),
+        (1, -5),
+        (2, 30),
+        (3, 80),
+        (4, 120),
+        (5, 70),
+        (6, 10),
+        (7, -30),
+        (8, -10),
+    ];
+    (0..5000u16)
+        .map(|i| {
+            let wander = (f64::from(i) / 150.0).sin() * 5.0;
+            let qrs = QRS
+                .iter()
+                .find(|&&(offset, _)| usize::from(i % 200) == 100 + offset)
+                .map_or(0, |&(_, v)| v);
+            #[allow(clippy::cast_possible_truncation)]
+            let value = wander as i16 + qrs * polarity;
+            value
+        })
+        .collect()
+}
+
+fn ecg_record(polarity: i16) -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = synthetic_ecg(polarity)
+        .into_iter()
+        .map(|v| [v, -v])
+        .collect();
+    two_signal_record(
+        250,
+        ["100/mV 12 0 0 0 0 II", "100/mV 12 0 0 0 0 aVR"],
+        &frames,
+    )
+}
+
+#[test]
+fn test_detect_upright_lead() {
+    let dir = ecg_record(1);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let estimate = detect_inversion(&mut record.signal_reader(0).unwrap()).unwrap();
+    assert!(estimate.beats >= MIN_INVERSION_BEATS);
+    assert!(!estimate.is_inverted());
+    assert!(estimate.confidence() > 0.9);
+}
+
+#[test]
+fn test_detect_inverted_lead() {
+    let dir = ecg_record(1);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let estimate = detect_inversion(&mut record.signal_reader(1).unwrap()).unwrap();
+    assert_eq!(estimate.beats, 25);
+    assert!(estimate.is_inverted());
+}
+
+#[test]
+fn test_flat_signal_is_inconclusive() {
+    let dir = write_record(
+        "flat",
+        "flat 1 250 500\nflat.dat 16 100/mV 12 0 0 0 0 II\n",
+        &[("flat.dat", &format16_bytes(&[0; 500]))],
+    );
+    let record = Record::open(dir.path().join("flat")).unwrap();
+
+    let estimate = detect_inversion(&mut record.signal_reader(0).unwrap()).unwrap();
+    assert_eq!(estimate.beats, 0);
+    assert!(!estimate.is_inverted());
+}
+
+#[test]
+fn test_pipeline_invert() {
+    let dir = ecg_record(-1);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let matrix = record
+        .pipeline()
+        .invert(&["II"])
+        .physical()
+        .frames(100..110)
+        .to_matrix()
+        .unwrap();
+
+    let stored = synthetic_ecg(-1);
+    let expected: Vec<f64> = stored[100..110]
+        .iter()
+        .map(|&v| -f64::from(v) / 100.0)
+        .collect();
+    assert_eq!(matrix.column_by_name("II").unwrap(), expected.as_slice());
+    assert_eq!(matrix.column_by_name("aVR").unwrap(), expected.as_slice());
+
+    // ADC values are mirrored around the baseline
+    let adc = record
+        .pipeline()
+        .invert(&["II"])
+        .frames(104..105)
+        .to_matrix()
+        .unwrap();
+    let flipped = -f64::from(stored[104]);
+    assert_eq!(adc.columns, vec![vec![flipped], vec![flipped]]);
+}
@@ -0,0 +1,71 @@
+use wfdb::Record;
+
+use super::common::{two_signal_record, write_record};
+
+/// Interleaved two-signal record where signal 1 holds -i at sample i.
+fn ramp_record(len: i16) -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..len).map(|i| [i, -i]).collect();
+    two_signal_record(100, ["200 16 0 0 0 0 I", "200 16 0 0 0 0 II"], &frames)
+}
+
+#[test]
+fn test_read_at_returns_windows_in_input_order() {
+    let dir = ramp_record(10_000);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let mut reader = record.signal_reader(1).unwrap();
+
+    // Unsorted, overlapping, duplicated and far-apart requests
+    let windows = reader.read_at(&[9000, 10, 12, 10, 500], 4).unwrap();
+
+    assert_eq!(
+        windows,
+        vec![
+            vec![-9000, -9001, -9002, -9003],
+            vec![-10, -11, -12, -13],
+            vec![-12, -13, -14, -15],
+            vec![-10, -11, -12, -13],
+            vec![-500, -501, -502, -503],
+        ]
+    );
+}
+
+#[test]
+fn test_read_at_truncates_at_end_and_restores_position() {
+    let dir = ramp_record(100);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let mut reader = record.signal_reader(0).unwrap();
+    reader.read_samples(5).unwrap();
+
+    let windows = reader.read_at(&[98, 200], 4).unwrap();
+    assert_eq!(windows, vec![vec![98, 99], vec![]]);
+
+    assert_eq!(reader.position(), 5);
+    assert_eq!(reader.read_samples(1).unwrap(), vec![5]);
+}
+
+#[test]
+fn test_read_at_start_near_u64_max() {
+    let dir = ramp_record(100);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let mut reader = record.signal_reader(0).unwrap();
+
+    let windows = reader.read_at(&[u64::MAX - 2, 97], 10).unwrap();
+    assert_eq!(windows, vec![vec![], vec![97, 98, 99]]);
+    assert_eq!(reader.position(), 0);
+}
+
+#[test]
+fn test_read_at_format8() {
+    let dir = write_record(
+        "rec",
+        "rec 1 100 4\nrec.dat 8 200 10 0 0 0 0 I\n",
+        &[("rec.dat", &[1, 1, 1, 1])],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.signal_reader(0).unwrap();
+    reader.read_samples(1).unwrap();
+
+    let windows = reader.read_at(&[2, 0], 2).unwrap();
+    assert_eq!(windows, vec![vec![3, 4], vec![1, 2]]);
+    assert_eq!(reader.read_samples(1).unwrap(), vec![2]);
+}
@@ -0,0 +1,152 @@
+#![allow(clippy::unwrap_used)]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use wfdb::remote::RemoteOptions;
+use wfdb::{Error, Record};
+
+use super::common::format16_bytes;
+
+/// Requested paths, in order.
+type RequestLog = Arc<Mutex<Vec<String>>>;
+
+/// Serve files over HTTP on a local port, answering 404 for other paths.
+///
+/// Returns the base URL of the server and the log of requested paths.
+pub fn serve(files: Vec<(&str, Vec<u8>)>) -> (String, RequestLog) {
+    let files: HashMap<String, Vec<u8>> = files
+        .into_iter()
+        .map(|(path, data)| (path.to_string(), data))
+        .collect();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let log = RequestLog::default();
+
+    let requests = Arc::clone(&log);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+
+            let path = request_line.split_whitespace().nth(1).unwrap().to_string();
+            let (status, body) = files.get(&path).map_or(("404 Not Found", &[][..]), |data| {
+                ("200 OK", data.as_slice())
+            });
+            requests.lock().unwrap().push(path);
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(body).unwrap();
+        }
+    });
+
+    (url, log)
+}
+
+pub fn options() -> RemoteOptions {
+    RemoteOptions {
+        max_retries: 0,
+        ..RemoteOptions::default()
+    }
+}
+
+#[test]
+fn test_open_url_downloads_and_caches() {
+    let (url, log) = serve(vec![
+        (
+            "/db/1.0/rec.hea",
+            b"rec 2 250 2\nrec.dat 16 200 12 0 0 0 0 I\nrec.dat 16 200 12 0 0 0 0 II\n".to_vec(),
+        ),
+        ("/db/1.0/rec.dat", format16_bytes(&[1, -1, 2, -2])),
+        (
+            "/db/1.0/ANNOTATORS",
+            b"atr\treference annotations\nqrs\tdetector\n".to_vec(),
+        ),
+        ("/db/1.0/rec.atr", vec![0, 0]),
+    ]);
+    let cache = tempfile::tempdir().unwrap();
+
+    let record =
+        Record::open_url_with_options(&format!("{url}/db/1.0/rec"), cache.path(), &options())
+            .unwrap();
+    assert_eq!(record.metadata().name(), "rec");
+    assert_eq!(
+        record
+            .multi_signal_reader()
+            .unwrap()
+            .read_frames(3)
+            .unwrap(),
+        vec![vec![1, -1], vec![2, -2]]
+    );
+    let host = url.trim_start_matches("http://").replace(':', "_");
+    let dir = cache.path().join(host).join("db").join("1.0");
+    assert_eq!(record.base_path(), dir);
+    assert!(dir.join("rec.atr").exists());
+    assert!(!dir.join("rec.qrs").exists());
+
+    // Cached files are not downloaded again
+    let first = log.lock().unwrap().len();
+    Record::open_url_with_options(&format!("{url}/db/1.0/rec.hea"), cache.path(), &options())
+        .unwrap();
+    let requests = log.lock().unwrap()[first..].to_vec();
+    assert_eq!(requests, vec!["/db/1.0/ANNOTATORS", "/db/1.0/rec.qrs"]);
+}
+
+#[test]
+fn test_open_url_multi_segment() {
+    let (url, _) = serve(vec![
+        ("/m/multi.hea", b"multi/2 1 250 3\nseg_a 1\n~ 2\n".to_vec()),
+        (
+            "/m/seg_a.hea",
+            b"seg_a 1 250 1\nseg_a.dat 16 200 12 0 0 0 0 I\n".to_vec(),
+        ),
+        ("/m/seg_a.dat", format16_bytes(&[5])),
+    ]);
+    let cache = tempfile::tempdir().unwrap();
+
+    let record =
+        Record::open_url_with_options(&format!("{url}/m/multi"), cache.path(), &options()).unwrap();
+    let mut reader = record.segment_signal_reader(0).unwrap();
+    assert_eq!(reader.read_samples(3).unwrap()[0], 5);
+}
+
+#[test]
+fn test_open_url_errors() {
+    let (url, _) = serve(vec![
+        ("/db/rec.hea", b"rec 1 250\nrec.dat 16\n".to_vec()),
+        ("/db/bad.hea", b"bad 1 250\n../escape.dat 16\n".to_vec()),
+    ]);
+    let cache = tempfile::tempdir().unwrap();
+    let open = |path: &str| Record::open_url_with_options(path, cache.path(), &options());
+
+    assert!(matches!(open("rec"), Err(Error::InvalidPath(_))));
+    assert!(matches!(
+        open(&format!("{url}/db/")),
+        Err(Error::InvalidPath(_))
+    ));
+    assert!(matches!(
+        open(&format!("{url}/db/missing")),
+        Err(Error::Remote(_))
+    ));
+    assert!(matches!(
+        open(&format!("{url}/db/rec")),
+        Err(Error::Remote(_))
+    ));
+    assert!(matches!(
+        open(&format!("{url}/db/bad")),
+        Err(Error::InvalidPath(_))
+    ));
+}
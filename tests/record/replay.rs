@@ -0,0 +1,58 @@
+use std::time::{Duration, Instant};
+
+use wfdb::record::replay;
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, write_record};
+
+fn ramp_record() -> tempfile::TempDir {
+    let samples: Vec<i16> = (0..20).collect();
+    write_record(
+        "rec",
+        "rec 1 1000 20\n\
+         rec.dat 16 200 12 0 0 0 0 I\n",
+        &[("rec.dat", &format16_bytes(&samples))],
+    )
+}
+
+#[test]
+fn test_replay_yields_all_frames() {
+    let dir = ramp_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut replay = replay(&record, f64::INFINITY).unwrap();
+    assert_eq!(replay.by_ref().take(5).count(), 5);
+    assert_eq!(replay.position(), 5_u64);
+
+    let frames = replay.collect::<wfdb::Result<Vec<_>>>().unwrap();
+    assert_eq!(frames.len(), 15);
+    assert_eq!(frames[14], vec![19]);
+}
+
+#[test]
+fn test_replay_paces_frames() {
+    let dir = ramp_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    // 20 frames at 1000 Hz and half speed: the last frame is due after 38 ms
+    let replay = replay(&record, 0.5).unwrap();
+    assert_eq!(replay.frame_interval(), Duration::from_millis(2));
+
+    let start = Instant::now();
+    assert_eq!(replay.count(), 20);
+    assert!(start.elapsed() >= Duration::from_millis(38));
+}
+
+#[test]
+fn test_replay_rejects_invalid_speed() {
+    let dir = ramp_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    for speed in [0.0, -1.0, f64::NAN] {
+        let result = replay(&record, speed);
+        assert!(
+            matches!(result, Err(Error::InvalidData(_))),
+            "Expected InvalidData error for speed {speed}"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::record::Interpolation;
+use wfdb::signal::INVALID_SAMPLE;
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, two_signal_record, write_record};
+
+/// Two channels at 100 Hz: a ramp (10 * i) and a constant 500, for i in 0..10.
+fn ramp_record() -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..10).map(|i| [10 * i, 500]).collect();
+    two_signal_record(100, ["100 12 0 0 0 0 RAMP", "100 12 0 0 0 0 FLAT"], &frames)
+}
+
+#[test]
+fn test_linear_upsampling() {
+    let dir = ramp_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let mut reader = record
+        .multi_signal_reader()
+        .unwrap()
+        .resample(200.0, Interpolation::Linear)
+        .unwrap();
+    assert!((reader.sampling_frequency() - 200.0).abs() < 1e-12);
+
+    let frames = reader.read_frames(100).unwrap();
+    assert_eq!(frames.len(), 19);
+    let ramp: Vec<i32> = frames.iter().map(|frame| frame[0]).collect();
+    assert_eq!(ramp, (0..19).map(|i| 5 * i).collect::<Vec<_>>());
+    assert!(frames.iter().all(|frame| frame[1] == 500));
+    assert_eq!(reader.position(), 19);
+    assert!(reader.read_frame().unwrap().is_none());
+}
+
+#[test]
+fn test_nearest() {
+    let dir = ramp_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let down: Vec<i32> = record
+        .multi_signal_reader()
+        .unwrap()
+        .resample(50.0, Interpolation::Nearest)
+        .unwrap()
+        .map(|frame| frame.unwrap()[0])
+        .collect();
+    assert_eq!(down, vec![0, 20, 40, 60, 80]);
+
+    let up: Vec<i32> = record
+        .multi_signal_reader()
+        .unwrap()
+        .resample(200.0, Interpolation::Nearest)
+        .unwrap()
+        .take(5)
+        .map(|frame| frame.unwrap()[0])
+        .collect();
+    assert_eq!(up, vec![0, 10, 10, 20, 20]);
+}
+
+#[test]
+fn test_polyphase_keeps_dc_and_removes_aliases() {
+    // A tone at the Nyquist frequency of the input, on top of an offset
+    let samples: Vec<i16> = (0..200)
+        .map(|i| if i % 2 == 0 { 1100 } else { -900 })
+        .collect();
+    let dir = write_record(
+        "tone",
+        "tone 1 100 200\ntone.dat 16 100 12 0 0 0 0 TONE\n",
+        &[("tone.dat", &format16_bytes(&samples))],
+    );
+    let record = Record::open(dir.path().join("tone")).unwrap();
+    let resample = |interpolation| {
+        record
+            .multi_signal_reader()
+            .unwrap()
+            .resample(50.0, interpolation)
+            .unwrap()
+            .map(|frame| frame.unwrap()[0])
+            .collect::<Vec<_>>()
+    };
+
+    // Taking every other sample aliases the tone to DC
+    assert!(resample(Interpolation::Nearest).iter().all(|&v| v == 1100));
+
+    let filtered = resample(Interpolation::Polyphase);
+    assert_eq!(filtered.len(), 100);
+    for &value in &filtered[20..80] {
+        assert!((value - 100).abs() <= 2, "aliased value {value}");
+    }
+}
+
+#[test]
+fn test_invalid_samples_propagate() {
+    let dir = write_record(
+        "gap",
+        "gap 1 100 4\ngap.dat 16 100 12 0 0 0 0 SIG\n",
+        &[("gap.dat", &format16_bytes(&[0, 100, i16::MIN, 300]))],
+    );
+    let record = Record::open(dir.path().join("gap")).unwrap();
+
+    let samples: Vec<i32> = record
+        .signal_reader(0)
+        .unwrap()
+        .resample(200.0, Interpolation::Linear)
+        .unwrap()
+        .map(Result::unwrap)
+        .map(|frame| frame[0])
+        .collect();
+    assert_eq!(
+        samples,
+        vec![
+            0,
+            50,
+            100,
+            INVALID_SAMPLE,
+            INVALID_SAMPLE,
+            INVALID_SAMPLE,
+            300
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_frequency() {
+    let dir = ramp_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    for frequency in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        let result = record
+            .multi_signal_reader()
+            .unwrap()
+            .resample(frequency, Interpolation::Linear);
+        assert!(matches!(result, Err(Error::InvalidData(_))));
+    }
+}
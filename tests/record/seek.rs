@@ -0,0 +1,173 @@
+#![allow(clippy::unwrap_used)]
+
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, format61_bytes, format160_bytes, write_record};
+
+/// Interleaved two-signal frames (i, -i) for i in 0..50, behind a 10-byte prefix.
+fn interleaved_samples() -> Vec<i16> {
+    (0..50).flat_map(|i| [i, -i]).collect()
+}
+
+fn offset_record(format: u16, encode: fn(&[i16]) -> Vec<u8>) -> tempfile::TempDir {
+    let mut data = vec![0xAA; 10];
+    data.extend(encode(&interleaved_samples()));
+    write_record(
+        "rec",
+        &format!(
+            "rec 2 100 50\n\
+             rec.dat {format}+10 200 16 0 0 0 0 I\n\
+             rec.dat {format}+10 200 16 0 0 0 0 II\n"
+        ),
+        &[("rec.dat", &data)],
+    )
+}
+
+fn check_seek_paths(format: u16, encode: fn(&[i16]) -> Vec<u8>) {
+    let dir = offset_record(format, encode);
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    // Single-signal reader over an interleaved file
+    let mut reader = record.signal_reader(1).unwrap();
+    assert_eq!(reader.read_samples(2).unwrap(), vec![0, -1]);
+    assert_eq!(reader.seek_to_sample(30).unwrap(), 30);
+    assert_eq!(reader.read_samples(3).unwrap(), vec![-30, -31, -32]);
+    reader.seek_to_sample(49).unwrap();
+    assert_eq!(reader.read_samples(5).unwrap(), vec![-49]);
+
+    // Time-based seeking (100 Hz)
+    reader.seek_to_time(0.2).unwrap();
+    assert_eq!(reader.read_samples(1).unwrap(), vec![-20]);
+
+    // Frame reader
+    let mut frames = record.multi_signal_reader().unwrap();
+    frames.seek_to_frame(12).unwrap();
+    assert_eq!(frames.read_frame().unwrap(), vec![12, -12]);
+    frames.seek_to_frame(0).unwrap();
+    assert_eq!(frames.read_frame().unwrap(), vec![0, 0]);
+    frames.seek_to_frame(50).unwrap();
+    assert!(frames.read_frame().unwrap().is_empty());
+}
+
+#[test]
+fn test_format61_seek_with_byte_offset() {
+    check_seek_paths(61, format61_bytes);
+}
+
+#[test]
+fn test_format160_seek_with_byte_offset() {
+    check_seek_paths(160, format160_bytes);
+}
+
+#[test]
+fn test_format160_single_signal_seek() {
+    let samples: Vec<i16> = (-20..20).collect();
+    let dir = write_record(
+        "rec",
+        "rec 1 100 40\n\
+         rec.dat 160 200 16 0 0 0 0 I\n",
+        &[("rec.dat", &format160_bytes(&samples))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.signal_reader(0).unwrap();
+    reader.seek_to_sample(25).unwrap();
+    assert_eq!(reader.read_samples(2).unwrap(), vec![5, 6]);
+    assert_eq!(reader.position(), 27);
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_seek_to_time_and_duration() {
+    let dir = offset_record(16, super::common::format16_bytes);
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut frames = record.multi_signal_reader().unwrap();
+    assert_eq!(frames.seek_to_time(0.125).unwrap(), 13);
+    assert_eq!(frames.read_frame().unwrap(), vec![13, -13]);
+    assert!((frames.position_seconds() - 0.14).abs() < 1e-12);
+    assert_eq!(
+        frames
+            .seek_to_duration(TimeDelta::milliseconds(300))
+            .unwrap(),
+        30
+    );
+    assert_eq!(frames.read_frame().unwrap(), vec![30, -30]);
+    assert!(frames.seek_to_time(-1.0).is_err());
+    assert!(frames.seek_to_time(f64::NAN).is_err());
+
+    let mut reader = record.signal_reader(0).unwrap();
+    reader
+        .seek_to_duration(TimeDelta::milliseconds(450))
+        .unwrap();
+    assert_eq!(reader.position_seconds(), Some(0.45));
+    assert_eq!(reader.read_samples(1).unwrap(), vec![45]);
+}
+
+#[test]
+fn test_seek_multi_frequency_signal() {
+    // 10 frames at 100 Hz: I has 4 samples per frame, II has 1
+    let samples: Vec<i16> = (0..10)
+        .flat_map(|i| [4 * i, 4 * i + 1, 4 * i + 2, 4 * i + 3, -i])
+        .collect();
+    let dir = write_record(
+        "multi",
+        "multi 2 100 10\nmulti.dat 16x4 200 16 0 0 0 0 I\nmulti.dat 16 200 16 0 0 0 0 II\n",
+        &[("multi.dat", &format16_bytes(&samples))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut i = record.signal_reader(0).unwrap();
+    assert_eq!(i.sampling_frequency(), Some(400.0));
+    assert_eq!(i.seek_to_frame(3).unwrap(), 12);
+    assert_eq!(i.current_frame(), 3);
+    assert_eq!(i.read_samples(2).unwrap(), vec![12, 13]);
+    // Times are converted at the signal's own sampling frequency
+    assert_eq!(i.seek_to_time(0.0525).unwrap(), 21);
+    assert_eq!(i.current_frame(), 5);
+    assert_eq!(i.read_samples(1).unwrap(), vec![21]);
+
+    let mut ii = record.signal_reader(1).unwrap();
+    assert_eq!(ii.seek_to_frame(3).unwrap(), 3);
+    assert_eq!(ii.seek_to_time(0.05).unwrap(), 5);
+    assert_eq!(ii.read_samples(1).unwrap(), vec![-5]);
+}
+
+#[test]
+fn test_seek_beyond_addressable_range() {
+    let dir = offset_record(16, format16_bytes);
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut frames = record.multi_signal_reader().unwrap();
+    assert!(matches!(
+        frames.seek_to_time(1e300),
+        Err(Error::InvalidData(_))
+    ));
+    assert!(matches!(
+        frames.seek_to_frame(u64::MAX),
+        Err(Error::InvalidData(_))
+    ));
+
+    // Interleaved signals seek lazily, and are past their end
+    let mut reader = record.signal_reader(0).unwrap();
+    assert!(matches!(
+        reader.seek_to_time(1e300),
+        Err(Error::InvalidData(_))
+    ));
+    reader.seek_to_sample(u64::MAX).unwrap();
+    assert!(reader.read_samples(1).unwrap().is_empty());
+
+    let dir = write_record(
+        "single",
+        "single 1 100 3\nsingle.dat 16 200 16 0 0 0 0 I\n",
+        &[("single.dat", &format16_bytes(&[1, 2, 3]))],
+    );
+    let record = Record::open(dir.path().join("single")).unwrap();
+    let mut reader = record.signal_reader(0).unwrap();
+    assert!(matches!(
+        reader.seek_to_sample(u64::MAX),
+        Err(Error::InvalidData(_))
+    ));
+}
@@ -0,0 +1,290 @@
+#![allow(clippy::unwrap_used)]
+
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
+use wfdb::Record;
+use wfdb::signal::INVALID_SAMPLE;
+
+use super::common::{format16_bytes, write_files, write_record};
+
+/// Write a fixed-layout record: two 2-signal segments around a null segment.
+fn fixed_layout_record() -> tempfile::TempDir {
+    let dir = write_record("multi", "multi/3 2 250 7\nseg_a 2\n~ 2\nseg_b 3\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 2 250 2\nseg_a.dat 16 200 12 0 0 0 0 I\nseg_a.dat 16 200 12 0 0 0 0 II\n",
+        &[("seg_a.dat", &format16_bytes(&[1, 10, 2, 20]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 2 250 3\nseg_b.dat 16 100 12 0 0 0 0 I\nseg_b.dat 16 100 12 0 0 0 0 II\n",
+        &[("seg_b.dat", &format16_bytes(&[3, 30, 4, 40, 5, 50]))],
+    );
+    dir
+}
+
+#[test]
+fn test_segment_signal_reader_fixed_layout() {
+    let dir = fixed_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_signal_reader(1).unwrap();
+    assert_eq!(reader.total_samples(), 7);
+    assert_eq!(
+        reader.read_samples(10).unwrap(),
+        vec![10, 20, INVALID_SAMPLE, INVALID_SAMPLE, 30, 40, 50]
+    );
+    assert_eq!(reader.position(), 7);
+    assert!(reader.read_samples(1).unwrap().is_empty());
+}
+
+#[test]
+fn test_segment_signal_reader_physical_per_segment() {
+    let dir = fixed_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_signal_reader(0).unwrap();
+    let values = reader.read_physical(7).unwrap();
+    assert_eq!(values.len(), 7);
+    assert!((values[0] - 0.005).abs() < 1e-12);
+    assert!((values[1] - 0.01).abs() < 1e-12);
+    assert!(values[2].is_nan() && values[3].is_nan());
+    assert!((values[4] - 0.03).abs() < 1e-12);
+    assert!((values[6] - 0.05).abs() < 1e-12);
+}
+
+#[test]
+fn test_segment_signal_reader_seek() {
+    let dir = fixed_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_signal_reader(0).unwrap();
+    assert_eq!(reader.seek_to_sample(5).unwrap(), 5);
+    assert_eq!(reader.current_segment(), 2);
+    assert_eq!(reader.read_samples(5).unwrap(), vec![4, 5]);
+
+    reader.seek_to_sample(1).unwrap();
+    assert_eq!(
+        reader.read_samples(3).unwrap(),
+        vec![2, INVALID_SAMPLE, INVALID_SAMPLE]
+    );
+    assert!(reader.seek_to_sample(7).is_err());
+}
+
+#[test]
+fn test_segment_signal_reader_samples_per_frame() {
+    // Two segments of 10 frames of a signal with 2 samples per frame
+    let dir = write_record("multi", "multi/2 1 360 20\nseg_a 10\nseg_b 10\n", &[]);
+    for (name, offset) in [("seg_a", 0), ("seg_b", 100)] {
+        let samples: Vec<i16> = (offset..offset + 20).collect();
+        write_files(
+            dir.path(),
+            name,
+            &format!("{name} 1 360 10\n{name}.dat 16x2 200 12 0 0 0 0 I\n"),
+            &[(&format!("{name}.dat"), &format16_bytes(&samples))],
+        );
+    }
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_signal_reader(0).unwrap();
+    assert!((reader.sampling_frequency() - 720.0).abs() < f64::EPSILON);
+    assert_eq!(reader.total_samples(), 40);
+    let samples = reader.read_samples(100).unwrap();
+    let expected: Vec<i32> = (0..20).chain(100..120).collect();
+    assert_eq!(samples, expected);
+
+    assert_eq!(reader.seek_to_sample(25).unwrap(), 25);
+    assert_eq!(reader.current_segment(), 1);
+    assert_eq!(reader.read_samples(2).unwrap(), vec![105, 106]);
+    assert!(reader.seek_to_sample(40).is_err());
+}
+
+/// Write a variable-layout record: a layout segment with II and PLETH, a
+/// segment with PLETH only, and a segment with both in reverse order.
+fn variable_layout_record() -> tempfile::TempDir {
+    let dir = write_record(
+        "multi",
+        "multi/3 2 250 5\nmulti_layout 0\nseg_a 2\nseg_b 3\n",
+        &[],
+    );
+    write_files(
+        dir.path(),
+        "multi_layout",
+        "multi_layout 2 250 0\n~ 0 200 12 0 0 0 0 II\n~ 0 200 12 0 0 0 0 PLETH\n",
+        &[],
+    );
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 2\nseg_a.dat 16 200 12 0 0 0 0 PLETH\n",
+        &[("seg_a.dat", &format16_bytes(&[7, 8]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 2 250 3\nseg_b.dat 16 200 12 0 0 0 0 PLETH\nseg_b.dat 16 200 12 0 0 0 0 II\n",
+        &[("seg_b.dat", &format16_bytes(&[9, 1, 10, 2, 11, 3]))],
+    );
+    dir
+}
+
+#[test]
+fn test_segment_signal_reader_variable_layout() {
+    let dir = variable_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut ii = record.segment_signal_reader(0).unwrap();
+    assert_eq!(
+        ii.read_samples(5).unwrap(),
+        vec![INVALID_SAMPLE, INVALID_SAMPLE, 1, 2, 3]
+    );
+    let mut pleth = record.segment_signal_reader(1).unwrap();
+    assert_eq!(pleth.read_samples(5).unwrap(), vec![7, 8, 9, 10, 11]);
+}
+
+#[test]
+fn test_segment_signal_reader_short_file_padded() {
+    let dir = write_record("multi", "multi/1 1 250 3\nseg_a 3\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 3\nseg_a.dat 16 200 12 0 0 0 0 I\n",
+        &[("seg_a.dat", &format16_bytes(&[1]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_signal_reader(0).unwrap();
+    assert_eq!(
+        reader.read_samples(3).unwrap(),
+        vec![1, INVALID_SAMPLE, INVALID_SAMPLE]
+    );
+}
+
+#[test]
+fn test_segment_signal_reader_errors() {
+    let dir = fixed_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+    assert!(record.segment_signal_reader(2).is_err());
+    assert!(record.signal_reader(0).is_err());
+
+    let single = Record::open(dir.path().join("seg_a")).unwrap();
+    assert!(single.segment_signal_reader(0).is_err());
+}
+
+#[test]
+fn test_segment_reader_seek_to_frame_third_segment() {
+    let dir = fixed_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_reader().unwrap();
+    assert_eq!(reader.seek_to_frame(5).unwrap(), 5);
+    assert_eq!(reader.read_frame().unwrap(), Some(vec![4, 40]));
+
+    #[allow(deprecated)]
+    let position = reader.seek_to_sample(4).unwrap();
+    assert_eq!(position, 4);
+    assert_eq!(reader.read_frame().unwrap(), Some(vec![3, 30]));
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_segment_reader_seek_to_time() {
+    let dir = fixed_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    // 250 Hz: 20 ms is frame 5, in the third segment
+    let mut reader = record.segment_reader().unwrap();
+    assert_eq!(reader.seek_to_time(0.02).unwrap(), 5);
+    assert_eq!(reader.read_frame().unwrap(), Some(vec![4, 40]));
+    assert!((reader.position_seconds() - 0.024).abs() < 1e-12);
+    assert_eq!(
+        reader.seek_to_duration(TimeDelta::milliseconds(4)).unwrap(),
+        1
+    );
+    assert_eq!(reader.read_frame().unwrap(), Some(vec![2, 20]));
+}
+
+#[test]
+fn test_segment_reader_null_segment_gap() {
+    let dir = fixed_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_reader().unwrap();
+    assert_eq!(reader.total_samples(), 7);
+    let frames = reader.read_frames(10).unwrap();
+    assert_eq!(
+        frames,
+        vec![
+            vec![1, 10],
+            vec![2, 20],
+            vec![INVALID_SAMPLE, INVALID_SAMPLE],
+            vec![INVALID_SAMPLE, INVALID_SAMPLE],
+            vec![3, 30],
+            vec![4, 40],
+            vec![5, 50],
+        ]
+    );
+    assert_eq!(reader.position(), 7);
+    assert_eq!(reader.read_frame().unwrap(), None);
+
+    // Seeking into the gap, then reading across its end
+    assert_eq!(reader.seek_to_frame(3).unwrap(), 3);
+    assert_eq!(reader.current_segment(), 1);
+    assert_eq!(
+        reader.read_frames(2).unwrap(),
+        vec![vec![INVALID_SAMPLE, INVALID_SAMPLE], vec![3, 30]]
+    );
+    assert!(reader.seek_to_frame(7).is_err());
+}
+
+#[test]
+fn test_segment_reader_short_file_padded() {
+    let dir = write_record("multi", "multi/2 1 250 4\nseg_a 3\nseg_b 1\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 3\nseg_a.dat 16 200 12 0 0 0 0 I\n",
+        &[("seg_a.dat", &format16_bytes(&[1]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 1 250 1\nseg_b.dat 16 200 12 0 0 0 0 I\n",
+        &[("seg_b.dat", &format16_bytes(&[2]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut reader = record.segment_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(5).unwrap(),
+        vec![vec![1], vec![INVALID_SAMPLE], vec![INVALID_SAMPLE], vec![2]]
+    );
+}
+
+#[test]
+fn test_segment_reader_variable_layout() {
+    let dir = variable_layout_record();
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    // Frames are in layout order (II, PLETH), absent signals are invalid
+    let mut reader = record.segment_reader().unwrap();
+    assert_eq!(reader.total_samples(), 5);
+    assert_eq!(
+        reader.read_frames(6).unwrap(),
+        vec![
+            vec![INVALID_SAMPLE, 7],
+            vec![INVALID_SAMPLE, 8],
+            vec![1, 9],
+            vec![2, 10],
+            vec![3, 11],
+        ]
+    );
+
+    assert_eq!(reader.seek_to_frame(1).unwrap(), 1);
+    assert_eq!(
+        reader.read_frames(2).unwrap(),
+        vec![vec![INVALID_SAMPLE, 8], vec![1, 9]]
+    );
+}
@@ -0,0 +1,141 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use wfdb::Record;
+use wfdb::record::ReadSeek;
+
+use super::common::{format16_bytes, write_files, write_record};
+
+#[test]
+fn test_two_records_sharing_one_file() {
+    // rec_a: two interleaved signals, 2 frames; rec_b: one signal, 3 samples
+    let dir = write_record(
+        "rec_a",
+        "rec_a 2 250 2\nshared.dat 16 200 12 0 0 0 0 I\nshared.dat 16 200 12 0 0 0 0 II\n",
+        &[("shared.dat", &format16_bytes(&[1, 10, 2, 20, 7, 8, 9]))],
+    );
+    write_files(
+        dir.path(),
+        "rec_b",
+        "rec_b 1 250 3\nshared.dat 16+8 200 12 0 0 0 0 III\n",
+        &[],
+    );
+    let rec_a = Record::open(dir.path().join("rec_a")).unwrap();
+    let rec_b = Record::open(dir.path().join("rec_b")).unwrap();
+
+    let mut reader = rec_a.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![vec![1, 10], vec![2, 20]]
+    );
+    assert_eq!(
+        rec_a.signal_reader(1).unwrap().read_samples(10).unwrap(),
+        vec![10, 20]
+    );
+
+    assert_eq!(
+        rec_b.signal_reader(0).unwrap().read_samples(10).unwrap(),
+        vec![7, 8, 9]
+    );
+    let mut reader = rec_b.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![vec![7], vec![8], vec![9]]
+    );
+}
+
+#[test]
+fn test_groups_at_different_offsets_in_one_file() {
+    let dir = write_record(
+        "rec",
+        "rec 2 250 2\nshared.dat 16+0 200 12 0 0 0 0 I\nshared.dat 16+4 200 12 0 0 0 0 II\n",
+        &[("shared.dat", &format16_bytes(&[1, 2, 3, 4]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    assert_eq!(
+        record.signal_reader(0).unwrap().read_samples(10).unwrap(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        record.signal_reader(1).unwrap().read_samples(10).unwrap(),
+        vec![3, 4]
+    );
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![vec![1, 3], vec![2, 4]]
+    );
+    reader.seek_to_frame(1).unwrap();
+    assert_eq!(reader.read_frame().unwrap(), vec![2, 4]);
+}
+
+#[test]
+fn test_offset_given_on_first_signal_of_group() {
+    let dir = write_record(
+        "rec",
+        "rec 2 250 2\nshared.dat 16+4 200 12 0 0 0 0 I\nshared.dat 16 200 12 0 0 0 0 II\n",
+        &[("shared.dat", &format16_bytes(&[99, 99, 1, 10, 2, 20]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    assert_eq!(
+        record.signal_reader(1).unwrap().read_samples(10).unwrap(),
+        vec![10, 20]
+    );
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![vec![1, 10], vec![2, 20]]
+    );
+}
+
+#[test]
+fn test_signal_readers_share_file() {
+    let opens = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&opens);
+    let record = Record::from_sources(
+        "rec 3 250 3\n\
+         rec.dat 16 200 12 0 0 0 0 I\n\
+         rec.dat 16 200 12 0 0 0 0 II\n\
+         rec.dat 16 200 12 0 0 0 0 III\n",
+        move |_: &str| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let bytes = format16_bytes(&[1, 10, 100, 2, 20, 200, 3, 30, 300]);
+            Ok(Box::new(Cursor::new(bytes)) as Box<dyn ReadSeek>)
+        },
+    )
+    .unwrap();
+
+    let mut readers = record.signal_readers(&[2, 0]).unwrap();
+    assert_eq!(opens.load(Ordering::SeqCst), 1);
+
+    // Each reader keeps its own position
+    assert_eq!(readers[0].read_samples(2).unwrap(), vec![100, 200]);
+    assert_eq!(readers[1].read_samples(1).unwrap(), vec![1]);
+    assert_eq!(readers[0].read_samples(10).unwrap(), vec![300]);
+    readers[1].seek_to_sample(2).unwrap();
+    assert_eq!(readers[1].read_samples(10).unwrap(), vec![3]);
+    assert_eq!(readers[0].position(), 3);
+
+    // Separate readers open the file each
+    record.signal_reader(1).unwrap();
+    assert_eq!(opens.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_signal_readers_out_of_bounds() {
+    let dir = write_record(
+        "rec",
+        "rec 1 250 2\nrec.dat 16 200 12 0 0 0 0 I\n",
+        &[("rec.dat", &format16_bytes(&[1, 2]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    assert!(record.signal_readers(&[0, 1]).is_err());
+    assert!(record.signal_readers(&[]).unwrap().is_empty());
+}
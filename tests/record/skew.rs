@@ -0,0 +1,81 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::signal::INVALID_SAMPLE;
+
+use super::common::{format16_bytes, write_record};
+
+/// Write a record whose second signal, in its own file, is skewed by 1.
+fn separate_files_record() -> tempfile::TempDir {
+    write_record(
+        "rec",
+        "rec 2 250 4\na.dat 16 200 12 0 0 0 0 I\nb.dat 16:1 200 12 0 0 0 0 II\n",
+        &[
+            ("a.dat", &format16_bytes(&[1, 2, 3, 4])),
+            ("b.dat", &format16_bytes(&[10, 20, 30, 40])),
+        ],
+    )
+}
+
+#[test]
+fn test_skew_multi_signal_reader() {
+    let dir = separate_files_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![
+            vec![1, 20],
+            vec![2, 30],
+            vec![3, 40],
+            vec![4, INVALID_SAMPLE]
+        ]
+    );
+
+    reader.seek_to_frame(1).unwrap();
+    assert_eq!(reader.read_frame().unwrap(), vec![2, 30]);
+}
+
+#[test]
+fn test_skew_signal_reader() {
+    let dir = separate_files_record();
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.signal_reader(1).unwrap();
+    assert_eq!(
+        reader.read_samples(10).unwrap(),
+        vec![20, 30, 40, INVALID_SAMPLE]
+    );
+
+    reader.seek_to_sample(1).unwrap();
+    assert_eq!(reader.read_samples(2).unwrap(), vec![30, 40]);
+    assert_eq!(
+        record.signal_reader(0).unwrap().read_samples(10).unwrap(),
+        vec![1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn test_skew_interleaved() {
+    let dir = write_record(
+        "rec",
+        "rec 2 250 3\nrec.dat 16 200 12 0 0 0 0 I\nrec.dat 16:2 200 12 0 0 0 0 II\n",
+        &[("rec.dat", &format16_bytes(&[1, 10, 2, 20, 3, 30]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(10).unwrap(),
+        vec![
+            vec![1, 30],
+            vec![2, INVALID_SAMPLE],
+            vec![3, INVALID_SAMPLE]
+        ]
+    );
+    assert_eq!(
+        record.signal_reader(1).unwrap().read_samples(10).unwrap(),
+        vec![30, INVALID_SAMPLE, INVALID_SAMPLE]
+    );
+}
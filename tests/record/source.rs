@@ -0,0 +1,86 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+
+use wfdb::record::{MemorySource, ReadSeek};
+use wfdb::signal::INVALID_SAMPLE;
+use wfdb::{Error, Record};
+
+use super::common::format16_bytes;
+
+#[test]
+fn test_from_sources_memory_single_segment() {
+    let mut source = MemorySource::new();
+    source.insert("rec.dat", format16_bytes(&[1, 10, 2, 20, 3, 30]));
+    let record = Record::from_sources(
+        "rec 2 250 3\nrec.dat 16 200 12 0 0 0 0 I\nrec.dat 16 200 12 0 0 0 0 II\n",
+        source,
+    )
+    .unwrap();
+
+    assert_eq!(
+        record.signal_reader(1).unwrap().read_samples(5).unwrap(),
+        vec![10, 20, 30]
+    );
+    let mut reader = record.multi_signal_reader().unwrap();
+    assert_eq!(
+        reader.read_frames(3).unwrap(),
+        vec![vec![1, 10], vec![2, 20], vec![3, 30]]
+    );
+
+    // Every reader gets its own stream
+    let mut first = record.signal_reader(0).unwrap();
+    let mut second = record.signal_reader(0).unwrap();
+    assert_eq!(first.read_samples(2).unwrap(), vec![1, 2]);
+    assert_eq!(second.read_samples(1).unwrap(), vec![1]);
+}
+
+#[test]
+fn test_from_sources_multi_segment() {
+    let mut source = MemorySource::new();
+    source.insert(
+        "seg_a.hea",
+        b"seg_a 1 250 2\nseg_a.dat 16 200 12 0 0 0 0 I\n".as_slice(),
+    );
+    source.insert("seg_a.dat", format16_bytes(&[1, 2]));
+    source.insert(
+        "seg_b.hea",
+        b"seg_b 1 250 1\nseg_b.dat 16 200 12 0 0 0 0 I\n".as_slice(),
+    );
+    source.insert("seg_b.dat", format16_bytes(&[3]));
+    let record = Record::from_sources("multi/3 1 250 4\nseg_a 2\n~ 1\nseg_b 1\n", source).unwrap();
+
+    let mut reader = record.segment_signal_reader(0).unwrap();
+    assert_eq!(
+        reader.read_samples(5).unwrap(),
+        vec![1, 2, INVALID_SAMPLE, 3]
+    );
+
+    let mut reader = record.segment_reader().unwrap();
+    assert_eq!(reader.read_frame().unwrap(), Some(vec![1]));
+}
+
+#[test]
+fn test_from_sources_closure() {
+    let record = Record::from_sources("rec 1 250 2\nrec.dat 16\n", |name: &str| {
+        assert_eq!(name, "rec.dat");
+        Ok(Box::new(Cursor::new(format16_bytes(&[7, 8]))) as Box<dyn ReadSeek>)
+    })
+    .unwrap();
+
+    assert_eq!(
+        record.signal_reader(0).unwrap().read_samples(2).unwrap(),
+        vec![7, 8]
+    );
+    assert!(record.base_path().as_os_str().is_empty());
+}
+
+#[test]
+fn test_from_sources_missing_file() {
+    let record = Record::from_sources("rec 1 250 2\nrec.dat 16\n", MemorySource::new()).unwrap();
+    assert!(matches!(
+        record.signal_reader(0),
+        Err(Error::InvalidPath(_))
+    ));
+    assert!(Record::from_sources("not a header", MemorySource::new()).is_err());
+}
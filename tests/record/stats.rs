@@ -0,0 +1,114 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+
+use super::common::{format16_bytes, write_files, write_record};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-12
+}
+
+#[test]
+fn test_signal_reader_stats() {
+    let dir = write_record(
+        "rec",
+        "rec 1 100 5\nrec.dat 16 200/mV 12 0 0 0 0 ECG\n",
+        &[("rec.dat", &format16_bytes(&[200, -400, -32768, 600, 0]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let stats = record.signal_reader(0).unwrap().stats().unwrap();
+    assert_eq!(stats.count, 5);
+    assert_eq!(stats.invalid, 1);
+    assert_eq!(stats.valid(), 4);
+    assert!(close(stats.min, -2.0));
+    assert!(close(stats.max, 3.0));
+    assert!(close(stats.mean, 0.5));
+    assert!(close(stats.rms, (14.0_f64 / 4.0).sqrt()));
+}
+
+#[test]
+fn test_signal_reader_stats_from_position() {
+    let dir = write_record(
+        "rec",
+        "rec 1 100 4\nrec.dat 16 100 12 0 0 0 0 ECG\n",
+        &[("rec.dat", &format16_bytes(&[100, 200, 300, 400]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.signal_reader(0).unwrap();
+    reader.seek_to_sample(2).unwrap();
+
+    let stats = reader.stats().unwrap();
+    assert_eq!(stats.count, 2);
+    assert!(close(stats.mean, 3.5));
+
+    let empty = reader.stats().unwrap();
+    assert_eq!(empty.count, 0);
+    assert!(empty.mean.is_nan() && empty.min.is_nan() && empty.rms.is_nan());
+}
+
+#[test]
+fn test_record_signal_stats() {
+    let dir = write_record(
+        "rec",
+        "rec 2 100 3\n\
+         rec.dat 16 200/mV 12 0 0 0 0 ECG\n\
+         rec.dat 16 100/mmHg 12 0 0 0 0 ABP\n",
+        &[("rec.dat", &format16_bytes(&[0, 100, 200, -200, -32768, 50]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let stats = record.signal_stats().unwrap();
+    assert_eq!(stats.len(), 2);
+    assert_eq!((stats[0].count, stats[0].invalid), (3, 1));
+    assert!(close(stats[0].min, 0.0) && close(stats[0].max, 1.0));
+    assert!(close(stats[0].mean, 0.5));
+    assert_eq!((stats[1].count, stats[1].invalid), (3, 0));
+    assert!(close(stats[1].mean, -0.5 / 3.0));
+    assert!(close(stats[1].rms, (5.25_f64 / 3.0).sqrt()));
+}
+
+#[test]
+fn test_record_signal_stats_multi_frequency() {
+    let dir = write_record(
+        "rec",
+        "rec 2 100 2\n\
+         rec.dat 16x2 100 12 0 0 0 0 FAST\n\
+         rec.dat 16 100 12 0 0 0 0 SLOW\n",
+        &[(
+            "rec.dat",
+            &format16_bytes(&[100, 200, 1000, 300, 400, 2000]),
+        )],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let stats = record.signal_stats().unwrap();
+    assert_eq!(stats[0].count, 4);
+    assert!(close(stats[0].mean, 2.5));
+    assert_eq!(stats[1].count, 2);
+    assert!(close(stats[1].mean, 15.0));
+}
+
+#[test]
+fn test_record_signal_stats_multi_segment() {
+    let dir = write_record("multi", "multi/3 1 250 5\nseg_a 2\n~ 1\nseg_b 2\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 2\nseg_a.dat 16 200 12 0 0 0 0 II\n",
+        &[("seg_a.dat", &format16_bytes(&[200, 400]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 1 250 2\nseg_b.dat 16 100 12 0 0 0 0 II\n",
+        &[("seg_b.dat", &format16_bytes(&[100, 200]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let stats = record.signal_stats().unwrap();
+    assert_eq!(stats.len(), 1);
+    assert_eq!((stats[0].count, stats[0].invalid), (5, 1));
+    assert!(close(stats[0].min, 1.0) && close(stats[0].max, 2.0));
+    assert!(close(stats[0].mean, 1.5));
+}
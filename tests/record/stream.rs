@@ -0,0 +1,56 @@
+use futures::StreamExt;
+use futures::executor::block_on;
+use wfdb::Record;
+
+use super::common::{format16_bytes, write_record};
+
+#[test]
+fn test_multi_signal_stream() {
+    let data = format16_bytes(&[1, 2, 3, 4, 5, 6]);
+    let dir = write_record(
+        "rec",
+        "rec 2 100 3\nrec.dat 16\nrec.dat 16\n",
+        &[("rec.dat", &data)],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let stream = record.multi_signal_reader().unwrap().into_stream();
+
+    let frames: Vec<_> = block_on(stream.map(Result::unwrap).collect());
+    assert_eq!(frames, vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+}
+
+#[test]
+fn test_stream_is_fused_after_end() {
+    let data = format16_bytes(&[7]);
+    let dir = write_record("rec", "rec 1 100 1\nrec.dat 16\n", &[("rec.dat", &data)]);
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut stream = record.multi_signal_reader().unwrap().into_stream();
+
+    block_on(async {
+        assert_eq!(stream.next().await.unwrap().unwrap(), vec![7]);
+        assert!(stream.next().await.is_none());
+        assert!(stream.next().await.is_none());
+    });
+}
+
+#[test]
+fn test_stream_longer_than_buffer() {
+    let samples: Vec<i16> = (0..1000).collect();
+    let dir = write_record(
+        "rec",
+        "rec 1 100 1000\nrec.dat 16\n",
+        &[("rec.dat", &format16_bytes(&samples))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let stream = record.multi_signal_reader().unwrap().into_stream();
+    let frames: Vec<_> = block_on(stream.map(Result::unwrap).collect());
+    assert_eq!(frames.len(), 1000);
+    assert_eq!(frames[999], vec![999]);
+
+    // Dropping the stream early stops its thread
+    let mut stream = record.multi_signal_reader().unwrap().into_stream();
+    let first: Vec<_> = block_on((&mut stream).take(3).map(Result::unwrap).collect());
+    assert_eq!(first, vec![vec![0], vec![1], vec![2]]);
+    drop(stream);
+}
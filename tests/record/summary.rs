@@ -0,0 +1,166 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+
+use super::common::{format16_bytes, write_files, write_record};
+
+#[test]
+fn test_summary_single_segment() {
+    let values: Vec<i16> = (0..24).collect();
+    let dir = write_record(
+        "rec",
+        "rec 2 360 8 10:30:00 01/06/2020\n\
+         rec.dat 16x2 200 12 0 0 0 0 MLII\n\
+         rec.dat 16 100(5)/uV 12 0 0 0 0\n",
+        &[("rec.dat", &format16_bytes(&values))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    assert_eq!(record.total_samples(), Some(8));
+
+    let summary = record.summary().unwrap();
+    assert_eq!(summary.name, "rec");
+    assert_eq!(summary.num_segments, None);
+    assert_eq!(summary.total_samples, Some(8));
+    assert!((summary.duration.unwrap() - 8.0 / 360.0).abs() < 1e-12);
+    assert_eq!(summary.signals.len(), 2);
+    assert_eq!(summary.signals[0].description.as_deref(), Some("MLII"));
+    assert_eq!(summary.signals[0].samples_per_frame, 2);
+    assert_eq!(summary.signals[1].units, "uV");
+    assert_eq!(
+        summary.to_string(),
+        "Record rec\n\
+         Starting time: [10:30:00 01/06/2020]\n\
+         Length: 0:00.022 (8 sample intervals)\n\
+         Sampling frequency: 360 Hz\n\
+         2 signals\n \
+         0: MLII (format 16x2, 200 adu/mV)\n \
+         1: (no description) (format 16, 100 adu/uV)\n"
+    );
+}
+
+#[test]
+fn test_summary_unknown_length() {
+    let dir = write_record("rec", "rec 1 250\nrec.dat 16 200 12 0 0 0 0 I\n", &[]);
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    assert_eq!(record.total_samples(), None);
+    #[cfg(feature = "chrono")]
+    assert_eq!(record.duration(), None);
+
+    let summary = record.summary().unwrap().to_string();
+    assert!(summary.contains("Starting time: not specified\n"));
+    assert!(summary.contains("Length: not specified\n"));
+    assert!(summary.ends_with("1 signal\n 0: I (format 16, 200 adu/mV)\n"));
+}
+
+#[test]
+fn test_summary_multi_segment() {
+    let dir = write_record(
+        "multi",
+        "multi/3 1 250 1000\nseg_a 15000\n~ 5000\nseg_b 2500\n",
+        &[],
+    );
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 15000\nseg_a.dat 16 200 12 0 0 0 0 I\n",
+        &[],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    // The segments are summed, not the master header's length
+    assert_eq!(record.total_samples(), Some(22_500));
+    #[cfg(feature = "chrono")]
+    assert_eq!(record.duration(), Some(chrono::TimeDelta::seconds(90)));
+
+    let summary = record.summary().unwrap();
+    assert_eq!(summary.num_segments, Some(3));
+    assert!(summary.to_string().starts_with(
+        "Record multi (3 segments)\n\
+         Starting time: not specified\n\
+         Length: 1:30.000 (22500 sample intervals)\n"
+    ));
+    assert_eq!(summary.signals[0].description.as_deref(), Some("I"));
+}
+
+#[test]
+fn test_describe_single_segment() {
+    let dir = write_record(
+        "rec",
+        "rec 3 360/720 2\n\
+         rec.dat 16x2 200 12 0 5 -3 0 MLII\n\
+         rec.dat 16 0 12 0 0 0 0\n\
+         other.dat 16 100/uV 16 10 10 7 512\n\
+         # age: 69\n",
+        &[],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let description = record.describe().unwrap();
+    assert_eq!(description, record.to_string());
+    assert_eq!(
+        description,
+        "Record rec\n\
+         Starting time: not specified\n\
+         Length: 0:00.006 (2 sample intervals)\n\
+         Sampling frequency: 360 Hz\n\
+         Counter frequency: 720 ticks/second\n\
+         3 signals\n\
+         Group 0, Signal 0:\n \
+         File: rec.dat\n \
+         Description: MLII\n \
+         Gain: 200 adu/mV\n \
+         Initial value: 5\n \
+         Storage format: 16 (2 samples per frame)\n \
+         I/O: can be unbuffered\n \
+         ADC resolution: 12 bits\n \
+         ADC zero: 0\n \
+         Baseline: 0\n \
+         Checksum: -3\n\
+         Group 0, Signal 1:\n \
+         File: rec.dat\n \
+         Description: \n \
+         Gain: uncalibrated; assume 200 adu/mV\n \
+         Initial value: 0\n \
+         Storage format: 16\n \
+         I/O: can be unbuffered\n \
+         ADC resolution: 12 bits\n \
+         ADC zero: 0\n \
+         Baseline: 0\n \
+         Checksum: 0\n\
+         Group 1, Signal 2:\n \
+         File: other.dat\n \
+         Description: \n \
+         Gain: 100 adu/uV\n \
+         Initial value: 10\n \
+         Storage format: 16\n \
+         I/O: 512-byte blocks\n \
+         ADC resolution: 16 bits\n \
+         ADC zero: 10\n \
+         Baseline: 10\n \
+         Checksum: 7\n\
+         Notes\n\
+         =====\n \
+         age: 69\n"
+    );
+}
+
+#[test]
+fn test_describe_multi_segment() {
+    let dir = write_record("multi", "multi/2 1 250 20\nseg_a 15\n~ 5\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 15\nseg_a.dat 16 200 12 0 0 0 0 I\n",
+        &[],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let description = record.describe().unwrap();
+    assert!(description.contains("Group 0, Signal 0:\n File: seg_a.dat\n Description: I\n"));
+    assert!(description.ends_with(
+        "2 segments\n Segment 0: seg_a (15 sample intervals)\n Segment 1: (null) (5 sample intervals)\n"
+    ));
+
+    // Without the segment headers, the signals are not described
+    let header_only = record.to_string();
+    assert!(header_only.contains("1 signal\n2 segments\n"));
+}
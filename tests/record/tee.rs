@@ -0,0 +1,120 @@
+use std::thread;
+
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, two_signal_record, write_files};
+
+/// Signals I and II at 250 Hz, holding `0..2 * frames` interleaved.
+fn two_channel_record(frames: i16) -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..frames).map(|i| [2 * i, 2 * i + 1]).collect();
+    two_signal_record(250, ["200 12 0 0 0 0 I", "200 12 0 0 0 0 II"], &frames)
+}
+
+#[test]
+fn test_tee_branches_see_all_frames() {
+    let dir = two_channel_record(1000);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let expected = record
+        .multi_signal_reader()
+        .unwrap()
+        .read_frames(1000)
+        .unwrap();
+
+    let (first, second) = record.multi_signal_reader().unwrap().tee(16);
+    let handle = thread::spawn(move || second.collect::<wfdb::Result<Vec<_>>>().unwrap());
+    let frames: Vec<_> = first.collect::<wfdb::Result<_>>().unwrap();
+
+    assert_eq!(frames, expected);
+    assert_eq!(handle.join().unwrap(), expected);
+}
+
+#[test]
+fn test_tee_alternating_consumers() {
+    let dir = two_channel_record(10);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let (mut first, mut second) = record.multi_signal_reader().unwrap().tee(2);
+
+    // The leading branch may run `capacity` frames ahead
+    assert_eq!(first.next().unwrap().unwrap(), vec![0, 1]);
+    assert_eq!(first.next().unwrap().unwrap(), vec![2, 3]);
+    assert_eq!(second.buffered(), 2);
+    assert_eq!(first.buffered(), 0);
+
+    assert_eq!(second.next().unwrap().unwrap(), vec![0, 1]);
+    assert_eq!(first.next().unwrap().unwrap(), vec![4, 5]);
+    assert_eq!(second.buffered(), 2);
+
+    assert_eq!(second.next().unwrap().unwrap(), vec![2, 3]);
+    assert_eq!(second.next().unwrap().unwrap(), vec![4, 5]);
+    assert_eq!(second.next().unwrap().unwrap(), vec![6, 7]);
+    assert_eq!(first.buffered(), 1);
+    assert_eq!(second.buffered(), 0);
+
+    // Consuming one branch alone would block once `capacity` frames wait
+    let handle = thread::spawn(move || second.count());
+    assert_eq!(first.count(), 7);
+    assert_eq!(handle.join().unwrap(), 6);
+}
+
+#[test]
+fn test_tee_dropped_branch_releases_other() {
+    let dir = two_channel_record(100);
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let (first, mut second) = record.multi_signal_reader().unwrap().tee(1);
+
+    assert_eq!(second.next().unwrap().unwrap(), vec![0, 1]);
+    drop(first);
+    assert_eq!(second.count(), 99);
+}
+
+#[test]
+fn test_tee_error_reaches_both_branches() {
+    let dir = two_channel_record(3);
+    // Truncate the last frame to a single sample
+    write_files(
+        dir.path(),
+        "pair",
+        "pair 2 250 3\npair.dat 16 200 12 0 0 0 0 I\npair.dat 16 200 12 0 0 0 0 II\n",
+        &[("pair.dat", &format16_bytes(&[0, 1, 2, 3, 4]))],
+    );
+    let record = Record::open(dir.path().join("pair")).unwrap();
+    let (first, second) = record.multi_signal_reader().unwrap().tee(8);
+
+    let branches: [_; 2] = (first, second).into();
+    for branch in branches {
+        let items: Vec<_> = branch.collect();
+        assert_eq!(items.len(), 3);
+        assert!(items[..2].iter().all(Result::is_ok));
+        assert!(matches!(items[2], Err(Error::SignalDecode { .. })));
+    }
+}
+
+#[test]
+fn test_tee_multi_segment() {
+    let dir = tempfile::tempdir().unwrap();
+    write_files(
+        dir.path(),
+        "multi",
+        "multi/2 1 250 5\nseg_a 2\nseg_b 3\n",
+        &[],
+    );
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 2\nseg_a.dat 16 200 12 0 0 0 0 I\n",
+        &[("seg_a.dat", &format16_bytes(&[1, 2]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 1 250 3\nseg_b.dat 16 200 12 0 0 0 0 I\n",
+        &[("seg_b.dat", &format16_bytes(&[3, 4, 5]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+    let (first, second) = record.segment_reader().unwrap().tee(4);
+
+    let expected = vec![vec![1], vec![2], vec![3], vec![4], vec![5]];
+    let handle = thread::spawn(move || second.map(Result::unwrap).collect::<Vec<_>>());
+    assert_eq!(first.map(Result::unwrap).collect::<Vec<_>>(), expected);
+    assert_eq!(handle.join().unwrap(), expected);
+}
@@ -0,0 +1,113 @@
+#![allow(clippy::unwrap_used)]
+
+use chrono::{NaiveDate, TimeDelta};
+use wfdb::Record;
+use wfdb::record::RecordClock;
+
+use super::common::{format16_bytes, write_record};
+
+fn timed_record(record_line: &str) -> tempfile::TempDir {
+    write_record(
+        "rec",
+        &format!("{record_line}\nrec.dat 16 200 12 0 0 0 0 I\nrec.dat 16 200 12 0 0 0 0 II\n"),
+        &[("rec.dat", &format16_bytes(&(0..20).collect::<Vec<_>>()))],
+    )
+}
+
+#[test]
+fn test_read_frames_timed() {
+    let dir = timed_record("rec 2 4/1000(50) 10 23:59:59 31/12/2020");
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+
+    let first = reader.read_frames_timed(6).unwrap();
+    assert_eq!(first.frames.len(), 6);
+    assert_eq!(first.timing.start_sample, 0);
+    assert!(first.timing.elapsed.abs() < 1e-12);
+    assert!((first.timing.counter - 50.0).abs() < 1e-9);
+
+    let second = reader.read_frames_physical_timed(6).unwrap();
+    assert_eq!(second.frames.len(), 4);
+    assert!((second.frames[0][0] - 0.06).abs() < 1e-12);
+    assert_eq!(second.timing.start_sample, 6);
+    assert!((second.timing.elapsed - 1.5).abs() < 1e-12);
+    assert!((second.timing.counter - 1550.0).abs() < 1e-9);
+    let expected = NaiveDate::from_ymd_opt(2021, 1, 1)
+        .unwrap()
+        .and_hms_milli_opt(0, 0, 0, 500)
+        .unwrap();
+    assert_eq!(second.timing.datetime, Some(expected));
+}
+
+#[test]
+fn test_read_frames_timed_without_base_date() {
+    let dir = timed_record("rec 2 4 10 10:00:00");
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+
+    reader.seek_to_frame(8).unwrap();
+    let chunk = reader.read_frames_timed(4).unwrap();
+    assert_eq!(chunk.frames.len(), 2);
+    assert_eq!(chunk.timing.start_sample, 8);
+    assert!((chunk.timing.elapsed - 2.0).abs() < 1e-12);
+    assert!((chunk.timing.counter - 8.0).abs() < 1e-9);
+    assert_eq!(chunk.timing.datetime, None);
+}
+
+#[test]
+fn test_record_clock() {
+    let dir = timed_record("rec 2 250 10 08:00:00 01/02/2023");
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let clock = record.clock();
+    assert_eq!(clock, RecordClock::from_metadata(record.metadata()));
+    assert_eq!(clock.base_datetime, record.metadata().base_datetime());
+    assert_eq!(
+        clock.datetime(250 * 3600).unwrap().to_string(),
+        "2023-02-01 09:00:00"
+    );
+}
+
+#[test]
+fn test_record_datetime_lookup() {
+    let dir = timed_record("rec 2 250 10 08:00:00 01/02/2023");
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let base = NaiveDate::from_ymd_opt(2023, 2, 1)
+        .unwrap()
+        .and_hms_opt(8, 0, 0)
+        .unwrap();
+
+    assert_eq!(record.sample_to_datetime(0), Some(base));
+    assert_eq!(
+        record.sample_to_datetime(5).unwrap().to_string(),
+        "2023-02-01 08:00:00.020"
+    );
+    assert_eq!(
+        record
+            .datetime_to_sample(base + TimeDelta::milliseconds(20))
+            .unwrap(),
+        5
+    );
+    // Rounded to the nearest sample
+    assert_eq!(
+        record
+            .datetime_to_sample(base + TimeDelta::milliseconds(23))
+            .unwrap(),
+        6
+    );
+    assert!(
+        record
+            .datetime_to_sample(base - TimeDelta::seconds(1))
+            .is_err()
+    );
+    assert!(
+        record
+            .datetime_to_sample(base + TimeDelta::seconds(1))
+            .is_err()
+    );
+
+    let untimed = timed_record("rec 2 250 10");
+    let record = Record::open(untimed.path().join("rec")).unwrap();
+    assert_eq!(record.sample_to_datetime(0), None);
+    assert!(record.datetime_to_sample(base).is_err());
+}
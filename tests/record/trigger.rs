@@ -0,0 +1,76 @@
+use wfdb::Record;
+use wfdb::record::trigger::{Condition, Event, TriggerSet};
+
+use super::common::{format16_bytes, write_record};
+
+#[test]
+fn test_threshold_crossings() {
+    let mut triggers = TriggerSet::new();
+    let rising = triggers.add(0, Condition::RisingAbove(1.0));
+    let falling = triggers.add(0, Condition::FallingBelow(0.0));
+
+    let mut events = Vec::new();
+    for (sample, value) in (0u64..).zip([0.5, 1.2, 1.5, 0.8, -0.1, 2.0]) {
+        triggers.process(sample, &[value], &mut events);
+    }
+
+    let fired: Vec<(usize, u64)> = events.iter().map(|e| (e.trigger, e.sample)).collect();
+    assert_eq!(fired, vec![(rising, 1), (falling, 4), (rising, 5)]);
+}
+
+#[test]
+fn test_slope_fires_once_per_run() {
+    let mut triggers = TriggerSet::new();
+    triggers.add(1, Condition::SlopeAbove(0.5));
+
+    let mut events = Vec::new();
+    for (sample, value) in (0u64..).zip([0.0, 1.0, 2.0, 3.0, 3.1, 4.0]) {
+        triggers.process(sample, &[0.0, value], &mut events);
+    }
+
+    let samples: Vec<u64> = events.iter().map(|e| e.sample).collect();
+    assert_eq!(samples, vec![1, 5]);
+    assert!(events.iter().all(|e| e.channel == 1));
+}
+
+#[test]
+fn test_events_from_reader() {
+    // Channel 0 ramps up in 1 mV steps; channel 1 drops sharply at frame 3
+    let data = format16_bytes(&[0, 0, 200, 0, 400, 0, 600, -400, 800, -400]);
+    let dir = write_record(
+        "rec",
+        "rec 2 100 5\n\
+         rec.dat 16 200/mV 12 0 0 0 0 I\n\
+         rec.dat 16 200/mV 12 0 0 0 0 II\n",
+        &[("rec.dat", &data)],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+
+    let mut triggers = TriggerSet::new();
+    triggers.add(0, Condition::RisingAbove(2.5));
+    triggers.add(1, Condition::SlopeBelow(-1.0));
+
+    let events = triggers
+        .events(&mut reader)
+        .collect::<wfdb::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            Event {
+                trigger: 0,
+                sample: 3,
+                channel: 0,
+                value: 3.0
+            },
+            Event {
+                trigger: 1,
+                sample: 3,
+                channel: 1,
+                value: -2.0
+            },
+        ]
+    );
+}
@@ -0,0 +1,94 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+
+use super::common::{format16_bytes, write_files, write_record};
+
+#[test]
+fn test_validate_matching_checksums() {
+    let dir = write_record(
+        "rec",
+        "rec 2 250 3\nrec.dat 16 200 12 0 0 6 0 I\nrec.dat 16 200 12 0 0 60 0 II\n",
+        &[("rec.dat", &format16_bytes(&[1, 10, 2, 20, 3, 30]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let report = record.validate().unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.checksums.len(), 2);
+    assert_eq!(report.checksums[1].computed, 60);
+    assert_eq!(report.checksums[1].num_samples, 3);
+    assert_eq!(report.checksums[1].description.as_deref(), Some("II"));
+}
+
+#[test]
+fn test_validate_reports_mismatch_per_channel() {
+    // Samples past the header length are ignored
+    let dir = write_record(
+        "rec",
+        "rec 2 250 2\nrec.dat 16 200 12 0 0 3 0 I\nrec.dat 16 200 12 0 0 31 0 II\n",
+        &[("rec.dat", &format16_bytes(&[1, 10, 2, 20, 3, 30]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    let report = record.validate().unwrap();
+    assert!(!report.is_valid());
+    let mismatches: Vec<_> = report.mismatches().collect();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].signal_index, 1);
+    assert_eq!(mismatches[0].expected, Some(31));
+    assert_eq!(mismatches[0].computed, 30);
+}
+
+#[test]
+fn test_validate_wraps_and_counts_invalid_samples() {
+    let dir = write_record(
+        "rec",
+        "rec 1 250 3\nrec.dat 16 200 12 0 0 32766 0 I\n",
+        &[("rec.dat", &format16_bytes(&[32767, 32767, i16::MIN]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    // 32767 + 32767 wraps to -2, and the invalid sample adds -32768
+    let report = record.validate().unwrap();
+    assert_eq!(report.checksums[0].computed, 32766);
+    assert!(report.is_valid());
+}
+
+#[test]
+fn test_validate_multi_segment() {
+    let dir = write_record("multi", "multi/2 1 250 3\nseg_a 2\n~ 1\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 2\nseg_a.dat 16 200 12 0 0 4 0 I\n",
+        &[("seg_a.dat", &format16_bytes(&[1, 2]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let report = record.validate().unwrap();
+    assert_eq!(report.checksums.len(), 1);
+    assert_eq!(report.checksums[0].segment, Some(0));
+    assert_eq!(report.checksums[0].computed, 3);
+    assert!(!report.is_valid());
+}
+
+#[test]
+fn test_signal_reader_verify_checksum() {
+    let dir = write_record(
+        "rec",
+        "rec 1 250 3\nrec.dat 16 200 12 0 0 6 0 I\n",
+        &[("rec.dat", &format16_bytes(&[1, 2, 3]))],
+    );
+    let record = Record::open(dir.path().join("rec")).unwrap();
+
+    assert_eq!(
+        record.signal_reader(0).unwrap().compute_checksum().unwrap(),
+        6
+    );
+    assert!(record.signal_reader(0).unwrap().verify_checksum().unwrap());
+
+    let mut reader = record.signal_reader(0).unwrap();
+    reader.read_samples(1).unwrap();
+    assert!(!reader.verify_checksum().unwrap());
+}
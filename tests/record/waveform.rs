@@ -0,0 +1,56 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::WaveformFile;
+
+use super::common::{format16_bytes, write_files, write_record};
+
+#[test]
+fn test_waveform_file_single_segment() {
+    let dir = write_record(
+        "rec",
+        "rec 2 100 3\n\
+         rec.dat 16 200/mV 12 0 0 0 0 ECG\n\
+         rec.dat 16 100/mmHg 12 0 0 0 0 ABP\n",
+        &[("rec.dat", &format16_bytes(&[0, 100, 200, -200, -32768, 50]))],
+    );
+
+    let waveform = WaveformFile::load(dir.path().join("rec")).unwrap();
+    assert_eq!(waveform.record_name, "rec");
+    assert_eq!(waveform.num_channels(), 2);
+    assert_eq!(waveform.num_samples(), 3);
+    assert!((waveform.duration() - 0.03).abs() < 1e-12);
+    assert_eq!(waveform.units, vec!["mV", "mmHg"]);
+
+    let ecg = waveform.signal_by_name("ECG").unwrap();
+    assert_eq!(&ecg[..2], &[0.0, 1.0]);
+    assert!(ecg[2].is_nan());
+    assert_eq!(waveform.signal(1).unwrap(), &[1.0, -2.0, 0.5]);
+    assert!(waveform.signal_by_name("PLETH").is_none());
+}
+
+#[test]
+fn test_waveform_file_multi_segment() {
+    let dir = write_record("multi", "multi/3 1 250 5\nseg_a 2\n~ 1\nseg_b 2\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 2\nseg_a.dat 16 200 12 0 0 0 0 II\n",
+        &[("seg_a.dat", &format16_bytes(&[200, 400]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 1 250 2\nseg_b.dat 16 100 12 0 0 0 0 II\n",
+        &[("seg_b.dat", &format16_bytes(&[100, 200]))],
+    );
+
+    let waveform = WaveformFile::load(dir.path().join("multi")).unwrap();
+    assert_eq!(waveform.channel_names, vec![Some("II".to_string())]);
+    assert!((waveform.sampling_frequency - 250.0).abs() < 1e-12);
+
+    let values = waveform.signal(0).unwrap();
+    assert_eq!(values.len(), 5);
+    assert_eq!(&values[..2], &[1.0, 2.0]);
+    assert!(values[2].is_nan());
+    assert_eq!(&values[3..], &[1.0, 2.0]);
+}
@@ -0,0 +1,109 @@
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use wfdb::{Error, Record};
+
+use super::common::{format16_bytes, two_signal_record, write_files, write_record};
+
+/// Two signals of 10 frames at 10 Hz: 0..10 (mV) and 0..-10 (mV).
+fn two_channel_record() -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..10).map(|i| [i * 100, -i * 100]).collect();
+    two_signal_record(10, ["100/mV 12 0 0 0 0 I", "100/mV 12 0 0 0 0 II"], &frames)
+}
+
+#[test]
+fn test_windows_overlapping() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    let windows = record
+        .windows(0.4, 0.2)
+        .unwrap()
+        .collect::<wfdb::Result<Vec<_>>>()
+        .unwrap();
+    let starts: Vec<u64> = windows.iter().map(|w| w.start_sample).collect();
+    assert_eq!(starts, vec![0, 2, 4, 6]);
+
+    let last = &windows[3];
+    assert_eq!(last.start_seconds(), 0.6);
+    assert_eq!(last.signals.num_samples(), 4);
+    assert_eq!(last.signals.sampling_frequency, 10.0);
+    assert_eq!(last.signals.units, vec!["mV", "mV"]);
+    assert_eq!(
+        last.signals.column_by_name("I").unwrap(),
+        &[6.0, 7.0, 8.0, 9.0]
+    );
+    assert_eq!(
+        last.signals.column_by_name("II").unwrap(),
+        &[-6.0, -7.0, -8.0, -9.0]
+    );
+}
+
+#[test]
+fn test_windows_with_gaps_between() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    // Frames 2..5 and 7..10 are skipped; the partial last window is dropped
+    let windows = record
+        .windows(0.2, 0.5)
+        .unwrap()
+        .collect::<wfdb::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[1].start_sample, 5);
+    assert_eq!(windows[1].signals.column(0).unwrap(), &[5.0, 6.0]);
+
+    let windows = record.windows(2.0, 1.0).unwrap();
+    assert_eq!(windows.window_len(), 20);
+    assert_eq!(windows.count(), 0);
+
+    // Windows far longer than the record allocate nothing up front
+    assert_eq!(record.windows(1e15, 1.0).unwrap().count(), 0);
+}
+
+#[test]
+fn test_windows_multi_segment() {
+    let dir = write_record("multi", "multi/3 1 10 7\nseg_a 3\n~ 2\nseg_b 2\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 10 3\nseg_a.dat 16 100/mV 12 0 0 0 0 ECG\n",
+        &[("seg_a.dat", &format16_bytes(&[100, 200, 300]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 1 10 2\nseg_b.dat 16 200/mV 12 0 0 0 0 ECG\n",
+        &[("seg_b.dat", &format16_bytes(&[800, 1000]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+
+    let mut windows = record.windows(0.7, 0.7).unwrap();
+    let window = windows.next().unwrap().unwrap();
+    assert!(windows.next().is_none());
+
+    assert_eq!(window.signals.channel_names, vec![Some("ECG".to_string())]);
+    let values = window.signals.column(0).unwrap();
+    assert_eq!(&values[..3], &[1.0, 2.0, 3.0]);
+    assert!(values[3].is_nan() && values[4].is_nan());
+    assert_eq!(&values[5..], &[4.0, 5.0]);
+}
+
+#[test]
+fn test_windows_invalid_lengths() {
+    let dir = two_channel_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    assert!(matches!(
+        record.windows(0.01, 1.0),
+        Err(Error::InvalidData(_))
+    ));
+    assert!(matches!(
+        record.windows(1.0, 0.0),
+        Err(Error::InvalidData(_))
+    ));
+    assert!(matches!(
+        record.windows(1.0, f64::NAN),
+        Err(Error::InvalidData(_))
+    ));
+}
@@ -0,0 +1,133 @@
+use wfdb::Error;
+use wfdb::record::wire::{Chunk, Encoding, HEADER_SIZE, Payload};
+use wfdb::signal::INVALID_SAMPLE;
+
+fn sample_frames() -> Vec<Vec<i32>> {
+    vec![vec![100, -20], vec![101, -21], vec![103, INVALID_SAMPLE]]
+}
+
+#[test]
+fn test_i16_round_trip() {
+    let chunk = Chunk::from_frames(&sample_frames(), 360.0, 42).unwrap();
+    let bytes = chunk.encode(Encoding::I16).unwrap();
+
+    assert_eq!(&bytes[..4], b"WFCH");
+    assert_eq!(bytes.len(), HEADER_SIZE + 6 * 2);
+
+    let decoded = Chunk::decode(&bytes).unwrap();
+    assert_eq!(decoded, chunk);
+    assert_eq!(decoded.start_frame, 42);
+    assert_eq!(decoded.num_frames(), 3);
+}
+
+#[test]
+fn test_delta_round_trip_full_range() {
+    let frames = vec![
+        vec![i32::MAX, INVALID_SAMPLE, 0],
+        vec![i32::MIN + 1, 5, -1],
+        vec![0, 6, -2],
+    ];
+    let chunk = Chunk::from_frames(&frames, 250.0, 0).unwrap();
+    let bytes = chunk.encode(Encoding::Delta).unwrap();
+    assert_eq!(Chunk::decode(&bytes).unwrap(), chunk);
+}
+
+#[test]
+fn test_delta_is_compact_for_smooth_signals() {
+    let frames: Vec<Vec<i32>> = (0..100).map(|i| vec![1000 + i, -1000 - i]).collect();
+    let chunk = Chunk::from_frames(&frames, 360.0, 0).unwrap();
+
+    let delta = chunk.encode(Encoding::Delta).unwrap();
+    let raw = chunk.encode(Encoding::I16).unwrap();
+    assert!(delta.len() < raw.len());
+}
+
+#[test]
+fn test_f32_round_trip() {
+    let frames = vec![vec![0.5, -1.25], vec![2.0, 3.5]];
+    let chunk = Chunk::from_physical_frames(&frames, 500.0, 7).unwrap();
+    let bytes = chunk.encode(Encoding::F32).unwrap();
+
+    let decoded = Chunk::decode(&bytes).unwrap();
+    assert_eq!(
+        decoded.payload,
+        Payload::Physical(vec![0.5, -1.25, 2.0, 3.5])
+    );
+    assert!((decoded.sampling_frequency - 500.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_encoding_payload_mismatch() {
+    let chunk = Chunk::from_frames(&sample_frames(), 360.0, 0).unwrap();
+    let result = chunk.encode(Encoding::F32);
+    assert!(
+        matches!(result, Err(Error::InvalidData(_))),
+        "Expected InvalidData error, got {result:?}"
+    );
+}
+
+#[test]
+fn test_i16_out_of_range() {
+    let chunk = Chunk::from_frames(&[vec![40_000]], 360.0, 0).unwrap();
+    assert!(chunk.encode(Encoding::I16).is_err());
+}
+
+#[test]
+fn test_encode_rejects_partial_frames() {
+    for (num_channels, values) in [(0, vec![1, 2]), (2, vec![1, 2, 3])] {
+        let chunk = Chunk {
+            sampling_frequency: 360.0,
+            start_frame: 0,
+            num_channels,
+            payload: Payload::Adc(values),
+        };
+        for encoding in [Encoding::I16, Encoding::Delta] {
+            let result = chunk.encode(encoding);
+            assert!(
+                matches!(result, Err(Error::InvalidData(_))),
+                "Expected InvalidData error, got {result:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ragged_frames() {
+    let result = Chunk::from_frames(&[vec![1, 2], vec![3]], 360.0, 0);
+    assert!(matches!(result, Err(Error::InvalidData(_))));
+}
+
+#[test]
+fn test_decode_rejects_malformed() {
+    let chunk = Chunk::from_frames(&sample_frames(), 360.0, 0).unwrap();
+    let bytes = chunk.encode(Encoding::I16).unwrap();
+
+    assert!(Chunk::decode(&bytes[..10]).is_err());
+    assert!(Chunk::decode(&bytes[..bytes.len() - 1]).is_err());
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] = b'X';
+    assert!(Chunk::decode(&bad_magic).is_err());
+
+    let mut bad_version = bytes.clone();
+    bad_version[4] = 99;
+    assert!(Chunk::decode(&bad_version).is_err());
+
+    let mut trailing = bytes;
+    trailing.push(0);
+    assert!(Chunk::decode(&trailing).is_err());
+}
+
+#[test]
+fn test_decode_rejects_oversized_header() {
+    let chunk = Chunk::from_frames(&sample_frames(), 360.0, 0).unwrap();
+    let mut bytes = chunk.encode(Encoding::I16).unwrap();
+    bytes.truncate(HEADER_SIZE);
+    bytes[6..8].copy_from_slice(&u16::MAX.to_le_bytes());
+    bytes[24..28].copy_from_slice(&u32::MAX.to_le_bytes());
+    // I16, Delta and F32 payloads
+    for code in 0..3 {
+        bytes[5] = code;
+        assert!(matches!(Chunk::decode(&bytes), Err(Error::InvalidData(_))));
+    }
+}
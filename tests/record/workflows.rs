@@ -0,0 +1,117 @@
+//! End-to-end workflows across modules, as executable documentation.
+
+#![allow(clippy::unwrap_used)]
+
+use wfdb::Record;
+use wfdb::record::export::{ChannelFormat, per_channel};
+
+use super::common::{format16_bytes, two_signal_record, write_files, write_record};
+
+/// Write a 2-signal record of 8 frames at 4 Hz with 200 ADC units per mV.
+fn ecg_record() -> tempfile::TempDir {
+    let frames: Vec<[i16; 2]> = (0..8).map(|i| [i * 100, -i * 100]).collect();
+    two_signal_record(4, ["200/mV 12 0 0 0 0 I", "200/mV 12 0 0 0 0 II"], &frames)
+}
+
+#[test]
+fn test_workflow_open_and_read_physical_window() {
+    let dir = ecg_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    // Read one second of lead I, starting at 0.5 s
+    let mut reader = record.signal_reader(0).unwrap();
+    assert_eq!(reader.seek_to_time(0.5).unwrap(), 2);
+    let window = reader.read_physical(4).unwrap();
+    assert_eq!(window, vec![1.0, 1.5, 2.0, 2.5]);
+
+    // The same window of every signal, as a matrix
+    let matrix = record.read_physical_frames(2..6).unwrap();
+    assert_eq!(matrix.column_by_name("I").unwrap(), window.as_slice());
+    assert_eq!(
+        matrix.column_by_name("II").unwrap(),
+        &[-1.0, -1.5, -2.0, -2.5]
+    );
+    assert!((matrix.duration() - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_workflow_slice_and_export_csv() {
+    let dir = ecg_record();
+    let record = Record::open(dir.path().join("pair")).unwrap();
+
+    // Export every channel, then check a slice against the exported rows
+    let out = dir.path().join("csv");
+    let manifest = per_channel(&record, &out, ChannelFormat::Csv).unwrap();
+    assert_eq!(manifest.channels[1].num_samples, 8);
+    let exported = std::fs::read_to_string(out.join(&manifest.channels[1].file_name)).unwrap();
+    let rows: Vec<f64> = exported.lines().map(|line| line.parse().unwrap()).collect();
+
+    let mut sliced = Vec::new();
+    record
+        .pipeline()
+        .select(&["II"])
+        .frames(3..7)
+        .physical()
+        .for_each_frame(|frame| sliced.push(frame[0]))
+        .unwrap();
+    assert_eq!(sliced, rows[3..7]);
+}
+
+#[test]
+fn test_workflow_multi_segment_streaming() {
+    let dir = write_record("multi", "multi/3 1 250 5\nseg_a 2\n~ 1\nseg_b 2\n", &[]);
+    write_files(
+        dir.path(),
+        "seg_a",
+        "seg_a 1 250 2\nseg_a.dat 16 100/mV 12 0 0 3 0 I\n",
+        &[("seg_a.dat", &format16_bytes(&[1, 2]))],
+    );
+    write_files(
+        dir.path(),
+        "seg_b",
+        "seg_b 1 250 2\nseg_b.dat 16 200/mV 12 0 0 7 0 I\n",
+        &[("seg_b.dat", &format16_bytes(&[3, 4]))],
+    );
+    let record = Record::open(dir.path().join("multi")).unwrap();
+    assert!(record.validate().unwrap().is_valid());
+
+    // Stream the signal in chunks across segments, scaled per segment
+    let mut reader = record.segment_signal_reader(0).unwrap();
+    let mut values = Vec::new();
+    loop {
+        let chunk = reader.read_physical(2).unwrap();
+        if chunk.is_empty() {
+            break;
+        }
+        values.extend(chunk);
+    }
+    assert_eq!(values.len(), 5);
+    assert_eq!(values[..2], [0.01, 0.02]);
+    assert!(values[2].is_nan());
+    assert_eq!(values[3..], [0.015, 0.02]);
+
+    // Seek back into the first segment
+    reader.seek_to_sample(1).unwrap();
+    assert_eq!(reader.read_samples(1).unwrap(), vec![2]);
+}
+
+#[cfg(feature = "remote")]
+#[test]
+fn test_workflow_remote_open() {
+    use super::remote::{options, serve};
+
+    let (url, _) = serve(vec![
+        (
+            "/db/rec.hea",
+            b"rec 1 250 3\nrec.dat 16 100/mV 12 0 0 6 0 I\n".to_vec(),
+        ),
+        ("/db/rec.dat", format16_bytes(&[1, 2, 3])),
+    ]);
+    let cache = tempfile::tempdir().unwrap();
+
+    let record =
+        Record::open_url_with_options(&format!("{url}/db/rec"), cache.path(), &options()).unwrap();
+    assert!(record.validate().unwrap().is_valid());
+    let matrix = record.read_all_physical().unwrap();
+    assert_eq!(matrix.column(0).unwrap(), &[0.01, 0.02, 0.03]);
+}
@@ -1,3 +1,5 @@
+mod record;
+
 use std::io::Cursor;
 use wfdb::{Header, Record};
 
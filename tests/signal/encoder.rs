@@ -0,0 +1,196 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+use wfdb::SignalFormat;
+use wfdb::signal::{
+    Format8Decoder, Format8Encoder, Format16Encoder, Format24Encoder, Format80Encoder,
+    Format212Encoder, Format310Encoder, Format311Encoder, FormatDecoder, FormatEncoder,
+    INVALID_SAMPLE, get_decoder, get_encoder,
+};
+
+/// Encode samples in one call and finish the encoder.
+fn encode(encoder: &mut dyn FormatEncoder, samples: &[i32]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    encoder.encode(&mut bytes, samples).unwrap();
+    encoder.finish(&mut bytes).unwrap();
+    bytes
+}
+
+/// Decode every sample of a byte stream.
+fn decode(format: SignalFormat, initial_value: i32, bytes: Vec<u8>) -> Vec<i32> {
+    let mut decoder = get_decoder(format, initial_value).unwrap();
+    decoder.decode(&mut Cursor::new(bytes), 1 << 16).unwrap()
+}
+
+#[test]
+fn test_encoders_round_trip() {
+    let cases: &[(SignalFormat, &[i32])] = &[
+        (
+            SignalFormat::Format16,
+            &[0, 1, -1, 32767, -32767, INVALID_SAMPLE],
+        ),
+        (
+            SignalFormat::Format61,
+            &[0, 1, -1, 32767, -32767, INVALID_SAMPLE],
+        ),
+        (
+            SignalFormat::Format24,
+            &[0, -1, 8_388_607, -8_388_607, INVALID_SAMPLE],
+        ),
+        (
+            SignalFormat::Format32,
+            &[0, -1, i32::MAX, i32::MIN + 1, INVALID_SAMPLE],
+        ),
+        (
+            SignalFormat::Format80,
+            &[0, 1, -1, 127, -127, INVALID_SAMPLE],
+        ),
+        (
+            SignalFormat::Format160,
+            &[0, 1, -1, 32767, -32767, INVALID_SAMPLE],
+        ),
+        (
+            SignalFormat::Format212,
+            &[0, -1, 2047, -2047, INVALID_SAMPLE, 5],
+        ),
+        (
+            SignalFormat::Format310,
+            &[0, -1, 511, -511, INVALID_SAMPLE, 3],
+        ),
+        (
+            SignalFormat::Format311,
+            &[0, -1, 511, -511, INVALID_SAMPLE, 3],
+        ),
+    ];
+    for &(format, samples) in cases {
+        let bytes = encode(get_encoder(format, 0).unwrap().as_mut(), samples);
+        assert_eq!(decode(format, 0, bytes), samples, "format {format:?}");
+    }
+}
+
+#[test]
+fn test_encoders_clamp_out_of_range() {
+    let cases = [
+        (SignalFormat::Format16, 32767),
+        (SignalFormat::Format24, 8_388_607),
+        (SignalFormat::Format80, 127),
+        (SignalFormat::Format160, 32767),
+        (SignalFormat::Format212, 2047),
+        (SignalFormat::Format311, 511),
+    ];
+    for (format, max) in cases {
+        let bytes = encode(
+            get_encoder(format, 0).unwrap().as_mut(),
+            &[max + 1, -max - 1, i32::MAX],
+        );
+        assert_eq!(
+            decode(format, 0, bytes),
+            [max, -max, max],
+            "format {format:?}"
+        );
+    }
+}
+
+#[test]
+fn test_encode_across_calls() {
+    // Groups split across calls are buffered until complete
+    let mut encoder = Format212Encoder::new();
+    let mut bytes = Vec::new();
+    encoder.encode(&mut bytes, &[1]).unwrap();
+    assert!(bytes.is_empty());
+    encoder.encode(&mut bytes, &[2, 3]).unwrap();
+    assert_eq!(bytes.len(), 3);
+    encoder.finish(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), 5);
+    assert_eq!(decode(SignalFormat::Format212, 0, bytes), [1, 2, 3]);
+
+    let mut encoder = Format310Encoder::new();
+    let mut bytes = Vec::new();
+    for sample in [10, -20, 30, 40] {
+        encoder.encode(&mut bytes, &[sample]).unwrap();
+    }
+    encoder.finish(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), 6);
+    assert_eq!(decode(SignalFormat::Format310, 0, bytes), [10, -20, 30, 40]);
+}
+
+#[test]
+fn test_encode_partial_group_padding() {
+    // Format 311 cannot end mid-group: the padding decodes as zeros
+    let bytes = encode(&mut Format311Encoder::new(), &[7, 8, 9, 10]);
+    assert_eq!(bytes.len(), 8);
+    assert_eq!(
+        decode(SignalFormat::Format311, 0, bytes),
+        [7, 8, 9, 10, 0, 0]
+    );
+
+    let bytes = encode(&mut Format310Encoder::new(), &[7, 8]);
+    assert_eq!(bytes.len(), 4);
+    assert_eq!(decode(SignalFormat::Format310, 0, bytes), [7, 8, 0]);
+}
+
+#[test]
+fn test_encoder_reset_discards_pending() {
+    let mut encoder = Format212Encoder::new();
+    let mut bytes = Vec::new();
+    encoder.encode(&mut bytes, &[1]).unwrap();
+    encoder.reset();
+    encoder.finish(&mut bytes).unwrap();
+    assert!(bytes.is_empty());
+}
+
+#[test]
+fn test_fixed_size_encoder_bytes() {
+    assert_eq!(
+        encode(&mut Format16Encoder::new(), &[1, -1]),
+        [0x01, 0x00, 0xFF, 0xFF]
+    );
+    assert_eq!(
+        encode(&mut Format24Encoder::new(), &[-2]),
+        [0xFE, 0xFF, 0xFF]
+    );
+    assert_eq!(
+        encode(&mut Format80Encoder::new(), &[0, INVALID_SAMPLE]),
+        [0x80, 0x00]
+    );
+}
+
+#[test]
+fn test_format8_encoder() {
+    let samples = [1000, 1010, 1000, 1127, INVALID_SAMPLE, 1120];
+    let bytes = encode(&mut Format8Encoder::new(1000), &samples);
+    assert_eq!(bytes, [0, 10, 0xF6, 127, 0, 0xF9]);
+    assert_eq!(
+        decode(SignalFormat::Format8, 1000, bytes),
+        [1000, 1010, 1000, 1127, 1127, 1120]
+    );
+
+    // Steps larger than a difference catch up over the following samples
+    let bytes = encode(&mut Format8Encoder::new(0), &[300, 300, 300]);
+    assert_eq!(decode(SignalFormat::Format8, 0, bytes), [127, 254, 300]);
+}
+
+#[test]
+fn test_format8_interleaved_encoder() {
+    // Signal 0 has two samples per frame, signal 1 one
+    let samples = [10, 12, 100, 14, 16, 90];
+    let mut encoder = Format8Encoder::interleaved(&[10, 100], &[2, 1]);
+    let bytes = encode(&mut encoder, &samples);
+    assert_eq!(bytes, [0, 2, 0, 2, 2, 0xF6]);
+
+    let mut decoder = Format8Decoder::interleaved(&[10, 100], &[2, 1]);
+    let all = decoder
+        .decode(&mut Cursor::new(&bytes), samples.len())
+        .unwrap();
+    assert_eq!(all, samples);
+
+    // Rewinding restarts every signal from its initial value
+    decoder.rewind();
+    let first_frame = decoder.decode(&mut Cursor::new(&bytes), 3).unwrap();
+    assert_eq!(first_frame, [10, 12, 100]);
+}
+
+#[test]
+fn test_unsupported_encoder_format() {
+    assert!(get_encoder(SignalFormat::Flac16, 0).is_err());
+}
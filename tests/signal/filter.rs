@@ -0,0 +1,105 @@
+#![allow(clippy::unwrap_used)]
+
+use std::f64::consts::PI;
+
+use wfdb::Error;
+use wfdb::signal::filter::{self, BUTTERWORTH_Q, Biquad, Filter, FilterChain, Fir};
+
+/// Sampling frequency of the test signals (Hz).
+const FS: f64 = 360.0;
+
+/// Generate `n` samples of a sine of `frequency` (Hz) and unit amplitude.
+#[allow(clippy::cast_precision_loss)]
+fn sine(frequency: f64, n: usize) -> Vec<f64> {
+    (0..n)
+        .map(|i| (2.0 * PI * frequency * i as f64 / FS).sin())
+        .collect()
+}
+
+/// Get the peak amplitude of the second half of `values`, after the
+/// transient.
+fn settled_amplitude(values: &[f64]) -> f64 {
+    values[values.len() / 2..]
+        .iter()
+        .fold(0.0, |peak: f64, value| peak.max(value.abs()))
+}
+
+#[test]
+fn test_baseline_wander_removes_offset() {
+    let mut filter = filter::baseline_wander(FS).unwrap();
+    let mut values: Vec<f64> = sine(10.0, 3600).iter().map(|v| v + 5.0).collect();
+    filter.process_slice(&mut values);
+
+    // No transient from the offset, and the 10 Hz component is kept
+    assert!(values[0].abs() < 1e-9);
+    assert!((settled_amplitude(&values) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn test_powerline_notch() {
+    let mut notch = filter::powerline_notch(FS, 60.0).unwrap();
+    let mut mains = sine(60.0, 7200);
+    notch.process_slice(&mut mains);
+    assert!(settled_amplitude(&mains) < 0.01);
+
+    notch.reset();
+    let mut ecg = sine(10.0, 7200);
+    notch.process_slice(&mut ecg);
+    assert!((settled_amplitude(&ecg) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn test_band_pass() {
+    let mut band = filter::band_pass(FS, 0.5, 40.0).unwrap();
+    assert_eq!(band.len(), 2);
+
+    let mut high = sine(150.0, 3600);
+    band.process_slice(&mut high);
+    assert!(settled_amplitude(&high) < 0.1);
+
+    band.reset();
+    let mut pass = sine(10.0, 3600);
+    band.process_slice(&mut pass);
+    assert!((settled_amplitude(&pass) - 1.0).abs() < 0.05);
+
+    assert!(matches!(
+        filter::band_pass(FS, 40.0, 0.5),
+        Err(Error::InvalidData(_))
+    ));
+}
+
+#[test]
+fn test_low_pass_keeps_constant() {
+    let mut low = Biquad::low_pass(FS, 10.0, BUTTERWORTH_Q).unwrap();
+    for _ in 0..10 {
+        assert!((low.process(3.0) - 3.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_nan_passes_through() {
+    let mut chain = FilterChain::new()
+        .then(filter::baseline_wander(FS).unwrap())
+        .then(Fir::moving_average(4).unwrap());
+    let first = chain.process(1.0);
+    assert!(chain.process(f64::NAN).is_nan());
+    assert!((chain.process(1.0) - first).abs() < 1e-9);
+}
+
+#[test]
+fn test_moving_average() {
+    let mut average = Fir::moving_average(3).unwrap();
+    let mut values = vec![3.0, 6.0, 9.0, 3.0];
+    average.process_slice(&mut values);
+    assert_eq!(values, vec![3.0, 4.0, 6.0, 6.0]);
+}
+
+#[test]
+fn test_invalid_parameters() {
+    assert!(Biquad::notch(FS, 180.0, 30.0).is_err());
+    assert!(Biquad::high_pass(FS, 0.0, BUTTERWORTH_Q).is_err());
+    assert!(Biquad::low_pass(FS, 10.0, 0.0).is_err());
+    assert!(Biquad::new([1.0, 0.0, 0.0], [0.0, 0.0, 0.0]).is_err());
+    assert!(Fir::new(Vec::new()).is_err());
+    assert!(filter::powerline_notch(100.0, 60.0).is_err());
+}
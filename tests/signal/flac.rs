@@ -0,0 +1,180 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+use wfdb::signal::{FlacDecoder, FormatDecoder, INVALID_SAMPLE, get_decoder};
+use wfdb::{Record, SignalFormat};
+
+/// CRC-8 of a FLAC frame header (polynomial 0x07).
+fn crc8(bytes: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in bytes {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// CRC-16 of a FLAC frame (polynomial 0x8005).
+fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc = 0u16;
+    for &byte in bytes {
+        crc ^= u16::from(byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Build a FLAC stream of verbatim subframes.
+///
+/// `channels[c]` holds the samples of channel `c`; every block holds at most
+/// `block_size` (< 128) samples per channel.
+fn flac_stream(bits: u32, channels: &[Vec<i32>], block_size: usize) -> Vec<u8> {
+    let num_samples = channels[0].len();
+    let num_channels = u64::try_from(channels.len()).unwrap();
+    let mut bytes = b"fLaC".to_vec();
+
+    // STREAMINFO, the last metadata block
+    bytes.extend_from_slice(&[0x80, 0, 0, 34]);
+    bytes.extend_from_slice(&16u16.to_be_bytes());
+    bytes.extend_from_slice(&4096u16.to_be_bytes());
+    bytes.extend_from_slice(&[0; 6]);
+    let packed: u64 = (250 << 44)
+        | ((num_channels - 1) << 41)
+        | (u64::from(bits - 1) << 36)
+        | u64::try_from(num_samples).unwrap();
+    bytes.extend_from_slice(&packed.to_be_bytes());
+    bytes.extend_from_slice(&[0; 16]);
+
+    let size_code = match bits {
+        8 => 0b001,
+        16 => 0b100,
+        _ => 0b110,
+    };
+    for (number, start) in (0..num_samples).step_by(block_size).enumerate() {
+        let len = block_size.min(num_samples - start);
+        let mut frame = vec![
+            0xFF,
+            0xF8,
+            0x60,
+            u8::try_from((channels.len() - 1) << 4 | size_code << 1).unwrap(),
+            u8::try_from(number).unwrap(),
+            u8::try_from(len - 1).unwrap(),
+        ];
+        frame.push(crc8(&frame));
+        for channel in channels {
+            frame.push(0x02); // Verbatim subframe
+            for &sample in &channel[start..start + len] {
+                let be = sample.to_be_bytes();
+                frame.extend_from_slice(&be[4 - bits as usize / 8..]);
+            }
+        }
+        frame.extend_from_slice(&crc16(&frame).to_be_bytes());
+        bytes.extend_from_slice(&frame);
+    }
+    bytes
+}
+
+#[test]
+fn test_flac_decoder_single_channel() {
+    let samples: Vec<i32> = (0..100).map(|i| i * 300 - 15000).collect();
+    let data = flac_stream(16, std::slice::from_ref(&samples), 32);
+
+    let mut decoder = FlacDecoder::new(16);
+    let output = decoder.decode(&mut Cursor::new(data), 1000).unwrap();
+    assert_eq!(output, samples);
+}
+
+#[test]
+fn test_flac_decoder_interleaves_channels() {
+    let first: Vec<i32> = (0..40).collect();
+    let second: Vec<i32> = (0..40).map(|i| -i).collect();
+    let data = flac_stream(24, &[first, second], 16);
+
+    let mut decoder = FlacDecoder::new(24);
+    let mut reader = Cursor::new(data);
+
+    // Reads may stop anywhere within a block
+    let mut buffer = [0; 5];
+    assert_eq!(decoder.decode_buf(&mut reader, &mut buffer).unwrap(), 5);
+    assert_eq!(buffer, [0, 0, 1, -1, 2]);
+    let rest = decoder.decode(&mut reader, 1000).unwrap();
+    assert_eq!(rest.len(), 75);
+    assert_eq!(&rest[..3], [-2, 3, -3]);
+    assert_eq!(&rest[73..], [39, -39]);
+}
+
+#[test]
+fn test_flac_invalid_sample_marker() {
+    for (format, bits, min) in [
+        (SignalFormat::Flac8, 8, -128),
+        (SignalFormat::Flac16, 16, -32768),
+        (SignalFormat::Flac24, 24, -8_388_608),
+    ] {
+        let data = flac_stream(bits, &[vec![1, min, min + 1]], 16);
+        let mut decoder = get_decoder(format, 0).unwrap();
+        let output = decoder.decode(&mut Cursor::new(data), 10).unwrap();
+        assert_eq!(output, [1, INVALID_SAMPLE, min + 1], "format {format:?}");
+    }
+}
+
+#[test]
+fn test_flac_empty_and_corrupt_streams() {
+    let mut decoder = FlacDecoder::new(16);
+    assert!(
+        decoder
+            .decode(&mut Cursor::new(Vec::new()), 10)
+            .unwrap()
+            .is_empty()
+    );
+
+    let mut decoder = FlacDecoder::new(16);
+    assert!(
+        decoder
+            .decode(&mut Cursor::new(b"not flac".to_vec()), 10)
+            .is_err()
+    );
+
+    // Corrupted frame data fails the frame checksum
+    let mut data = flac_stream(16, &[vec![1, 2, 3]], 16);
+    let last = data.len() - 3;
+    data[last] ^= 0xFF;
+    let mut decoder = FlacDecoder::new(16);
+    assert!(decoder.decode(&mut Cursor::new(data), 10).is_err());
+}
+
+#[test]
+fn test_flac_record() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("rec.hea"),
+        "rec 2 250 50\nrec.dat 516 200 16 0 0 0 0 I\nrec.dat 516 100 16 0 0 0 0 II\n",
+    )
+    .unwrap();
+    let first: Vec<i32> = (0..50).map(|i| i * 10).collect();
+    let second: Vec<i32> = (0..50).map(|i| 1000 - i).collect();
+    std::fs::write(
+        dir.path().join("rec.dat"),
+        flac_stream(16, &[first, second], 20),
+    )
+    .unwrap();
+
+    let record = Record::open(dir.path().join("rec")).unwrap();
+    let mut reader = record.multi_signal_reader().unwrap();
+    let frames = reader.read_frames(100).unwrap();
+    assert_eq!(frames.len(), 50);
+    assert_eq!(frames[0], [0, 1000]);
+    assert_eq!(frames[49], [490, 951]);
+    assert!(reader.seek_to_frame(10).is_err());
+}
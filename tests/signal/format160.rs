@@ -0,0 +1,42 @@
+use std::io::Cursor;
+use wfdb::signal::{Format160Decoder, FormatDecoder, INVALID_SAMPLE};
+
+#[test]
+fn test_format160_decoder() {
+    // Offset binary: stored value minus 32768
+    #[rustfmt::skip]
+    let data: Vec<u8> = vec![
+        0x00, 0x80,  // 32768 -> 0
+        0x01, 0x80,  // 32769 -> 1
+        0xFF, 0x7F,  // 32767 -> -1
+        0xFF, 0xFF,  // 65535 -> 32767
+        0x01, 0x00,  // 1 -> -32767
+        0x00, 0x00,  // 0 -> -32768 (invalid marker)
+    ];
+
+    let mut reader = Cursor::new(data);
+    let mut decoder = Format160Decoder::new();
+
+    let samples = decoder.decode(&mut reader, 6).unwrap();
+    assert_eq!(samples, vec![0, 1, -1, 32767, -32767, INVALID_SAMPLE]);
+}
+
+#[test]
+fn test_format160_decoder_partial() {
+    let data: Vec<u8> = vec![0x00, 0x80, 0x01];
+    let mut reader = Cursor::new(data);
+    let mut decoder = Format160Decoder::new();
+
+    let mut samples = vec![0; 2];
+    let n = decoder.decode_buf(&mut reader, &mut samples).unwrap();
+
+    assert_eq!(n, 1);
+    assert_eq!(samples[0], 0);
+}
+
+#[test]
+fn test_format160_frame_size() {
+    let decoder = Format160Decoder::new();
+    assert_eq!(decoder.bytes_per_sample(), Some(2));
+    assert_eq!(decoder.bytes_per_frame(3), Some(6));
+}
@@ -0,0 +1,41 @@
+use std::io::Cursor;
+use wfdb::signal::{Format61Decoder, FormatDecoder, INVALID_SAMPLE};
+
+#[test]
+fn test_format61_decoder() {
+    // Big-endian two's complement
+    #[rustfmt::skip]
+    let data: Vec<u8> = vec![
+        0x00, 0x01,  // 1
+        0xFF, 0xFF,  // -1
+        0x01, 0x00,  // 256
+        0x7F, 0xFF,  // 32767
+        0x80, 0x00,  // -32768 (invalid marker)
+    ];
+
+    let mut reader = Cursor::new(data);
+    let mut decoder = Format61Decoder::new();
+
+    let samples = decoder.decode(&mut reader, 5).unwrap();
+    assert_eq!(samples, vec![1, -1, 256, 32767, INVALID_SAMPLE]);
+}
+
+#[test]
+fn test_format61_decoder_partial() {
+    let data: Vec<u8> = vec![0x00, 0x01, 0xFF];
+    let mut reader = Cursor::new(data);
+    let mut decoder = Format61Decoder::new();
+
+    let mut samples = vec![0; 2];
+    let n = decoder.decode_buf(&mut reader, &mut samples).unwrap();
+
+    assert_eq!(n, 1);
+    assert_eq!(samples[0], 1);
+}
+
+#[test]
+fn test_format61_frame_size() {
+    let decoder = Format61Decoder::new();
+    assert_eq!(decoder.bytes_per_sample(), Some(2));
+    assert_eq!(decoder.bytes_per_frame(2), Some(4));
+}
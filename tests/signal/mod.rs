@@ -1,7 +1,16 @@
 pub mod common;
+pub mod encoder;
+pub mod filter;
+#[cfg(feature = "flac")]
+pub mod flac;
 pub mod format0;
-pub mod format8;
 pub mod format16;
+pub mod format160;
 pub mod format212;
 pub mod format310;
 pub mod format311;
+pub mod format61;
+pub mod format8;
+pub mod packing;
+pub mod scale;
+pub mod units;
@@ -0,0 +1,177 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+use wfdb::signal::packing::{
+    INVALID_10_BIT, INVALID_212, pack_212, pack_310, pack_311, unpack_212, unpack_310, unpack_311,
+};
+use wfdb::signal::{
+    Format212Decoder, Format310Decoder, Format311Decoder, FormatDecoder, INVALID_SAMPLE,
+};
+
+/// Every valid 12-bit sample, plus the invalid sample.
+fn samples_12_bit() -> impl Iterator<Item = i32> + Clone {
+    (INVALID_212 + 1..=2047).chain([INVALID_SAMPLE])
+}
+
+/// Every valid 10-bit sample, plus the invalid sample.
+fn samples_10_bit() -> impl Iterator<Item = i32> + Clone {
+    (INVALID_10_BIT + 1..=511).chain([INVALID_SAMPLE])
+}
+
+// [Format 212]
+
+#[test]
+fn test_212_known_layout() {
+    // Sample 0 = 0x123, sample 1 = 0x456
+    assert_eq!(pack_212([0x123, 0x456]), [0x23, 0x41, 0x56]);
+    assert_eq!(unpack_212([0x23, 0x41, 0x56]), [0x123, 0x456]);
+    assert_eq!(unpack_212([0xFF, 0xFF, 0xFF]), [-1, -1]);
+    assert_eq!(
+        unpack_212([0x00, 0x88, 0x00]),
+        [INVALID_SAMPLE, INVALID_SAMPLE]
+    );
+}
+
+#[test]
+fn test_212_every_byte_triple_round_trips() {
+    for value in 0..1u32 << 24 {
+        let [b0, b1, b2, _] = value.to_le_bytes();
+        let bytes = [b0, b1, b2];
+        assert_eq!(pack_212(unpack_212(bytes)), bytes, "bytes {bytes:02x?}");
+    }
+}
+
+#[test]
+fn test_212_every_sample_pair_round_trips() {
+    for first in samples_12_bit() {
+        for second in samples_12_bit() {
+            let samples = [first, second];
+            assert_eq!(unpack_212(pack_212(samples)), samples);
+        }
+    }
+}
+
+#[test]
+fn test_212_invalid_marker() {
+    assert_eq!(
+        pack_212([INVALID_SAMPLE, INVALID_212]),
+        pack_212([INVALID_212, INVALID_212])
+    );
+}
+
+// [Format 310]
+
+#[test]
+fn test_310_known_layout() {
+    // Samples 1, 2 and 0x3FF (-1): low 5 bits of the third sample in the
+    // first word, high 5 bits in the second word
+    let bytes = pack_310([1, 2, -1]);
+    assert_eq!(bytes, [0x02, 0xF8, 0x04, 0xF8]);
+    assert_eq!(unpack_310(bytes), [1, 2, -1]);
+}
+
+#[test]
+fn test_310_every_sample_pair_round_trips() {
+    for a in samples_10_bit() {
+        for b in samples_10_bit() {
+            for samples in [[a, b, 0], [a, 0, b], [0, a, b]] {
+                assert_eq!(unpack_310(pack_310(samples)), samples);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_310_ignores_unused_bits() {
+    for value in 0..1u32 << 16 {
+        let [b0, b1, _, _] = value.to_le_bytes();
+        let bytes = [b0, b1, b0 ^ 0x5A, b1];
+        let repacked = pack_310(unpack_310(bytes));
+        assert_eq!(repacked, [b0 & 0xFE, b1, (b0 ^ 0x5A) & 0xFE, b1]);
+    }
+}
+
+// [Format 311]
+
+#[test]
+fn test_311_known_layout() {
+    let bytes = pack_311([1, 2, -1]);
+    assert_eq!(u32::from_le_bytes(bytes), 1 | (2 << 10) | (0x3FF << 20));
+    assert_eq!(unpack_311(bytes), [1, 2, -1]);
+}
+
+#[test]
+fn test_311_every_sample_pair_round_trips() {
+    for a in samples_10_bit() {
+        for b in samples_10_bit() {
+            for samples in [[a, b, 0], [a, 0, b], [0, a, b]] {
+                assert_eq!(unpack_311(pack_311(samples)), samples);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_311_ignores_unused_bits() {
+    let bytes = pack_311([5, -5, INVALID_SAMPLE]);
+    let mut noisy = bytes;
+    noisy[3] |= 0xC0;
+    assert_eq!(unpack_311(noisy), [5, -5, INVALID_SAMPLE]);
+    assert_eq!(pack_311(unpack_311(noisy)), bytes);
+}
+
+// [Decoder agreement]
+
+/// Deterministic pseudo-random bytes.
+fn noise(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_u32;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state.to_le_bytes()[0]
+        })
+        .collect()
+}
+
+fn decode_all(decoder: &mut dyn FormatDecoder, data: Vec<u8>, count: usize) -> Vec<i32> {
+    let mut samples = vec![0; count];
+    let n = decoder
+        .decode_buf(&mut Cursor::new(data), &mut samples)
+        .unwrap();
+    samples.truncate(n);
+    samples
+}
+
+#[test]
+fn test_decoders_match_unpacking() {
+    let data = noise(1200);
+
+    let expected: Vec<i32> = data
+        .chunks_exact(3)
+        .flat_map(|c| unpack_212([c[0], c[1], c[2]]))
+        .collect();
+    assert_eq!(
+        decode_all(&mut Format212Decoder::new(), data.clone(), 800),
+        expected
+    );
+
+    let expected: Vec<i32> = data
+        .chunks_exact(4)
+        .flat_map(|c| unpack_310([c[0], c[1], c[2], c[3]]))
+        .collect();
+    assert_eq!(
+        decode_all(&mut Format310Decoder::new(), data.clone(), 900),
+        expected
+    );
+
+    let expected: Vec<i32> = data
+        .chunks_exact(4)
+        .flat_map(|c| unpack_311([c[0], c[1], c[2], c[3]]))
+        .collect();
+    assert_eq!(
+        decode_all(&mut Format311Decoder::new(), data, 900),
+        expected
+    );
+}
@@ -0,0 +1,80 @@
+use wfdb::SignalInfo;
+use wfdb::signal::{INVALID_SAMPLE, RoundingMode, SignalScale};
+
+#[test]
+fn test_scale_round_trip() {
+    let scale = SignalScale::new(200.0, 1024, "mV");
+    assert!(scale.is_calibrated());
+    assert!((scale.to_physical(1224) - 1.0).abs() < f64::EPSILON);
+    assert!((scale.to_physical(924) + 0.5).abs() < f64::EPSILON);
+    assert_eq!(scale.to_adc(1.0), 1224);
+    assert_eq!(scale.to_adc(-0.5), 924);
+}
+
+#[test]
+fn test_scale_invalid_sample_policy() {
+    let scale = SignalScale::new(200.0, 0, "mV");
+    assert!(scale.to_physical(INVALID_SAMPLE).is_nan());
+    assert_eq!(scale.to_adc(f64::NAN), INVALID_SAMPLE);
+
+    let uncalibrated = SignalScale::new(0.0, 0, "mV");
+    assert!(uncalibrated.to_physical(INVALID_SAMPLE).is_nan());
+}
+
+#[test]
+fn test_scale_uncalibrated() {
+    let scale = SignalScale::new(0.0, 100, "mV");
+    assert!(!scale.is_calibrated());
+    assert!((scale.to_physical(42) - 42.0).abs() < f64::EPSILON);
+    assert_eq!(scale.to_adc(41.6), 42);
+}
+
+#[test]
+fn test_scale_slices() {
+    let scale = SignalScale::new(10.0, 0, "mV");
+
+    let mut physical = [0.0; 3];
+    let n = scale.to_physical_slice(&[10, -20, INVALID_SAMPLE, 40], &mut physical);
+    assert_eq!(n, 3);
+    assert!((physical[0] - 1.0).abs() < f64::EPSILON);
+    assert!((physical[1] + 2.0).abs() < f64::EPSILON);
+    assert!(physical[2].is_nan());
+
+    let mut adc = [0; 4];
+    let n = scale.to_adc_slice(&[1.0, -2.0], &mut adc);
+    assert_eq!(n, 2);
+    assert_eq!(&adc[..2], &[10, -20]);
+}
+
+#[test]
+fn test_scale_from_signal_info() {
+    let signal = SignalInfo::from_signal_line("sig.dat 16 100(5)/uV").unwrap();
+    assert_eq!(signal.scale(), SignalScale::new(100.0, 5, "uV"));
+
+    // Defaults apply when fields are omitted
+    let signal = SignalInfo::from_signal_line("sig.dat 16").unwrap();
+    let scale = signal.scale();
+    assert!((scale.gain - SignalInfo::DEFAULT_ADC_GAIN).abs() < f64::EPSILON);
+    assert_eq!(scale.units, signal.units());
+}
+
+#[test]
+fn test_scale_rounding_modes() {
+    // 0.0125 mV * 200 = 2.5 ADC units, exactly halfway
+    let scale = SignalScale::new(200.0, 0, "mV");
+    assert_eq!(scale.to_adc(0.0125), 3);
+    assert_eq!(scale.to_adc(-0.0125), -3);
+
+    let scale = scale.with_rounding(RoundingMode::HalfEven);
+    assert_eq!(scale.to_adc(0.0125), 2);
+    assert_eq!(scale.to_adc(0.0175), 4);
+    assert_eq!(scale.to_adc(-0.0125), -2);
+
+    let scale = scale.with_rounding(RoundingMode::Truncate);
+    assert_eq!(scale.to_adc(0.0135), 2);
+    assert_eq!(scale.to_adc(-0.0135), -2);
+    assert_eq!(scale.to_adc(f64::NAN), INVALID_SAMPLE);
+
+    let uncalibrated = SignalScale::new(0.0, 0, "mV").with_rounding(RoundingMode::HalfEven);
+    assert_eq!(uncalibrated.to_adc(4.5), 4);
+}
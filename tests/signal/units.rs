@@ -0,0 +1,66 @@
+#![allow(clippy::unwrap_used)]
+
+use wfdb::signal::SignalScale;
+use wfdb::signal::units::{Dimension, Unit, conversion_factor};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-9 * expected.abs(),
+        "{actual} != {expected}"
+    );
+}
+
+#[test]
+fn test_parse_units_and_aliases() {
+    assert_eq!(Unit::parse("mV").unwrap().dimension, Dimension::Voltage);
+    assert_eq!(Unit::parse("\u{b5}V").unwrap().symbol, "uV");
+    assert_eq!(Unit::parse("\u{3bc}V").unwrap().symbol, "uV");
+    assert_eq!(
+        Unit::parse(" mmHg ").unwrap().dimension,
+        Dimension::Pressure
+    );
+    assert_eq!(Unit::parse("bpm").unwrap().dimension, Dimension::Frequency);
+    assert!(Unit::parse("MV").is_none());
+    assert!(Unit::parse("degC").is_none());
+    assert!(Unit::parse("").is_none());
+}
+
+#[test]
+fn test_conversion_factors() {
+    assert_close(conversion_factor("mV", "uV").unwrap(), 1000.0);
+    assert_close(conversion_factor("uV", "mV").unwrap(), 0.001);
+    assert_close(conversion_factor("V", "mV").unwrap(), 1000.0);
+    assert_close(conversion_factor("kPa", "mmHg").unwrap(), 7.500_615_758);
+    assert_close(conversion_factor("cmH2O", "mmHg").unwrap(), 0.735_559_135);
+    assert_close(conversion_factor("Hz", "bpm").unwrap(), 60.0);
+    assert_close(conversion_factor("mV", "mV").unwrap(), 1.0);
+    assert_eq!(conversion_factor("mV", "mmHg"), None);
+    assert_eq!(conversion_factor("mV", "furlong"), None);
+}
+
+#[test]
+fn test_scale_in_units() {
+    let scale = SignalScale::new(200.0, 1024, "mV");
+    let converted = scale.in_units("uV").unwrap();
+    assert_eq!(converted.units, "uV");
+    assert_eq!(converted.baseline, 1024);
+    assert_close(converted.to_physical(1224), 1000.0);
+    assert_eq!(converted.to_adc(-500.0), 924);
+
+    assert_eq!(scale.in_units("mV").unwrap(), scale);
+    assert!(scale.in_units("mmHg").is_none());
+
+    // Uncalibrated values are raw ADC values and cannot be converted
+    assert!(SignalScale::new(0.0, 0, "mV").in_units("uV").is_none());
+}
+
+#[test]
+fn test_scale_normalized() {
+    let scale = SignalScale::new(1.0, 0, "kPa");
+    let normalized = scale.normalized(&["mV", "mmHg"]);
+    assert_eq!(normalized.units, "mmHg");
+    assert_close(normalized.to_physical(1), 7.500_615_758);
+
+    assert_eq!(scale.normalized::<&str>(&[]), scale);
+    assert_eq!(scale.normalized(&["uV"]), scale);
+}